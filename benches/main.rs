@@ -5,9 +5,9 @@ extern crate test;
 use test::{black_box, Bencher};
 
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyString};
+use pyo3::types::{PyBytes, PyDict, PyString};
 
-use _pydantic_core::SchemaValidator;
+use _pydantic_core::{error_table, NativeInput, SchemaValidator};
 
 fn build_schema_validator(py: Python, code: &str) -> SchemaValidator {
     let schema: &PyDict = py.eval(code, None, None).unwrap().extract().unwrap();
@@ -24,11 +24,19 @@ fn ints_json(bench: &mut Bencher) {
     let py = gil.python();
     let validator = build_schema_validator(py, "{'type': 'int'}");
 
-    let result = validator.validate_json(py, json(py, "123"), None, None).unwrap();
+    let result = validator
+        .validate_json(py, json(py, "123"), None, None, None, None)
+        .unwrap();
     let result_int: i64 = result.extract(py).unwrap();
     assert_eq!(result_int, 123);
 
-    bench.iter(|| black_box(validator.validate_json(py, json(py, "123"), None, None).unwrap()))
+    bench.iter(|| {
+        black_box(
+            validator
+                .validate_json(py, json(py, "123"), None, None, None, None)
+                .unwrap(),
+        )
+    })
 }
 
 #[bench]
@@ -47,6 +55,44 @@ fn ints_python(bench: &mut Bencher) {
     bench.iter(|| black_box(validator.validate_python(py, input, None, None).unwrap()))
 }
 
+#[bench]
+fn ints_bytes_rust(bench: &mut Bencher) {
+    let gil = Python::acquire_gil();
+    let py = gil.python();
+    let validator = build_schema_validator(py, "{'type': 'int'}");
+
+    let data: &[u8] = b"123";
+    let result = validator.validate_bytes(py, data, None, None).unwrap();
+    let result_int: i64 = result.extract(py).unwrap();
+    assert_eq!(result_int, 123);
+
+    let data = black_box(data);
+    bench.iter(|| black_box(validator.validate_bytes(py, data, None, None).unwrap()))
+}
+
+#[bench]
+fn ints_bytes_python(bench: &mut Bencher) {
+    let gil = Python::acquire_gil();
+    let py = gil.python();
+    let validator = build_schema_validator(py, "{'type': 'int'}");
+
+    let data: &[u8] = b"123";
+    let result = validator
+        .validate_python(py, PyBytes::new(py, data), None, None)
+        .unwrap();
+    let result_int: i64 = result.extract(py).unwrap();
+    assert_eq!(result_int, 123);
+
+    let data = black_box(data);
+    bench.iter(|| {
+        black_box(
+            validator
+                .validate_python(py, PyBytes::new(py, data), None, None)
+                .unwrap(),
+        )
+    })
+}
+
 #[bench]
 fn list_int_json(bench: &mut Bencher) {
     let gil = Python::acquire_gil();
@@ -57,7 +103,13 @@ fn list_int_json(bench: &mut Bencher) {
         (0..100).map(|x| x.to_string()).collect::<Vec<String>>().join(",")
     );
 
-    bench.iter(|| black_box(validator.validate_json(py, json(py, &code), None, None).unwrap()))
+    bench.iter(|| {
+        black_box(
+            validator
+                .validate_json(py, json(py, &code), None, None, None, None)
+                .unwrap(),
+        )
+    })
 }
 
 fn list_int_input(py: Python<'_>) -> (SchemaValidator, PyObject) {
@@ -111,7 +163,7 @@ fn list_error_json(bench: &mut Bencher) {
             .join(", ")
     );
 
-    match validator.validate_json(py, json(py, &code), None, None) {
+    match validator.validate_json(py, json(py, &code), None, None, None, None) {
         Ok(_) => panic!("unexpectedly valid"),
         Err(e) => {
             let v = e.value(py);
@@ -122,10 +174,12 @@ fn list_error_json(bench: &mut Bencher) {
         }
     };
 
-    bench.iter(|| match validator.validate_json(py, json(py, &code), None, None) {
-        Ok(_) => panic!("unexpectedly valid"),
-        Err(e) => black_box(e),
-    })
+    bench.iter(
+        || match validator.validate_json(py, json(py, &code), None, None, None, None) {
+            Ok(_) => panic!("unexpectedly valid"),
+            Err(e) => black_box(e),
+        },
+    )
 }
 
 fn list_error_python_input(py: Python<'_>) -> (SchemaValidator, PyObject) {
@@ -196,7 +250,13 @@ fn list_any_json(bench: &mut Bencher) {
         (0..100).map(|x| x.to_string()).collect::<Vec<String>>().join(",")
     );
 
-    bench.iter(|| black_box(validator.validate_json(py, json(py, &code), None, None).unwrap()))
+    bench.iter(|| {
+        black_box(
+            validator
+                .validate_json(py, json(py, &code), None, None, None, None)
+                .unwrap(),
+        )
+    })
 }
 
 #[bench]
@@ -238,7 +298,13 @@ fn dict_json(bench: &mut Bencher) {
             .join(", ")
     );
 
-    bench.iter(|| black_box(validator.validate_json(py, json(py, &code), None, None).unwrap()))
+    bench.iter(|| {
+        black_box(
+            validator
+                .validate_json(py, json(py, &code), None, None, None, None)
+                .unwrap(),
+        )
+    })
 }
 
 #[bench]
@@ -336,7 +402,13 @@ fn typed_dict_json(bench: &mut Bencher) {
 
     let code = r#"{"a": 1, "b": 2, "c": 3, "d": 4, "e": 5, "f": 6, "g": 7, "h": 8, "i": 9, "j": 0}"#.to_string();
 
-    bench.iter(|| black_box(validator.validate_json(py, json(py, &code), None, None).unwrap()))
+    bench.iter(|| {
+        black_box(
+            validator
+                .validate_json(py, json(py, &code), None, None, None, None)
+                .unwrap(),
+        )
+    })
 }
 
 #[bench]
@@ -445,3 +517,262 @@ fn complete_model(bench: &mut Bencher) {
         black_box(validator.validate_python(py, input, None, None).unwrap());
     })
 }
+
+#[bench]
+fn list_int_native(bench: &mut Bencher) {
+    let gil = Python::acquire_gil();
+    let py = gil.python();
+    let validator = build_schema_validator(py, "{'type': 'list', 'items_schema': 'int'}");
+
+    // built in rust, no python object is converted
+    let input = NativeInput::List((0..100).map(|i| NativeInput::Str(i.to_string())).collect());
+    let result = validator.validate_native_input(py, &input, None, None).unwrap();
+    let result_ints: Vec<i64> = result.extract(py).unwrap();
+    assert_eq!(result_ints, (0..100).collect::<Vec<i64>>());
+
+    bench.iter(|| {
+        black_box(
+            validator
+                .validate_native_input(py, black_box(&input), None, None)
+                .unwrap(),
+        )
+    })
+}
+
+#[test]
+fn native_input_keys() {
+    let gil = Python::acquire_gil();
+    let py = gil.python();
+
+    // python dicts can't have list keys
+    let input = NativeInput::Dict(vec![(NativeInput::List(vec![]), NativeInput::Int(1))]);
+    let validator = build_schema_validator(py, "{'type': 'dict'}");
+    let err = validator.validate_native_input(py, &input, None, None).unwrap_err();
+    assert_eq!(err.to_string(), "TypeError: unhashable type: 'list'");
+    assert!(input.try_to_object(py).is_err());
+
+    // `2**53 + 1` and `2.0**53` are different keys, `1` and `1.0` are the same
+    let validator = build_schema_validator(py, "{'type': 'dict', 'key_transform': 'lower'}");
+    let input = NativeInput::Dict(vec![
+        (NativeInput::Int(2_i64.pow(53) + 1), NativeInput::Int(1)),
+        (NativeInput::Float(2_f64.powi(53)), NativeInput::Int(2)),
+    ]);
+    let result = validator.validate_native_input(py, &input, None, None).unwrap();
+    assert_eq!(result.as_ref(py).len().unwrap(), 2);
+    let input = NativeInput::Dict(vec![
+        (NativeInput::Int(1), NativeInput::Int(1)),
+        (NativeInput::Float(1.0), NativeInput::Int(2)),
+    ]);
+    let err = validator.validate_native_input(py, &input, None, None).unwrap_err();
+    assert!(err.to_string().contains("dict_duplicate_key"), "{}", err);
+
+    // ints beyond `i64` are too large for `int`, as with `validate_python`
+    let validator = build_schema_validator(py, "{'type': 'int'}");
+    let big_int = py.eval("2**64", None, None).unwrap();
+    let err = validator.validate_native(py, big_int, None, None).unwrap_err();
+    assert!(err.to_string().contains("int_too_large"), "{}", err);
+}
+
+fn table_rows(rows: &[[&str; 4]]) -> Vec<[String; 4]> {
+    rows.iter().map(|row| row.map(String::from)).collect()
+}
+
+#[test]
+fn error_table_plain() {
+    let rows = table_rows(&[
+        ["name", "string_type", "Input should be a valid string", "1"],
+        [
+            "friends -> 0 -> id",
+            "int_parsing",
+            "Input should be a valid integer, unable to parse string as an integer",
+            "'x'",
+        ],
+        ["", "missing", "Field required", ""],
+    ]);
+    insta::assert_snapshot!(error_table(&rows, false));
+}
+
+#[test]
+fn error_table_color() {
+    let rows = table_rows(&[["", "int_parsing", "Input should be a valid integer", "'üüü'"]]);
+    insta::assert_snapshot!(error_table(&rows, true));
+}
+
+#[test]
+fn error_table_empty() {
+    insta::assert_snapshot!(error_table(&[], false));
+}
+
+fn bytes_err(validator: &SchemaValidator, py: Python, data: &[u8], strict: Option<bool>) -> String {
+    validator
+        .validate_bytes(py, data, strict, None)
+        .unwrap_err()
+        .to_string()
+}
+
+#[test]
+fn bytes_input_str() {
+    let gil = Python::acquire_gil();
+    let py = gil.python();
+    let validator = build_schema_validator(py, "{'type': 'str'}");
+
+    let result = validator.validate_bytes(py, "héllo".as_bytes(), None, None).unwrap();
+    assert_eq!(result.extract::<String>(py).unwrap(), "héllo");
+
+    // invalid UTF-8 is reported with the raw bytes as the input
+    let err = bytes_err(&validator, py, b"\xff", None);
+    assert!(err.contains("str_unicode"), "{}", err);
+    assert!(err.contains(r"b'\xff'"), "{}", err);
+
+    let err = bytes_err(&validator, py, b"foo", Some(true));
+    assert!(err.contains("str_type"), "{}", err);
+}
+
+#[test]
+fn bytes_input_bytes() {
+    let gil = Python::acquire_gil();
+    let py = gil.python();
+    let validator = build_schema_validator(py, "{'type': 'bytes'}");
+
+    // bytes needn't be valid UTF-8, in lax or strict mode
+    for strict in [None, Some(true)] {
+        let result = validator.validate_bytes(py, b"\xff", strict, None).unwrap();
+        assert_eq!(result.extract::<Vec<u8>>(py).unwrap(), b"\xff");
+    }
+}
+
+#[test]
+fn bytes_input_bool() {
+    let gil = Python::acquire_gil();
+    let py = gil.python();
+    let validator = build_schema_validator(py, "{'type': 'bool'}");
+
+    let result = validator.validate_bytes(py, b"true", None, None).unwrap();
+    assert!(result.extract::<bool>(py).unwrap());
+    let result = validator.validate_bytes(py, b"0", None, None).unwrap();
+    assert!(!result.extract::<bool>(py).unwrap());
+
+    let err = bytes_err(&validator, py, b"maybe", None);
+    assert!(err.contains("bool_parsing"), "{}", err);
+    // invalid UTF-8 is a parsing error, not a unicode error
+    let err = bytes_err(&validator, py, b"\xff", None);
+    assert!(err.contains("bool_parsing"), "{}", err);
+    let err = bytes_err(&validator, py, b"true", Some(true));
+    assert!(err.contains("bool_type"), "{}", err);
+}
+
+#[test]
+fn bytes_input_int() {
+    let gil = Python::acquire_gil();
+    let py = gil.python();
+    let validator = build_schema_validator(py, "{'type': 'int'}");
+
+    let result = validator.validate_bytes(py, b"-123", None, None).unwrap();
+    assert_eq!(result.extract::<i64>(py).unwrap(), -123);
+
+    let err = bytes_err(&validator, py, b"x", None);
+    assert!(err.contains("int_parsing"), "{}", err);
+    let err = bytes_err(&validator, py, b"\xff", None);
+    assert!(err.contains("int_parsing"), "{}", err);
+    let err = bytes_err(&validator, py, b"123", Some(true));
+    assert!(err.contains("int_type"), "{}", err);
+}
+
+#[test]
+fn bytes_input_float() {
+    let gil = Python::acquire_gil();
+    let py = gil.python();
+    let validator = build_schema_validator(py, "{'type': 'float'}");
+
+    let result = validator.validate_bytes(py, b"1.5", None, None).unwrap();
+    assert_eq!(result.extract::<f64>(py).unwrap(), 1.5);
+    let result = validator.validate_bytes(py, b"2", None, None).unwrap();
+    assert_eq!(result.extract::<f64>(py).unwrap(), 2.0);
+
+    let err = bytes_err(&validator, py, b"x", None);
+    assert!(err.contains("float_parsing"), "{}", err);
+    let err = bytes_err(&validator, py, b"\xff", None);
+    assert!(err.contains("float_parsing"), "{}", err);
+    let err = bytes_err(&validator, py, b"1.5", Some(true));
+    assert!(err.contains("float_type"), "{}", err);
+}
+
+#[test]
+fn bytes_input_datetimes() {
+    let gil = Python::acquire_gil();
+    let py = gil.python();
+
+    let cases: [(&str, &[u8], &str, &str, &str); 4] = [
+        (
+            "date",
+            b"2022-06-08",
+            "2022-06-08",
+            "date_from_datetime_parsing",
+            "date_type",
+        ),
+        ("time", b"12:13:14", "12:13:14", "time_parsing", "time_type"),
+        (
+            "datetime",
+            b"2022-06-08T12:13:14",
+            "2022-06-08 12:13:14",
+            "datetime_parsing",
+            "datetime_type",
+        ),
+        (
+            "timedelta",
+            b"P1DT1H",
+            "1 day, 1:00:00",
+            "time_delta_parsing",
+            "time_delta_type",
+        ),
+    ];
+    for (schema_type, data, expected, lax_error, strict_error) in cases {
+        let validator = build_schema_validator(py, &format!("{{'type': '{}'}}", schema_type));
+        let result = validator.validate_bytes(py, data, None, None).unwrap();
+        assert_eq!(result.as_ref(py).str().unwrap().to_str().unwrap(), expected);
+
+        let err = bytes_err(&validator, py, b"foobar", None);
+        assert!(err.contains(lax_error), "{}", err);
+        let err = bytes_err(&validator, py, data, Some(true));
+        assert!(err.contains(strict_error), "{}", err);
+    }
+}
+
+#[test]
+fn bytes_input_other_types() {
+    let gil = Python::acquire_gil();
+    let py = gil.python();
+
+    let cases = [
+        ("{'type': 'list', 'items_schema': {'type': 'int'}}", "list_type"),
+        ("{'type': 'tuple', 'items_schema': {'type': 'int'}}", "tuple_type"),
+        ("{'type': 'set', 'items_schema': {'type': 'int'}}", "set_type"),
+        (
+            "{'type': 'frozenset', 'items_schema': {'type': 'int'}}",
+            "frozen_set_type",
+        ),
+        ("{'type': 'dict'}", "dict_type"),
+        ("{'type': 'arguments', 'arguments_schema': []}", "arguments_type"),
+    ];
+    for (schema, error_type) in cases {
+        let validator = build_schema_validator(py, schema);
+        for strict in [None, Some(true)] {
+            let err = bytes_err(&validator, py, b"[1, 2]", strict);
+            assert!(err.contains(error_type), "{}: {}", schema, err);
+        }
+    }
+}
+
+#[test]
+fn bytes_input_error_location() {
+    let gil = Python::acquire_gil();
+    let py = gil.python();
+    let validator = build_schema_validator(py, "{'type': 'int'}");
+
+    // the error has no location and shows the input as python bytes
+    let err = bytes_err(&validator, py, b"x\xff", None);
+    let lines: Vec<&str> = err.lines().collect();
+    assert_eq!(lines[0], "ValidationError: 1 validation error for int");
+    assert!(lines[3].starts_with("          int_parsing"), "{}", err);
+    assert!(lines[3].ends_with(r"b'x\xff'"), "{}", err);
+}
@@ -9,7 +9,7 @@ import re
 from collections.abc import Callable
 from datetime import date, datetime, time, timedelta
 from pathlib import Path
-from typing import Any, Dict, ForwardRef, List, Type, Union
+from typing import Any, Dict, ForwardRef, List, Tuple, Type, Union
 
 from black import Mode, TargetVersion, format_file_contents
 from typing_extensions import get_args, is_typeddict
@@ -65,6 +65,11 @@ def get_schema(obj):
             'keys_schema': get_schema(obj.__args__[0]),
             'values_schema': get_schema(obj.__args__[1]),
         }
+    elif issubclass(origin, Tuple):
+        if len(obj.__args__) == 2 and obj.__args__[1] is Ellipsis:
+            return {'type': 'tuple', 'mode': 'variable', 'items_schema': get_schema(obj.__args__[0])}
+        else:
+            return {'type': 'tuple', 'mode': 'positional', 'items_schema': [get_schema(a) for a in obj.__args__]}
     elif issubclass(origin, Type):
         # can't really use 'is-instance' since this is used for the class_ parameter of
         # 'is-instance' validators
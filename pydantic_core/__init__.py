@@ -1,4 +1,44 @@
-from ._pydantic_core import PydanticValueError, SchemaError, SchemaValidator, ValidationError, __version__
+from ._pydantic_core import (
+    PydanticUndefined,
+    PydanticUndefinedType,
+    PydanticValueError,
+    SchemaError,
+    SchemaSerializer,
+    SchemaValidator,
+    SecretBytes,
+    SecretStr,
+    Url,
+    ValidationError,
+    __version__,
+    clear_schema_cache,
+    custom_error,
+    error_kind_description,
+    list_all_errors,
+    schema_cache_info,
+    validate_many,
+    validate_native,
+)
 from ._types import Config, Schema
 
-__all__ = '__version__', 'Config', 'Schema', 'SchemaValidator', 'SchemaError', 'ValidationError', 'PydanticValueError'
+__all__ = (
+    '__version__',
+    'Config',
+    'Schema',
+    'SchemaValidator',
+    'SchemaSerializer',
+    'SchemaError',
+    'ValidationError',
+    'PydanticValueError',
+    'PydanticUndefined',
+    'PydanticUndefinedType',
+    'Url',
+    'SecretStr',
+    'SecretBytes',
+    'clear_schema_cache',
+    'custom_error',
+    'error_kind_description',
+    'list_all_errors',
+    'schema_cache_info',
+    'validate_many',
+    'validate_native',
+)
@@ -1,5 +1,5 @@
 import sys
-from typing import Any, TypedDict
+from typing import Any, Awaitable, Callable, Iterable, Iterator, TypedDict
 
 from pydantic_core._types import Config, Schema
 
@@ -8,36 +8,127 @@ if sys.version_info < (3, 11):
 else:
     from typing import NotRequired
 
-__all__ = '__version__', 'SchemaValidator', 'SchemaError', 'ValidationError', 'PydanticValueError'
+__all__ = (
+    '__version__',
+    'SchemaValidator',
+    'SchemaError',
+    'ValidationError',
+    'PydanticValueError',
+    'PydanticUndefined',
+    'PydanticUndefinedType',
+    'Url',
+    'SecretStr',
+    'SecretBytes',
+    'clear_schema_cache',
+    'custom_error',
+    'error_kind_description',
+    'list_all_errors',
+    'schema_cache_info',
+    'validate_many',
+    'validate_native',
+)
 __version__: str
 
 class SchemaValidator:
     def __init__(self, schema: Schema, config: 'Config | None' = None) -> None: ...
+    def __deepcopy__(self, memo: 'dict[int, Any]') -> 'SchemaValidator': ...
+    def __getstate__(self) -> 'tuple[Schema, Config | None]': ...
+    def __setstate__(self, state: 'tuple[Schema, Config | None]') -> None: ...
     def validate_python(self, input: Any, strict: 'bool | None' = None, context: Any = None) -> Any: ...
+    def validate_python_async(
+        self, input: Any, strict: 'bool | None' = None, context: Any = None
+    ) -> 'Awaitable[Any]': ...
+    def iterate_validation_errors(
+        self, input: Any, strict: 'bool | None' = None, context: Any = None
+    ) -> 'Iterator[ErrorDetails]': ...
+    def validate_many(
+        self, inputs: 'Iterable[Any]', strict: 'bool | None' = None, context: Any = None
+    ) -> 'tuple[list[Any], list[list[ErrorDetails] | None]]': ...
+    def validate_native(self, input: Any, strict: 'bool | None' = None, context: Any = None) -> Any: ...
+    def validate_python_streaming(
+        self,
+        input: Any,
+        item_callback: 'Callable[[Any, Any], None]',
+        consume: bool = False,
+        strict: 'bool | None' = None,
+        context: Any = None,
+    ) -> Any: ...
+    def validate_python_with_warnings(
+        self, input: Any, strict: 'bool | None' = None, context: Any = None
+    ) -> 'tuple[Any, list[ErrorDetails]]': ...
+    def validate_debug(
+        self, input: Any, strict: 'bool | None' = None, context: Any = None
+    ) -> 'tuple[Any, list[TraceEntry]]': ...
+    # only available when built with the `profiling` feature
+    def statistics(self) -> 'list[ValidatorStatistics]': ...
+    def validate_strings(self, input: Any, context: Any = None) -> Any: ...
     def isinstance_python(self, input: Any, strict: 'bool | None' = None, context: Any = None) -> bool: ...
     def validate_json(
-        self, input: 'str | bytes | bytearray', strict: 'bool | None' = None, context: Any = None
+        self,
+        input: 'str | bytes | bytearray',
+        strict: 'bool | None' = None,
+        context: Any = None,
+        source_position: bool = False,
+        allow_partial: bool = False,
     ) -> Any: ...
     def isinstance_json(
         self, input: 'str | bytes | bytearray', strict: 'bool | None' = None, context: Any = None
     ) -> bool: ...
-    def validate_assignment(self, field: str, input: Any, data: 'dict[str, Any]') -> 'dict[str, Any]': ...
+    def validate_msgpack(
+        self, input: 'bytes | bytearray', strict: 'bool | None' = None, context: Any = None
+    ) -> Any: ...
+    def validate_assignment(
+        self, obj: Any, field: str, input: Any, strict: 'bool | None' = None, context: Any = None
+    ) -> Any: ...
+    def known_fields(self) -> 'list[str] | None': ...
+    def json_schema(self) -> 'dict[str, Any]': ...
+    def schema_repr(self) -> 'dict[str, Any]': ...
+    def metadata(self) -> 'dict[str, Any]': ...
+
+class SchemaSerializer:
+    def __init__(self, schema: Schema, config: 'Config | None' = None) -> None: ...
+    def to_python(self, value: Any) -> Any: ...
+    def to_jsonable_python(self, value: Any) -> Any: ...
+    def to_json(self, value: Any, indent: 'int | None' = None) -> bytes: ...
+
+class SerializationInfo:
+    @property
+    def mode(self) -> str: ...
 
 class SchemaError(Exception):
-    pass
+    def errors(self) -> 'list[SchemaErrorDetails]': ...
+
+class SchemaErrorDetails(TypedDict):
+    loc: 'list[int | str]'
+    message: str
+    kind: NotRequired[str]
+    input_value: NotRequired[Any]
+    context: NotRequired['dict[str, Any]']
 
 class ErrorDetails(TypedDict):
     kind: str
     loc: 'list[int | str]'
     message: str
-    input_value: Any
+    # the input which failed validation, or its repr truncated to 200 characters if that's longer,
+    # omitted with `hide_input_in_errors` and for sensitive fields
+    input_value: NotRequired[Any]
     context: NotRequired['dict[str, Any]']
+    url: NotRequired[str]
+
+class TraceEntry(TypedDict):
+    loc: 'list[int | str]'
+    validator: str
+    decision: str
 
 class ValidationError(ValueError):
     title: str
 
     def error_count(self) -> int: ...
-    def errors(self) -> 'list[ErrorDetails]': ...
+    def errors(self, include_url: bool = True, messages: 'dict[str, str] | None' = None) -> 'list[ErrorDetails]': ...
+    def errors_by_kind(
+        self, include_url: bool = True, messages: 'dict[str, str] | None' = None
+    ) -> 'dict[str, list[ErrorDetails]]': ...
+    def pretty(self, color: 'bool | None' = None) -> str: ...
 
 class PydanticValueError(ValueError):
     kind: str
@@ -46,3 +137,63 @@ class PydanticValueError(ValueError):
 
     def __init__(self, kind: str, message_template: str, context: 'dict[str, str | int] | None' = None) -> None: ...
     def message(self) -> str: ...
+
+class PydanticUndefinedType:
+    def __copy__(self) -> 'PydanticUndefinedType': ...
+    def __deepcopy__(self, memo: Any) -> 'PydanticUndefinedType': ...
+
+PydanticUndefined: PydanticUndefinedType
+
+class Url:
+    scheme: str
+    username: 'str | None'
+    password: 'str | None'
+    host: 'str | None'
+    port: 'int | None'
+    path: str
+    query: 'str | None'
+    fragment: 'str | None'
+
+class SecretStr:
+    def get_secret_value(self) -> str: ...
+    def __len__(self) -> int: ...
+
+class SecretBytes:
+    def get_secret_value(self) -> bytes: ...
+    def __len__(self) -> int: ...
+
+class ValidatorStatistics(TypedDict):
+    name: str
+    calls: int
+    errors: int
+    time: float
+
+class SchemaCacheInfo(TypedDict):
+    hits: int
+    misses: int
+    size: int
+    max_size: int
+
+def custom_error(
+    kind: str, message_template: str, context: 'dict[str, str | int] | None' = None
+) -> PydanticValueError: ...
+def error_kind_description(kind: str) -> str: ...
+
+class ErrorKindInfo(TypedDict):
+    kind: str
+    message_template: str
+    description: str
+
+def list_all_errors() -> 'list[ErrorKindInfo]': ...
+def clear_schema_cache() -> None: ...
+def schema_cache_info() -> SchemaCacheInfo: ...
+def validate_many(
+    schema: Schema,
+    inputs: 'Iterable[Any]',
+    config: 'Config | None' = None,
+    strict: 'bool | None' = None,
+    context: Any = None,
+) -> 'tuple[list[Any], list[list[ErrorDetails] | None]]': ...
+def validate_native(
+    schema: Schema, input: Any, config: 'Config | None' = None, strict: 'bool | None' = None, context: Any = None
+) -> Any: ...
@@ -2,7 +2,7 @@ from __future__ import annotations
 
 import sys
 from datetime import date, datetime, time, timedelta
-from typing import Any, Callable, Dict, List, Optional, Type, Union
+from typing import Any, Callable, Dict, List, Optional, Tuple, Type, Union
 
 if sys.version_info < (3, 11):
     from typing_extensions import NotRequired, Required
@@ -15,14 +15,70 @@ else:
     from typing import Literal, TypedDict
 
 
+# the type returned by a serialization function, in JSON mode values of the given type are converted without checking
+# what other type they might be, 'any' (the default) means the output is serialized based on its type
+ReturnType = Literal['any', 'none', 'int', 'bool', 'float', 'str', 'bytes', 'datetime', 'date', 'time', 'timedelta']
+
+
+class PlainSerializerSchema(TypedDict):
+    type: Literal['function-plain']
+    # called as `function(value, info)`, the return value is used as the serialized value
+    function: Callable[..., Any]
+    return_type: NotRequired[ReturnType]
+
+
+class WrapSerializerSchema(TypedDict):
+    type: Literal['function-wrap']
+    # called as `function(value, serializer, info)` where `serializer(value)` serializes the value according to the
+    # schema, the return value is used as the serialized value
+    function: Callable[..., Any]
+    return_type: NotRequired[ReturnType]
+
+
+# set as `serialization` on a schema to customise how `SchemaSerializer` serializes values of that schema,
+# it has no effect on validation
+SerSchema = Union[PlainSerializerSchema, WrapSerializerSchema]
+
+
 class AnySchema(TypedDict):
     type: Literal['any']
+    serialization: NotRequired[SerSchema]
+
 
+class SchemaMetadata(TypedDict, total=False):
+    # documentation only, doesn't affect validation, included in `SchemaValidator.json_schema()`
+    title: str
+    description: str
+    examples: List[Any]
 
-class BoolSchema(TypedDict, total=False):
+
+class BoolSchema(SchemaMetadata, total=False):
     type: Required[Literal['bool']]
     strict: bool
+    # strings accepted in lax mode, compared case-insensitively,
+    # default: ['1', 't', 'y', 'on', 'yes', 'true'] and ['0', 'f', 'n', 'no', 'off', 'false'], the two can't overlap
+    true_values: List[str]
+    false_values: List[str]
+    ref: str
+    serialization: SerSchema
+
+
+class StrictBoolSchema(SchemaMetadata, total=False):
+    type: Required[Literal['strict-bool']]
+    # validated as with `BoolSchema` with `strict: True`, config can't make it lax
+    true_values: List[str]
+    false_values: List[str]
     ref: str
+    serialization: SerSchema
+
+
+class LenientCoercionConfig(TypedDict, total=False):
+    # which coercions are allowed in lax mode, defaults match lax mode without this config
+    coerce_numbers_to_str: bool  # default: False
+    coerce_str_to_int: bool  # default: True
+    coerce_bool_to_int: bool  # default: True
+    coerce_str_to_bool: bool  # default: True
+    coerce_numbers_to_bool: bool  # default: True
 
 
 class Config(TypedDict, total=False):
@@ -31,41 +87,130 @@ class Config(TypedDict, total=False):
     config_choose_priority: int
     # if configs are merged, which should take precedence, default 0, default means child takes precedence
     config_merge_priority: int
+    # how deeply recursive schemas may be nested before a recursion error is raised, default 100
+    max_depth: int
+    # omit input values from errors, they're always omitted for fields named like 'password', 'secret' or 'token'
+    hide_input_in_errors: bool
+    # guard against huge untrusted inputs, dicts and lists with more items are rejected before any item is validated,
+    # this is checked before, so takes precedence over, `min_items` and `max_items` on the schema
+    max_input_length: int
+    # fine grained control of lax mode coercions for int, str and bool validators
+    lenient_coercion: LenientCoercionConfig
+    # default for `validate_default` on typed-dict fields and arguments parameters
+    validate_default: bool
     # settings related to typed_dicts only
     typed_dict_extra_behavior: Literal['allow', 'forbid', 'ignore']
     typed_dict_total: bool  # default: True
     # used on typed-dicts and tagged union keys
     from_attributes: bool
-    revalidate_models: bool
+    # `True` and `False` are the same as 'always' and 'never'
+    revalidate_models: Union[bool, Literal['never', 'always', 'subclass-instances']]  # default: 'never'
     # used on typed-dicts and arguments
     populate_by_name: bool  # replaces `allow_population_by_field_name` in pydantic v1
     # fields related to string fields only
+    str_pattern: str
     str_max_length: int
     str_min_length: int
     str_strip_whitespace: bool
     str_to_lower: bool
     str_to_upper: bool
+    str_bytes_encoding: str
+    # how errors from each choice of a union are combined, default: 'all'
+    union_error_mode: Literal['all', 'best', 'deduplicated']
+    # fields related to float fields only
+    allow_inf_nan: bool  # default: False
+    # how `SchemaSerializer` serializes timedeltas in JSON, default: 'iso8601'
+    ser_json_timedelta: Literal['iso8601', 'float']
 
 
-class DictSchema(TypedDict, total=False):
+class DictSchema(SchemaMetadata, total=False):
     type: Required[Literal['dict']]
     keys_schema: Schema  # default: AnySchema
     values_schema: Schema  # default: AnySchema
     min_items: int
     max_items: int
+    # maximum length of keys which are strings after validation
+    key_max_length: int
+    # applied to string keys before validation, keys which transform to the same string are dict_duplicate_key errors
+    key_transform: Literal['lower', 'upper', 'snake', 'camel']
+    # skip validating the value when its key is invalid, so only the key's errors are reported, default: False
+    fail_fast_per_entry: bool
+    # in lax mode, parse str inputs as JSON and validate the object they contain, default: False
+    coerce_json_string: bool
+    # keys which must be in the output after key validation, each missing key is a `missing` error
+    required_keys: List[str]
     strict: bool
     ref: str
+    serialization: SerSchema
 
 
-class FloatSchema(TypedDict, total=False):
+class MappingSchema(SchemaMetadata, total=False):
+    type: Required[Literal['mapping']]
+    keys_schema: Schema  # default: AnySchema
+    values_schema: Schema  # default: AnySchema
+    # called with the validated dict to create the output, e.g. `OrderedDict`, default: dict
+    target_class: Type[Any]
+    min_items: int
+    max_items: int
+    key_max_length: int
+    key_transform: Literal['lower', 'upper', 'snake', 'camel']
+    fail_fast_per_entry: bool
+    coerce_json_string: bool
+    required_keys: List[str]
+    strict: bool
+    ref: str
+    serialization: SerSchema
+
+
+class FloatConstraints(SchemaMetadata, total=False):
+    multiple_of: float  # checked with a relative tolerance of 1e-9, so 0.3 is a multiple of 0.1
+    le: float
+    ge: float
+    lt: float
+    gt: float
+    allow_inf_nan: bool  # whether 'NaN', '+inf', and '-inf' should be allowed, default: False
+    strict: bool
+    ref: str
+    serialization: SerSchema
+
+
+class FloatSchema(FloatConstraints, total=False):
     type: Required[Literal['float']]
+
+
+# shorthands for float schemas with a fixed bound, e.g. 'positive-float' is `{'type': 'float', 'gt': 0}`
+# 'allow_inf_nan' is ignored, NaN and infinite values are always rejected
+class FiniteFloatSchema(FloatConstraints, total=False):
+    type: Required[Literal['finite-float']]
+
+
+class PositiveFloatSchema(FloatConstraints, total=False):
+    type: Required[Literal['positive-float']]
+
+
+class NegativeFloatSchema(FloatConstraints, total=False):
+    type: Required[Literal['negative-float']]
+
+
+class NonNegativeFloatSchema(FloatConstraints, total=False):
+    type: Required[Literal['non-negative-float']]
+
+
+class NonPositiveFloatSchema(FloatConstraints, total=False):
+    type: Required[Literal['non-positive-float']]
+
+
+class StrictFloatSchema(SchemaMetadata, total=False):
+    type: Required[Literal['strict-float']]
+    # validated as with `FloatSchema` with `strict: True`, config can't make it lax
     multiple_of: float
     le: float
     ge: float
     lt: float
     gt: float
-    strict: bool
+    allow_inf_nan: bool
     ref: str
+    serialization: SerSchema
 
 
 class FunctionSchema(TypedDict):
@@ -73,25 +218,63 @@ class FunctionSchema(TypedDict):
     mode: Literal['before', 'after', 'wrap']
     function: Callable[..., Any]
     schema: NotRequired[Schema]
+    signature_check: NotRequired[bool]
     ref: NotRequired[str]
+    serialization: NotRequired[SerSchema]
 
 
 class FunctionPlainSchema(TypedDict):
     type: Literal['function']
     mode: Literal['plain']
     function: Callable[..., Any]
+    signature_check: NotRequired[bool]
     ref: NotRequired[str]
+    serialization: NotRequired[SerSchema]
 
 
-class IntSchema(TypedDict, total=False):
-    type: Required[Literal['int']]
+class IntConstraints(SchemaMetadata, total=False):
     multiple_of: int
     le: int
     ge: int
     lt: int
     gt: int
     strict: bool
+    allow_inexact_int_from_float: bool
+    ref: str
+    serialization: SerSchema
+
+
+class IntSchema(IntConstraints, total=False):
+    type: Required[Literal['int']]
+
+
+# shorthands for int schemas with a fixed bound, e.g. 'positive-int' is `{'type': 'int', 'gt': 0}`
+class PositiveIntSchema(IntConstraints, total=False):
+    type: Required[Literal['positive-int']]
+
+
+class NegativeIntSchema(IntConstraints, total=False):
+    type: Required[Literal['negative-int']]
+
+
+class NonNegativeIntSchema(IntConstraints, total=False):
+    type: Required[Literal['non-negative-int']]
+
+
+class NonPositiveIntSchema(IntConstraints, total=False):
+    type: Required[Literal['non-positive-int']]
+
+
+class StrictIntSchema(SchemaMetadata, total=False):
+    type: Required[Literal['strict-int']]
+    # validated as with `IntSchema` with `strict: True`, config can't make it lax
+    multiple_of: int
+    le: int
+    ge: int
+    lt: int
+    gt: int
     ref: str
+    serialization: SerSchema
 
 
 class ListSchema(TypedDict, total=False):
@@ -100,13 +283,32 @@ class ListSchema(TypedDict, total=False):
     min_items: int
     max_items: int
     strict: bool
+    # return an iterator which validates items as they're consumed, min_items and max_items are then only
+    # checked as iteration proceeds
+    lazy: bool
+    # raise a list_item_not_unique error if any item is equal to an earlier item
+    unique_items: bool
+    # if false, the output is sorted, default true
+    preserve_order: bool
+    ref: str
+    serialization: SerSchema
+
+
+class FlatListSchema(TypedDict, total=False):
+    type: Required[Literal['flat-list']]
+    items_schema: Schema  # default: AnySchema
+    # number of levels of nested lists to flatten, default 1 (a list of lists)
+    max_depth: int
+    strict: bool
     ref: str
+    serialization: SerSchema
 
 
 class LiteralSchema(TypedDict):
     type: Literal['literal']
     expected: List[Any]
     ref: NotRequired[str]
+    serialization: NotRequired[SerSchema]
 
 
 class NewClassSchema(TypedDict):
@@ -115,6 +317,7 @@ class NewClassSchema(TypedDict):
     schema: Schema
     strict: NotRequired[bool]
     ref: NotRequired[str]
+    serialization: NotRequired[SerSchema]
     config: NotRequired[Config]
 
 
@@ -123,6 +326,7 @@ class TypedDictField(TypedDict, total=False):
     required: bool
     default: Any
     default_factory: Callable[[], Any]
+    validate_default: bool  # validate `default` in strict mode when building, default: False
     on_error: Literal['raise', 'omit', 'fallback_on_default']  # default: 'raise'
     alias: Union[str, List[Union[str, int]], List[List[Union[str, int]]]]
 
@@ -134,23 +338,37 @@ class TypedDictSchema(TypedDict, total=False):
     extra_validator: Schema
     return_fields_set: bool
     ref: str
+    serialization: SerSchema
     # all these values can be set via config, equivalent fields have `typed_dict_` prefix
     extra_behavior: Literal['allow', 'forbid', 'ignore']
-    total: bool  # default: True
+    # default: True, with False fields are only required if they set `required: True`, and the output only
+    # contains the fields which were provided, `default` and `default_factory` are only used by `on_error`
+    total: bool
     populate_by_name: bool  # replaces `allow_population_by_field_name` in pydantic v1
     from_attributes: bool
 
 
-class NoneSchema(TypedDict):
+class ChainMapSchema(TypedDict, total=False):
+    type: Required[Literal['chain-map']]
+    schema: Required[TypedDictSchema]
+    deep: bool  # default: False
+    strict: bool
+    ref: str
+    serialization: SerSchema
+
+
+class NoneSchema(SchemaMetadata):
     type: Literal['none']
     ref: NotRequired[str]
+    serialization: NotRequired[SerSchema]
 
 
-class NullableSchema(TypedDict, total=False):
+class NullableSchema(SchemaMetadata, total=False):
     type: Required[Literal['nullable']]
     schema: Required[Schema]
     strict: bool
     ref: str
+    serialization: SerSchema
 
 
 class RecursiveReferenceSchema(TypedDict):
@@ -165,6 +383,7 @@ class SetSchema(TypedDict, total=False):
     max_items: int
     strict: bool
     ref: str
+    serialization: SerSchema
 
 
 class FrozenSetSchema(TypedDict, total=False):
@@ -174,10 +393,42 @@ class FrozenSetSchema(TypedDict, total=False):
     max_items: int
     strict: bool
     ref: str
+    serialization: SerSchema
 
 
-class StringSchema(TypedDict, total=False):
+class StringSchema(SchemaMetadata, total=False):
     type: Required[Literal['str']]
+    # compiled with rust's regex crate, not python's `re`: look-around and backreferences are not supported,
+    # the match isn't anchored (like `re.search`)
+    pattern: str
+    max_length: int
+    min_length: int
+    strip_whitespace: bool
+    to_lower: bool
+    to_upper: bool
+    # codec used to decode `bytes` and `bytearray` in lax mode, default: 'utf-8'
+    bytes_encoding: str
+    strict: bool
+    ref: str
+    serialization: SerSchema
+
+
+class StrictStringSchema(SchemaMetadata, total=False):
+    type: Required[Literal['strict-str']]
+    # validated as with `StringSchema` with `strict: True`, config can't make it lax
+    pattern: str
+    max_length: int
+    min_length: int
+    strip_whitespace: bool
+    to_lower: bool
+    to_upper: bool
+    ref: str
+    serialization: SerSchema
+
+
+class SecretStrSchema(TypedDict, total=False):
+    type: Required[Literal['secret-str']]
+    # validated as with `StringSchema`, the output is a `SecretStr` and input values are masked in errors
     pattern: str
     max_length: int
     min_length: int
@@ -186,13 +437,27 @@ class StringSchema(TypedDict, total=False):
     to_upper: bool
     strict: bool
     ref: str
+    serialization: SerSchema
+
+
+class NamedTupleSchema(TypedDict, total=False):
+    type: Required[Literal['namedtuple']]
+    class_type: Required[Type[Any]]  # a `typing.NamedTuple` or `collections.namedtuple` class
+    fields: Dict[str, Schema]  # keys must be field names of class_type, default for each field: AnySchema
+    strict: bool
+    ref: str
+    serialization: SerSchema
 
 
 class UnionSchema(TypedDict, total=False):
     type: Required[Literal['union']]
     choices: Required[List[Schema]]
     strict: bool
+    # 'best' returns only errors from the choice with the deepest error location, 'deduplicated' drops errors
+    # with the same location and kind as an error from an earlier choice, default: 'all'
+    error_mode: Literal['all', 'best', 'deduplicated']
     ref: str
+    serialization: SerSchema
 
 
 class TaggedUnionSchema(TypedDict):
@@ -201,6 +466,7 @@ class TaggedUnionSchema(TypedDict):
     discriminator: Union[str, List[Union[str, int]], List[List[Union[str, int]]], Callable[[Any], Optional[str]]]
     strict: NotRequired[bool]
     ref: NotRequired[str]
+    serialization: NotRequired[SerSchema]
 
 
 class BytesSchema(TypedDict, total=False):
@@ -209,6 +475,26 @@ class BytesSchema(TypedDict, total=False):
     min_length: int
     strict: bool
     ref: str
+    serialization: SerSchema
+
+
+class StrictBytesSchema(TypedDict, total=False):
+    type: Required[Literal['strict-bytes']]
+    # validated as with `BytesSchema` with `strict: True`, config can't make it lax
+    max_length: int
+    min_length: int
+    ref: str
+    serialization: SerSchema
+
+
+class SecretBytesSchema(TypedDict, total=False):
+    type: Required[Literal['secret-bytes']]
+    # validated as with `BytesSchema`, the output is a `SecretBytes` and input values are masked in errors
+    max_length: int
+    min_length: int
+    strict: bool
+    ref: str
+    serialization: SerSchema
 
 
 class DateSchema(TypedDict, total=False):
@@ -219,6 +505,7 @@ class DateSchema(TypedDict, total=False):
     lt: date
     gt: date
     ref: str
+    serialization: SerSchema
 
 
 class TimeSchema(TypedDict, total=False):
@@ -229,6 +516,7 @@ class TimeSchema(TypedDict, total=False):
     lt: time
     gt: time
     ref: str
+    serialization: SerSchema
 
 
 class DatetimeSchema(TypedDict, total=False):
@@ -239,6 +527,7 @@ class DatetimeSchema(TypedDict, total=False):
     lt: datetime
     gt: datetime
     ref: str
+    serialization: SerSchema
 
 
 class TimedeltaSchema(TypedDict, total=False):
@@ -249,6 +538,7 @@ class TimedeltaSchema(TypedDict, total=False):
     lt: timedelta
     gt: timedelta
     ref: str
+    serialization: SerSchema
 
 
 class TuplePositionalSchema(TypedDict, total=False):
@@ -258,6 +548,7 @@ class TuplePositionalSchema(TypedDict, total=False):
     extra_schema: Schema
     strict: bool
     ref: str
+    serialization: SerSchema
 
 
 class TupleVariableSchema(TypedDict, total=False):
@@ -268,11 +559,39 @@ class TupleVariableSchema(TypedDict, total=False):
     max_items: int
     strict: bool
     ref: str
+    serialization: SerSchema
+
+
+class IpAddressSchema(TypedDict, total=False):
+    type: Required[Literal['ip-address']]
+    version: Literal[4, 6]  # default: either version is allowed
+    # also accept integers and packed bytes (4 or 16 bytes, big-endian) in lax mode, default: False
+    allow_packed: bool
+    strict: bool
+    ref: str
+    serialization: SerSchema
+
+
+class UrlSchema(TypedDict, total=False):
+    type: Required[Literal['url']]
+    max_length: int  # checked against the URL after normalization, including for `Url` instances
+    allowed_schemes: List[str]
+    host_required: bool  # default False, hosts are always required for http, https, ws, wss and ftp
+    strict: bool
+    ref: str
+    serialization: SerSchema
 
 
 class IsInstanceSchema(TypedDict):
     type: Literal['is-instance']
-    class_: Type[Any]
+    class_: Union[Type[Any], Tuple[Type[Any], ...]]  # a tuple of classes behaves like the second argument to isinstance
+
+
+class IsSubclassSchema(TypedDict, total=False):
+    type: Required[Literal['is-subclass']]
+    class_: Required[Type[Any]]
+    # in lax mode a string such as 'collections.OrderedDict' is looked up in sys.modules and used as the class
+    strict: bool
 
 
 class CallableSchema(TypedDict):
@@ -285,6 +604,7 @@ class Parameter(TypedDict, total=False):
     schema: Required[Schema]
     default: Any
     default_factory: Callable[[], Any]
+    validate_default: bool  # default: False
     alias: Union[str, List[Union[str, int]], List[List[Union[str, int]]]]
 
 
@@ -295,6 +615,7 @@ class ArgumentsSchema(TypedDict, total=False):
     var_args_schema: Schema
     var_kwargs_schema: Schema
     ref: str
+    serialization: SerSchema
 
 
 class CallSchema(TypedDict):
@@ -303,6 +624,45 @@ class CallSchema(TypedDict):
     arguments_schema: Schema
     return_schema: NotRequired[Schema]
     ref: NotRequired[str]
+    serialization: NotRequired[SerSchema]
+
+
+class GetValidatorsSchema(TypedDict):
+    type: Literal['get-validators']
+    class_type: Type[Any]  # must have a `__get_validators__` class method, as in pydantic v1
+    ref: NotRequired[str]
+    serialization: NotRequired[SerSchema]
+
+
+class ChainSchema(TypedDict):
+    type: Literal['chain']
+    # validated in order, each step's output is the next step's input
+    steps: List[Schema]
+    ref: NotRequired[str]
+    serialization: NotRequired[SerSchema]
+
+
+class SpanSchema(TypedDict):
+    type: Literal['span']
+    # prepended to the location of all errors from `schema`
+    loc_prefix: List[Union[str, int]]
+    schema: Schema
+    ref: NotRequired[str]
+    serialization: NotRequired[SerSchema]
+
+
+class SwitchChoice(TypedDict):
+    predicate: Callable[[Any], bool]
+    schema: Schema
+
+
+class SwitchSchema(TypedDict):
+    type: Literal['switch']
+    # the schema of the first choice whose predicate returns a truthy value for the input is used
+    choices: List[SwitchChoice]
+    default_schema: NotRequired[Schema]  # used if no predicate matches, otherwise a no_match_for_union error
+    ref: NotRequired[str]
+    serialization: NotRequired[SerSchema]
 
 
 # pydantic allows types to be defined via a simple string instead of dict with just `type`, e.g.
@@ -311,11 +671,25 @@ BareType = Literal[
     'any',
     'none',
     'str',
+    'strict-str',
     'bytes',
+    'strict-bytes',
     'dict',
     'int',
+    'strict-int',
+    'positive-int',
+    'negative-int',
+    'non-negative-int',
+    'non-positive-int',
     'bool',
+    'strict-bool',
     'float',
+    'strict-float',
+    'finite-float',
+    'positive-float',
+    'negative-float',
+    'non-negative-float',
+    'non-positive-float',
     'dict',
     'list',
     'tuple',
@@ -325,7 +699,11 @@ BareType = Literal[
     'time',
     'datetime',
     'timedelta',
+    'ip-address',
+    'url',
     'callable',
+    'secret-str',
+    'secret-bytes',
 ]
 
 # generate_self_schema.py is hard coded to convert this Union[BareType, Union[...rest]] where the second union is tagged
@@ -334,15 +712,32 @@ Schema = Union[
     BareType,
     AnySchema,
     BoolSchema,
+    StrictBoolSchema,
     BytesSchema,
+    StrictBytesSchema,
+    SecretBytesSchema,
     DictSchema,
+    MappingSchema,
     FloatSchema,
+    StrictFloatSchema,
+    FiniteFloatSchema,
+    PositiveFloatSchema,
+    NegativeFloatSchema,
+    NonNegativeFloatSchema,
+    NonPositiveFloatSchema,
     FunctionSchema,
     FunctionPlainSchema,
     IntSchema,
+    StrictIntSchema,
+    PositiveIntSchema,
+    NegativeIntSchema,
+    NonNegativeIntSchema,
+    NonPositiveIntSchema,
     ListSchema,
+    FlatListSchema,
     LiteralSchema,
     TypedDictSchema,
+    ChainMapSchema,
     NewClassSchema,
     NoneSchema,
     NullableSchema,
@@ -350,16 +745,26 @@ Schema = Union[
     SetSchema,
     FrozenSetSchema,
     StringSchema,
+    StrictStringSchema,
+    SecretStrSchema,
     TuplePositionalSchema,
     TupleVariableSchema,
+    NamedTupleSchema,
     UnionSchema,
     TaggedUnionSchema,
     DateSchema,
     TimeSchema,
     DatetimeSchema,
     TimedeltaSchema,
+    IpAddressSchema,
+    UrlSchema,
     IsInstanceSchema,
+    IsSubclassSchema,
     CallableSchema,
     ArgumentsSchema,
     CallSchema,
+    GetValidatorsSchema,
+    ChainSchema,
+    SpanSchema,
+    SwitchSchema,
 ]
@@ -1,49 +1,99 @@
 use std::error::Error;
 use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
-use pyo3::exceptions::{PyException, PyKeyError};
+use pyo3::exceptions::PyException;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyString};
+use pyo3::types::{PyDate, PyDateTime, PyDelta, PyDict, PyList, PyString, PyTime, PyType};
 use pyo3::{intern, FromPyObject, PyErrArguments};
 
-use crate::errors::{pretty_line_errors, ValError};
+use crate::errors::{pretty_py_line_errors, py_err_string, PyLineError, ValError};
+use crate::undefined::is_undefined;
 
 pub trait SchemaDict<'py> {
     fn get_as<T>(&'py self, key: &PyString) -> PyResult<Option<T>>
     where
-        T: FromPyObject<'py>;
+        T: FromPyObject<'py> + SchemaValue;
 
     fn get_as_req<T>(&'py self, key: &PyString) -> PyResult<T>
     where
-        T: FromPyObject<'py>;
+        T: FromPyObject<'py> + SchemaValue;
 }
 
 impl<'py> SchemaDict<'py> for PyDict {
     fn get_as<T>(&'py self, key: &PyString) -> PyResult<Option<T>>
     where
-        T: FromPyObject<'py>,
+        T: FromPyObject<'py> + SchemaValue,
     {
         match self.get_item(key) {
-            Some(t) => Ok(Some(<T>::extract(t)?)),
+            Some(t) => Ok(Some(extract_schema_value(t, key)?)),
             None => Ok(None),
         }
     }
 
     fn get_as_req<T>(&'py self, key: &PyString) -> PyResult<T>
     where
-        T: FromPyObject<'py>,
+        T: FromPyObject<'py> + SchemaValue,
     {
         match self.get_item(key) {
-            Some(t) => <T>::extract(t),
-            None => py_error!(PyKeyError; "{}", key),
+            Some(t) => extract_schema_value(t, key),
+            None => Err(SchemaError::new_at(
+                self.py(),
+                "Field required".to_string(),
+                vec![key.to_string()],
+            )),
         }
     }
 }
 
+fn extract_schema_value<'py, T>(value: &'py PyAny, key: &PyString) -> PyResult<T>
+where
+    T: FromPyObject<'py> + SchemaValue,
+{
+    <T>::extract(value).map_err(|err| {
+        let py = value.py();
+        let detail = format!("{}, expected {}", py_err_string(py, err), T::EXPECTED);
+        SchemaError::new_at(py, detail, vec![key.to_string()])
+    })
+}
+
+/// a python flavoured name for a type extracted from a schema by `get_as` or `get_as_req`, for error messages
+pub trait SchemaValue {
+    const EXPECTED: &'static str;
+}
+
+macro_rules! schema_value {
+    ($expected:literal, $($type:ty),+ $(,)?) => {
+        $(
+            impl SchemaValue for $type {
+                const EXPECTED: &'static str = $expected;
+            }
+        )+
+    };
+}
+
+schema_value!("any", &PyAny, PyObject);
+schema_value!("str", String, &str, &PyString);
+schema_value!("bool", bool);
+schema_value!("int", i32, i64);
+schema_value!("non-negative int", u8, usize);
+schema_value!("float", f64);
+schema_value!("dict", &PyDict);
+schema_value!("list", &PyList);
+schema_value!("type", &PyType);
+schema_value!("date", &PyDate);
+schema_value!("time", &PyTime);
+schema_value!("datetime", &PyDateTime);
+schema_value!("timedelta", &PyDelta);
+
+impl<T> SchemaValue for Vec<T> {
+    const EXPECTED: &'static str = "list";
+}
+
 impl<'py> SchemaDict<'py> for Option<&PyDict> {
     fn get_as<T>(&'py self, key: &PyString) -> PyResult<Option<T>>
     where
-        T: FromPyObject<'py>,
+        T: FromPyObject<'py> + SchemaValue,
     {
         match self {
             Some(d) => d.get_as(key),
@@ -54,11 +104,15 @@ impl<'py> SchemaDict<'py> for Option<&PyDict> {
     #[cfg_attr(has_no_coverage, no_coverage)]
     fn get_as_req<T>(&'py self, key: &PyString) -> PyResult<T>
     where
-        T: FromPyObject<'py>,
+        T: FromPyObject<'py> + SchemaValue,
     {
         match self {
             Some(d) => d.get_as_req(key),
-            None => py_error!(PyKeyError; "{}", key),
+            None => Err(SchemaError::new_at(
+                key.py(),
+                "Field required".to_string(),
+                vec![key.to_string()],
+            )),
         }
     }
 }
@@ -70,7 +124,7 @@ pub fn schema_or_config<'py, T>(
     config_key: &PyString,
 ) -> PyResult<Option<T>>
 where
-    T: FromPyObject<'py>,
+    T: FromPyObject<'py> + SchemaValue,
 {
     match schema.get_as(schema_key)? {
         Some(v) => Ok(Some(v)),
@@ -87,7 +141,7 @@ pub fn schema_or_config_same<'py, T>(
     key: &PyString,
 ) -> PyResult<Option<T>>
 where
-    T: FromPyObject<'py>,
+    T: FromPyObject<'py> + SchemaValue,
 {
     schema_or_config(schema, config, key, key)
 }
@@ -97,11 +151,115 @@ pub fn is_strict(schema: &PyDict, config: Option<&PyDict>) -> PyResult<bool> {
     Ok(schema_or_config_same(schema, config, intern!(py, "strict"))?.unwrap_or(false))
 }
 
+/// check a minimum and maximum length read from a schema (or config), `None` means unbounded, a minimum of `0`
+/// allows empty values so is the same as no minimum, it's dropped with a warning since it's usually a mistake
+pub fn validate_length_constraints(
+    py: Python,
+    min_key: &str,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+) -> PyResult<(Option<usize>, Option<usize>)> {
+    match min_length {
+        Some(0) => {
+            let message = format!("`{}: 0` has no effect, omit it to allow empty values", min_key);
+            // pyo3 doesn't expose `UserWarning` yet
+            let category = py.import("builtins")?.getattr(intern!(py, "UserWarning"))?;
+            PyErr::warn(py, category, &message, 1)?;
+            BUILD_WARNINGS.fetch_add(1, Ordering::Relaxed);
+            Ok((None, max_length))
+        }
+        _ => Ok((min_length, max_length)),
+    }
+}
+
+static BUILD_WARNINGS: AtomicUsize = AtomicUsize::new(0);
+
+/// how many warnings have been raised while building validators, `SchemaValidator` doesn't cache validators whose
+/// build warned, so the warning is raised every time the schema is built
+pub fn build_warning_count() -> usize {
+    BUILD_WARNINGS.load(Ordering::Relaxed)
+}
+
+/// get `default` from a field or parameter schema, `PydanticUndefined` is the same as no default
+pub fn get_default(schema: &PyDict) -> PyResult<Option<PyObject>> {
+    let py = schema.py();
+    match schema.get_item(intern!(py, "default")) {
+        Some(default) if !is_undefined(default) => Ok(Some(default.into_py(py))),
+        _ => Ok(None),
+    }
+}
+
+/// `title`, `description` and `examples` which any schema can include to document the values it accepts,
+/// they don't affect validation, validators store them to include in their JSON Schema and return from `metadata`
+#[derive(Debug, Clone)]
+pub struct SchemaMetadata {
+    title: Option<String>,
+    description: Option<String>,
+    examples: Option<Vec<PyObject>>,
+}
+
+impl SchemaMetadata {
+    /// `None` if the schema doesn't include any of the keys
+    pub fn from_schema(schema: &PyDict) -> PyResult<Option<Self>> {
+        let py = schema.py();
+        let metadata = Self {
+            title: schema.get_as(intern!(py, "title"))?,
+            description: schema.get_as(intern!(py, "description"))?,
+            examples: schema.get_as(intern!(py, "examples"))?,
+        };
+        match metadata.title.is_none() && metadata.description.is_none() && metadata.examples.is_none() {
+            true => Ok(None),
+            false => Ok(Some(metadata)),
+        }
+    }
+
+    /// set the keys which were included in the schema, JSON Schema uses the same names
+    pub fn update_dict(&self, dict: &PyDict) -> PyResult<()> {
+        let py = dict.py();
+        if let Some(ref title) = self.title {
+            dict.set_item(intern!(py, "title"), title)?;
+        }
+        if let Some(ref description) = self.description {
+            dict.set_item(intern!(py, "description"), description)?;
+        }
+        if let Some(ref examples) = self.examples {
+            dict.set_item(intern!(py, "examples"), PyList::new(py, examples))?;
+        }
+        Ok(())
+    }
+}
+
+/// implement `Debug` for a validator with a `metadata: Option<SchemaMetadata>` field like `#[derive(Debug)]`
+/// would, except `metadata` is only included if it's set, so validator reprs don't all end with `metadata: None`
+macro_rules! debug_with_metadata {
+    ($name:ident $(, $field:ident)* $(,)?) => {
+        impl std::fmt::Debug for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let mut debug = f.debug_struct(stringify!($name));
+                $(
+                    debug.field(stringify!($field), &self.$field);
+                )*
+                if let Some(ref metadata) = self.metadata {
+                    debug.field("metadata", metadata);
+                }
+                debug.finish()
+            }
+        }
+    };
+}
+pub(crate) use debug_with_metadata;
+
 // we could perhaps do clever things here to store each schema error, or have different types for the top
 // level error group, and other errors, we could perhaps also support error groups!?
 #[pyclass(extends=PyException, module="pydantic_core._pydantic_core")]
 pub struct SchemaError {
     message: String,
+    // `message` without the location of the error within the schema
+    detail: String,
+    // keys and validator types leading to the problem, stored innermost first like `Location`
+    path: Vec<String>,
+    // every problem found when validating the schema against the self-schema, see `errors()`
+    line_errors: Vec<PyLineError>,
 }
 
 impl fmt::Debug for SchemaError {
@@ -131,11 +289,48 @@ impl SchemaError {
         PyErr::new::<SchemaError, A>(args)
     }
 
+    /// an error at `path` within the schema, `path` is innermost first
+    fn new_at(py: Python, detail: String, path: Vec<String>) -> PyErr {
+        let message = match path.is_empty() {
+            true => detail.clone(),
+            false => {
+                let path_str = path.iter().rev().map(String::as_str).collect::<Vec<_>>().join(" -> ");
+                format!("Invalid Schema:\n{}\n  {}", path_str, detail)
+            }
+        };
+        let err = Self::new_err(message);
+        if let Ok(mut schema_error) = err.value(py).extract::<PyRefMut<Self>>() {
+            schema_error.detail = detail;
+            schema_error.path = path;
+        }
+        err
+    }
+
+    /// Add `item` to the start of the schema path of an error raised while building a validator,
+    /// other exceptions are converted to a `SchemaError` including their type
+    pub fn with_outer_path(py: Python, err: PyErr, item: String) -> PyErr {
+        let existing = match err.value(py).extract::<PyRef<Self>>() {
+            Ok(schema_error) => Some((schema_error.detail.clone(), schema_error.path.clone())),
+            Err(_) => None,
+        };
+        let (detail, mut path) = match existing {
+            Some(existing) => existing,
+            None => (py_err_string(py, err), Vec::new()),
+        };
+        path.push(item);
+        Self::new_at(py, detail, path)
+    }
+
     pub fn from_val_error(py: Python, error: ValError) -> PyErr {
         match error {
             ValError::LineErrors(line_errors) => {
-                let details = pretty_line_errors(py, line_errors);
-                SchemaError::new_err(format!("Invalid Schema:\n{}", details))
+                let line_errors: Vec<PyLineError> = line_errors.into_iter().map(|e| e.into_py(py)).collect();
+                let details = pretty_py_line_errors(py, line_errors.iter(), false);
+                let err = SchemaError::new_err(format!("Invalid Schema:\n{}", details));
+                if let Ok(mut schema_error) = err.value(py).extract::<PyRefMut<Self>>() {
+                    schema_error.line_errors = line_errors;
+                }
+                err
             }
             ValError::InternalErr(py_err) => py_err,
         }
@@ -146,7 +341,30 @@ impl SchemaError {
 impl SchemaError {
     #[new]
     fn py_new(message: String) -> Self {
-        Self { message }
+        Self {
+            detail: message.clone(),
+            message,
+            path: Vec::new(),
+            line_errors: Vec::new(),
+        }
+    }
+
+    /// every problem with the schema found by validating it against the self-schema, in the same form as
+    /// `ValidationError.errors(include_url=False)`, otherwise the single problem found while building validators
+    /// with just `loc` (the schema path) and `message`
+    fn errors(&self, py: Python) -> PyResult<Py<PyList>> {
+        if self.line_errors.is_empty() {
+            let error = PyDict::new(py);
+            error.set_item("loc", self.path.iter().rev().collect::<Vec<_>>())?;
+            error.set_item("message", &self.detail)?;
+            return Ok(PyList::new(py, [error]).into());
+        }
+        let errors = self
+            .line_errors
+            .iter()
+            .map(|e| e.as_dict(py, None, false, None))
+            .collect::<PyResult<Vec<PyObject>>>()?;
+        Ok(PyList::new(py, errors).into())
     }
 
     fn __repr__(&self) -> String {
@@ -158,6 +376,18 @@ impl SchemaError {
     }
 }
 
+/// Adds a key or validator type to the schema path of a build error, e.g.
+/// `build_validator(d, config, build_context).with_schema_path("items_schema")?`
+pub trait SchemaPath<T> {
+    fn with_schema_path(self, item: impl ToString) -> PyResult<T>;
+}
+
+impl<T> SchemaPath<T> for PyResult<T> {
+    fn with_schema_path(self, item: impl ToString) -> PyResult<T> {
+        self.map_err(|err| Python::with_gil(|py| SchemaError::with_outer_path(py, err, item.to_string())))
+    }
+}
+
 macro_rules! py_error {
     ($msg:expr) => {
         crate::build_tools::py_error!(crate::build_tools::SchemaError; $msg)
@@ -1,8 +1,13 @@
+use std::env;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::once_cell::GILOnceCell;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyList};
 
-use strum::{Display, EnumMessage};
+use strum::{Display, EnumIter, EnumMessage, IntoEnumIterator, IntoStaticStr};
 
+use super::value_exception::render_template;
 use super::PydanticValueError;
 
 /// Definite each validation error.
@@ -10,7 +15,7 @@ use super::PydanticValueError;
 /// * the variables in the message need to match the enum struct
 /// * you need to add an entry to the `render` enum to render the error message as a template
 /// * you need to add an entry to the `py_dict` enum to generate `ctx` for error messages
-#[derive(Display, EnumMessage, Clone)]
+#[derive(Display, EnumMessage, EnumIter, IntoStaticStr, Clone)]
 #[cfg_attr(debug_assertions, derive(Debug))]
 #[strum(serialize_all = "snake_case")]
 pub enum ErrorKind {
@@ -19,6 +24,14 @@ pub enum ErrorKind {
     #[strum(message = "Invalid JSON: {error}")]
     InvalidJson {
         error: String,
+        // byte offset of the error in the input, if known
+        position: Option<usize>,
+        // one-based line and (character) column of the error, only set when requested via `source_position`
+        source_position: Option<(usize, usize)>,
+    },
+    #[strum(message = "Invalid msgpack: {error}")]
+    InvalidMsgpack {
+        error: String,
     },
     // ---------------------
     // recursion error
@@ -32,6 +45,8 @@ pub enum ErrorKind {
     Missing,
     #[strum(message = "Extra inputs are not permitted")]
     ExtraForbidden,
+    #[strum(message = "Extra input was ignored")]
+    ExtraIgnored,
     #[strum(message = "Keys should be strings")]
     InvalidKey,
     #[strum(message = "Error extracting attribute: {error}")]
@@ -86,6 +101,13 @@ pub enum ErrorKind {
         max_length: usize,
         input_length: usize,
     },
+    #[strum(
+        message = "Input is too large, it should have at most {max_input_length} item{expected_plural}, got {input_length} item{input_plural}"
+    )]
+    InputTooLarge {
+        max_input_length: usize,
+        input_length: usize,
+    },
     // ---------------------
     // string errors
     #[strum(message = "Input should be a valid string")]
@@ -114,10 +136,26 @@ pub enum ErrorKind {
     // dict errors
     #[strum(message = "Input should be a valid dictionary")]
     DictType,
+    #[strum(
+        message = "Input should be a valid dictionary, expected {expected}",
+        serialize = "dict_type"
+    )]
+    DictTypeExpected {
+        expected: String,
+    },
     #[strum(message = "Unable to convert mapping to a dictionary, error: {error}")]
     DictFromMapping {
         error: String,
     },
+    #[strum(message = "Dictionary keys should be unique after key_transform, '{key}' is a duplicate")]
+    DictDuplicateKey {
+        key: String,
+    },
+    #[strum(message = "Unable to convert dictionary to {class_name}, error: {error}")]
+    MappingTargetClass {
+        class_name: String,
+        error: String,
+    },
     // ---------------------
     // generic list-list errors
     #[strum(message = "Error iterating over object")]
@@ -126,14 +164,40 @@ pub enum ErrorKind {
     // list errors
     #[strum(message = "Input should be a valid list/array")]
     ListType,
+    #[strum(
+        message = "Input should be a valid list/array, expected {expected}",
+        serialize = "list_type"
+    )]
+    ListTypeExpected {
+        expected: String,
+    },
+    #[strum(message = "List should have unique items, item {index} is a duplicate")]
+    ListItemNotUnique {
+        index: usize,
+    },
+    #[strum(message = "Unable to sort list items, error: {error}")]
+    ListUnsortable {
+        error: String,
+    },
     // ---------------------
     // tuple errors
     #[strum(message = "Input should be a valid tuple")]
     TupleType,
+    #[strum(
+        message = "Input should be a valid tuple, expected {expected}",
+        serialize = "tuple_type"
+    )]
+    TupleTypeExpected {
+        expected: String,
+    },
     // ---------------------
     // set errors
     #[strum(message = "Input should be a valid set")]
     SetType,
+    #[strum(message = "Input should be a valid set, expected {expected}", serialize = "set_type")]
+    SetTypeExpected {
+        expected: String,
+    },
     // ---------------------
     // bool errors
     #[strum(message = "Input should be a valid boolean")]
@@ -148,6 +212,8 @@ pub enum ErrorKind {
     IntParsing,
     #[strum(message = "Input should be a valid integer, got a number with a fractional part")]
     IntFromFloat,
+    #[strum(message = "Input should be a valid integer, got a number too large to represent")]
+    IntTooLarge,
     #[strum(message = "Input should be a valid integer, got {nan_value}")]
     IntNan {
         nan_value: &'static str,
@@ -184,6 +250,8 @@ pub enum ErrorKind {
     FloatType,
     #[strum(message = "Input should be a valid number, unable to parse string as an number")]
     FloatParsing,
+    #[strum(serialize = "finite_number", message = "Input should be a finite number")]
+    FiniteNumberRequired,
     #[strum(serialize = "multiple_of", message = "Input should be a multiple of {multiple_of}")]
     FloatMultipleOf {
         multiple_of: f64,
@@ -298,12 +366,53 @@ pub enum ErrorKind {
     // frozenset errors
     #[strum(message = "Input should be a valid frozenset")]
     FrozenSetType,
+    #[strum(
+        message = "Input should be a valid frozenset, expected {expected}",
+        serialize = "frozen_set_type"
+    )]
+    FrozenSetTypeExpected {
+        expected: String,
+    },
+    // ---------------------
+    // ip address errors
+    #[strum(message = "Input should be a valid IP address")]
+    IpAddressType,
+    #[strum(message = "Input should be a valid IP address, unable to parse input as an IPv4 or IPv6 address")]
+    IpAddressParsing,
+    #[strum(message = "Input should be an IPv{version} address")]
+    IpVersion {
+        version: u8,
+    },
+    // ---------------------
+    // url errors
+    #[strum(message = "URL input should be a string or URL")]
+    UrlType,
+    #[strum(message = "Input should be a valid URL, {error}")]
+    UrlParsing {
+        error: String,
+    },
+    #[strum(message = "URL should have at most {max_length} characters")]
+    UrlTooLong {
+        max_length: usize,
+    },
+    #[strum(message = "URL scheme should be {expected_schemes}")]
+    UrlSchemeNotAllowed {
+        expected_schemes: String,
+    },
+    #[strum(message = "URL host required")]
+    UrlHostRequired,
     // ---------------------
     // introspection types - e.g. isinstance, callable
     #[strum(message = "Input should be an instance of {class}")]
     IsInstanceOf {
         class: String,
     },
+    #[strum(message = "Input should be a subclass of {class}")]
+    IsSubclassOf {
+        class: String,
+    },
+    #[strum(message = "Input should be a class")]
+    ClassType,
     #[strum(message = "Input should be callable")]
     CallableType,
     // ---------------------
@@ -320,6 +429,8 @@ pub enum ErrorKind {
     UnionTagNotFound {
         discriminator: String,
     },
+    #[strum(message = "Input does not match any of the expected cases")]
+    NoMatchForUnion,
     // ---------------------
     // argument errors
     #[strum(message = "Arguments must be a tuple of (positional arguments, keyword arguments) or a plain dict")]
@@ -376,6 +487,56 @@ fn plural_s(value: &usize) -> &'static str {
     }
 }
 
+/// default location of documentation for each error kind, override with the `PYDANTIC_ERRORS_URL_BASE`
+/// environment variable, e.g. to point at an internal mirror
+const ERRORS_URL_BASE: &str = "https://errors.pydantic.dev";
+
+/// docs are versioned by minor release, e.g. "0.1"
+static DOCS_VERSION: GILOnceCell<String> = GILOnceCell::new();
+
+/// the start of every error kind's documentation url, including the docs version, e.g.
+/// `https://errors.pydantic.dev/0.1/v`, `PYDANTIC_ERRORS_URL_BASE` is read on each call so it can be changed at runtime
+pub fn errors_url_base(py: Python) -> String {
+    let base = env::var("PYDANTIC_ERRORS_URL_BASE").unwrap_or_else(|_| ERRORS_URL_BASE.to_string());
+    let version = DOCS_VERSION.get_or_init(py, || {
+        crate::get_version()
+            .splitn(3, '.')
+            .take(2)
+            .collect::<Vec<_>>()
+            .join(".")
+    });
+    format!("{}/{}/v", base.trim_end_matches('/'), version)
+}
+
+/// description of an error kind by its `kind` string, `None` if the kind is unknown;
+/// where several variants share a kind (e.g. `too_short`) the first variant's description is used
+pub fn error_kind_description(kind: &str) -> Option<&'static str> {
+    ErrorKind::iter()
+        .find(|error_kind| error_kind.to_string() == kind)
+        .map(|error_kind| error_kind.description())
+}
+
+#[pyfunction]
+#[pyo3(name = "error_kind_description")]
+pub fn py_error_kind_description(kind: &str) -> PyResult<&'static str> {
+    error_kind_description(kind).ok_or_else(|| PyValueError::new_err(format!("Unknown error kind: {:?}", kind)))
+}
+
+/// every error kind with its message template and description, one entry per `ErrorKind` variant
+#[pyfunction]
+pub fn list_all_errors(py: Python) -> PyResult<Py<PyList>> {
+    let errors = ErrorKind::iter()
+        .map(|error_kind| {
+            let d = PyDict::new(py);
+            d.set_item("kind", error_kind.to_string())?;
+            d.set_item("message_template", error_kind.get_message())?;
+            d.set_item("description", error_kind.description())?;
+            Ok(d)
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+    Ok(PyList::new(py, errors).into())
+}
+
 impl ErrorKind {
     pub fn kind(&self) -> String {
         match self {
@@ -384,9 +545,35 @@ impl ErrorKind {
         }
     }
 
+    /// the last part of the link to the documentation for this kind of error, e.g. `int_parsing` in
+    /// `https://errors.pydantic.dev/0.1/v/int_parsing`, see `errors_url_base`
+    pub fn error_url(&self) -> &'static str {
+        self.into()
+    }
+
+    /// custom errors aren't documented so have no url
+    pub fn has_url(&self) -> bool {
+        !matches!(self, Self::CustomError { .. })
+    }
+
+    /// the equivalent of a type error which names the type the validator expects, e.g. `SetType` becomes
+    /// `SetTypeExpected`, other kinds are unchanged
+    pub fn with_expected(self, expected: &str) -> Self {
+        let expected = expected.to_string();
+        match self {
+            Self::DictType => Self::DictTypeExpected { expected },
+            Self::ListType => Self::ListTypeExpected { expected },
+            Self::TupleType => Self::TupleTypeExpected { expected },
+            Self::SetType => Self::SetTypeExpected { expected },
+            Self::FrozenSetType => Self::FrozenSetTypeExpected { expected },
+            kind => kind,
+        }
+    }
+
     pub fn render_message(&self, py: Python) -> PyResult<String> {
         match self {
-            Self::InvalidJson { error } => render!(self, error),
+            Self::InvalidJson { error, .. } => render!(self, error),
+            Self::InvalidMsgpack { error } => render!(self, error),
             Self::GetAttributeError { error } => render!(self, error),
             Self::ModelClassType { class_name } => render!(self, class_name),
             Self::GreaterThan { gt } => render!(self, gt),
@@ -409,10 +596,27 @@ impl ErrorKind {
                 let input_plural = plural_s(input_length);
                 to_string_render!(self, max_length, input_length, expected_plural, input_plural)
             }
+            Self::InputTooLarge {
+                max_input_length,
+                input_length,
+            } => {
+                let expected_plural = plural_s(max_input_length);
+                let input_plural = plural_s(input_length);
+                to_string_render!(self, max_input_length, input_length, expected_plural, input_plural)
+            }
+            Self::ListTypeExpected { expected } => render!(self, expected),
+            Self::TupleTypeExpected { expected } => render!(self, expected),
+            Self::SetTypeExpected { expected } => render!(self, expected),
+            Self::FrozenSetTypeExpected { expected } => render!(self, expected),
+            Self::ListItemNotUnique { index } => to_string_render!(self, index),
+            Self::ListUnsortable { error } => render!(self, error),
             Self::StrTooShort { min_length } => to_string_render!(self, min_length),
             Self::StrTooLong { max_length } => to_string_render!(self, max_length),
             Self::StrPatternMismatch { pattern } => render!(self, pattern),
+            Self::DictTypeExpected { expected } => render!(self, expected),
             Self::DictFromMapping { error } => render!(self, error),
+            Self::DictDuplicateKey { key } => render!(self, key),
+            Self::MappingTargetClass { class_name, error } => render!(self, class_name, error),
             Self::IntNan { nan_value } => render!(self, nan_value),
             Self::IntMultipleOf { multiple_of } => to_string_render!(self, multiple_of),
             Self::IntGreaterThan { gt } => to_string_render!(self, gt),
@@ -437,7 +641,12 @@ impl ErrorKind {
             Self::DateTimeParsing { error } => render!(self, error),
             Self::DateTimeObjectInvalid { error } => render!(self, error),
             Self::TimeDeltaParsing { error } => render!(self, error),
+            Self::IpVersion { version } => to_string_render!(self, version),
+            Self::UrlParsing { error } => render!(self, error),
+            Self::UrlTooLong { max_length } => to_string_render!(self, max_length),
+            Self::UrlSchemeNotAllowed { expected_schemes } => render!(self, expected_schemes),
             Self::IsInstanceOf { class } => render!(self, class),
+            Self::IsSubclassOf { class } => render!(self, class),
             Self::UnionTagInvalid {
                 discriminator,
                 tag,
@@ -448,9 +657,303 @@ impl ErrorKind {
         }
     }
 
+    /// render the message using a caller supplied template, e.g. a translation, falling back to the
+    /// default message if `templates` has no entry for this kind. `{placeholder}`s are substituted from the
+    /// error context, unknown placeholders are left unchanged
+    pub fn render_message_with(&self, py: Python, templates: Option<&PyDict>) -> PyResult<String> {
+        let template: String = match templates.and_then(|t| t.get_item(self.kind())) {
+            Some(template) => template.extract()?,
+            None => return self.render_message(py),
+        };
+        let context = self.py_dict(py)?;
+        render_template(py, &template, context.as_ref().map(|c| c.as_ref(py)), None)
+    }
+
+    /// description of the error kind for documentation generators and IDE plugins, including the context keys,
+    /// see `error_kind_description`
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::InvalidInput => "Generic error for input which isn't valid, no context.",
+            Self::InvalidJson { .. } => {
+                "The input to `validate_json` is not valid JSON. Context: `error`, plus `position` \
+                 (byte offset) if known and `line` and `column` if `source_position` was set."
+            }
+            Self::InvalidMsgpack { .. } => "The input to `validate_msgpack` is not valid msgpack. Context: `error`.",
+            Self::RecursionLoop => {
+                "A cyclic reference was detected in the input, or `max_depth` was exceeded while \
+                 validating recursive schemas, no context."
+            }
+            Self::DictAttributesType => {
+                "A typed-dict with `from_attributes` got an input which is neither a dictionary nor \
+                 an object with attributes, no context."
+            }
+            Self::Missing => "A required field, argument or item is missing from the input, no context.",
+            Self::ExtraForbidden => {
+                "The input has a field which is not in the schema and `extra_behavior` is 'forbid', \
+                 no context."
+            }
+            Self::ExtraIgnored => {
+                "Warning only: the input has a field which is not in the schema and was ignored, no \
+                 context."
+            }
+            Self::InvalidKey => "A dictionary key is not a string where field names are required, no context.",
+            Self::GetAttributeError { .. } => {
+                "Getting an attribute from the input raised an exception, e.g. with \
+                 `from_attributes`. Context: `error`."
+            }
+            Self::ModelClassType { .. } => {
+                "The input to a strict new-class schema is not an instance of the class. Context: \
+                 `class_name`."
+            }
+            Self::NoneRequired => "The input to a none schema is not `None` (or `null` in JSON), no context.",
+            Self::Bool => "The input is not a valid boolean, no context.",
+            Self::GreaterThan { .. } => {
+                "The input is not greater than the `gt` constraint, used for ints, floats, dates, \
+                 times, datetimes and timedeltas. Context: `gt`."
+            }
+            Self::GreaterThanEqual { .. } => {
+                "The input is less than the `ge` constraint, used for ints, floats, dates, times, \
+                 datetimes and timedeltas. Context: `ge`."
+            }
+            Self::LessThan { .. } => {
+                "The input is not less than the `lt` constraint, used for ints, floats, dates, times, \
+                 datetimes and timedeltas. Context: `lt`."
+            }
+            Self::LessThanEqual { .. } => {
+                "The input is greater than the `le` constraint, used for ints, floats, dates, times, \
+                 datetimes and timedeltas. Context: `le`."
+            }
+            Self::TooShort { .. } => {
+                "The input has fewer items than `min_items` (or characters or bytes than `min_length` \
+                 for strings and bytes). Context: `min_length`, plus `input_length` for collections."
+            }
+            Self::TooLong { .. } => {
+                "The input has more items than `max_items` (or characters or bytes than `max_length` \
+                 for strings and bytes). Context: `max_length`, plus `input_length` for collections."
+            }
+            Self::InputTooLarge { .. } => {
+                "A dict or list input has more items than the `max_input_length` config, checked before any item \
+                 is validated. Context: `max_input_length`, `input_length`."
+            }
+            Self::StrType => {
+                "The input is not a string, or not a type which can be converted to a string in lax \
+                 mode, no context."
+            }
+            Self::StrUnicode => {
+                "Bytes input could not be decoded as a string, using utf-8 or `bytes_encoding`, no \
+                 context."
+            }
+            Self::StrTooShort { .. } => "The string has fewer characters than `min_length`. Context: `min_length`.",
+            Self::StrTooLong { .. } => "The string has more characters than `max_length`. Context: `max_length`.",
+            Self::StrPatternMismatch { .. } => "The string does not match `pattern`. Context: `pattern`.",
+            Self::DictType => "The input is not a dictionary, or a mapping in lax mode, no context.",
+            Self::DictTypeExpected { .. } => {
+                "As `dict_type`, from a dict schema with keys or values schemas. Context: `expected`, e.g. \
+                 `dict[str, int]`."
+            }
+            Self::DictFromMapping { .. } => {
+                "Converting a mapping input to a dictionary raised an exception. Context: `error`."
+            }
+            Self::DictDuplicateKey { .. } => {
+                "Two keys of the input are the same once `key_transform` is applied. Context: `key`."
+            }
+            Self::MappingTargetClass { .. } => {
+                "Calling a mapping schema's `target_class` with the validated dictionary raised an exception. \
+                 Context: `class_name`, `error`."
+            }
+            Self::IterationError => "Iterating over the input raised an exception, no context.",
+            Self::ListType => "The input is not a list, or a tuple, set, frozenset or similar in lax mode, no context.",
+            Self::ListTypeExpected { .. } => {
+                "As `list_type`, from a list schema with an items schema. Context: `expected`, e.g. `list[int]`."
+            }
+            Self::ListItemNotUnique { .. } => {
+                "A list with `unique_items` has an item equal to an earlier item. Context: `index`."
+            }
+            Self::ListUnsortable { .. } => {
+                "Sorting the output of a list with `preserve_order` false raised an exception, e.g. as the items \
+                 can't be compared. Context: `error`."
+            }
+            Self::TupleType => {
+                "The input is not a tuple, or a list, set, frozenset or similar in lax mode, no \
+                 context."
+            }
+            Self::TupleTypeExpected { .. } => {
+                "As `tuple_type`, from a tuple schema with items schemas. Context: `expected`, e.g. `tuple[int, ...]`."
+            }
+            Self::SetType => "The input is not a set, or a list, tuple, frozenset or similar in lax mode, no context.",
+            Self::SetTypeExpected { .. } => {
+                "As `set_type`, from a set schema with an items schema. Context: `expected`, e.g. `set[int]`."
+            }
+            Self::BoolType => {
+                "The input is not a boolean, or a type which can be interpreted as a boolean in lax \
+                 mode, no context."
+            }
+            Self::BoolParsing => "The string or number input could not be interpreted as a boolean, no context.",
+            Self::IntType => {
+                "The input is not an integer, or a type which can be converted to an integer in lax \
+                 mode, no context."
+            }
+            Self::IntParsing => "The string input could not be parsed as an integer, no context.",
+            Self::IntFromFloat => {
+                "The float input has a fractional part so can't be converted to an integer, no \
+                 context."
+            }
+            Self::IntTooLarge => "The number input is outside the range of a 64-bit integer, no context.",
+            Self::IntNan { .. } => {
+                "The float input is infinite or NaN so can't be converted to an integer. Context: \
+                 `nan_value`."
+            }
+            Self::IntMultipleOf { .. } => {
+                "The input is not a multiple of `multiple_of`, used for ints and floats. Context: \
+                 `multiple_of`."
+            }
+            Self::IntGreaterThan { .. } => "The integer input is not greater than `gt`. Context: `gt`.",
+            Self::IntGreaterThanEqual { .. } => "The integer input is less than `ge`. Context: `ge`.",
+            Self::IntLessThan { .. } => "The integer input is not less than `lt`. Context: `lt`.",
+            Self::IntLessThanEqual { .. } => "The integer input is greater than `le`. Context: `le`.",
+            Self::FloatType => {
+                "The input is not a float, or a type which can be converted to a float in lax mode, \
+                 no context."
+            }
+            Self::FloatParsing => "The string input could not be parsed as a float, no context.",
+            Self::FiniteNumberRequired => {
+                "The float input is infinite or NaN and `allow_inf_nan` is not set, no context."
+            }
+            Self::FloatMultipleOf { .. } => {
+                "The float input is not a multiple of `multiple_of`. Context: `multiple_of`."
+            }
+            Self::FloatGreaterThan { .. } => "The float input is not greater than `gt`. Context: `gt`.",
+            Self::FloatGreaterThanEqual { .. } => "The float input is less than `ge`. Context: `ge`.",
+            Self::FloatLessThan { .. } => "The float input is not less than `lt`. Context: `lt`.",
+            Self::FloatLessThanEqual { .. } => "The float input is greater than `le`. Context: `le`.",
+            Self::BytesType => {
+                "The input is not bytes, or a type which can be converted to bytes in lax mode, no \
+                 context."
+            }
+            Self::BytesTooShort { .. } => "The bytes input is shorter than `min_length`. Context: `min_length`.",
+            Self::BytesTooLong { .. } => "The bytes input is longer than `max_length`. Context: `max_length`.",
+            Self::ValueError { .. } => "A validator function raised a `ValueError`. Context: `error`.",
+            Self::AssertionError { .. } => "A validator function raised an `AssertionError`. Context: `error`.",
+            Self::CustomError { .. } => {
+                "A validator function raised a `PydanticValueError`, the kind, message and context \
+                 are set by the function."
+            }
+            Self::LiteralSingleError { .. } => {
+                "The input is not equal to the single expected value of a literal schema. Context: \
+                 `expected`."
+            }
+            Self::LiteralMultipleError { .. } => {
+                "The input is not equal to any of the expected values of a literal schema. Context: \
+                 `expected`."
+            }
+            Self::DateType => {
+                "The input is not a date, or a type which can be converted to a date in lax mode, no \
+                 context."
+            }
+            Self::DateParsing { .. } => "The string or bytes input could not be parsed as a date. Context: `error`.",
+            Self::DateFromDatetimeParsing { .. } => {
+                "The input could not be parsed as a date or datetime. Context: `error`."
+            }
+            Self::DateFromDatetimeInexact => "A datetime input to a date schema has a non-zero time, no context.",
+            Self::TimeType => {
+                "The input is not a time, or a type which can be converted to a time in lax mode, no \
+                 context."
+            }
+            Self::TimeParsing { .. } => "The string or bytes input could not be parsed as a time. Context: `error`.",
+            Self::DateTimeType => {
+                "The input is not a datetime, or a type which can be converted to a datetime in lax \
+                 mode, no context."
+            }
+            Self::DateTimeParsing { .. } => {
+                "The string, bytes or number input could not be parsed as a datetime. Context: \
+                 `error`."
+            }
+            Self::DateTimeObjectInvalid { .. } => {
+                "The datetime input is invalid, e.g. its `tzinfo` raised an error. Context: `error`."
+            }
+            Self::TimeDeltaType => {
+                "The input is not a timedelta, or a type which can be converted to a timedelta in lax \
+                 mode, no context."
+            }
+            Self::TimeDeltaParsing { .. } => {
+                "The string, bytes or number input could not be parsed as a timedelta. Context: \
+                 `error`."
+            }
+            Self::FrozenSetType => {
+                "The input is not a frozenset, or a list, tuple, set or similar in lax mode, no \
+                 context."
+            }
+            Self::FrozenSetTypeExpected { .. } => {
+                "As `frozen_set_type`, from a frozenset schema with an items schema. Context: `expected`, e.g. \
+                 `frozenset[int]`."
+            }
+            Self::IpAddressType => {
+                "The input is not an IP address object, or a string, bytes or int in lax mode, no \
+                 context."
+            }
+            Self::IpAddressParsing => "The input could not be parsed as an IPv4 or IPv6 address, no context.",
+            Self::IpVersion { .. } => {
+                "The IP address is not of the version required by the schema. Context: `version`."
+            }
+            Self::UrlType => "The input is not a string or `Url`, no context.",
+            Self::UrlParsing { .. } => "The string input could not be parsed as a URL. Context: `error`.",
+            Self::UrlTooLong { .. } => "The URL is longer than `max_length`. Context: `max_length`.",
+            Self::UrlSchemeNotAllowed { .. } => {
+                "The URL scheme is not in `allowed_schemes`. Context: `expected_schemes`."
+            }
+            Self::UrlHostRequired => "The URL has no host and `host_required` is set, no context.",
+            Self::IsInstanceOf { .. } => {
+                "The input is not an instance of the class of an is-instance schema. Context: \
+                 `class`."
+            }
+            Self::IsSubclassOf { .. } => {
+                "The input is not a subclass of the class of an is-subclass schema. Context: `class`."
+            }
+            Self::ClassType => "The input to an is-subclass schema is not a class, no context.",
+            Self::CallableType => "The input to a callable schema is not callable, no context.",
+            Self::UnionTagInvalid { .. } => {
+                "The tag found in the input of a tagged-union does not match any choice. Context: \
+                 `discriminator`, `tag`, `expected_tags`."
+            }
+            Self::UnionTagNotFound { .. } => {
+                "The tag of a tagged-union could not be found in the input. Context: `discriminator`."
+            }
+            Self::NoMatchForUnion => "The input does not match any case of a switch schema, no context.",
+            Self::ArgumentsType => {
+                "The input to an arguments schema is not a tuple of (args, kwargs) or a dict, no \
+                 context."
+            }
+            Self::UnexpectedKeywordArgument => {
+                "A keyword argument was passed which is not in the arguments schema, no context."
+            }
+            Self::MissingKeywordArgument => "A required keyword-only argument is missing, no context.",
+            Self::UnexpectedPositionalArgument => {
+                "More positional arguments were passed than the arguments schema allows, no context."
+            }
+            Self::MissingPositionalArgument => "A required positional-only argument is missing, no context.",
+            Self::MultipleArgumentValues => "An argument was passed both by position and by keyword, no context.",
+        }
+    }
+
     pub fn py_dict(&self, py: Python) -> PyResult<Option<Py<PyDict>>> {
         match self {
-            Self::InvalidJson { error } => py_dict!(py, error),
+            Self::InvalidJson {
+                error,
+                position,
+                source_position,
+            } => {
+                let dict = PyDict::new(py);
+                dict.set_item("error", error)?;
+                if let Some(position) = position {
+                    dict.set_item("position", position)?;
+                }
+                if let Some((line, column)) = source_position {
+                    dict.set_item("line", line)?;
+                    dict.set_item("column", column)?;
+                }
+                Ok(Some(dict.into_py(py)))
+            }
+            Self::InvalidMsgpack { error } => py_dict!(py, error),
             Self::GetAttributeError { error } => py_dict!(py, error),
             Self::ModelClassType { class_name } => py_dict!(py, class_name),
             Self::GreaterThan { gt } => py_dict!(py, gt),
@@ -465,10 +968,23 @@ impl ErrorKind {
                 max_length,
                 input_length,
             } => py_dict!(py, max_length, input_length),
+            Self::InputTooLarge {
+                max_input_length,
+                input_length,
+            } => py_dict!(py, max_input_length, input_length),
+            Self::ListTypeExpected { expected } => py_dict!(py, expected),
+            Self::TupleTypeExpected { expected } => py_dict!(py, expected),
+            Self::SetTypeExpected { expected } => py_dict!(py, expected),
+            Self::FrozenSetTypeExpected { expected } => py_dict!(py, expected),
+            Self::ListItemNotUnique { index } => py_dict!(py, index),
+            Self::ListUnsortable { error } => py_dict!(py, error),
             Self::StrTooShort { min_length } => py_dict!(py, min_length),
             Self::StrTooLong { max_length } => py_dict!(py, max_length),
             Self::StrPatternMismatch { pattern } => py_dict!(py, pattern),
+            Self::DictTypeExpected { expected } => py_dict!(py, expected),
             Self::DictFromMapping { error } => py_dict!(py, error),
+            Self::DictDuplicateKey { key } => py_dict!(py, key),
+            Self::MappingTargetClass { class_name, error } => py_dict!(py, class_name, error),
             Self::IntNan { nan_value } => py_dict!(py, nan_value),
             Self::IntMultipleOf { multiple_of } => py_dict!(py, multiple_of),
             Self::IntGreaterThan { gt } => py_dict!(py, gt),
@@ -493,7 +1009,12 @@ impl ErrorKind {
             Self::DateTimeParsing { error } => py_dict!(py, error),
             Self::DateTimeObjectInvalid { error } => py_dict!(py, error),
             Self::TimeDeltaParsing { error } => py_dict!(py, error),
+            Self::IpVersion { version } => py_dict!(py, version),
+            Self::UrlParsing { error } => py_dict!(py, error),
+            Self::UrlTooLong { max_length } => py_dict!(py, max_length),
+            Self::UrlSchemeNotAllowed { expected_schemes } => py_dict!(py, expected_schemes),
             Self::IsInstanceOf { class } => py_dict!(py, class),
+            Self::IsSubclassOf { class } => py_dict!(py, class),
             Self::UnionTagInvalid {
                 discriminator,
                 tag,
@@ -1,8 +1,9 @@
 use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
+use pyo3::types::PyBytes;
 use pyo3::PyDowncastError;
 
-use crate::input::{Input, JsonInput};
+use crate::input::{Input, JsonInput, NativeInput};
 
 use super::kinds::ErrorKind;
 use super::location::{LocItem, Location};
@@ -59,11 +60,48 @@ impl<'a> ValError<'a> {
             Self::InternalErr(err) => Self::InternalErr(err),
         }
     }
+
+    /// replace the kind of each line error with `f(kind)`, e.g. so a type error from the input can name the type
+    /// the validator expects
+    pub fn map_kind(self, f: impl Fn(ErrorKind) -> ErrorKind) -> Self {
+        match self {
+            Self::LineErrors(line_errors) => Self::LineErrors(
+                line_errors
+                    .into_iter()
+                    .map(|line_error| ValLineError {
+                        kind: f(line_error.kind),
+                        ..line_error
+                    })
+                    .collect(),
+            ),
+            Self::InternalErr(err) => Self::InternalErr(err),
+        }
+    }
+
+    /// name the type the validator expects in type errors, see `ErrorKind::with_expected`, errors are unchanged if
+    /// `expected` is `None`
+    pub fn with_expected(self, expected: Option<&str>) -> Self {
+        match expected {
+            Some(expected) => self.map_kind(|kind| kind.with_expected(expected)),
+            None => self,
+        }
+    }
+
+    /// convert input values to python objects so the error no longer borrows the input,
+    /// needed when the input was created during validation, e.g. by merging dicts
+    pub fn into_owned<'b>(self, py: Python) -> ValError<'b> {
+        match self {
+            Self::LineErrors(line_errors) => {
+                ValError::LineErrors(line_errors.into_iter().map(|e| e.into_owned(py)).collect())
+            }
+            Self::InternalErr(err) => ValError::InternalErr(err),
+        }
+    }
 }
 
 pub fn pretty_line_errors(py: Python, line_errors: Vec<ValLineError>) -> String {
     let py_line_errors: Vec<PyLineError> = line_errors.into_iter().map(|e| e.into_py(py)).collect();
-    pretty_py_line_errors(py, py_line_errors.iter())
+    pretty_py_line_errors(py, py_line_errors.iter(), false)
 }
 
 /// A `ValLineError` is a single error that occurred during validation which is converted to a `PyLineError`
@@ -109,6 +147,14 @@ impl<'a> ValLineError<'a> {
         self
     }
 
+    pub fn into_owned<'b>(self, py: Python) -> ValLineError<'b> {
+        ValLineError {
+            kind: self.kind,
+            location: self.location,
+            input_value: InputValue::PyObject(self.input_value.to_object(py)),
+        }
+    }
+
     // change the kind on a error in place
     pub fn with_kind(mut self, kind: ErrorKind) -> Self {
         self.kind = kind;
@@ -120,7 +166,9 @@ impl<'a> ValLineError<'a> {
 pub enum InputValue<'a> {
     PyAny(&'a PyAny),
     JsonInput(&'a JsonInput),
+    NativeInput(&'a NativeInput),
     String(&'a str),
+    Bytes(&'a [u8]),
     PyObject(PyObject),
 }
 
@@ -135,7 +183,9 @@ impl<'a> ToPyObject for InputValue<'a> {
         match self {
             Self::PyAny(input) => input.into_py(py),
             Self::JsonInput(input) => input.to_object(py),
+            Self::NativeInput(input) => input.to_object(py),
             Self::String(input) => input.into_py(py),
+            Self::Bytes(input) => PyBytes::new(py, input).into_py(py),
             Self::PyObject(py_obj) => py_obj.into_py(py),
         }
     }
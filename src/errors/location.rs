@@ -5,20 +5,28 @@ use pyo3::types::PyList;
 
 /// Used to store individual items of the error location, e.g. a string for key/field names
 /// or a number for array indices.
-#[derive(Clone)]
-#[cfg_attr(debug_assertions, derive(Debug))]
+/// Always `Debug` since validators, e.g. `SpanValidator`, store location items.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum LocItem {
     /// string type key, used to identify items from a dict or anything that implements `__getitem__`
     S(String),
     /// integer key, used to get items from a list, tuple OR a dict with int keys `Dict[int, ...]` (python only)
-    I(usize),
+    I(i64),
+    /// marker following a dict key in the location of an error raised while validating the key itself rather
+    /// than its value, rendered as `[key]`
+    Key,
 }
 
+const KEY_MARKER: &str = "[key]";
+
 impl fmt::Display for LocItem {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            // strings are quoted only where they could be mistaken for an index or the key marker
+            Self::S(s) if s == KEY_MARKER || s.parse::<i64>().is_ok() => write!(f, "{:?}", s),
             Self::S(s) => write!(f, "{}", s),
             Self::I(i) => write!(f, "{}", i),
+            Self::Key => write!(f, "{}", KEY_MARKER),
         }
     }
 }
@@ -37,6 +45,12 @@ impl From<&str> for LocItem {
 
 impl From<usize> for LocItem {
     fn from(i: usize) -> Self {
+        Self::I(i as i64)
+    }
+}
+
+impl From<i64> for LocItem {
+    fn from(i: i64) -> Self {
         Self::I(i)
     }
 }
@@ -46,17 +60,21 @@ impl ToPyObject for LocItem {
         match self {
             Self::S(val) => val.to_object(py),
             Self::I(val) => val.to_object(py),
+            Self::Key => KEY_MARKER.to_object(py),
         }
     }
 }
 
+/// input values are never included in errors for fields whose name contains one of these
+const SENSITIVE_NAMES: [&str; 3] = ["password", "secret", "token"];
+
 /// Error locations are represented by a vector of `LocItem`s.
 /// e.g. if the error occurred in the third member of a list called `foo`,
 /// the location would be `["foo", 2]`.
 /// Note: location in List is stored in **REVERSE** so adding an "outer" item to location involves
 /// pushing to the vec which is faster than inserting and shifting everything along.
 /// Then when "using" location in `Display` and `ToPyObject` order has to be reversed
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(debug_assertions, derive(Debug))]
 pub enum Location {
     // no location, avoid creating an unnecessary vec
@@ -101,6 +119,29 @@ impl Location {
         Self::List(loc)
     }
 
+    /// number of items in the location, e.g. how deeply nested the error is
+    pub fn depth(&self) -> usize {
+        match self {
+            Self::List(loc) => loc.len(),
+            Self::Empty => 0,
+        }
+    }
+
+    /// whether any string item in the location looks like the name of a field holding sensitive data,
+    /// e.g. `password`, `client_secret` or `API_TOKEN`
+    pub fn is_sensitive(&self) -> bool {
+        match self {
+            Self::List(loc) => loc.iter().any(|item| match item {
+                LocItem::S(s) => {
+                    let s = s.to_lowercase();
+                    SENSITIVE_NAMES.iter().any(|name| s.contains(name))
+                }
+                LocItem::I(_) | LocItem::Key => false,
+            }),
+            Self::Empty => false,
+        }
+    }
+
     pub fn with_outer(&mut self, loc_item: LocItem) {
         match self {
             Self::List(ref mut loc) => loc.push(loc_item),
@@ -3,14 +3,16 @@ use pyo3::prelude::*;
 mod kinds;
 mod line_error;
 mod location;
+mod pretty;
 mod validation_exception;
 mod value_exception;
 
-pub use self::kinds::ErrorKind;
+pub use self::kinds::{errors_url_base, list_all_errors, py_error_kind_description, ErrorKind};
 pub use self::line_error::{pretty_line_errors, InputValue, ValError, ValLineError, ValResult};
-pub use self::location::LocItem;
-pub use self::validation_exception::ValidationError;
-pub use self::value_exception::PydanticValueError;
+pub use self::location::{LocItem, Location};
+pub use self::pretty::error_table;
+pub use self::validation_exception::{pretty_py_line_errors, PyLineError, ValidationError};
+pub use self::value_exception::{custom_error, PydanticValueError};
 
 pub fn py_err_string(py: Python, err: PyErr) -> String {
     let value = err.value(py);
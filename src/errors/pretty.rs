@@ -0,0 +1,87 @@
+use std::{env, io};
+
+use is_terminal::IsTerminal;
+
+const HEADERS: [&str; 4] = ["Location", "Type", "Message", "Input"];
+const COLUMN_SEPARATOR: &str = "  ";
+/// longer input representations are truncated to this many characters in the table, keeping the start and end
+const MAX_INPUT_LENGTH: usize = 50;
+/// `ValidationError.errors()` includes inputs with a longer repr as their repr truncated to this many characters
+pub const MAX_ERRORS_INPUT_LENGTH: usize = 200;
+
+const BOLD: &str = "\x1b[1m";
+const RED: &str = "\x1b[31m";
+const RESET: &str = "\x1b[0m";
+
+/// format errors as a table, one row per error with columns matching `HEADERS`
+pub fn error_table(rows: &[[String; 4]], color: bool) -> String {
+    let mut widths = HEADERS.map(|h| h.chars().count());
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let header = format_row(
+        &HEADERS.map(String::from),
+        &widths,
+        color.then_some(BOLD),
+        color.then_some(BOLD),
+    );
+    let separator = widths
+        .iter()
+        .map(|w| "-".repeat(*w))
+        .collect::<Vec<_>>()
+        .join(COLUMN_SEPARATOR);
+    let mut lines = vec![header, separator];
+    lines.extend(
+        rows.iter()
+            .map(|row| format_row(row, &widths, None, color.then_some(RED))),
+    );
+    lines.join("\n")
+}
+
+fn format_row(row: &[String; 4], widths: &[usize; 4], style: Option<&str>, kind_style: Option<&str>) -> String {
+    let last = row.len() - 1;
+    let cells: Vec<String> = row
+        .iter()
+        .enumerate()
+        .map(|(index, cell)| {
+            // pad before adding escape codes so they don't count towards the column width,
+            // the last column isn't padded to avoid trailing whitespace
+            let padded = match index == last {
+                true => cell.clone(),
+                false => format!("{:width$}", cell, width = widths[index]),
+            };
+            let cell_style = if index == 1 { kind_style } else { style };
+            match cell_style {
+                Some(code) => format!("{}{}{}", code, padded, RESET),
+                None => padded,
+            }
+        })
+        .collect();
+    cells.join(COLUMN_SEPARATOR)
+}
+
+pub fn truncate_input(input: &str) -> String {
+    truncate_repr(input, MAX_INPUT_LENGTH)
+}
+
+/// truncate `input` to `max_length` characters with `...` in the middle
+pub fn truncate_repr(input: &str, max_length: usize) -> String {
+    let length = input.chars().count();
+    if length > max_length {
+        let keep_start = (max_length - 3) / 2 + 1;
+        let keep_end = max_length - 3 - keep_start;
+        let start: String = input.chars().take(keep_start).collect();
+        let end: String = input.chars().skip(length - keep_end).collect();
+        format!("{}...{}", start, end)
+    } else {
+        input.to_string()
+    }
+}
+
+/// colors are used if `NO_COLOR` isn't set and stdout is a terminal
+pub fn use_color() -> bool {
+    env::var_os("NO_COLOR").is_none() && io::stdout().is_terminal()
+}
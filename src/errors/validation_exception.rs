@@ -3,13 +3,14 @@ use std::fmt::Write;
 
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyList};
 
 use crate::input::repr_string;
 
-use super::kinds::ErrorKind;
+use super::kinds::{errors_url_base, ErrorKind};
 use super::line_error::ValLineError;
-use super::location::Location;
+use super::location::{LocItem, Location};
+use super::pretty::{error_table, truncate_input, truncate_repr, use_color, MAX_ERRORS_INPUT_LENGTH};
 use super::ValError;
 
 #[pyclass(extends=PyValueError, module="pydantic_core._pydantic_core")]
@@ -18,25 +19,30 @@ use super::ValError;
 pub struct ValidationError {
     line_errors: Vec<PyLineError>,
     title: PyObject,
+    hide_input_in_errors: bool,
 }
 
 impl ValidationError {
-    pub fn from_val_error(py: Python, title: PyObject, error: ValError) -> PyErr {
+    pub fn from_val_error(py: Python, title: PyObject, error: ValError, hide_input_in_errors: bool) -> PyErr {
         match error {
             ValError::LineErrors(raw_errors) => {
                 let line_errors: Vec<PyLineError> = raw_errors.into_iter().map(|e| e.into_py(py)).collect();
-                PyErr::new::<ValidationError, _>((line_errors, title))
+                PyErr::new::<ValidationError, _>((line_errors, title, hide_input_in_errors))
             }
             ValError::InternalErr(err) => err,
         }
     }
 
     fn display(&self, py: Python) -> String {
+        let line_errors = pretty_py_line_errors(py, self.line_errors.iter(), self.hide_input_in_errors);
+        format!("{}\n{}", self.summary(py), line_errors)
+    }
+
+    fn summary(&self, py: Python) -> String {
         let count = self.line_errors.len();
         let plural = if count == 1 { "" } else { "s" };
         let title: &str = self.title.extract(py).unwrap();
-        let line_errors = pretty_py_line_errors(py, self.line_errors.iter());
-        format!("{} validation error{} for {}\n{}", count, plural, title, line_errors)
+        format!("{} validation error{} for {}", count, plural, title)
     }
 }
 
@@ -54,8 +60,13 @@ impl<'a> IntoPy<ValError<'a>> for ValidationError {
 #[pymethods]
 impl ValidationError {
     #[new]
-    fn py_new(line_errors: Vec<PyLineError>, title: PyObject) -> Self {
-        Self { line_errors, title }
+    #[args(hide_input_in_errors = "false")]
+    fn py_new(line_errors: Vec<PyLineError>, title: PyObject, hide_input_in_errors: bool) -> Self {
+        Self {
+            line_errors,
+            title,
+            hide_input_in_errors,
+        }
     }
 
     #[getter]
@@ -67,21 +78,55 @@ impl ValidationError {
         self.line_errors.len()
     }
 
-    fn errors(&self, py: Python) -> PyResult<PyObject> {
+    /// each error's input is included as `input_value`, as its repr truncated to `MAX_ERRORS_INPUT_LENGTH`
+    /// characters if the repr is longer, each error includes a `url` to its documentation unless `include_url`
+    /// is false, `messages` optionally maps error kinds to message templates, e.g. translations,
+    /// see `ErrorKind::render_message_with`
+    #[args(include_url = "true")]
+    fn errors(&self, py: Python, include_url: bool, messages: Option<&PyDict>) -> PyResult<PyObject> {
+        let url_base = include_url.then(|| errors_url_base(py));
         Ok(self
             .line_errors
             .iter()
-            .map(|e| e.as_dict(py))
+            .map(|e| e.as_dict(py, url_base.as_deref(), self.hide_input_in_errors, messages))
             .collect::<PyResult<Vec<PyObject>>>()?
             .into_py(py))
     }
 
+    /// errors grouped by kind, kinds are in the order they first occur
+    #[args(include_url = "true")]
+    fn errors_by_kind(&self, py: Python, include_url: bool, messages: Option<&PyDict>) -> PyResult<PyObject> {
+        let url_base = include_url.then(|| errors_url_base(py));
+        let groups = PyDict::new(py);
+        for line_error in &self.line_errors {
+            let error = line_error.as_dict(py, url_base.as_deref(), self.hide_input_in_errors, messages)?;
+            let kind = line_error.kind.kind();
+            match groups.get_item(&kind) {
+                Some(group) => group.cast_as::<PyList>()?.append(error)?,
+                None => groups.set_item(kind, PyList::new(py, [error]))?,
+            }
+        }
+        Ok(groups.into_py(py))
+    }
+
+    /// errors formatted as a table, `color` defaults to whether stdout is a terminal and `NO_COLOR` isn't set
+    fn pretty(&self, py: Python, color: Option<bool>) -> String {
+        let color = color.unwrap_or_else(use_color);
+        let rows: Vec<[String; 4]> = self
+            .line_errors
+            .iter()
+            .map(|e| e.table_row(py, self.hide_input_in_errors))
+            .collect();
+        format!("{}\n{}", self.summary(py), error_table(&rows, color))
+    }
+
+    /// one error per line with its kind, input value and input type, see `pretty` for the `str()` form
     fn __repr__(&self, py: Python) -> String {
         self.display(py)
     }
 
     fn __str__(&self, py: Python) -> String {
-        self.__repr__(py)
+        self.pretty(py, None)
     }
 }
 
@@ -100,9 +145,13 @@ macro_rules! truncate_input_value {
     };
 }
 
-pub fn pretty_py_line_errors<'a>(py: Python, line_errors_iter: impl Iterator<Item = &'a PyLineError>) -> String {
+pub fn pretty_py_line_errors<'a>(
+    py: Python,
+    line_errors_iter: impl Iterator<Item = &'a PyLineError>,
+    hide_input_in_errors: bool,
+) -> String {
     line_errors_iter
-        .map(|i| i.pretty(py))
+        .map(|i| i.pretty(py, hide_input_in_errors))
         .collect::<Result<Vec<_>, _>>()
         .unwrap_or_else(|err| vec![format!("[error formatting line errors: {}]", err)])
         .join("\n")
@@ -140,34 +189,90 @@ impl<'a> IntoPy<ValLineError<'a>> for PyLineError {
 }
 
 impl PyLineError {
-    pub fn as_dict(&self, py: Python) -> PyResult<PyObject> {
+    /// warnings are recorded as `PyLineError`s, containers then add their location, see `Extra::warn`
+    pub fn with_outer_location(&mut self, loc_item: LocItem) {
+        self.location.with_outer(loc_item);
+    }
+
+    /// the input is omitted if `hide_input_in_errors` is set, or the location looks like a sensitive field
+    fn hide_input(&self, hide_input_in_errors: bool) -> bool {
+        hide_input_in_errors || self.location.is_sensitive()
+    }
+
+    /// `url_base` is from `errors_url_base`, urls are omitted if it's `None`
+    pub fn as_dict(
+        &self,
+        py: Python,
+        url_base: Option<&str>,
+        hide_input_in_errors: bool,
+        messages: Option<&PyDict>,
+    ) -> PyResult<PyObject> {
         let dict = PyDict::new(py);
         dict.set_item("kind", self.kind.kind())?;
         dict.set_item("loc", self.location.to_object(py))?;
-        dict.set_item("message", self.kind.render_message(py)?)?;
-        dict.set_item("input_value", &self.input_value)?;
+        dict.set_item("message", self.kind.render_message_with(py, messages)?)?;
+        if !self.hide_input(hide_input_in_errors) {
+            dict.set_item("input_value", self.errors_input_value(py))?;
+        }
         if let Some(context) = self.kind.py_dict(py)? {
             dict.set_item("context", context)?;
         }
+        if let Some(url_base) = url_base {
+            if self.kind.has_url() {
+                dict.set_item("url", format!("{}/{}", url_base, self.kind.error_url()))?;
+            }
+        }
         Ok(dict.into_py(py))
     }
 
-    fn pretty(&self, py: Python) -> Result<String, fmt::Error> {
-        let mut output = String::with_capacity(200);
-        write!(output, "{}", self.location)?;
+    /// the input itself, unless its repr is longer than `MAX_ERRORS_INPUT_LENGTH`, then the truncated repr
+    fn errors_input_value(&self, py: Python) -> PyObject {
+        match repr_string(self.input_value.as_ref(py)) {
+            Ok(repr) if repr.chars().count() > MAX_ERRORS_INPUT_LENGTH => {
+                truncate_repr(&repr, MAX_ERRORS_INPUT_LENGTH).into_py(py)
+            }
+            _ => self.input_value.clone_ref(py),
+        }
+    }
 
+    fn table_row(&self, py: Python, hide_input_in_errors: bool) -> [String; 4] {
+        let location = match self.location {
+            Location::List(ref loc) => loc.iter().rev().map(|i| i.to_string()).collect::<Vec<_>>().join(" -> "),
+            Location::Empty => String::new(),
+        };
         let message = match self.kind.render_message(py) {
             Ok(message) => message,
             Err(err) => format!("(error rendering message: {})", err),
         };
-        write!(output, "  {} [kind={}", message, self.kind.kind())?;
-
+        if self.hide_input(hide_input_in_errors) {
+            return [location, self.kind.kind(), message, String::new()];
+        }
         let input_value = self.input_value.as_ref(py);
         let input_str = match repr_string(input_value) {
             Ok(s) => s,
             Err(_) => input_value.to_string(),
         };
-        truncate_input_value!(output, input_str);
+        [location, self.kind.kind(), message, truncate_input(&input_str)]
+    }
+
+    fn pretty(&self, py: Python, hide_input_in_errors: bool) -> Result<String, fmt::Error> {
+        let mut output = String::with_capacity(200);
+        write!(output, "{}", self.location)?;
+
+        let message = match self.kind.render_message(py) {
+            Ok(message) => message,
+            Err(err) => format!("(error rendering message: {})", err),
+        };
+        write!(output, "  {} [kind={}", message, self.kind.kind())?;
+
+        let input_value = self.input_value.as_ref(py);
+        if !self.hide_input(hide_input_in_errors) {
+            let input_str = match repr_string(input_value) {
+                Ok(s) => s,
+                Err(_) => input_value.to_string(),
+            };
+            truncate_input_value!(output, input_str);
+        }
 
         if let Ok(type_) = input_value.get_type().name() {
             write!(output, ", input_type={}", type_)?;
@@ -1,13 +1,19 @@
-use pyo3::exceptions::PyValueError;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::once_cell::GILOnceCell;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyString};
 
+use ahash::AHashMap;
+use regex::{Captures, Regex};
+
 use crate::input::Input;
 
 use super::{ErrorKind, ValError};
 
+static PLACEHOLDER_RE: GILOnceCell<Regex> = GILOnceCell::new();
+
 #[pyclass(extends=PyValueError, module="pydantic_core._pydantic_core")]
-#[derive(Clone)]
+#[derive(Clone, Default)]
 #[cfg_attr(debug_assertions, derive(Debug))]
 pub struct PydanticValueError {
     kind: String,
@@ -18,12 +24,20 @@ pub struct PydanticValueError {
 #[pymethods]
 impl PydanticValueError {
     #[new]
-    fn py_new(py: Python, kind: String, message_template: String, context: Option<&PyDict>) -> Self {
-        Self {
+    fn py_new(py: Python, kind: String, message_template: String, context: Option<&PyDict>) -> PyResult<Self> {
+        // a `TypeError` rather than `ValueError` so a mistake in a validator function isn't reported
+        // as a validation error, see `convert_err`
+        if !PyString::new(py, &kind).call_method0("isidentifier")?.is_true()? {
+            return Err(PyTypeError::new_err(format!(
+                "kind should be a valid python identifier, got {:?}",
+                kind
+            )));
+        }
+        Ok(Self {
             kind,
             message_template,
             context: context.map(|c| c.into_py(py)),
-        }
+        })
     }
 
     #[getter]
@@ -41,22 +55,11 @@ impl PydanticValueError {
         self.context.as_ref().map(|c| c.clone_ref(py))
     }
 
+    /// `message_template` with `{key}` placeholders filled from `context`, placeholders missing from the context
+    /// are rendered as `?`
     pub fn message(&self, py: Python) -> PyResult<String> {
-        let mut message = self.message_template.clone();
-        if let Some(ref context) = self.context {
-            for item in context.as_ref(py).items().iter() {
-                let (key, value): (&PyString, &PyAny) = item.extract()?;
-                if let Ok(py_str) = value.cast_as::<PyString>() {
-                    message = message.replace(&format!("{{{}}}", key.to_str()?), py_str.to_str()?);
-                } else if let Ok(value_int) = value.extract::<i64>() {
-                    message = message.replace(&format!("{{{}}}", key.to_str()?), &value_int.to_string());
-                } else {
-                    // fallback for anything else just in case
-                    message = message.replace(&format!("{{{}}}", key.to_str()?), &value.to_string());
-                }
-            }
-        }
-        Ok(message)
+        let context = self.context.as_ref().map(|c| c.as_ref(py));
+        render_template(py, &self.message_template, context, Some("?"))
     }
 
     fn __str__(&self, py: Python) -> PyResult<String> {
@@ -72,9 +75,53 @@ impl PydanticValueError {
     }
 }
 
+/// fill `{key}` placeholders in `template` from `context` in a single pass, so values containing `{...}` are never
+/// substituted themselves, placeholders missing from the context are replaced with `missing`, or left unchanged
+/// if it's `None`
+pub fn render_template(
+    py: Python,
+    template: &str,
+    context: Option<&PyDict>,
+    missing: Option<&str>,
+) -> PyResult<String> {
+    let mut values: AHashMap<&str, String> = AHashMap::new();
+    if let Some(context) = context {
+        for item in context.items().iter() {
+            let (key, value): (&PyString, &PyAny) = item.extract()?;
+            let value = if let Ok(py_str) = value.cast_as::<PyString>() {
+                py_str.to_str()?.to_string()
+            } else if let Ok(value_int) = value.extract::<i64>() {
+                value_int.to_string()
+            } else {
+                // fallback for anything else just in case
+                value.to_string()
+            };
+            values.insert(key.to_str()?, value);
+        }
+    }
+    let placeholder_re = PLACEHOLDER_RE.get_or_init(py, || Regex::new(r"\{(\w+)\}").unwrap());
+    let message = placeholder_re.replace_all(template, |caps: &Captures| match values.get(&caps[1]) {
+        Some(value) => value.clone(),
+        None => missing.map_or_else(|| caps[0].to_string(), ToString::to_string),
+    });
+    Ok(message.into_owned())
+}
+
 impl PydanticValueError {
     pub fn into_val_error<'a>(self, input: &'a impl Input<'a>) -> ValError<'a> {
         let kind = ErrorKind::CustomError { value_error: self };
         ValError::new(kind, input)
     }
 }
+
+/// Create a custom error for validators outside pydantic-core, e.g. `raise custom_error('not_even', 'Input should be
+/// even, got {value}', {'value': 3})` in a validator function gives an error with kind `not_even` in `errors()`
+#[pyfunction]
+pub fn custom_error(
+    py: Python,
+    kind: String,
+    message_template: String,
+    context: Option<&PyDict>,
+) -> PyResult<Py<PydanticValueError>> {
+    Py::new(py, PydanticValueError::py_new(py, kind, message_template, context)?)
+}
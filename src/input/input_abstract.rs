@@ -3,7 +3,7 @@ use std::fmt;
 use pyo3::prelude::*;
 use pyo3::types::{PyString, PyType};
 
-use crate::errors::{InputValue, LocItem, ValResult};
+use crate::errors::{ErrorKind, InputValue, LocItem, ValError, ValResult};
 use crate::input::datetime::EitherTime;
 
 use super::datetime::{EitherDate, EitherDateTime, EitherTimedelta};
@@ -25,6 +25,12 @@ pub trait Input<'a>: fmt::Debug + ToPyObject {
 
     fn is_none(&self) -> bool;
 
+    /// the number of items in a list or dict like input where it's known without iterating over or copying the
+    /// input, `None` for generators and anything which isn't a container, used to check `max_input_length` first
+    fn input_len(&self) -> Option<usize> {
+        None
+    }
+
     fn is_type(&self, _class: &PyType) -> ValResult<bool> {
         Ok(false)
     }
@@ -38,6 +44,14 @@ pub trait Input<'a>: fmt::Debug + ToPyObject {
         Ok(false)
     }
 
+    fn is_subclass(&self, _class: &PyType) -> PyResult<bool> {
+        Ok(false)
+    }
+
+    fn is_class(&self) -> bool {
+        false
+    }
+
     fn callable(&self) -> bool {
         false
     }
@@ -64,7 +78,11 @@ pub trait Input<'a>: fmt::Debug + ToPyObject {
             self.lax_bytes()
         }
     }
-    fn strict_bytes(&'a self) -> ValResult<EitherBytes<'a>>;
+    /// inputs which are never bytes can rely on the defaults, which fail with `BytesType`
+    #[cfg_attr(has_no_coverage, no_coverage)]
+    fn strict_bytes(&'a self) -> ValResult<EitherBytes<'a>> {
+        Err(ValError::new_custom_input(ErrorKind::BytesType, self.as_error_value()))
+    }
     #[cfg_attr(has_no_coverage, no_coverage)]
     fn lax_bytes(&'a self) -> ValResult<EitherBytes<'a>> {
         self.strict_bytes()
@@ -0,0 +1,158 @@
+use std::str::from_utf8;
+
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+use crate::errors::{ErrorKind, InputValue, LocItem, ValError, ValResult};
+
+use super::datetime::{
+    bytes_as_date, bytes_as_datetime, bytes_as_time, bytes_as_timedelta, EitherDate, EitherDateTime, EitherTime,
+};
+use super::shared::{str_as_bool, str_as_int};
+use super::{EitherBytes, EitherString, EitherTimedelta, GenericArguments, GenericListLike, GenericMapping, Input};
+
+/// Raw bytes from a rust buffer, validated as though they were a python `bytes` object but without
+/// creating one until (and unless) it's required for the output
+#[derive(Debug, Clone, Copy)]
+pub struct BytesInput<'a>(pub &'a [u8]);
+
+impl<'a> BytesInput<'a> {
+    fn as_str(&self, unicode_error: ErrorKind) -> ValResult<&str> {
+        match from_utf8(self.0) {
+            Ok(s) => Ok(s),
+            Err(_) => Err(ValError::new(unicode_error, self)),
+        }
+    }
+}
+
+impl<'a> ToPyObject for BytesInput<'a> {
+    fn to_object(&self, py: Python) -> PyObject {
+        PyBytes::new(py, self.0).into_py(py)
+    }
+}
+
+impl<'a> Input<'a> for BytesInput<'a> {
+    #[cfg_attr(has_no_coverage, no_coverage)]
+    fn as_loc_item(&self) -> LocItem {
+        String::from_utf8_lossy(self.0).to_string().into()
+    }
+
+    fn as_error_value(&'a self) -> InputValue<'a> {
+        InputValue::Bytes(self.0)
+    }
+
+    fn is_none(&self) -> bool {
+        false
+    }
+
+    fn validate_args(&'a self) -> ValResult<'a, GenericArguments<'a>> {
+        Err(ValError::new(ErrorKind::ArgumentsType, self))
+    }
+
+    fn strict_str(&'a self) -> ValResult<EitherString<'a>> {
+        Err(ValError::new(ErrorKind::StrType, self))
+    }
+    fn lax_str(&'a self) -> ValResult<EitherString<'a>> {
+        Ok(self.as_str(ErrorKind::StrUnicode)?.into())
+    }
+
+    fn validate_bytes(&'a self, _strict: bool) -> ValResult<EitherBytes<'a>> {
+        Ok(self.0.into())
+    }
+    #[cfg_attr(has_no_coverage, no_coverage)]
+    fn strict_bytes(&'a self) -> ValResult<EitherBytes<'a>> {
+        self.validate_bytes(false)
+    }
+
+    fn strict_bool(&self) -> ValResult<bool> {
+        Err(ValError::new(ErrorKind::BoolType, self))
+    }
+    fn lax_bool(&self) -> ValResult<bool> {
+        str_as_bool(self, self.as_str(ErrorKind::BoolParsing)?)
+    }
+
+    fn strict_int(&self) -> ValResult<i64> {
+        Err(ValError::new(ErrorKind::IntType, self))
+    }
+    fn lax_int(&self) -> ValResult<i64> {
+        str_as_int(self, self.as_str(ErrorKind::IntParsing)?)
+    }
+
+    fn strict_float(&self) -> ValResult<f64> {
+        Err(ValError::new(ErrorKind::FloatType, self))
+    }
+    fn lax_float(&self) -> ValResult<f64> {
+        match self.as_str(ErrorKind::FloatParsing)?.parse() {
+            Ok(f) => Ok(f),
+            Err(_) => Err(ValError::new(ErrorKind::FloatParsing, self)),
+        }
+    }
+
+    fn validate_dict(&'a self, _strict: bool) -> ValResult<GenericMapping<'a>> {
+        Err(ValError::new(ErrorKind::DictType, self))
+    }
+    #[cfg_attr(has_no_coverage, no_coverage)]
+    fn strict_dict(&'a self) -> ValResult<GenericMapping<'a>> {
+        self.validate_dict(false)
+    }
+
+    fn validate_list(&'a self, _strict: bool) -> ValResult<GenericListLike<'a>> {
+        Err(ValError::new(ErrorKind::ListType, self))
+    }
+    #[cfg_attr(has_no_coverage, no_coverage)]
+    fn strict_list(&'a self) -> ValResult<GenericListLike<'a>> {
+        self.validate_list(false)
+    }
+
+    fn validate_tuple(&'a self, _strict: bool) -> ValResult<GenericListLike<'a>> {
+        Err(ValError::new(ErrorKind::TupleType, self))
+    }
+    #[cfg_attr(has_no_coverage, no_coverage)]
+    fn strict_tuple(&'a self) -> ValResult<GenericListLike<'a>> {
+        self.validate_tuple(false)
+    }
+
+    fn validate_set(&'a self, _strict: bool) -> ValResult<GenericListLike<'a>> {
+        Err(ValError::new(ErrorKind::SetType, self))
+    }
+    #[cfg_attr(has_no_coverage, no_coverage)]
+    fn strict_set(&'a self) -> ValResult<GenericListLike<'a>> {
+        self.validate_set(false)
+    }
+
+    fn validate_frozenset(&'a self, _strict: bool) -> ValResult<GenericListLike<'a>> {
+        Err(ValError::new(ErrorKind::FrozenSetType, self))
+    }
+    #[cfg_attr(has_no_coverage, no_coverage)]
+    fn strict_frozenset(&'a self) -> ValResult<GenericListLike<'a>> {
+        self.validate_frozenset(false)
+    }
+
+    fn strict_date(&self) -> ValResult<EitherDate> {
+        Err(ValError::new(ErrorKind::DateType, self))
+    }
+    fn lax_date(&self) -> ValResult<EitherDate> {
+        bytes_as_date(self, self.0)
+    }
+
+    fn strict_time(&self) -> ValResult<EitherTime> {
+        Err(ValError::new(ErrorKind::TimeType, self))
+    }
+    fn lax_time(&self) -> ValResult<EitherTime> {
+        bytes_as_time(self, self.0)
+    }
+
+    fn strict_datetime(&self) -> ValResult<EitherDateTime> {
+        Err(ValError::new(ErrorKind::DateTimeType, self))
+    }
+    fn lax_datetime(&self) -> ValResult<EitherDateTime> {
+        bytes_as_datetime(self, self.0)
+    }
+
+    fn strict_timedelta(&self) -> ValResult<EitherTimedelta> {
+        Err(ValError::new(ErrorKind::TimeDeltaType, self))
+    }
+    fn lax_timedelta(&self) -> ValResult<EitherTimedelta> {
+        bytes_as_timedelta(self, self.0)
+    }
+}
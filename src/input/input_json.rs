@@ -15,7 +15,7 @@ impl<'a> Input<'a> for JsonInput {
     #[cfg_attr(has_no_coverage, no_coverage)]
     fn as_loc_item(&self) -> LocItem {
         match self {
-            JsonInput::Int(i) => LocItem::I(*i as usize),
+            JsonInput::Int(i) => LocItem::I(*i),
             JsonInput::String(s) => s.as_str().into(),
             v => format!("{:?}", v).into(),
         }
@@ -29,6 +29,14 @@ impl<'a> Input<'a> for JsonInput {
         matches!(self, JsonInput::Null)
     }
 
+    fn input_len(&self) -> Option<usize> {
+        match self {
+            JsonInput::Array(array) => Some(array.len()),
+            JsonInput::Object(object) => Some(object.len()),
+            _ => None,
+        }
+    }
+
     fn validate_args(&'a self) -> ValResult<'a, GenericArguments<'a>> {
         match self {
             JsonInput::Object(kwargs) => Ok(JsonArgs::new(None, Some(kwargs)).into()),
@@ -0,0 +1,419 @@
+use std::str::from_utf8;
+
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyBytes, PyDict, PyFloat, PyInt, PyList, PyString};
+
+use crate::errors::{ErrorKind, InputValue, LocItem, ValError, ValResult};
+
+use super::datetime::{
+    bytes_as_date, bytes_as_datetime, bytes_as_time, bytes_as_timedelta, float_as_datetime, float_as_duration,
+    float_as_time, int_as_datetime, int_as_duration, int_as_time, EitherDate, EitherDateTime, EitherTime,
+};
+use super::shared::{float_as_int, int_as_bool, str_as_bool, str_as_int};
+use super::{
+    repr_string, EitherBytes, EitherString, EitherTimedelta, GenericArguments, GenericListLike, GenericMapping, Input,
+};
+
+/// A value built in rust, validated as though it were the equivalent python object, so the coercion and errors of
+/// the python path can be compared against a reference which doesn't touch python objects until the output is built,
+/// see `SchemaValidator.validate_native`, or `SchemaValidator::validate_native_input` to validate a value built
+/// in rust without converting a python object first.
+///
+/// Only types without rust equivalents are left out, so inputs to schemas which check python types (e.g.
+/// `is-instance`, `callable`, `arguments`, or `date`/`time`/`datetime`/`timedelta` objects rather than strings and
+/// numbers) behave differently from the python path.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NativeInput {
+    None,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Str(String),
+    Bytes(Vec<u8>),
+    List(NativeArray),
+    Dict(NativeDict),
+}
+pub type NativeArray = Vec<NativeInput>;
+/// entries in insertion order like a python dict, keys should be unique, lookups find the first match
+pub type NativeDict = Vec<(NativeInput, NativeInput)>;
+
+impl NativeInput {
+    pub fn from_python(obj: &PyAny) -> PyResult<Self> {
+        // bool has to come before int as bools are ints in python
+        if obj.is_none() {
+            Ok(Self::None)
+        } else if let Ok(py_bool) = obj.cast_as::<PyBool>() {
+            Ok(Self::Bool(py_bool.is_true()))
+        } else if obj.cast_as::<PyInt>().is_ok() {
+            // as with JSON, ints beyond `i64` fall back to a float, which int validators reject with `int_too_large`
+            match obj.extract::<i64>() {
+                Ok(int) => Ok(Self::Int(int)),
+                Err(_) => Ok(Self::Float(obj.extract()?)),
+            }
+        } else if let Ok(py_float) = obj.cast_as::<PyFloat>() {
+            Ok(Self::Float(py_float.value()))
+        } else if let Ok(py_str) = obj.cast_as::<PyString>() {
+            Ok(Self::Str(py_str.to_str()?.to_string()))
+        } else if let Ok(py_bytes) = obj.cast_as::<PyBytes>() {
+            Ok(Self::Bytes(py_bytes.as_bytes().to_vec()))
+        } else if let Ok(py_list) = obj.cast_as::<PyList>() {
+            Ok(Self::List(
+                py_list.iter().map(Self::from_python).collect::<PyResult<_>>()?,
+            ))
+        } else if let Ok(py_dict) = obj.cast_as::<PyDict>() {
+            let entries = py_dict
+                .iter()
+                .map(|(key, value)| Ok((Self::from_python(key)?, Self::from_python(value)?)))
+                .collect::<PyResult<_>>()?;
+            Ok(Self::Dict(entries))
+        } else {
+            let type_name = obj.get_type().name().unwrap_or("unknown");
+            Err(PyTypeError::new_err(format!(
+                "Unable to convert {} to a native value, expected None, bool, int, float, str, bytes, list or dict",
+                type_name
+            )))
+        }
+    }
+
+    /// python dicts can't have list or dict keys, so neither can dicts validated as though they were python,
+    /// the same `TypeError` as python's, checked by `SchemaValidator::validate_native_input` before validating
+    pub fn check_keys(&self) -> PyResult<()> {
+        match self {
+            Self::List(list) => list.iter().try_for_each(Self::check_keys),
+            Self::Dict(dict) => dict.iter().try_for_each(|(key, value)| {
+                match key {
+                    Self::List(_) => return Err(PyTypeError::new_err("unhashable type: 'list'")),
+                    Self::Dict(_) => return Err(PyTypeError::new_err("unhashable type: 'dict'")),
+                    _ => (),
+                }
+                value.check_keys()
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// the equivalent python object, an error if a dict has a list or dict key, see `check_keys`
+    pub fn try_to_object(&self, py: Python) -> PyResult<PyObject> {
+        match self {
+            Self::List(v) => Ok(v
+                .iter()
+                .map(|v| v.try_to_object(py))
+                .collect::<PyResult<Vec<_>>>()?
+                .into_py(py)),
+            Self::Dict(d) => {
+                let dict = PyDict::new(py);
+                for (k, v) in d.iter() {
+                    dict.set_item(k.try_to_object(py)?, v.try_to_object(py)?)?;
+                }
+                Ok(dict.into_py(py))
+            }
+            _ => Ok(self.to_object(py)),
+        }
+    }
+
+    fn as_str(&self, unicode_error: ErrorKind) -> ValResult<Option<&str>> {
+        match self {
+            Self::Str(s) => Ok(Some(s)),
+            Self::Bytes(b) => match from_utf8(b) {
+                Ok(s) => Ok(Some(s)),
+                Err(_) => Err(ValError::new(unicode_error, self)),
+            },
+            _ => Ok(None),
+        }
+    }
+}
+
+/// Look up an item the way a python dict would, where `1`, `1.0` and `True` are the same key
+pub fn native_dict_get<'a>(dict: &'a NativeDict, key: &NativeInput) -> Option<&'a NativeInput> {
+    dict.iter().find(|(k, _)| native_key_eq(k, key)).map(|(_, v)| v)
+}
+
+pub fn native_dict_get_mut<'a>(dict: &'a mut NativeDict, key: &NativeInput) -> Option<&'a mut NativeInput> {
+    dict.iter_mut().find(|(k, _)| native_key_eq(k, key)).map(|(_, v)| v)
+}
+
+fn native_key_eq(a: &NativeInput, b: &NativeInput) -> bool {
+    let as_int = |v: &NativeInput| match v {
+        NativeInput::Bool(b) => Some(*b as i64),
+        NativeInput::Int(i) => Some(*i),
+        _ => None,
+    };
+    match (a, b) {
+        (NativeInput::Float(a), NativeInput::Float(b)) => a == b,
+        (NativeInput::Float(f), other) | (other, NativeInput::Float(f)) => match as_int(other) {
+            Some(i) => int_float_eq(i, *f),
+            None => false,
+        },
+        _ => match (as_int(a), as_int(b)) {
+            (Some(a), Some(b)) => a == b,
+            _ => a == b,
+        },
+    }
+}
+
+/// compared exactly as python does, rather than as `f64` which would make e.g. `2**53 + 1` equal to `2.0**53`,
+/// `i64::MAX as f64` rounds up to 2**63 which is out of range
+fn int_float_eq(int: i64, float: f64) -> bool {
+    float.fract() == 0.0 && float >= i64::MIN as f64 && float < i64::MAX as f64 && float as i64 == int
+}
+
+impl ToPyObject for NativeInput {
+    fn to_object(&self, py: Python) -> PyObject {
+        match self {
+            Self::None => py.None(),
+            Self::Bool(b) => b.into_py(py),
+            Self::Int(i) => i.into_py(py),
+            Self::Float(f) => f.into_py(py),
+            Self::Str(s) => s.into_py(py),
+            Self::Bytes(b) => PyBytes::new(py, b).into_py(py),
+            Self::List(v) => v.iter().map(|v| v.to_object(py)).collect::<Vec<_>>().into_py(py),
+            // python can't represent list or dict keys, `check_keys` rejects them before validation, if it was
+            // skipped such keys are replaced by their repr, as with `as_loc_item`, rather than panicking
+            Self::Dict(d) => {
+                let dict = PyDict::new(py);
+                for (k, v) in d.iter() {
+                    let (key, value) = (k.to_object(py), v.to_object(py));
+                    if dict.set_item(&key, &value).is_err() {
+                        let key_repr = repr_string(key.as_ref(py)).unwrap_or_else(|_| format!("{:?}", k));
+                        // a str key can't fail
+                        let _ = dict.set_item(key_repr, value);
+                    }
+                }
+                dict.into_py(py)
+            }
+        }
+    }
+}
+
+impl<'a> Input<'a> for NativeInput {
+    fn as_loc_item(&self) -> LocItem {
+        match self {
+            Self::Str(s) => s.as_str().into(),
+            Self::Int(i) => (*i).into(),
+            Self::Bool(b) => (*b as i64).into(),
+            // same as python, which uses the repr
+            _ => Python::with_gil(|py| match repr_string(self.to_object(py).as_ref(py)) {
+                Ok(s) => s.into(),
+                Err(_) => format!("{:?}", self).into(),
+            }),
+        }
+    }
+
+    fn as_error_value(&'a self) -> InputValue<'a> {
+        InputValue::NativeInput(self)
+    }
+
+    fn is_none(&self) -> bool {
+        matches!(self, Self::None)
+    }
+
+    fn input_len(&self) -> Option<usize> {
+        match self {
+            Self::List(list) => Some(list.len()),
+            Self::Dict(dict) => Some(dict.len()),
+            _ => None,
+        }
+    }
+
+    fn validate_args(&'a self) -> ValResult<'a, GenericArguments<'a>> {
+        Err(ValError::new(ErrorKind::ArgumentsType, self))
+    }
+
+    fn strict_str(&'a self) -> ValResult<EitherString<'a>> {
+        match self {
+            Self::Str(s) => Ok(s.as_str().into()),
+            _ => Err(ValError::new(ErrorKind::StrType, self)),
+        }
+    }
+    fn lax_str(&'a self) -> ValResult<EitherString<'a>> {
+        match self.as_str(ErrorKind::StrUnicode)? {
+            Some(s) => Ok(s.into()),
+            None => Err(ValError::new(ErrorKind::StrType, self)),
+        }
+    }
+
+    fn strict_bytes(&'a self) -> ValResult<EitherBytes<'a>> {
+        match self {
+            Self::Bytes(b) => Ok(b.as_slice().into()),
+            _ => Err(ValError::new(ErrorKind::BytesType, self)),
+        }
+    }
+    fn lax_bytes(&'a self) -> ValResult<EitherBytes<'a>> {
+        match self {
+            Self::Bytes(b) => Ok(b.as_slice().into()),
+            Self::Str(s) => Ok(s.as_bytes().into()),
+            _ => Err(ValError::new(ErrorKind::BytesType, self)),
+        }
+    }
+
+    fn strict_bool(&self) -> ValResult<bool> {
+        match self {
+            Self::Bool(b) => Ok(*b),
+            _ => Err(ValError::new(ErrorKind::BoolType, self)),
+        }
+    }
+    fn lax_bool(&self) -> ValResult<bool> {
+        if let Some(s) = self.as_str(ErrorKind::BoolParsing)? {
+            return str_as_bool(self, s);
+        }
+        match self {
+            Self::Bool(b) => Ok(*b),
+            Self::Int(int) => int_as_bool(self, *int),
+            Self::Float(float) => match float_as_int(self, *float) {
+                Ok(int) => int_as_bool(self, int),
+                _ => Err(ValError::new(ErrorKind::BoolType, self)),
+            },
+            _ => Err(ValError::new(ErrorKind::BoolType, self)),
+        }
+    }
+
+    fn strict_int(&self) -> ValResult<i64> {
+        match self {
+            Self::Int(i) => Ok(*i),
+            _ => Err(ValError::new(ErrorKind::IntType, self)),
+        }
+    }
+    fn lax_int(&self) -> ValResult<i64> {
+        if let Some(s) = self.as_str(ErrorKind::IntParsing)? {
+            return str_as_int(self, s);
+        }
+        match self {
+            Self::Bool(b) => Ok(*b as i64),
+            Self::Int(i) => Ok(*i),
+            Self::Float(f) => float_as_int(self, *f),
+            _ => Err(ValError::new(ErrorKind::IntType, self)),
+        }
+    }
+
+    fn strict_float(&self) -> ValResult<f64> {
+        match self {
+            Self::Float(f) => Ok(*f),
+            Self::Int(i) => Ok(*i as f64),
+            _ => Err(ValError::new(ErrorKind::FloatType, self)),
+        }
+    }
+    fn lax_float(&self) -> ValResult<f64> {
+        if let Some(s) = self.as_str(ErrorKind::FloatParsing)? {
+            return match s.parse() {
+                Ok(f) => Ok(f),
+                Err(_) => Err(ValError::new(ErrorKind::FloatParsing, self)),
+            };
+        }
+        match self {
+            Self::Bool(b) => Ok(*b as i64 as f64),
+            Self::Float(f) => Ok(*f),
+            Self::Int(i) => Ok(*i as f64),
+            _ => Err(ValError::new(ErrorKind::FloatType, self)),
+        }
+    }
+
+    fn strict_dict(&'a self) -> ValResult<GenericMapping<'a>> {
+        match self {
+            Self::Dict(dict) => Ok(dict.into()),
+            _ => Err(ValError::new(ErrorKind::DictType, self)),
+        }
+    }
+
+    fn validate_typed_dict(&'a self, strict: bool, from_attributes: bool) -> ValResult<GenericMapping<'a>> {
+        match self {
+            Self::Dict(dict) => Ok(dict.into()),
+            // there are no objects to get attributes from
+            _ if from_attributes => Err(ValError::new(ErrorKind::DictAttributesType, self)),
+            _ => self.validate_dict(strict),
+        }
+    }
+
+    fn strict_list(&'a self) -> ValResult<GenericListLike<'a>> {
+        match self {
+            Self::List(list) => Ok(list.into()),
+            _ => Err(ValError::new(ErrorKind::ListType, self)),
+        }
+    }
+
+    // there are no tuples, sets or frozensets, so these are only accepted in lax mode, from lists
+
+    fn strict_tuple(&'a self) -> ValResult<GenericListLike<'a>> {
+        Err(ValError::new(ErrorKind::TupleType, self))
+    }
+    fn lax_tuple(&'a self) -> ValResult<GenericListLike<'a>> {
+        match self {
+            Self::List(list) => Ok(list.into()),
+            _ => Err(ValError::new(ErrorKind::TupleType, self)),
+        }
+    }
+
+    fn strict_set(&'a self) -> ValResult<GenericListLike<'a>> {
+        Err(ValError::new(ErrorKind::SetType, self))
+    }
+    fn lax_set(&'a self) -> ValResult<GenericListLike<'a>> {
+        match self {
+            Self::List(list) => Ok(list.into()),
+            _ => Err(ValError::new(ErrorKind::SetType, self)),
+        }
+    }
+
+    fn strict_frozenset(&'a self) -> ValResult<GenericListLike<'a>> {
+        Err(ValError::new(ErrorKind::FrozenSetType, self))
+    }
+    fn lax_frozenset(&'a self) -> ValResult<GenericListLike<'a>> {
+        match self {
+            Self::List(list) => Ok(list.into()),
+            _ => Err(ValError::new(ErrorKind::FrozenSetType, self)),
+        }
+    }
+
+    // likewise there are no date, time, datetime or timedelta values, so they're only coerced from strings and numbers
+
+    fn strict_date(&self) -> ValResult<EitherDate> {
+        Err(ValError::new(ErrorKind::DateType, self))
+    }
+    fn lax_date(&self) -> ValResult<EitherDate> {
+        match self {
+            Self::Str(s) => bytes_as_date(self, s.as_bytes()),
+            Self::Bytes(b) => bytes_as_date(self, b),
+            _ => Err(ValError::new(ErrorKind::DateType, self)),
+        }
+    }
+
+    fn strict_time(&self) -> ValResult<EitherTime> {
+        Err(ValError::new(ErrorKind::TimeType, self))
+    }
+    fn lax_time(&self) -> ValResult<EitherTime> {
+        match self {
+            Self::Str(s) => bytes_as_time(self, s.as_bytes()),
+            Self::Bytes(b) => bytes_as_time(self, b),
+            Self::Int(i) => int_as_time(self, *i, 0),
+            Self::Float(f) => float_as_time(self, *f),
+            _ => Err(ValError::new(ErrorKind::TimeType, self)),
+        }
+    }
+
+    fn strict_datetime(&self) -> ValResult<EitherDateTime> {
+        Err(ValError::new(ErrorKind::DateTimeType, self))
+    }
+    fn lax_datetime(&self) -> ValResult<EitherDateTime> {
+        match self {
+            Self::Str(s) => bytes_as_datetime(self, s.as_bytes()),
+            Self::Bytes(b) => bytes_as_datetime(self, b),
+            Self::Int(i) => int_as_datetime(self, *i, 0),
+            Self::Float(f) => float_as_datetime(self, *f),
+            _ => Err(ValError::new(ErrorKind::DateTimeType, self)),
+        }
+    }
+
+    fn strict_timedelta(&self) -> ValResult<EitherTimedelta> {
+        Err(ValError::new(ErrorKind::TimeDeltaType, self))
+    }
+    fn lax_timedelta(&self) -> ValResult<EitherTimedelta> {
+        match self {
+            Self::Str(s) => bytes_as_timedelta(self, s.as_bytes()),
+            Self::Bytes(b) => bytes_as_timedelta(self, b),
+            // unlike datetimes and times, python accepts bools as timedeltas since it doesn't check for them
+            Self::Bool(b) => Ok(int_as_duration(*b as i64).into()),
+            Self::Int(i) => Ok(int_as_duration(*i).into()),
+            Self::Float(f) => Ok(float_as_duration(*f).into()),
+            _ => Err(ValError::new(ErrorKind::TimeDeltaType, self)),
+        }
+    }
+}
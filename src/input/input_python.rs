@@ -47,7 +47,7 @@ impl<'a> Input<'a> for PyAny {
     fn as_loc_item(&self) -> LocItem {
         if let Ok(py_str) = self.cast_as::<PyString>() {
             py_str.to_string_lossy().as_ref().into()
-        } else if let Ok(key_int) = self.extract::<usize>() {
+        } else if let Ok(key_int) = self.extract::<i64>() {
             key_int.into()
         } else {
             match repr_string(self) {
@@ -69,6 +69,25 @@ impl<'a> Input<'a> for PyAny {
         self.is_none()
     }
 
+    fn input_len(&self) -> Option<usize> {
+        if self.cast_as::<PyList>().is_ok()
+            || self.cast_as::<PyTuple>().is_ok()
+            || self.cast_as::<PyDict>().is_ok()
+            || self.cast_as::<PySet>().is_ok()
+            || self.cast_as::<PyFrozenSet>().is_ok()
+            || is_dict_view(self)
+        {
+            self.len().ok()
+        } else if self.cast_as::<PyString>().is_ok() || self.cast_as::<PyBytes>().is_ok() {
+            // `cast_as::<PyMapping>` accepts strings, see `mapping_as_dict`
+            None
+        } else if let Ok(mapping) = self.cast_as::<PyMapping>() {
+            mapping.len().ok()
+        } else {
+            None
+        }
+    }
+
     fn is_type(&self, class: &PyType) -> ValResult<bool> {
         Ok(self.get_type().eq(class)?)
     }
@@ -81,6 +100,17 @@ impl<'a> Input<'a> for PyAny {
         self.is_instance(class)
     }
 
+    fn is_subclass(&self, class: &PyType) -> PyResult<bool> {
+        match self.cast_as::<PyType>() {
+            Ok(py_type) => py_type.is_subclass(class),
+            Err(_) => Ok(false),
+        }
+    }
+
+    fn is_class(&self) -> bool {
+        self.cast_as::<PyType>().is_ok()
+    }
+
     fn callable(&self) -> bool {
         self.is_callable()
     }
@@ -548,6 +578,16 @@ impl<'a> Input<'a> for PyAny {
     }
 }
 
+#[cfg(not(PyPy))]
+fn is_dict_view(obj: &PyAny) -> bool {
+    obj.cast_as::<PyDictKeys>().is_ok() || obj.cast_as::<PyDictValues>().is_ok()
+}
+
+#[cfg(PyPy)]
+fn is_dict_view(_obj: &PyAny) -> bool {
+    false
+}
+
 /// return None if obj is not a mapping (cast_as::<PyMapping> fails or mapping.items returns an AttributeError)
 /// otherwise try to covert the mapping to a dict and return an Some(error) if it fails
 fn mapping_as_dict(obj: &PyAny) -> Option<ValResult<GenericMapping>> {
@@ -4,18 +4,25 @@ use pyo3::prelude::*;
 mod _pyo3_dict;
 mod datetime;
 mod input_abstract;
+mod input_bytes;
 mod input_json;
+mod input_native;
 mod input_python;
 mod parse_json;
+mod parse_msgpack;
 mod return_enums;
 mod shared;
 
 pub use datetime::{EitherDate, EitherDateTime, EitherTime, EitherTimedelta};
 pub use input_abstract::Input;
-pub use parse_json::{JsonInput, JsonObject};
+pub use input_bytes::BytesInput;
+pub use input_native::{native_dict_get, native_dict_get_mut, NativeDict, NativeInput};
+pub use parse_json::{complete_truncated_json, JsonInput, JsonObject};
+pub use parse_msgpack::parse_msgpack;
 pub use return_enums::{
     py_string_str, EitherBytes, EitherString, GenericArguments, GenericListLike, GenericMapping, JsonArgs, PyArgs,
 };
+pub use shared::float_as_int;
 
 pub fn repr_string(v: &PyAny) -> PyResult<String> {
     v.repr()?.extract()
@@ -63,7 +63,11 @@ impl<'de> Deserialize<'de> for JsonInput {
             }
 
             fn visit_u64<E>(self, value: u64) -> Result<JsonInput, E> {
-                Ok(JsonInput::Int(value as i64))
+                // integers beyond `i64::MAX` can't be represented exactly, fall back to a float rather than wrapping
+                match i64::try_from(value) {
+                    Ok(i) => Ok(JsonInput::Int(i)),
+                    Err(_) => Ok(JsonInput::Float(value as f64)),
+                }
             }
 
             fn visit_f64<E>(self, value: f64) -> Result<JsonInput, E> {
@@ -171,3 +175,94 @@ impl<'de> Visitor<'de> for KeyDeserializer {
         unreachable!()
     }
 }
+
+enum OpenContainer {
+    Array,
+    Object { expect_key: bool },
+}
+
+/// Complete JSON which was cut off mid-document, for `validate_json(..., allow_partial=True)`: the incomplete tail
+/// is dropped and the top level array or object is closed, e.g. `{"a": [1, 2], "b": "x` becomes `{"a": [1, 2]}`.
+/// Items of the top level array or object are kept only if they're complete, so a nested array or object which
+/// was never closed is dropped whole, e.g. `[{"id": 1}, {"id": 2, "ms` becomes `[{"id": 1}]`, as is a number
+/// unless it's followed by a comma since more digits may have been cut off, complete strings, `true`, `false`
+/// and `null` are kept. This doesn't check the JSON is otherwise valid, it should only be used after parsing
+/// failed at the end of the input.
+/// `None` if nothing complete came before the end of the input, e.g. a truncated top level string
+pub fn complete_truncated_json(bytes: &[u8]) -> Option<Vec<u8>> {
+    let mut stack: Vec<OpenContainer> = Vec::new();
+    // where the input can be cut, and whether the top level container is still open there, cuts are only made
+    // between items of the top level container, or once it's closed
+    let mut cut: Option<(usize, bool)> = None;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (index, byte) in bytes.iter().enumerate() {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if *byte == b'\\' {
+                escaped = true;
+            } else if *byte == b'"' {
+                in_string = false;
+                match stack.as_slice() {
+                    // an object key isn't complete until it has a value
+                    [OpenContainer::Object { expect_key: true }] => (),
+                    [_] => cut = Some((index + 1, true)),
+                    [] => cut = Some((index + 1, false)),
+                    _ => (),
+                }
+            }
+            continue;
+        }
+        match byte {
+            b'"' => in_string = true,
+            b'[' | b'{' => {
+                stack.push(match byte {
+                    b'[' => OpenContainer::Array,
+                    _ => OpenContainer::Object { expect_key: true },
+                });
+                if stack.len() == 1 {
+                    cut = Some((index + 1, true));
+                }
+            }
+            b']' | b'}' => {
+                stack.pop();
+                if stack.len() <= 1 {
+                    cut = Some((index + 1, !stack.is_empty()));
+                }
+            }
+            b':' => {
+                if let Some(OpenContainer::Object { expect_key }) = stack.last_mut() {
+                    *expect_key = false;
+                }
+            }
+            b',' => {
+                if let Some(OpenContainer::Object { expect_key }) = stack.last_mut() {
+                    *expect_key = true;
+                }
+                if stack.len() == 1 {
+                    cut = Some((index, true));
+                }
+            }
+            b'e' | b'l' if stack.len() == 1 => {
+                let before = &bytes[..=index];
+                if [&b"true"[..], b"false", b"null"]
+                    .iter()
+                    .any(|literal| before.ends_with(literal))
+                {
+                    cut = Some((index + 1, true));
+                }
+            }
+            _ => (),
+        }
+    }
+    let (position, top_level_open) = cut?;
+    let mut completed = bytes[..position].to_vec();
+    if top_level_open {
+        completed.push(match stack.first()? {
+            OpenContainer::Array => b']',
+            OpenContainer::Object { .. } => b'}',
+        });
+    }
+    Some(completed)
+}
@@ -0,0 +1,171 @@
+use std::collections::hash_map::Entry;
+use std::fmt;
+use std::io::Cursor;
+
+use ahash::AHashMap;
+
+use serde::de::{Deserialize, Error as SerdeError, MapAccess, SeqAccess, Visitor};
+
+use super::input_native::{NativeDict, NativeInput};
+
+/// Decode msgpack into a `NativeInput` tree, without creating any python objects, validated as though it were
+/// the equivalent python object, hence error kinds match `validate_python`:
+/// * `str` and `bin` become `str` and `bytes` respectively
+/// * arrays become lists, maps become dicts with keys of any type except arrays and maps
+/// * as with JSON, integers beyond `i64::MAX` become floats
+///
+/// The `Err` is a description of why the input isn't valid msgpack.
+pub fn parse_msgpack(bytes: &[u8]) -> Result<NativeInput, String> {
+    let mut deserializer = rmp_serde::Deserializer::new(Cursor::new(bytes));
+    let value = NativeInput::deserialize(&mut deserializer).map_err(|e| e.to_string())?;
+    match deserializer.position() as usize {
+        position if position < bytes.len() => Err(format!("trailing data at byte {}", position)),
+        _ => Ok(value),
+    }
+}
+
+impl<'de> Deserialize<'de> for NativeInput {
+    fn deserialize<D>(deserializer: D) -> Result<NativeInput, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(NativeInputVisitor)
+    }
+}
+
+struct NativeInputVisitor;
+
+impl<'de> Visitor<'de> for NativeInputVisitor {
+    type Value = NativeInput;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("any msgpack value except extension types")
+    }
+
+    fn visit_bool<E>(self, value: bool) -> Result<NativeInput, E> {
+        Ok(NativeInput::Bool(value))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<NativeInput, E> {
+        Ok(NativeInput::Int(value))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<NativeInput, E> {
+        match i64::try_from(value) {
+            Ok(i) => Ok(NativeInput::Int(i)),
+            Err(_) => Ok(NativeInput::Float(value as f64)),
+        }
+    }
+
+    fn visit_f32<E>(self, value: f32) -> Result<NativeInput, E> {
+        Ok(NativeInput::Float(value as f64))
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<NativeInput, E> {
+        Ok(NativeInput::Float(value))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<NativeInput, E> {
+        Ok(NativeInput::Str(value.to_string()))
+    }
+
+    fn visit_string<E>(self, value: String) -> Result<NativeInput, E> {
+        Ok(NativeInput::Str(value))
+    }
+
+    fn visit_bytes<E>(self, value: &[u8]) -> Result<NativeInput, E> {
+        Ok(NativeInput::Bytes(value.to_vec()))
+    }
+
+    fn visit_byte_buf<E>(self, value: Vec<u8>) -> Result<NativeInput, E> {
+        Ok(NativeInput::Bytes(value))
+    }
+
+    fn visit_unit<E>(self) -> Result<NativeInput, E> {
+        Ok(NativeInput::None)
+    }
+
+    fn visit_none<E>(self) -> Result<NativeInput, E> {
+        Ok(NativeInput::None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<NativeInput, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_seq<V>(self, mut visitor: V) -> Result<NativeInput, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        // the length in the input isn't trusted to preallocate
+        let mut list = Vec::new();
+        while let Some(item) = visitor.next_element()? {
+            list.push(item);
+        }
+        Ok(NativeInput::List(list))
+    }
+
+    fn visit_map<V>(self, mut visitor: V) -> Result<NativeInput, V::Error>
+    where
+        V: MapAccess<'de>,
+    {
+        let mut dict: NativeDict = Vec::new();
+        let mut index: AHashMap<MapKey, usize> = AHashMap::new();
+        while let Some(key) = visitor.next_key::<NativeInput>()? {
+            // the same error as python would give when the map is validated as a dict
+            match key {
+                NativeInput::List(_) => return Err(V::Error::custom("TypeError: unhashable type: 'list'")),
+                NativeInput::Dict(_) => return Err(V::Error::custom("TypeError: unhashable type: 'dict'")),
+                _ => (),
+            }
+            let value = visitor.next_value()?;
+            // like a python dict, a repeated key keeps its first position and takes the last value
+            match MapKey::new(&key) {
+                Some(map_key) => match index.entry(map_key) {
+                    Entry::Occupied(entry) => dict[*entry.get()].1 = value,
+                    Entry::Vacant(entry) => {
+                        entry.insert(dict.len());
+                        dict.push((key, value));
+                    }
+                },
+                None => dict.push((key, value)),
+            }
+        }
+        Ok(NativeInput::Dict(dict))
+    }
+}
+
+/// A map key which is equal to another exactly when python would consider them the same dict key, so repeated
+/// keys can be found without comparing every pair, where `1`, `1.0` and `True` are the same key
+#[derive(Hash, PartialEq, Eq)]
+enum MapKey {
+    None,
+    Int(i64),
+    Float(u64),
+    Str(String),
+    Bytes(Vec<u8>),
+}
+
+impl MapKey {
+    /// `None` for NaN, which is never equal to another key
+    fn new(key: &NativeInput) -> Option<Self> {
+        match key {
+            NativeInput::None => Some(Self::None),
+            NativeInput::Bool(b) => Some(Self::Int(*b as i64)),
+            NativeInput::Int(i) => Some(Self::Int(*i)),
+            NativeInput::Float(f) if f.is_nan() => None,
+            // as in `int_float_eq`, `i64::MAX as f64` rounds up to 2**63 which is out of range, -0.0 becomes 0
+            NativeInput::Float(f) if f.fract() == 0.0 && *f >= i64::MIN as f64 && *f < i64::MAX as f64 => {
+                Some(Self::Int(*f as i64))
+            }
+            NativeInput::Float(f) => Some(Self::Float(f.to_bits())),
+            NativeInput::Str(s) => Some(Self::Str(s.clone())),
+            NativeInput::Bytes(b) => Some(Self::Bytes(b.clone())),
+            // rejected above
+            NativeInput::List(_) | NativeInput::Dict(_) => None,
+        }
+    }
+}
@@ -7,6 +7,7 @@ use crate::errors::{ErrorKind, InputValue, ValError, ValLineError, ValResult};
 use crate::recursion_guard::RecursionGuard;
 use crate::validators::{CombinedValidator, Extra, Validator};
 
+use super::input_native::{NativeArray, NativeDict, NativeInput};
 use super::parse_json::{JsonArray, JsonInput, JsonObject};
 use super::Input;
 
@@ -20,6 +21,7 @@ pub enum GenericListLike<'a> {
     Set(&'a PySet),
     FrozenSet(&'a PyFrozenSet),
     JsonArray(&'a [JsonInput]),
+    NativeList(&'a [NativeInput]),
 }
 
 macro_rules! derive_from {
@@ -37,6 +39,7 @@ derive_from!(GenericListLike, Set, PySet);
 derive_from!(GenericListLike, FrozenSet, PyFrozenSet);
 derive_from!(GenericListLike, JsonArray, JsonArray);
 derive_from!(GenericListLike, JsonArray, [JsonInput]);
+derive_from!(GenericListLike, NativeList, NativeArray);
 
 fn validate_iter_to_vec<'a, 's>(
     py: Python<'a>,
@@ -50,8 +53,12 @@ fn validate_iter_to_vec<'a, 's>(
     let mut output: Vec<PyObject> = Vec::with_capacity(length);
     let mut errors: Vec<ValLineError> = Vec::new();
     for (index, item) in iter.enumerate() {
-        match validator.validate(py, item, extra, slots, recursion_guard) {
+        let sink_mark = extra.sink_mark();
+        let result = validator.validate(py, item, extra, slots, recursion_guard);
+        extra.with_outer_location_since(sink_mark, || index.into());
+        match result {
             Ok(item) => output.push(item),
+            Err(err @ ValError::LineErrors(_)) if extra.fail_fast => return Err(err),
             Err(ValError::LineErrors(line_errors)) => {
                 errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index.into())));
             }
@@ -74,6 +81,7 @@ impl<'a> GenericListLike<'a> {
             Self::Set(v) => v.len(),
             Self::FrozenSet(v) => v.len(),
             Self::JsonArray(v) => v.len(),
+            Self::NativeList(v) => v.len(),
         }
     }
 
@@ -138,6 +146,9 @@ impl<'a> GenericListLike<'a> {
             Self::JsonArray(list_like) => {
                 validate_iter_to_vec(py, list_like.iter(), length, validator, extra, slots, recursion_guard)
             }
+            Self::NativeList(list_like) => {
+                validate_iter_to_vec(py, list_like.iter(), length, validator, extra, slots, recursion_guard)
+            }
         }
     }
 
@@ -148,6 +159,7 @@ impl<'a> GenericListLike<'a> {
             Self::Set(list_like) => list_like.iter().map(|i| i.to_object(py)).collect(),
             Self::FrozenSet(list_like) => list_like.iter().map(|i| i.to_object(py)).collect(),
             Self::JsonArray(list_like) => list_like.iter().map(|i| i.to_object(py)).collect(),
+            Self::NativeList(list_like) => list_like.iter().map(|i| i.to_object(py)).collect(),
         }
     }
 }
@@ -157,11 +169,13 @@ pub enum GenericMapping<'a> {
     PyDict(&'a PyDict),
     PyGetAttr(&'a PyAny),
     JsonObject(&'a JsonObject),
+    NativeDict(&'a NativeDict),
 }
 
 derive_from!(GenericMapping, PyDict, PyDict);
 derive_from!(GenericMapping, PyGetAttr, PyAny);
 derive_from!(GenericMapping, JsonObject, JsonObject);
+derive_from!(GenericMapping, NativeDict, NativeDict);
 
 #[cfg_attr(debug_assertions, derive(Debug))]
 pub struct PyArgs<'a> {
@@ -282,6 +296,13 @@ impl<'a> EitherBytes<'a> {
             EitherBytes::Py(py_bytes) => py_bytes.len(),
         }
     }
+
+    pub fn as_slice(&'a self) -> &'a [u8] {
+        match self {
+            EitherBytes::Cow(bytes) => bytes,
+            EitherBytes::Py(py_bytes) => py_bytes.as_bytes(),
+        }
+    }
 }
 
 impl<'a> IntoPy<PyObject> for EitherBytes<'a> {
@@ -61,6 +61,9 @@ pub fn float_as_int<'a>(input: &'a impl Input<'a>, float: f64) -> ValResult<'a,
         Err(ValError::new(ErrorKind::IntNan { nan_value: "NaN" }, input))
     } else if float % 1.0 != 0.0 {
         Err(ValError::new(ErrorKind::IntFromFloat, input))
+    } else if float >= i64::MAX as f64 || float < i64::MIN as f64 {
+        // `as i64` would saturate, `i64::MAX as f64` rounds up to 2**63 so this doesn't reject valid values
+        Err(ValError::new(ErrorKind::IntTooLarge, input))
     } else {
         Ok(float as i64)
     }
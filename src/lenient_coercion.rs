@@ -0,0 +1,126 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString};
+
+use crate::build_tools::{py_error, SchemaDict, SchemaPath};
+use crate::errors::{ErrorKind, ValError, ValResult};
+use crate::input::{EitherString, Input};
+
+/// Which coercions are allowed in lax mode, set via the `lenient_coercion` config dict,
+/// e.g. `{'lenient_coercion': {'coerce_str_to_int': False}}`, flags which aren't set keep the default lax behaviour,
+/// unknown flags are a schema error so a typo can't silently leave a coercion enabled.
+/// It's passed to validators via `Extra`, strict mode ignores it entirely.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LenientCoercionConfig(u8);
+
+impl LenientCoercionConfig {
+    /// `int` and `float` inputs to `str` validators
+    pub const COERCE_NUMBERS_TO_STR: u8 = 1 << 0;
+    /// `str` inputs to `int` validators
+    pub const COERCE_STR_TO_INT: u8 = 1 << 1;
+    /// `bool` inputs to `int` validators
+    pub const COERCE_BOOL_TO_INT: u8 = 1 << 2;
+    /// `str` inputs to `bool` validators
+    pub const COERCE_STR_TO_BOOL: u8 = 1 << 3;
+    /// `int` and `float` inputs to `bool` validators
+    pub const COERCE_NUMBERS_TO_BOOL: u8 = 1 << 4;
+
+    const FLAGS: [(&'static str, u8); 5] = [
+        ("coerce_numbers_to_str", Self::COERCE_NUMBERS_TO_STR),
+        ("coerce_str_to_int", Self::COERCE_STR_TO_INT),
+        ("coerce_bool_to_int", Self::COERCE_BOOL_TO_INT),
+        ("coerce_str_to_bool", Self::COERCE_STR_TO_BOOL),
+        ("coerce_numbers_to_bool", Self::COERCE_NUMBERS_TO_BOOL),
+    ];
+
+    pub fn from_config(config: Option<&PyDict>) -> PyResult<Self> {
+        let mut coercion = Self::default();
+        let py_config = match config {
+            Some(config) => config,
+            None => return Ok(coercion),
+        };
+        let flags: Option<&PyDict> = py_config.get_as(intern!(py_config.py(), "lenient_coercion"))?;
+        if let Some(flags) = flags {
+            for key in flags.keys() {
+                if !Self::FLAGS.iter().any(|(name, _)| key.eq(*name).unwrap_or(false)) {
+                    let expected = Self::FLAGS.map(|(name, _)| format!("'{}'", name)).join(", ");
+                    return py_error!("Unknown flag, should be one of: {}", expected)
+                        .with_schema_path(key.str()?)
+                        .with_schema_path("lenient_coercion");
+                }
+            }
+            for (name, flag) in Self::FLAGS {
+                match flags
+                    .get_as::<bool>(PyString::new(flags.py(), name))
+                    .with_schema_path("lenient_coercion")?
+                {
+                    Some(true) => coercion.0 |= flag,
+                    Some(false) => coercion.0 &= !flag,
+                    None => (),
+                }
+            }
+        }
+        Ok(coercion)
+    }
+
+    /// allow every coercion from `str`, for `validate_strings` where all inputs are strings
+    pub fn with_str_coercion(self) -> Self {
+        Self(self.0 | Self::COERCE_STR_TO_INT | Self::COERCE_STR_TO_BOOL)
+    }
+
+    pub fn allows(&self, flag: u8) -> bool {
+        self.0 & flag == flag
+    }
+
+    /// in lax mode, reject inputs whose coercion to `int` has been disabled
+    pub fn check_int<'a>(&self, input: &'a impl Input<'a>) -> ValResult<'a, ()> {
+        let disallowed = (!self.allows(Self::COERCE_BOOL_TO_INT) && input.strict_bool().is_ok())
+            || (!self.allows(Self::COERCE_STR_TO_INT) && input.strict_str().is_ok());
+        match disallowed {
+            true => Err(ValError::new(ErrorKind::IntType, input)),
+            false => Ok(()),
+        }
+    }
+
+    /// in lax mode, reject inputs whose coercion to `bool` has been disabled
+    pub fn check_bool<'a>(&self, input: &'a impl Input<'a>) -> ValResult<'a, ()> {
+        let disallowed = (!self.allows(Self::COERCE_STR_TO_BOOL) && input.strict_str().is_ok())
+            || (!self.allows(Self::COERCE_NUMBERS_TO_BOOL) && is_number(input));
+        match disallowed {
+            true => Err(ValError::new(ErrorKind::BoolType, input)),
+            false => Ok(()),
+        }
+    }
+
+    /// in lax mode, convert numbers to strings if `coerce_numbers_to_str` is set, using python's `str()`
+    pub fn number_as_str<'a>(
+        &self,
+        py: Python<'a>,
+        input: &'a impl Input<'a>,
+    ) -> ValResult<'a, Option<EitherString<'a>>> {
+        match self.allows(Self::COERCE_NUMBERS_TO_STR) && is_number(input) {
+            true => {
+                let py_str = input.to_object(py).into_ref(py).str()?;
+                Ok(Some(py_str.into()))
+            }
+            false => Ok(None),
+        }
+    }
+}
+
+impl Default for LenientCoercionConfig {
+    /// the lax behaviour before `lenient_coercion` was added
+    fn default() -> Self {
+        Self(
+            Self::COERCE_STR_TO_INT
+                | Self::COERCE_BOOL_TO_INT
+                | Self::COERCE_STR_TO_BOOL
+                | Self::COERCE_NUMBERS_TO_BOOL,
+        )
+    }
+}
+
+// bools are excluded by `strict_int` and `strict_float`
+fn is_number<'a>(input: &'a impl Input<'a>) -> bool {
+    input.strict_int().is_ok() || input.strict_float().is_ok()
+}
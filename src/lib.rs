@@ -10,13 +10,22 @@ static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
 mod build_tools;
 mod errors;
 mod input;
+mod lenient_coercion;
 mod lookup_key;
 mod recursion_guard;
+mod schema_cache;
+mod schema_serializer;
+mod secret_bytes;
+mod secret_str;
+mod undefined;
+mod url;
 mod validators;
 
 // required for benchmarks
 pub use build_tools::SchemaError;
-pub use errors::{PydanticValueError, ValidationError};
+pub use errors::{error_table, PydanticValueError, ValidationError};
+pub use input::NativeInput;
+pub use schema_serializer::SchemaSerializer;
 pub use validators::SchemaValidator;
 
 pub fn get_version() -> String {
@@ -30,11 +39,24 @@ pub fn get_version() -> String {
 }
 
 #[pymodule]
-fn _pydantic_core(_py: Python, m: &PyModule) -> PyResult<()> {
+fn _pydantic_core(py: Python, m: &PyModule) -> PyResult<()> {
     m.add("__version__", get_version())?;
     m.add_class::<SchemaValidator>()?;
+    m.add_class::<SchemaSerializer>()?;
     m.add_class::<ValidationError>()?;
     m.add_class::<SchemaError>()?;
     m.add_class::<PydanticValueError>()?;
+    m.add_class::<undefined::PydanticUndefinedType>()?;
+    m.add_class::<url::Url>()?;
+    m.add_class::<secret_str::SecretStr>()?;
+    m.add_class::<secret_bytes::SecretBytes>()?;
+    m.add("PydanticUndefined", undefined::PydanticUndefinedType::new(py))?;
+    m.add_function(wrap_pyfunction!(errors::custom_error, m)?)?;
+    m.add_function(wrap_pyfunction!(errors::py_error_kind_description, m)?)?;
+    m.add_function(wrap_pyfunction!(errors::list_all_errors, m)?)?;
+    m.add_function(wrap_pyfunction!(schema_cache::clear_schema_cache, m)?)?;
+    m.add_function(wrap_pyfunction!(schema_cache::schema_cache_info, m)?)?;
+    m.add_function(wrap_pyfunction!(validators::validate_many, m)?)?;
+    m.add_function(wrap_pyfunction!(validators::validate_native, m)?)?;
     Ok(())
 }
@@ -5,7 +5,7 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyString};
 
 use crate::build_tools::py_error;
-use crate::input::{JsonInput, JsonObject};
+use crate::input::{native_dict_get, JsonInput, JsonObject, NativeDict, NativeInput};
 
 /// Used got getting items from python dicts, python objects, or JSON objects, in different ways
 #[derive(Debug, Clone)]
@@ -80,6 +80,15 @@ impl LookupKey {
         LookupKey::Simple(key.to_string(), py_string!(py, key))
     }
 
+    /// the top level keys this lookup may read, e.g. the alias and, with `populate_by_name`, the field name
+    pub fn keys(&self) -> Vec<&str> {
+        match self {
+            Self::Simple(key, _) => vec![key],
+            Self::Choice(key1, key2, _, _) => vec![key1, key2],
+            Self::PathChoices(paths) => paths.iter().map(|path| path.first().unwrap().get_key()).collect(),
+        }
+    }
+
     fn path_choice(obj: &PyAny) -> PyResult<Path> {
         let path = obj
             .extract::<&PyList>()?
@@ -199,6 +208,32 @@ impl LookupKey {
             }
         }
     }
+
+    pub fn native_get<'data, 's>(&'s self, dict: &'data NativeDict) -> PyResult<Option<(&'s str, &'data NativeInput)>> {
+        let get = |key: &str| native_dict_get(dict, &NativeInput::Str(key.to_string()));
+        match self {
+            LookupKey::Simple(key, _) => Ok(get(key).map(|value| (key.as_str(), value))),
+            LookupKey::Choice(key1, key2, _, _) => match get(key1) {
+                Some(value) => Ok(Some((key1, value))),
+                None => Ok(get(key2).map(|value| (key2.as_str(), value))),
+            },
+            LookupKey::PathChoices(path_choices) => {
+                for path in path_choices {
+                    let mut path_iter = path.iter();
+                    // as with `json_get`, the first step is always a string key of `dict`
+                    let v = match path_iter.next().unwrap().native_dict_get(dict) {
+                        Some(v) => v,
+                        None => continue,
+                    };
+                    if let Some(v) = path_iter.try_fold(v, |d, loc| loc.native_get(d)) {
+                        let key = path.first().unwrap().get_key();
+                        return Ok(Some((key, v)));
+                    }
+                }
+                Ok(None)
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -292,6 +327,25 @@ impl PathItem {
         }
     }
 
+    /// like `py_get_item`, ints index lists and look up int keys in dicts
+    pub fn native_get<'a>(&self, native: &'a NativeInput) -> Option<&'a NativeInput> {
+        match native {
+            NativeInput::Dict(dict) => self.native_dict_get(dict),
+            NativeInput::List(list) => match self {
+                Self::I(index) => list.get(*index),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    pub fn native_dict_get<'a>(&self, dict: &'a NativeDict) -> Option<&'a NativeInput> {
+        match self {
+            Self::S(key, _) => native_dict_get(dict, &NativeInput::Str(key.clone())),
+            Self::I(index) => native_dict_get(dict, &NativeInput::Int(*index as i64)),
+        }
+    }
+
     pub fn json_obj_get<'a>(&self, json_obj: &'a JsonObject) -> Option<&'a JsonInput> {
         match self {
             Self::S(key, _) => json_obj.get(key),
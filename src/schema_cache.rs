@@ -0,0 +1,240 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Write;
+use std::sync::Mutex;
+
+use ahash::AHashMap;
+use pyo3::once_cell::GILOnceCell;
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyBytes, PyDict, PyFloat, PyList, PyLong, PyString, PyTuple};
+use pyo3::PyTypeInfo;
+
+use crate::validators::SchemaValidator;
+
+const DEFAULT_CACHE_SIZE: usize = 512;
+/// schemas nested more deeply than this aren't cached, this also guards against self-referencing dicts
+const MAX_FINGERPRINT_DEPTH: usize = 255;
+
+static SCHEMA_CACHE: GILOnceCell<Mutex<SchemaCache>> = GILOnceCell::new();
+
+/// A structural key for a schema and config: dicts, lists and tuples are compared by their contents, exact
+/// `str`, `int`, `float`, `bool`, `bytes` and `None` by value.
+///
+/// Schemas including any other object (functions, classes, defaults etc.) aren't cached, the cached validator
+/// would hold a strong reference to the object and keep it alive until the entry is evicted.
+pub struct Fingerprint {
+    key: String,
+}
+
+impl Fingerprint {
+    /// `None` if the schema can't be cached: if it's nested too deeply, or if it includes an object which isn't
+    /// a dict, list, tuple or one of the immutable builtins above
+    pub fn new(schema: &PyAny, config: Option<&PyDict>) -> PyResult<Option<Self>> {
+        let mut fingerprint = Self {
+            key: String::with_capacity(256),
+        };
+        if !fingerprint.push_value(schema, 0)? {
+            return Ok(None);
+        }
+        fingerprint.key.push('|');
+        if let Some(config) = config {
+            if !fingerprint.push_value(config, 0)? {
+                return Ok(None);
+            }
+        }
+        Ok(Some(fingerprint))
+    }
+
+    fn push_value(&mut self, value: &PyAny, depth: usize) -> PyResult<bool> {
+        if depth > MAX_FINGERPRINT_DEPTH {
+            return Ok(false);
+        }
+        if let Ok(dict) = value.cast_as::<PyDict>() {
+            write!(self.key, "d{}{{", dict.len()).unwrap();
+            for (k, v) in dict.iter() {
+                if !self.push_value(k, depth + 1)? {
+                    return Ok(false);
+                }
+                // defaults are returned as is in validator output, so two equal but distinct mutable defaults
+                // mustn't share a validator
+                let is_default = k.cast_as::<PyString>().ok().and_then(|s| s.to_str().ok()) == Some("default");
+                if is_default {
+                    if !self.push_primitive(v)? {
+                        return Ok(false);
+                    }
+                } else if !self.push_value(v, depth + 1)? {
+                    return Ok(false);
+                }
+            }
+            self.key.push('}');
+        } else if let Ok(list) = value.cast_as::<PyList>() {
+            write!(self.key, "l{}[", list.len()).unwrap();
+            for item in list.iter() {
+                if !self.push_value(item, depth + 1)? {
+                    return Ok(false);
+                }
+            }
+            self.key.push(']');
+        } else if PyTuple::is_exact_type_of(value) {
+            let tuple: &PyTuple = value.cast_as()?;
+            write!(self.key, "t{}(", tuple.len()).unwrap();
+            for item in tuple.iter() {
+                if !self.push_value(item, depth + 1)? {
+                    return Ok(false);
+                }
+            }
+            self.key.push(')');
+        } else {
+            return self.push_primitive(value);
+        }
+        Ok(true)
+    }
+
+    /// add immutable builtin values to the key, returns `false` if the value isn't one of them
+    fn push_primitive(&mut self, value: &PyAny) -> PyResult<bool> {
+        if value.is_none() {
+            self.key.push('N');
+        } else if PyBool::is_exact_type_of(value) {
+            let b: bool = value.extract()?;
+            self.key.push(if b { 'T' } else { 'F' });
+        } else if PyLong::is_exact_type_of(value) {
+            write!(self.key, "i{};", value.str()?.to_str()?).unwrap();
+        } else if PyFloat::is_exact_type_of(value) {
+            write!(self.key, "f{};", value.repr()?.to_str()?).unwrap();
+        } else if PyString::is_exact_type_of(value) {
+            let py_str: &PyString = value.cast_as()?;
+            match py_str.to_str() {
+                Ok(s) => write!(self.key, "s{:?}", s).unwrap(),
+                // e.g. lone surrogates
+                Err(_) => return Ok(false),
+            }
+        } else if PyBytes::is_exact_type_of(value) {
+            let py_bytes: &PyBytes = value.cast_as()?;
+            write!(self.key, "y{:?}", py_bytes.as_bytes()).unwrap();
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+}
+
+struct CacheEntry {
+    validator: SchemaValidator,
+    last_used: u64,
+}
+
+/// Least recently used cache of built `SchemaValidator`s, entries removed from the cache are returned
+/// so they can be dropped once the cache is unlocked, since dropping them may run arbitrary python code
+struct SchemaCache {
+    entries: AHashMap<String, CacheEntry>,
+    /// keys by when they were last used, the first key is the next to be evicted
+    order: BTreeMap<u64, String>,
+    max_size: usize,
+    hits: u64,
+    misses: u64,
+    counter: u64,
+}
+
+impl SchemaCache {
+    fn new() -> Self {
+        let max_size = match env::var("PYDANTIC_CORE_SCHEMA_CACHE_SIZE") {
+            Ok(size) => size.trim().parse().unwrap_or(DEFAULT_CACHE_SIZE),
+            Err(_) => DEFAULT_CACHE_SIZE,
+        };
+        Self {
+            entries: AHashMap::new(),
+            order: BTreeMap::new(),
+            max_size,
+            hits: 0,
+            misses: 0,
+            counter: 0,
+        }
+    }
+
+    fn get(&mut self, key: &str) -> Option<SchemaValidator> {
+        self.counter += 1;
+        let entry = match self.entries.get_mut(key) {
+            Some(entry) => entry,
+            None => {
+                self.misses += 1;
+                return None;
+            }
+        };
+        self.hits += 1;
+        self.order.remove(&entry.last_used);
+        entry.last_used = self.counter;
+        self.order.insert(self.counter, key.to_string());
+        Some(entry.validator.clone())
+    }
+
+    fn insert(&mut self, fingerprint: Fingerprint, validator: SchemaValidator) -> Vec<CacheEntry> {
+        let mut evicted = Vec::new();
+        if self.max_size == 0 {
+            return evicted;
+        }
+        // other validators may have been built and cached since the `get` for this schema
+        self.counter += 1;
+        evicted.extend(self.remove(&fingerprint.key));
+        while self.entries.len() >= self.max_size {
+            let oldest = match self.order.values().next() {
+                Some(oldest) => oldest.clone(),
+                None => break,
+            };
+            evicted.extend(self.remove(&oldest));
+        }
+        self.order.insert(self.counter, fingerprint.key.clone());
+        self.entries.insert(
+            fingerprint.key,
+            CacheEntry {
+                validator,
+                last_used: self.counter,
+            },
+        );
+        evicted
+    }
+
+    fn remove(&mut self, key: &str) -> Option<CacheEntry> {
+        let entry = self.entries.remove(key)?;
+        self.order.remove(&entry.last_used);
+        Some(entry)
+    }
+
+    fn clear(&mut self) -> Vec<CacheEntry> {
+        self.order.clear();
+        self.entries.drain().map(|(_, entry)| entry).collect()
+    }
+}
+
+fn schema_cache(py: Python) -> &'static Mutex<SchemaCache> {
+    SCHEMA_CACHE.get_or_init(py, || Mutex::new(SchemaCache::new()))
+}
+
+pub fn get(py: Python, fingerprint: &Fingerprint) -> Option<SchemaValidator> {
+    schema_cache(py).lock().unwrap().get(&fingerprint.key)
+}
+
+pub fn insert(py: Python, fingerprint: Fingerprint, validator: SchemaValidator) {
+    let _evicted = schema_cache(py).lock().unwrap().insert(fingerprint, validator);
+}
+
+/// remove all cached validators and reset the hit and miss counts
+#[pyfunction]
+pub fn clear_schema_cache(py: Python) {
+    let _evicted = {
+        let mut cache = schema_cache(py).lock().unwrap();
+        cache.hits = 0;
+        cache.misses = 0;
+        cache.clear()
+    };
+}
+
+#[pyfunction]
+pub fn schema_cache_info(py: Python) -> PyResult<PyObject> {
+    let cache = schema_cache(py).lock().unwrap();
+    let info = PyDict::new(py);
+    info.set_item("hits", cache.hits)?;
+    info.set_item("misses", cache.misses)?;
+    info.set_item("size", cache.entries.len())?;
+    info.set_item("max_size", cache.max_size)?;
+    Ok(info.into_py(py))
+}
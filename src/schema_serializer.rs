@@ -0,0 +1,766 @@
+use std::sync::Arc;
+
+use ahash::AHashSet;
+use pyo3::exceptions::PyValueError;
+use pyo3::once_cell::GILOnceCell;
+use pyo3::prelude::*;
+use pyo3::types::{
+    PyBool, PyByteArray, PyBytes, PyDate, PyDateTime, PyDelta, PyDeltaAccess, PyDict, PyFloat, PyFrozenSet, PyInt,
+    PyList, PySet, PyString, PyTime, PyTuple, PyType,
+};
+use pyo3::{intern, AsPyPointer};
+use serde::ser::{Error as SerError, Serialize, SerializeMap, SerializeSeq, Serializer};
+
+use crate::build_tools::{py_error, SchemaDict, SchemaPath};
+use crate::validators::validate_schema;
+
+/// Convert python objects, typically the output of a `SchemaValidator`, back to python primitives or JSON.
+///
+/// The schema has the same structure as the corresponding `SchemaValidator` schema and is checked against the same
+/// self-schema, containers and models are serialized according to the schema, other values are serialized based
+/// on their type.
+///
+/// The serializer tree is shared rather than copied by `clone`, see `SerializationCallable`.
+#[pyclass(module = "pydantic_core._pydantic_core")]
+#[derive(Debug, Clone)]
+pub struct SchemaSerializer {
+    serializer: Arc<TypeSerializer>,
+    slots: Arc<[TypeSerializer]>,
+    timedelta_mode: TimedeltaMode,
+}
+
+#[pymethods]
+impl SchemaSerializer {
+    #[new]
+    pub fn py_new(py: Python, schema: &PyAny, config: Option<&PyDict>) -> PyResult<Self> {
+        let schema_obj = validate_schema(py, schema)?;
+        let mut build_context = SerializerBuildContext::default();
+        let serializer = TypeSerializer::build(schema_obj.as_ref(py), &mut build_context)?;
+        Ok(Self {
+            serializer: Arc::new(serializer),
+            slots: build_context.into_slots()?.into(),
+            timedelta_mode: TimedeltaMode::from_config(config)?,
+        })
+    }
+
+    /// Python objects with the same types as the input, except models which become dicts
+    pub fn to_python(&self, py: Python, value: &PyAny) -> PyResult<PyObject> {
+        self.serialize(py, value, &self.serializer, SerMode::Python, &mut AHashSet::new())
+    }
+
+    /// Python objects which can be encoded as JSON, e.g. dates become ISO 8601 strings and sets become lists
+    pub fn to_jsonable_python(&self, py: Python, value: &PyAny) -> PyResult<PyObject> {
+        self.serialize(py, value, &self.serializer, SerMode::Json, &mut AHashSet::new())
+    }
+
+    pub fn to_json(&self, py: Python, value: &PyAny, indent: Option<usize>) -> PyResult<PyObject> {
+        let jsonable = self.to_jsonable_python(py, value)?;
+        let json_value = JsonValue(jsonable.as_ref(py));
+        let bytes = match indent {
+            Some(indent) => {
+                let indent = vec![b' '; indent];
+                let mut writer: Vec<u8> = Vec::with_capacity(128);
+                let formatter = serde_json::ser::PrettyFormatter::with_indent(&indent);
+                let mut ser = serde_json::Serializer::with_formatter(&mut writer, formatter);
+                json_value.serialize(&mut ser).map(|_| writer)
+            }
+            None => serde_json::to_vec(&json_value),
+        }
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok(PyBytes::new(py, &bytes).into_py(py))
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "SchemaSerializer(serializer={:#?}, slots={:#?})",
+            self.serializer, self.slots
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SerMode {
+    Python,
+    Json,
+}
+
+/// How `timedelta`s are serialized in JSON, set via `ser_json_timedelta` in config
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TimedeltaMode {
+    /// e.g. `P1DT2.5S`
+    Iso8601,
+    /// total seconds
+    Float,
+}
+
+impl TimedeltaMode {
+    fn from_config(config: Option<&PyDict>) -> PyResult<Self> {
+        let py_config = match config {
+            Some(config) => config,
+            None => return Ok(Self::Iso8601),
+        };
+        match py_config.get_as::<&str>(intern!(py_config.py(), "ser_json_timedelta"))? {
+            None | Some("iso8601") => Ok(Self::Iso8601),
+            Some("float") => Ok(Self::Float),
+            Some(s) => py_error!(r#"Invalid ser_json_timedelta: "{}""#, s),
+        }
+    }
+}
+
+/// Serializer for one node of the schema, schema types without a variant here are serialized based on the type
+/// of the value via `Any`
+#[derive(Debug, Clone)]
+enum TypeSerializer {
+    Any,
+    /// `list`, `set`, `frozenset` and variable length `tuple` schemas
+    Items(Box<TypeSerializer>),
+    /// positional `tuple` schemas, extra items are serialized as `Any`
+    TuplePositional(Vec<TypeSerializer>),
+    Dict {
+        keys: Box<TypeSerializer>,
+        values: Box<TypeSerializer>,
+    },
+    /// field name and serializer, keys which aren't fields are serialized as `Any`
+    TypedDict(Vec<(String, TypeSerializer)>),
+    /// the `__dict__` of the model is serialized with the inner `typed-dict` serializer
+    NewClass(Box<TypeSerializer>),
+    /// index into `slots`, used for schemas with `ref` and for `recursive-ref`
+    Ref(usize),
+    /// schemas with `serialization` set
+    Function(Box<FunctionSerializer>),
+}
+
+/// `function-plain` and `function-wrap` serializers, the function's output is used as the serialized value
+#[derive(Debug, Clone)]
+struct FunctionSerializer {
+    function: PyObject,
+    /// for `function-wrap`, the serializer for the schema itself, passed to the function as `serializer`
+    wrapped: Option<Arc<TypeSerializer>>,
+    return_type: ReturnType,
+}
+
+impl FunctionSerializer {
+    fn build(ser_schema: &PyDict, serializer: TypeSerializer) -> PyResult<TypeSerializer> {
+        let py = ser_schema.py();
+        let wrapped = match ser_schema.get_as_req::<&str>(intern!(py, "type"))? {
+            "function-wrap" => Some(Arc::new(serializer)),
+            _ => None,
+        };
+        let return_type = ReturnType::from_schema(ser_schema)?;
+        Ok(TypeSerializer::Function(Box::new(Self {
+            function: ser_schema.get_as_req::<&PyAny>(intern!(py, "function"))?.into_py(py),
+            wrapped,
+            return_type,
+        })))
+    }
+}
+
+/// `return_type` of a serialization function, in JSON mode output of that type is converted directly rather than
+/// going through `serialize_any`, output of another type falls back to `serialize_any`
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ReturnType {
+    Any,
+    None,
+    Int,
+    Bool,
+    Float,
+    Str,
+    Bytes,
+    Datetime,
+    Date,
+    Time,
+    Timedelta,
+}
+
+impl ReturnType {
+    fn from_schema(ser_schema: &PyDict) -> PyResult<Self> {
+        match ser_schema.get_as::<&str>(intern!(ser_schema.py(), "return_type"))? {
+            None | Some("any") => Ok(Self::Any),
+            Some("none") => Ok(Self::None),
+            Some("int") => Ok(Self::Int),
+            Some("bool") => Ok(Self::Bool),
+            Some("float") => Ok(Self::Float),
+            Some("str") => Ok(Self::Str),
+            Some("bytes") => Ok(Self::Bytes),
+            Some("datetime") => Ok(Self::Datetime),
+            Some("date") => Ok(Self::Date),
+            Some("time") => Ok(Self::Time),
+            Some("timedelta") => Ok(Self::Timedelta),
+            Some(s) => py_error!(r#"Invalid return_type: "{}""#, s),
+        }
+    }
+}
+
+impl TypeSerializer {
+    fn build(schema: &PyAny, build_context: &mut SerializerBuildContext) -> PyResult<Self> {
+        let py = schema.py();
+        let dict = match schema.cast_as::<PyDict>() {
+            Ok(dict) => dict,
+            // bare types like `'int'` have no nested schemas
+            Err(_) => return Ok(Self::Any),
+        };
+        let schema_type: &str = dict.get_as_req(intern!(py, "type"))?;
+        if schema_type == "recursive-ref" {
+            let schema_ref: String = dict.get_as_req(intern!(py, "schema_ref"))?;
+            return Ok(Self::Ref(build_context.find_slot_id(&schema_ref)?));
+        }
+        match dict.get_as::<String>(intern!(py, "ref"))? {
+            Some(schema_ref) => {
+                if let Some(slot_id) = build_context.get_slot_id(&schema_ref) {
+                    return Ok(Self::Ref(slot_id));
+                }
+                let slot_id = build_context.prepare_slot(schema_ref);
+                let serializer = Self::build_type(schema_type, dict, build_context).with_schema_path(schema_type)?;
+                build_context.complete_slot(slot_id, serializer);
+                Ok(Self::Ref(slot_id))
+            }
+            None => Self::build_type(schema_type, dict, build_context).with_schema_path(schema_type),
+        }
+    }
+
+    fn build_type(schema_type: &str, schema: &PyDict, build_context: &mut SerializerBuildContext) -> PyResult<Self> {
+        let serializer = Self::build_schema_type(schema_type, schema, build_context)?;
+        match schema.get_as::<&PyDict>(intern!(schema.py(), "serialization"))? {
+            Some(ser_schema) => FunctionSerializer::build(ser_schema, serializer).with_schema_path("serialization"),
+            None => Ok(serializer),
+        }
+    }
+
+    fn build_schema_type(
+        schema_type: &str,
+        schema: &PyDict,
+        build_context: &mut SerializerBuildContext,
+    ) -> PyResult<Self> {
+        let py = schema.py();
+        let build_item = |key: &'static str, build_context: &mut SerializerBuildContext| -> PyResult<Self> {
+            match schema.get_item(key) {
+                Some(item_schema) => Self::build(item_schema, build_context).with_schema_path(key),
+                None => Ok(Self::Any),
+            }
+        };
+        match schema_type {
+            "list" | "set" | "frozenset" => Ok(Self::Items(Box::new(build_item("items_schema", build_context)?))),
+            "tuple" => match schema.get_as::<&str>(intern!(py, "mode"))? {
+                Some("positional") => {
+                    let items_schema: &PyList = schema.get_as_req(intern!(py, "items_schema"))?;
+                    let serializers = items_schema
+                        .iter()
+                        .enumerate()
+                        .map(|(index, item_schema)| Self::build(item_schema, build_context).with_schema_path(index))
+                        .collect::<PyResult<Vec<Self>>>()
+                        .with_schema_path("items_schema")?;
+                    Ok(Self::TuplePositional(serializers))
+                }
+                _ => Ok(Self::Items(Box::new(build_item("items_schema", build_context)?))),
+            },
+            "dict" | "mapping" => Ok(Self::Dict {
+                keys: Box::new(build_item("keys_schema", build_context)?),
+                values: Box::new(build_item("values_schema", build_context)?),
+            }),
+            "typed-dict" => {
+                let fields: &PyDict = schema.get_as_req(intern!(py, "fields"))?;
+                let serializers = fields
+                    .iter()
+                    .map(|(name, field_info)| {
+                        let name: String = name.extract()?;
+                        let field_info: &PyDict = field_info.cast_as()?;
+                        let serializer = Self::build(field_info.get_as_req(intern!(py, "schema"))?, build_context)
+                            .with_schema_path("schema")
+                            .with_schema_path(name.as_str())?;
+                        Ok((name, serializer))
+                    })
+                    .collect::<PyResult<Vec<(String, Self)>>>()
+                    .with_schema_path("fields")?;
+                Ok(Self::TypedDict(serializers))
+            }
+            "new-class" => Ok(Self::NewClass(Box::new(build_item("schema", build_context)?))),
+            // the output has the type of the inner schema
+            "nullable" | "function" | "span" | "chain-map" => build_item("schema", build_context),
+            "chain" => {
+                let steps: &PyList = schema.get_as_req(intern!(py, "steps"))?;
+                match steps.iter().last() {
+                    Some(last_step) => Self::build(last_step, build_context).with_schema_path("steps"),
+                    None => Ok(Self::Any),
+                }
+            }
+            _ => Ok(Self::Any),
+        }
+    }
+}
+
+/// Equivalent of `BuildContext` for serializers, slots are named by `ref`
+#[derive(Default)]
+struct SerializerBuildContext {
+    slots: Vec<(String, Option<TypeSerializer>)>,
+}
+
+impl SerializerBuildContext {
+    fn prepare_slot(&mut self, slot_ref: String) -> usize {
+        self.slots.push((slot_ref, None));
+        self.slots.len() - 1
+    }
+
+    fn complete_slot(&mut self, slot_id: usize, serializer: TypeSerializer) {
+        self.slots[slot_id].1 = Some(serializer);
+    }
+
+    fn get_slot_id(&self, slot_ref: &str) -> Option<usize> {
+        self.slots.iter().position(|(r, _)| r == slot_ref)
+    }
+
+    fn find_slot_id(&self, slot_ref: &str) -> PyResult<usize> {
+        match self.get_slot_id(slot_ref) {
+            Some(id) => Ok(id),
+            None => py_error!("Slots Error: ref '{}' not found", slot_ref),
+        }
+    }
+
+    fn into_slots(self) -> PyResult<Vec<TypeSerializer>> {
+        self.slots
+            .into_iter()
+            .map(|(slot_ref, serializer)| match serializer {
+                Some(serializer) => Ok(serializer),
+                None => py_error!("Slots Error: slot '{}' not yet filled", slot_ref),
+            })
+            .collect()
+    }
+}
+
+impl SchemaSerializer {
+    fn serialize(
+        &self,
+        py: Python,
+        value: &PyAny,
+        serializer: &TypeSerializer,
+        mode: SerMode,
+        seen: &mut AHashSet<usize>,
+    ) -> PyResult<PyObject> {
+        if value.is_none() {
+            return Ok(py.None());
+        }
+        match serializer {
+            TypeSerializer::Any => self.serialize_any(py, value, mode, seen),
+            TypeSerializer::Ref(slot_id) => self.serialize(py, value, &self.slots[*slot_id], mode, seen),
+            TypeSerializer::Items(items) => match self.serialize_items(py, value, |_| items, mode, seen)? {
+                Some(output) => Ok(output),
+                None => self.serialize_any(py, value, mode, seen),
+            },
+            TypeSerializer::TuplePositional(items) => {
+                let item_serializer = |index: usize| items.get(index).unwrap_or(&TypeSerializer::Any);
+                match self.serialize_items(py, value, item_serializer, mode, seen)? {
+                    Some(output) => Ok(output),
+                    None => self.serialize_any(py, value, mode, seen),
+                }
+            }
+            TypeSerializer::Dict { keys, values } => match value.cast_as::<PyDict>() {
+                Ok(dict) => self.serialize_dict(py, dict, |_| (keys, values), mode, seen),
+                Err(_) => self.serialize_any(py, value, mode, seen),
+            },
+            TypeSerializer::TypedDict(fields) => match value.cast_as::<PyDict>() {
+                Ok(dict) => {
+                    let field_serializer = |key: &PyAny| {
+                        let field = key
+                            .cast_as::<PyString>()
+                            .ok()
+                            .and_then(|key| key.to_str().ok())
+                            .and_then(|key| fields.iter().find(|(name, _)| name == key));
+                        match field {
+                            Some((_, serializer)) => (&TypeSerializer::Any, serializer),
+                            None => (&TypeSerializer::Any, &TypeSerializer::Any),
+                        }
+                    };
+                    self.serialize_dict(py, dict, field_serializer, mode, seen)
+                }
+                Err(_) => self.serialize_any(py, value, mode, seen),
+            },
+            TypeSerializer::NewClass(inner) => match value.getattr(intern!(py, "__dict__")) {
+                Ok(model_dict) => self.serialize(py, model_dict, inner, mode, seen),
+                Err(_) => self.serialize(py, value, inner, mode, seen),
+            },
+            TypeSerializer::Function(function_serializer) => {
+                self.serialize_function(py, value, function_serializer, mode, seen)
+            }
+        }
+    }
+
+    /// in python mode the function's output is returned as is, in JSON mode it's converted according to
+    /// `return_type` so it can be encoded
+    fn serialize_function(
+        &self,
+        py: Python,
+        value: &PyAny,
+        function_serializer: &FunctionSerializer,
+        mode: SerMode,
+        seen: &mut AHashSet<usize>,
+    ) -> PyResult<PyObject> {
+        let info = SerializationInfo { mode };
+        let output = match function_serializer.wrapped {
+            Some(ref wrapped) => {
+                // `seen` is lent to the callable while the function runs, then taken back, rather than copied
+                let serializer = Py::new(
+                    py,
+                    SerializationCallable {
+                        schema_serializer: self.clone(),
+                        serializer: wrapped.clone(),
+                        mode,
+                        seen: std::mem::take(seen),
+                    },
+                )?;
+                let output = function_serializer
+                    .function
+                    .call1(py, (value, serializer.clone_ref(py), info));
+                *seen = std::mem::take(&mut serializer.borrow_mut(py).seen);
+                output?
+            }
+            None => function_serializer.function.call1(py, (value, info))?,
+        };
+        match mode {
+            SerMode::Python => Ok(output),
+            SerMode::Json => self.serialize_return(py, output.as_ref(py), function_serializer.return_type, seen),
+        }
+    }
+
+    fn serialize_return(
+        &self,
+        py: Python,
+        value: &PyAny,
+        return_type: ReturnType,
+        seen: &mut AHashSet<usize>,
+    ) -> PyResult<PyObject> {
+        let output = match return_type {
+            ReturnType::None if value.is_none() => Some(py.None()),
+            ReturnType::Int if value.cast_as::<PyInt>().is_ok() => Some(value.into_py(py)),
+            ReturnType::Bool if value.cast_as::<PyBool>().is_ok() => Some(value.into_py(py)),
+            ReturnType::Float if value.cast_as::<PyFloat>().is_ok() => Some(value.into_py(py)),
+            ReturnType::Str if value.cast_as::<PyString>().is_ok() => Some(value.into_py(py)),
+            ReturnType::Bytes => match value.cast_as::<PyBytes>() {
+                Ok(py_bytes) => Some(bytes_as_str(py, py_bytes.as_bytes())?),
+                Err(_) => None,
+            },
+            ReturnType::Datetime if value.cast_as::<PyDateTime>().is_ok() => {
+                Some(value.call_method0("isoformat")?.into_py(py))
+            }
+            ReturnType::Date if value.cast_as::<PyDate>().is_ok() => Some(value.call_method0("isoformat")?.into_py(py)),
+            ReturnType::Time if value.cast_as::<PyTime>().is_ok() => Some(value.call_method0("isoformat")?.into_py(py)),
+            ReturnType::Timedelta => match value.cast_as::<PyDelta>() {
+                Ok(py_timedelta) => Some(timedelta_json(py, py_timedelta, self.timedelta_mode)),
+                Err(_) => None,
+            },
+            _ => None,
+        };
+        match output {
+            Some(output) => Ok(output),
+            None => self.serialize_any(py, value, SerMode::Json, seen),
+        }
+    }
+
+    /// serialize `list`, `tuple`, `set` and `frozenset`, returns `None` for other types,
+    /// in python mode the container type is preserved, in JSON mode they all become lists
+    fn serialize_items<'s>(
+        &'s self,
+        py: Python,
+        value: &PyAny,
+        item_serializer: impl Fn(usize) -> &'s TypeSerializer,
+        mode: SerMode,
+        seen: &mut AHashSet<usize>,
+    ) -> PyResult<Option<PyObject>> {
+        let is_container = value.cast_as::<PyList>().is_ok()
+            || value.cast_as::<PyTuple>().is_ok()
+            || value.cast_as::<PySet>().is_ok()
+            || value.cast_as::<PyFrozenSet>().is_ok();
+        if !is_container {
+            return Ok(None);
+        }
+        let id = enter(value, seen)?;
+        let items = value
+            .iter()?
+            .enumerate()
+            .map(|(index, item)| self.serialize(py, item?, item_serializer(index), mode, seen))
+            .collect::<PyResult<Vec<PyObject>>>();
+        seen.remove(&id);
+        let items = items?;
+        let output = if mode == SerMode::Json || value.cast_as::<PyList>().is_ok() {
+            PyList::new(py, items).into_py(py)
+        } else if value.cast_as::<PyTuple>().is_ok() {
+            PyTuple::new(py, items).into_py(py)
+        } else if value.cast_as::<PySet>().is_ok() {
+            PySet::new(py, &items)?.into_py(py)
+        } else {
+            PyFrozenSet::new(py, &items)?.into_py(py)
+        };
+        Ok(Some(output))
+    }
+
+    fn serialize_dict<'s>(
+        &'s self,
+        py: Python,
+        dict: &PyDict,
+        entry_serializers: impl Fn(&PyAny) -> (&'s TypeSerializer, &'s TypeSerializer),
+        mode: SerMode,
+        seen: &mut AHashSet<usize>,
+    ) -> PyResult<PyObject> {
+        let id = enter(dict, seen)?;
+        let output = PyDict::new(py);
+        let result = dict.iter().try_for_each(|(key, value)| {
+            let (key_serializer, value_serializer) = entry_serializers(key);
+            let output_key = self.serialize(py, key, key_serializer, mode, seen)?;
+            let output_key = match mode {
+                SerMode::Python => output_key,
+                SerMode::Json => json_key(py, output_key.as_ref(py))?,
+            };
+            let output_value = self.serialize(py, value, value_serializer, mode, seen)?;
+            output.set_item(output_key, output_value)
+        });
+        seen.remove(&id);
+        result?;
+        Ok(output.into_py(py))
+    }
+
+    /// serialize based on the type of `value`, used where the schema doesn't determine the output
+    fn serialize_any(
+        &self,
+        py: Python,
+        value: &PyAny,
+        mode: SerMode,
+        seen: &mut AHashSet<usize>,
+    ) -> PyResult<PyObject> {
+        if value.is_none()
+            || value.cast_as::<PyBool>().is_ok()
+            || value.cast_as::<PyInt>().is_ok()
+            || value.cast_as::<PyFloat>().is_ok()
+            || value.cast_as::<PyString>().is_ok()
+        {
+            return Ok(value.into_py(py));
+        }
+        if let Some(output) = self.serialize_items(py, value, |_| &TypeSerializer::Any, mode, seen)? {
+            return Ok(output);
+        }
+        if let Ok(dict) = value.cast_as::<PyDict>() {
+            return self.serialize_dict(py, dict, |_| (&TypeSerializer::Any, &TypeSerializer::Any), mode, seen);
+        }
+        // model instances, e.g. the output of `new-class` validators
+        if value.hasattr(intern!(py, "__fields_set__"))? {
+            let model_dict = value.getattr(intern!(py, "__dict__"))?;
+            let id = enter(value, seen)?;
+            let output = self.serialize_any(py, model_dict, mode, seen);
+            seen.remove(&id);
+            return output;
+        }
+        if mode == SerMode::Python {
+            return Ok(value.into_py(py));
+        }
+
+        if let Ok(py_bytes) = value.cast_as::<PyBytes>() {
+            bytes_as_str(py, py_bytes.as_bytes())
+        } else if let Ok(py_byte_array) = value.cast_as::<PyByteArray>() {
+            bytes_as_str(py, &py_byte_array.to_vec())
+        } else if value.cast_as::<PyDateTime>().is_ok()
+            || value.cast_as::<PyDate>().is_ok()
+            || value.cast_as::<PyTime>().is_ok()
+        {
+            Ok(value.call_method0("isoformat")?.into_py(py))
+        } else if let Ok(py_timedelta) = value.cast_as::<PyDelta>() {
+            Ok(timedelta_json(py, py_timedelta, self.timedelta_mode))
+        } else if value.is_instance(json_types(py).enum_type.as_ref(py))? {
+            let enum_value = value.getattr(intern!(py, "value"))?;
+            self.serialize_any(py, enum_value, mode, seen)
+        } else if value.is_instance(json_types(py).secret_bytes_type.as_ref(py))? {
+            let secret_value = value.call_method0("get_secret_value")?;
+            let encoded = json_types(py).b64encode.call1(py, (secret_value,))?;
+            encoded.call_method1(py, "decode", ("ascii",))
+        } else if json_types(py)
+            .str_types
+            .iter()
+            .any(|str_type| value.is_instance(str_type.as_ref(py)).unwrap_or(false))
+        {
+            Ok(value.str()?.into_py(py))
+        } else {
+            let type_name = value.get_type().name().unwrap_or("unknown");
+            py_error!(PyValueError; "Unable to serialize unknown type: {}", type_name)
+        }
+    }
+}
+
+/// Passed to serialization functions as `info`
+#[pyclass(module = "pydantic_core._pydantic_core")]
+#[derive(Debug, Clone)]
+struct SerializationInfo {
+    mode: SerMode,
+}
+
+#[pymethods]
+impl SerializationInfo {
+    /// `'python'` for `to_python`, `'json'` for `to_jsonable_python` and `to_json`
+    #[getter]
+    fn mode(&self) -> &'static str {
+        match self.mode {
+            SerMode::Python => "python",
+            SerMode::Json => "json",
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("SerializationInfo(mode='{}')", self.mode())
+    }
+}
+
+/// Passed to `function-wrap` serialization functions as `serializer`, calling it serializes a value according to
+/// the schema the function is attached to, the `SchemaSerializer` and the wrapped node are shared with the
+/// serializer which created it
+#[pyclass(module = "pydantic_core._pydantic_core")]
+#[derive(Debug, Clone)]
+struct SerializationCallable {
+    schema_serializer: SchemaSerializer,
+    serializer: Arc<TypeSerializer>,
+    mode: SerMode,
+    seen: AHashSet<usize>,
+}
+
+#[pymethods]
+impl SerializationCallable {
+    fn __call__(&mut self, py: Python, value: &PyAny) -> PyResult<PyObject> {
+        self.schema_serializer
+            .serialize(py, value, &self.serializer, self.mode, &mut self.seen)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("SerializationCallable({:?})", self.serializer)
+    }
+}
+
+/// record that we're serializing the contents of `value`, so circular references are an error
+/// rather than a stack overflow, the returned id should be removed from `seen` once `value` is finished
+fn enter(value: &PyAny, seen: &mut AHashSet<usize>) -> PyResult<usize> {
+    let id = value.as_ptr() as usize;
+    match seen.insert(id) {
+        true => Ok(id),
+        false => py_error!(PyValueError; "Circular reference detected (id repeated)"),
+    }
+}
+
+/// JSON object keys must be strings, other keys are converted as python's `json` module does
+fn json_key(py: Python, key: &PyAny) -> PyResult<PyObject> {
+    if key.cast_as::<PyString>().is_ok() {
+        Ok(key.into_py(py))
+    } else if key.is_none() {
+        Ok("null".into_py(py))
+    } else if let Ok(py_bool) = key.cast_as::<PyBool>() {
+        Ok(if py_bool.is_true() { "true" } else { "false" }.into_py(py))
+    } else if key.cast_as::<PyInt>().is_ok() || key.cast_as::<PyFloat>().is_ok() {
+        Ok(key.str()?.into_py(py))
+    } else {
+        let type_name = key.get_type().name().unwrap_or("unknown");
+        py_error!(PyValueError; "Dict keys must be str, int, float, bool or None in JSON, not {}", type_name)
+    }
+}
+
+fn bytes_as_str(py: Python, bytes: &[u8]) -> PyResult<PyObject> {
+    match std::str::from_utf8(bytes) {
+        Ok(s) => Ok(s.into_py(py)),
+        Err(_) => py_error!(PyValueError; "bytes are not valid UTF-8 and can't be serialized to JSON"),
+    }
+}
+
+fn timedelta_json(py: Python, py_timedelta: &PyDelta, timedelta_mode: TimedeltaMode) -> PyObject {
+    let total_microseconds = (py_timedelta.get_days() as i64 * 86_400 + py_timedelta.get_seconds() as i64) * 1_000_000
+        + py_timedelta.get_microseconds() as i64;
+    match timedelta_mode {
+        TimedeltaMode::Float => (total_microseconds as f64 / 1_000_000.0).into_py(py),
+        TimedeltaMode::Iso8601 => {
+            let sign = if total_microseconds < 0 { "-" } else { "" };
+            let total_microseconds = total_microseconds.unsigned_abs();
+            let days = total_microseconds / 86_400_000_000;
+            let seconds = (total_microseconds % 86_400_000_000) / 1_000_000;
+            let microseconds = total_microseconds % 1_000_000;
+            let mut iso = format!("{}P", sign);
+            if days > 0 {
+                iso.push_str(&format!("{}D", days));
+            }
+            if seconds > 0 || microseconds > 0 || days == 0 {
+                iso.push_str(&format!("T{}", seconds));
+                if microseconds > 0 {
+                    let fraction = format!("{:06}", microseconds);
+                    iso.push_str(&format!(".{}", fraction.trim_end_matches('0')));
+                }
+                iso.push('S');
+            }
+            iso.into_py(py)
+        }
+    }
+}
+
+struct JsonTypes {
+    enum_type: Py<PyType>,
+    /// types serialized as `str(value)` in JSON
+    str_types: Vec<Py<PyType>>,
+    /// `SecretBytes` are serialized as their value base64 encoded in JSON
+    secret_bytes_type: Py<PyType>,
+    b64encode: PyObject,
+}
+
+static JSON_TYPES: GILOnceCell<JsonTypes> = GILOnceCell::new();
+
+fn json_types(py: Python<'_>) -> &JsonTypes {
+    JSON_TYPES.get_or_init(py, || {
+        let get_type = |module: &str, name: &str| -> Py<PyType> {
+            py.import(module).unwrap().getattr(name).unwrap().extract().unwrap()
+        };
+        JsonTypes {
+            enum_type: get_type("enum", "Enum"),
+            str_types: vec![
+                get_type("decimal", "Decimal"),
+                get_type("uuid", "UUID"),
+                get_type("ipaddress", "IPv4Address"),
+                get_type("ipaddress", "IPv6Address"),
+                get_type("pydantic_core._pydantic_core", "Url"),
+                get_type("pydantic_core._pydantic_core", "SecretStr"),
+            ],
+            secret_bytes_type: get_type("pydantic_core._pydantic_core", "SecretBytes"),
+            b64encode: py.import("base64").unwrap().getattr("b64encode").unwrap().into_py(py),
+        }
+    })
+}
+
+/// Encode the output of `to_jsonable_python` with serde
+struct JsonValue<'py>(&'py PyAny);
+
+impl<'py> Serialize for JsonValue<'py> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let value = self.0;
+        if value.is_none() {
+            serializer.serialize_none()
+        } else if let Ok(py_bool) = value.cast_as::<PyBool>() {
+            serializer.serialize_bool(py_bool.is_true())
+        } else if value.cast_as::<PyInt>().is_ok() {
+            if let Ok(int) = value.extract::<i64>() {
+                serializer.serialize_i64(int)
+            } else if let Ok(int) = value.extract::<u64>() {
+                serializer.serialize_u64(int)
+            } else {
+                Err(S::Error::custom("int is too large to serialize to JSON"))
+            }
+        } else if let Ok(py_float) = value.cast_as::<PyFloat>() {
+            serializer.serialize_f64(py_float.value())
+        } else if let Ok(py_str) = value.cast_as::<PyString>() {
+            serializer.serialize_str(py_str.to_str().map_err(|e| S::Error::custom(e.to_string()))?)
+        } else if let Ok(py_list) = value.cast_as::<PyList>() {
+            let mut seq = serializer.serialize_seq(Some(py_list.len()))?;
+            for item in py_list.iter() {
+                seq.serialize_element(&JsonValue(item))?;
+            }
+            seq.end()
+        } else if let Ok(py_dict) = value.cast_as::<PyDict>() {
+            let mut map = serializer.serialize_map(Some(py_dict.len()))?;
+            for (key, value) in py_dict.iter() {
+                map.serialize_entry(&JsonValue(key), &JsonValue(value))?;
+            }
+            map.end()
+        } else {
+            let type_name = value.get_type().name().unwrap_or("unknown");
+            Err(S::Error::custom(format!(
+                "Unable to serialize unknown type: {}",
+                type_name
+            )))
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use pyo3::prelude::*;
+use pyo3::pyclass::CompareOp;
+use pyo3::types::PyBytes;
+
+/// Bytes whose value is hidden in `repr()` and `str()`, the value is available via `get_secret_value()`.
+///
+/// Unlike `SecretStr`, the length is shown, e.g. `SecretBytes(b'**10 bytes**')`, this is deliberate since the
+/// length of binary secrets like keys is usually fixed and already known, and it makes debugging easier.
+/// In JSON, `SchemaSerializer` outputs the value base64 encoded.
+#[pyclass(module = "pydantic_core._pydantic_core")]
+#[derive(Clone)]
+pub struct SecretBytes {
+    secret_value: Vec<u8>,
+}
+
+#[pymethods]
+impl SecretBytes {
+    pub fn get_secret_value<'py>(&self, py: Python<'py>) -> &'py PyBytes {
+        PyBytes::new(py, &self.secret_value)
+    }
+
+    fn __str__(&self) -> String {
+        match self.secret_value.len() {
+            1 => "b'**1 byte**'".to_string(),
+            len => format!("b'**{} bytes**'", len),
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("SecretBytes({})", self.__str__())
+    }
+
+    fn __len__(&self) -> usize {
+        self.secret_value.len()
+    }
+
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyObject {
+        let py = other.py();
+        match other.extract::<PyRef<Self>>() {
+            Ok(other) => match op {
+                CompareOp::Eq => (self.secret_value == other.secret_value).into_py(py),
+                CompareOp::Ne => (self.secret_value != other.secret_value).into_py(py),
+                _ => py.NotImplemented(),
+            },
+            Err(_) => py.NotImplemented(),
+        }
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.secret_value.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+impl SecretBytes {
+    pub fn new(secret_value: Vec<u8>) -> Self {
+        Self { secret_value }
+    }
+}
@@ -0,0 +1,58 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use pyo3::prelude::*;
+use pyo3::pyclass::CompareOp;
+
+/// shown instead of the value in `repr()` and `str()`
+const MASK: &str = "**********";
+
+/// A string whose value is hidden in `repr()` and `str()`, the value is available via `get_secret_value()`
+#[pyclass(module = "pydantic_core._pydantic_core")]
+#[derive(Clone)]
+pub struct SecretStr {
+    secret_value: String,
+}
+
+#[pymethods]
+impl SecretStr {
+    pub fn get_secret_value(&self) -> &str {
+        &self.secret_value
+    }
+
+    fn __str__(&self) -> &'static str {
+        MASK
+    }
+
+    fn __repr__(&self) -> String {
+        format!("SecretStr('{}')", MASK)
+    }
+
+    fn __len__(&self) -> usize {
+        self.secret_value.chars().count()
+    }
+
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyObject {
+        let py = other.py();
+        match other.extract::<PyRef<Self>>() {
+            Ok(other) => match op {
+                CompareOp::Eq => (self.secret_value == other.secret_value).into_py(py),
+                CompareOp::Ne => (self.secret_value != other.secret_value).into_py(py),
+                _ => py.NotImplemented(),
+            },
+            Err(_) => py.NotImplemented(),
+        }
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.secret_value.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+impl SecretStr {
+    pub fn new(secret_value: String) -> Self {
+        Self { secret_value }
+    }
+}
@@ -0,0 +1,45 @@
+use pyo3::once_cell::GILOnceCell;
+use pyo3::prelude::*;
+use pyo3::AsPyPointer;
+
+static UNDEFINED_CELL: GILOnceCell<Py<PydanticUndefinedType>> = GILOnceCell::new();
+
+/// singleton used to mark a value as "not set", e.g. a field default which is the same as no default
+#[pyclass(module = "pydantic_core._pydantic_core")]
+#[derive(Debug)]
+pub struct PydanticUndefinedType {}
+
+#[pymethods]
+impl PydanticUndefinedType {
+    fn __repr__(&self) -> &'static str {
+        "PydanticUndefined"
+    }
+
+    fn __copy__(&self, py: Python) -> Py<Self> {
+        Self::new(py)
+    }
+
+    fn __deepcopy__(&self, py: Python, _memo: &PyAny) -> Py<Self> {
+        Self::new(py)
+    }
+
+    fn __reduce__(&self) -> &'static str {
+        // pickle looks up `PydanticUndefined` on this class's module, so the singleton survives a round trip
+        "PydanticUndefined"
+    }
+}
+
+impl PydanticUndefinedType {
+    pub fn new(py: Python) -> Py<Self> {
+        get_undefined(py).clone_ref(py)
+    }
+}
+
+fn get_undefined(py: Python<'_>) -> &Py<PydanticUndefinedType> {
+    UNDEFINED_CELL.get_or_init(py, || Py::new(py, PydanticUndefinedType {}).unwrap())
+}
+
+/// identity check against `PydanticUndefined` by pointer comparison, no python comparison is involved
+pub fn is_undefined(ob: &PyAny) -> bool {
+    ob.as_ptr() == get_undefined(ob.py()).as_ptr()
+}
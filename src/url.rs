@@ -0,0 +1,117 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use pyo3::prelude::*;
+use pyo3::pyclass::CompareOp;
+use url::Host;
+
+/// A parsed URL, a thin wrapper around `url::Url` which stores the serialized URL and the offsets of each part,
+/// so attribute access is a slice of the string, never a re-parse.
+///
+/// Parsing and normalization follow the WHATWG URL standard as implemented by the `url` crate, for "special"
+/// schemes (http, https, ws, wss, ftp, file):
+/// * the scheme and host are lowercased, international (IDN) hosts are converted to punycode,
+///   e.g. `http://münchen.de` has `host == 'xn--mnchen-3ya.de'`
+/// * default ports are removed, e.g. `http://example.com:80` has `port == None`, a port which isn't the
+///   default for the scheme, or any port for a non-special scheme, e.g. `postgres://localhost:5432`, is kept
+/// * an empty path is replaced with `/`, e.g. `https://example.com` becomes `https://example.com/`,
+///   but an existing trailing slash is never added or removed otherwise, e.g. `https://example.com/foo`
+///   and `https://example.com/foo/` are different URLs; non-special schemes keep an empty path,
+///   e.g. `redis://localhost`
+#[pyclass(module = "pydantic_core._pydantic_core")]
+#[derive(Debug, Clone)]
+pub struct Url {
+    lib_url: url::Url,
+}
+
+#[pymethods]
+impl Url {
+    #[getter]
+    pub fn scheme(&self) -> &str {
+        self.lib_url.scheme()
+    }
+
+    #[getter]
+    fn username(&self) -> Option<&str> {
+        match self.lib_url.username() {
+            "" => None,
+            username => Some(username),
+        }
+    }
+
+    #[getter]
+    fn password(&self) -> Option<&str> {
+        self.lib_url.password()
+    }
+
+    /// IPv6 hosts are returned without the brackets used in the URL, e.g. `::1`
+    #[getter]
+    fn host(&self) -> Option<String> {
+        match self.lib_url.host()? {
+            Host::Ipv6(address) => Some(address.to_string()),
+            _ => self.lib_url.host_str().map(ToString::to_string),
+        }
+    }
+
+    #[getter]
+    fn port(&self) -> Option<u16> {
+        self.lib_url.port()
+    }
+
+    #[getter]
+    fn path(&self) -> &str {
+        self.lib_url.path()
+    }
+
+    #[getter]
+    fn query(&self) -> Option<&str> {
+        self.lib_url.query()
+    }
+
+    #[getter]
+    fn fragment(&self) -> Option<&str> {
+        self.lib_url.fragment()
+    }
+
+    fn __str__(&self) -> &str {
+        self.lib_url.as_str()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Url('{}')", self.lib_url)
+    }
+
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyObject {
+        let py = other.py();
+        match other.extract::<PyRef<Self>>() {
+            Ok(other) => match op {
+                CompareOp::Eq => (self.lib_url == other.lib_url).into_py(py),
+                CompareOp::Ne => (self.lib_url != other.lib_url).into_py(py),
+                _ => py.NotImplemented(),
+            },
+            Err(_) => py.NotImplemented(),
+        }
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.lib_url.as_str().hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+impl Url {
+    pub fn as_str(&self) -> &str {
+        self.lib_url.as_str()
+    }
+
+    pub fn has_host(&self) -> bool {
+        self.lib_url.has_host()
+    }
+
+    /// Parse a string into a `Url`, the error is the `url` crate's description of why the string isn't a
+    /// valid URL, e.g. "relative URL without a base".
+    pub fn parse(url_str: &str) -> Result<Self, url::ParseError> {
+        url::Url::parse(url_str).map(|lib_url| Self { lib_url })
+    }
+}
@@ -39,4 +39,8 @@ impl Validator for AnyValidator {
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
+
+    fn expected_repr(&self) -> String {
+        "Any".to_string()
+    }
 }
@@ -3,14 +3,14 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyString, PyTuple};
 
-use crate::build_tools::{py_error, schema_or_config_same, SchemaDict};
+use crate::build_tools::{get_default, py_error, schema_or_config_same, SchemaDict, SchemaPath};
 use crate::errors::{ErrorKind, ValError, ValLineError, ValResult};
 use crate::input::{GenericArguments, Input};
 use crate::lookup_key::LookupKey;
 use crate::recursion_guard::RecursionGuard;
-use crate::SchemaError;
 
-use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::function::convert_err;
+use super::{build_validator, validate_default, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 
 #[derive(Debug, Clone)]
 struct Parameter {
@@ -20,6 +20,8 @@ struct Parameter {
     kwarg_key: Option<Py<PyString>>,
     default: Option<PyObject>,
     default_factory: Option<PyObject>,
+    // check `default` is valid when building, see `validate_default`
+    validate_default: bool,
     validator: CombinedValidator,
 }
 
@@ -76,11 +78,15 @@ impl BuildValidator for ArgumentsValidator {
 
             let schema: &PyAny = arg
                 .get_as_req(intern!(py, "schema"))
-                .map_err(|err| SchemaError::new_err(format!("Parameter \"{}\":\n  {}", name, err)))?;
+                .with_schema_path(arg_index)
+                .with_schema_path("arguments_schema")?;
 
-            let validator = build_validator(schema, config, build_context)?;
+            let validator = build_validator(schema, config, build_context)
+                .with_schema_path("schema")
+                .with_schema_path(arg_index)
+                .with_schema_path("arguments_schema")?;
 
-            let default = arg.get_as(intern!(py, "default"))?;
+            let default = get_default(arg)?;
             let default_factory = arg.get_as(intern!(py, "default_factory"))?;
             if default.is_some() && default_factory.is_some() {
                 return py_error!("'default' and 'default_factory' cannot be used together");
@@ -96,6 +102,7 @@ impl BuildValidator for ArgumentsValidator {
                 kwarg_key,
                 default,
                 default_factory,
+                validate_default: schema_or_config_same(arg, config, intern!(py, "validate_default"))?.unwrap_or(false),
                 validator,
             });
         }
@@ -104,11 +111,15 @@ impl BuildValidator for ArgumentsValidator {
             parameters,
             positional_params_count,
             var_args_validator: match schema.get_item(intern!(py, "var_args_schema")) {
-                Some(v) => Some(Box::new(build_validator(v, config, build_context)?)),
+                Some(v) => Some(Box::new(
+                    build_validator(v, config, build_context).with_schema_path("var_args_schema")?,
+                )),
                 None => None,
             },
             var_kwargs_validator: match schema.get_item(intern!(py, "var_kwargs_schema")) {
-                Some(v) => Some(Box::new(build_validator(v, config, build_context)?)),
+                Some(v) => Some(Box::new(
+                    build_validator(v, config, build_context).with_schema_path("var_kwargs_schema")?,
+                )),
                 None => None,
             },
         }
@@ -160,6 +171,10 @@ impl Validator for ArgumentsValidator {
             ($args:ident, $get_method:ident, $get_macro:ident, $slice_macro:ident) => {{
                 // go through arguments getting the value from args or kwargs and validating it
                 for (index, parameter) in self.parameters.iter().enumerate() {
+                    // errors added directly rather than returned by a child validator, see `Extra.fail_fast`
+                    if extra.fail_fast && !errors.is_empty() {
+                        return Err(ValError::LineErrors(errors));
+                    }
                     let mut pos_value = None;
                     if let Some(args) = $args.args {
                         if parameter.positional {
@@ -185,11 +200,12 @@ impl Validator for ArgumentsValidator {
                             ));
                         }
                         (Some(pos_value), None) => {
-                            match parameter
-                                .validator
-                                .validate(py, pos_value, extra, slots, recursion_guard)
-                            {
+                            let sink_mark = extra.sink_mark();
+                            let result = parameter.validator.validate(py, pos_value, extra, slots, recursion_guard);
+                            extra.with_outer_location_since(sink_mark, || index.into());
+                            match result {
                                 Ok(value) => output_args.push(value),
+                                Err(err @ ValError::LineErrors(_)) if extra.fail_fast => return Err(err),
                                 Err(ValError::LineErrors(line_errors)) => {
                                     errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index.into())));
                                 }
@@ -197,11 +213,12 @@ impl Validator for ArgumentsValidator {
                             }
                         }
                         (None, Some(kw_value)) => {
-                            match parameter
-                                .validator
-                                .validate(py, kw_value, extra, slots, recursion_guard)
-                            {
+                            let sink_mark = extra.sink_mark();
+                            let result = parameter.validator.validate(py, kw_value, extra, slots, recursion_guard);
+                            extra.with_outer_location_since(sink_mark, || parameter.name.clone().into());
+                            match result {
                                 Ok(value) => output_kwargs.set_item(parameter.kwarg_key.as_ref().unwrap(), value)?,
+                                Err(err @ ValError::LineErrors(_)) if extra.fail_fast => return Err(err),
                                 Err(ValError::LineErrors(line_errors)) => {
                                     errors.extend(
                                         line_errors
@@ -220,11 +237,24 @@ impl Validator for ArgumentsValidator {
                                     output_args.push(default.clone_ref(py));
                                 }
                             } else if let Some(ref default_factory) = parameter.default_factory {
-                                let default = default_factory.call0(py)?;
-                                if let Some(ref kwarg_key) = parameter.kwarg_key {
-                                    output_kwargs.set_item(kwarg_key, default)?;
-                                } else {
-                                    output_args.push(default);
+                                match default_factory.call0(py) {
+                                    Ok(default) => {
+                                        if let Some(ref kwarg_key) = parameter.kwarg_key {
+                                            output_kwargs.set_item(kwarg_key, default)?;
+                                        } else {
+                                            output_args.push(default);
+                                        }
+                                    }
+                                    // as with function validators, only `ValueError` and `AssertionError` are
+                                    // validation errors, anything else is probably a bug and is raised
+                                    Err(err) => match convert_err(py, err, input) {
+                                        ValError::LineErrors(line_errors) => errors.extend(
+                                            line_errors
+                                                .into_iter()
+                                                .map(|err| err.with_outer_location(parameter.name.clone().into())),
+                                        ),
+                                        err => return Err(err),
+                                    },
                                 }
                             } else if parameter.kwarg_key.is_some() {
                                 errors.push(ValLineError::new_with_loc(
@@ -238,14 +268,21 @@ impl Validator for ArgumentsValidator {
                         }
                     }
                 }
+                if extra.fail_fast && !errors.is_empty() {
+                    return Err(ValError::LineErrors(errors));
+                }
                 // if there are args check any where index > positional_params_count since they won't have been checked yet
                 if let Some(args) = $args.args {
                     let len = args.len();
                     if len > self.positional_params_count {
                         if let Some(ref validator) = self.var_args_validator {
                             for (index, item) in $slice_macro!(args, self.positional_params_count, len).iter().enumerate() {
-                                match validator.validate(py, item, extra, slots, recursion_guard) {
+                                let sink_mark = extra.sink_mark();
+                                let result = validator.validate(py, item, extra, slots, recursion_guard);
+                                extra.with_outer_location_since(sink_mark, || (index + self.positional_params_count).into());
+                                match result {
                                     Ok(value) => output_args.push(value),
+                                    Err(err @ ValError::LineErrors(_)) if extra.fail_fast => return Err(err),
                                     Err(ValError::LineErrors(line_errors)) => {
                                         errors.extend(
                                             line_errors
@@ -270,8 +307,12 @@ impl Validator for ArgumentsValidator {
                 // if there are kwargs check any that haven't been processed yet
                 if let Some(kwargs) = $args.kwargs {
                     for (raw_key, value) in kwargs.iter() {
+                        if extra.fail_fast && !errors.is_empty() {
+                            return Err(ValError::LineErrors(errors));
+                        }
                         let either_str = match raw_key.strict_str() {
                             Ok(k) => k,
+                            Err(err @ ValError::LineErrors(_)) if extra.fail_fast => return Err(err),
                             Err(ValError::LineErrors(line_errors)) => {
                                 for err in line_errors {
                                     errors.push(
@@ -285,15 +326,21 @@ impl Validator for ArgumentsValidator {
                         };
                         if !used_kwargs.contains(either_str.as_cow()?.as_ref()) {
                             match self.var_kwargs_validator {
-                                Some(ref validator) => match validator.validate(py, value, extra, slots, recursion_guard) {
-                                    Ok(value) => output_kwargs.set_item(either_str.as_py_string(py), value)?,
-                                    Err(ValError::LineErrors(line_errors)) => {
-                                        for err in line_errors {
-                                            errors.push(err.with_outer_location(raw_key.as_loc_item()));
+                                Some(ref validator) => {
+                                    let sink_mark = extra.sink_mark();
+                                    let result = validator.validate(py, value, extra, slots, recursion_guard);
+                                    extra.with_outer_location_since(sink_mark, || raw_key.as_loc_item());
+                                    match result {
+                                        Ok(value) => output_kwargs.set_item(either_str.as_py_string(py), value)?,
+                                        Err(err @ ValError::LineErrors(_)) if extra.fail_fast => return Err(err),
+                                        Err(ValError::LineErrors(line_errors)) => {
+                                            for err in line_errors {
+                                                errors.push(err.with_outer_location(raw_key.as_loc_item()));
+                                            }
                                         }
+                                        Err(err) => return Err(err),
                                     }
-                                    Err(err) => return Err(err),
-                                },
+                                }
                                 None => {
                                     errors.push(ValLineError::new_with_loc(
                                         ErrorKind::UnexpectedKeywordArgument,
@@ -321,4 +368,35 @@ impl Validator for ArgumentsValidator {
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
+
+    fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
+        self.parameters
+            .iter_mut()
+            .try_for_each(|p| p.validator.complete(build_context))?;
+        if let Some(ref mut v) = self.var_args_validator {
+            v.complete(build_context)?;
+        }
+        if let Some(ref mut v) = self.var_kwargs_validator {
+            v.complete(build_context)?;
+        }
+        if self
+            .parameters
+            .iter()
+            .any(|p| p.validate_default && p.default.is_some())
+        {
+            let slots = build_context.slots_snapshot()?;
+            Python::with_gil(|py| -> PyResult<()> {
+                for (index, parameter) in self.parameters.iter().enumerate() {
+                    if let (true, Some(default)) = (parameter.validate_default, &parameter.default) {
+                        validate_default(py, &parameter.validator, default, &slots)
+                            .with_schema_path("default")
+                            .with_schema_path(index)
+                            .with_schema_path("arguments_schema")?;
+                    }
+                }
+                Ok(())
+            })?;
+        }
+        Ok(())
+    }
 }
@@ -1,18 +1,23 @@
+use ahash::AHashSet;
+use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use crate::build_tools::is_strict;
-use crate::errors::ValResult;
+use crate::build_tools::{debug_with_metadata, is_strict, py_error, SchemaDict, SchemaMetadata};
+use crate::errors::{ErrorKind, ValError, ValResult};
 use crate::input::Input;
 use crate::recursion_guard::RecursionGuard;
 
-use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{json_schema_type, strict_shorthand, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct BoolValidator {
     strict: bool,
+    metadata: Option<SchemaMetadata>,
 }
 
+debug_with_metadata!(BoolValidator, strict);
+
 impl BuildValidator for BoolValidator {
     const EXPECTED_TYPE: &'static str = "bool";
 
@@ -21,13 +26,22 @@ impl BuildValidator for BoolValidator {
         config: Option<&PyDict>,
         _build_context: &mut BuildContext,
     ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        if schema.get_item(intern!(py, "true_values")).is_some()
+            || schema.get_item(intern!(py, "false_values")).is_some()
+        {
+            return BoolValuesValidator::build(schema, config);
+        }
         Ok(Self {
             strict: is_strict(schema, config)?,
+            metadata: SchemaMetadata::from_schema(schema)?,
         }
         .into())
     }
 }
 
+strict_shorthand!(StrictBoolBuilder, "strict-bool", BoolValidator);
+
 impl Validator for BoolValidator {
     fn validate<'s, 'data>(
         &'s self,
@@ -37,12 +51,124 @@ impl Validator for BoolValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
+        let strict = extra.strict.unwrap_or(self.strict);
+        if !strict {
+            extra.coercion.check_bool(input)?;
+        }
         // TODO in theory this could be quicker if we used PyBool rather than going to a bool
         // and back again, might be worth profiling?
-        Ok(input.validate_bool(extra.strict.unwrap_or(self.strict))?.into_py(py))
+        Ok(input.validate_bool(strict)?.into_py(py))
     }
 
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
+
+    fn json_schema(&self, py: Python, _slots: &[CombinedValidator], _definitions: &PyDict) -> PyResult<PyObject> {
+        let schema = json_schema_type(py, "boolean")?;
+        if let Some(ref metadata) = self.metadata {
+            metadata.update_dict(schema)?;
+        }
+        Ok(schema.into_py(py))
+    }
+
+    fn metadata(&self) -> Option<&SchemaMetadata> {
+        self.metadata.as_ref()
+    }
+}
+
+const DEFAULT_TRUE_VALUES: [&str; 6] = ["1", "t", "y", "on", "yes", "true"];
+const DEFAULT_FALSE_VALUES: [&str; 6] = ["0", "f", "n", "no", "off", "false"];
+
+/// bool validator where the strings accepted in lax mode are customised with `true_values` and/or `false_values`,
+/// comparison is case-insensitive
+#[derive(Clone)]
+pub struct BoolValuesValidator {
+    strict: bool,
+    metadata: Option<SchemaMetadata>,
+    true_values: AHashSet<String>,
+    false_values: AHashSet<String>,
+}
+
+debug_with_metadata!(BoolValuesValidator, strict, true_values, false_values);
+
+impl BoolValuesValidator {
+    fn build(schema: &PyDict, config: Option<&PyDict>) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let true_values = build_values(schema.get_as(intern!(py, "true_values"))?, DEFAULT_TRUE_VALUES);
+        let false_values = build_values(schema.get_as(intern!(py, "false_values"))?, DEFAULT_FALSE_VALUES);
+        // a value in both sets would always be true, this includes overlap with the defaults
+        let mut overlap: Vec<&String> = true_values.intersection(&false_values).collect();
+        if !overlap.is_empty() {
+            overlap.sort();
+            return py_error!(
+                "Values cannot be in both 'true_values' and 'false_values': {:?}",
+                overlap
+            );
+        }
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            metadata: SchemaMetadata::from_schema(schema)?,
+            true_values,
+            false_values,
+        }
+        .into())
+    }
+}
+
+fn build_values(values: Option<Vec<String>>, default: [&str; 6]) -> AHashSet<String> {
+    match values {
+        Some(values) => values.into_iter().map(|v| v.to_lowercase()).collect(),
+        None => default.iter().map(|v| v.to_string()).collect(),
+    }
+}
+
+impl Validator for BoolValuesValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        _slots: &'data [CombinedValidator],
+        _recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        if extra.strict.unwrap_or(self.strict) {
+            return Ok(input.strict_bool()?.into_py(py));
+        }
+        extra.coercion.check_bool(input)?;
+        match input.lax_str() {
+            Ok(either_str) => {
+                let value = either_str.as_cow()?.to_lowercase();
+                if self.true_values.contains(&value) {
+                    Ok(true.into_py(py))
+                } else if self.false_values.contains(&value) {
+                    Ok(false.into_py(py))
+                } else {
+                    Err(ValError::new(ErrorKind::BoolParsing, input))
+                }
+            }
+            // not a string, use the standard lax logic for bools, ints and floats
+            Err(_) => Ok(input.lax_bool()?.into_py(py)),
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        "constrained-bool"
+    }
+
+    fn expected_repr(&self) -> String {
+        BoolValidator::EXPECTED_TYPE.to_string()
+    }
+
+    fn json_schema(&self, py: Python, _slots: &[CombinedValidator], _definitions: &PyDict) -> PyResult<PyObject> {
+        let schema = json_schema_type(py, "boolean")?;
+        if let Some(ref metadata) = self.metadata {
+            metadata.update_dict(schema)?;
+        }
+        Ok(schema.into_py(py))
+    }
+
+    fn metadata(&self) -> Option<&SchemaMetadata> {
+        self.metadata.as_ref()
+    }
 }
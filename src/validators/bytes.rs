@@ -2,12 +2,12 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use crate::build_tools::{is_strict, SchemaDict};
+use crate::build_tools::{is_strict, validate_length_constraints, SchemaDict};
 use crate::errors::{ErrorKind, ValError, ValResult};
 use crate::input::Input;
 use crate::recursion_guard::RecursionGuard;
 
-use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{strict_shorthand, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub struct BytesValidator {
@@ -36,6 +36,8 @@ impl BuildValidator for BytesValidator {
     }
 }
 
+strict_shorthand!(StrictBytesBuilder, "strict-bytes", BytesValidator);
+
 impl Validator for BytesValidator {
     fn validate<'s, 'data>(
         &'s self,
@@ -90,15 +92,25 @@ impl Validator for BytesConstrainedValidator {
     fn get_name(&self) -> &str {
         "constrained-bytes"
     }
+
+    fn expected_repr(&self) -> String {
+        BytesValidator::EXPECTED_TYPE.to_string()
+    }
 }
 
 impl BytesConstrainedValidator {
     fn build(schema: &PyDict, config: Option<&PyDict>) -> PyResult<CombinedValidator> {
         let py = schema.py();
+        let (min_length, max_length) = validate_length_constraints(
+            py,
+            "min_length",
+            schema.get_as(intern!(py, "min_length"))?,
+            schema.get_as(intern!(py, "max_length"))?,
+        )?;
         Ok(Self {
             strict: is_strict(schema, config)?,
-            min_length: schema.get_as(intern!(py, "min_length"))?,
-            max_length: schema.get_as(intern!(py, "max_length"))?,
+            min_length,
+            max_length,
         }
         .into())
     }
@@ -3,7 +3,7 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyTuple};
 
-use crate::build_tools::SchemaDict;
+use crate::build_tools::{SchemaDict, SchemaPath};
 use crate::errors::ValResult;
 use crate::input::Input;
 use crate::recursion_guard::RecursionGuard;
@@ -29,11 +29,14 @@ impl BuildValidator for CallValidator {
         let py = schema.py();
 
         let arguments_schema: &PyAny = schema.get_as_req(intern!(py, "arguments_schema"))?;
-        let arguments_validator = Box::new(build_validator(arguments_schema, config, build_context)?);
+        let arguments_validator =
+            Box::new(build_validator(arguments_schema, config, build_context).with_schema_path("arguments_schema")?);
 
         let return_schema = schema.get_item(intern!(py, "return_schema"));
         let return_validator = match return_schema {
-            Some(return_schema) => Some(Box::new(build_validator(return_schema, config, build_context)?)),
+            Some(return_schema) => Some(Box::new(
+                build_validator(return_schema, config, build_context).with_schema_path("return_schema")?,
+            )),
             None => None,
         };
         let function: &PyAny = schema.get_as_req(intern!(py, "function"))?;
@@ -59,10 +62,12 @@ impl Validator for CallValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
+        let sink_mark = extra.sink_mark();
         let args = self
             .arguments_validator
             .validate(py, input, extra, slots, recursion_guard)
             .map_err(|e| e.with_outer_location("arguments".into()))?;
+        extra.with_outer_location_since(sink_mark, || "arguments".into());
 
         let return_value = if let Ok((args, kwargs)) = args.extract::<(&PyTuple, &PyDict)>(py) {
             self.function.call(py, args, Some(kwargs))?
@@ -74,9 +79,12 @@ impl Validator for CallValidator {
         };
 
         if let Some(return_validator) = &self.return_validator {
-            return_validator
+            let sink_mark = extra.sink_mark();
+            let result = return_validator
                 .validate(py, return_value.into_ref(py), extra, slots, recursion_guard)
-                .map_err(|e| e.with_outer_location("return-value".into()))
+                .map_err(|e| e.with_outer_location("return-value".into()));
+            extra.with_outer_location_since(sink_mark, || "return-value".into());
+            result
         } else {
             Ok(return_value.to_object(py))
         }
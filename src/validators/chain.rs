@@ -0,0 +1,76 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use crate::build_tools::{py_error, SchemaDict, SchemaPath};
+use crate::errors::ValResult;
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+/// Run each step in order, the output of one step is the input to the next
+#[derive(Debug, Clone)]
+pub struct ChainValidator {
+    steps: Vec<CombinedValidator>,
+    name: String,
+}
+
+impl BuildValidator for ChainValidator {
+    const EXPECTED_TYPE: &'static str = "chain";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let mut steps: Vec<CombinedValidator> = schema
+            .get_as_req::<&PyList>(intern!(schema.py(), "steps"))?
+            .iter()
+            .enumerate()
+            .map(|(index, step)| build_validator(step, config, build_context).with_schema_path(index))
+            .collect::<PyResult<_>>()
+            .with_schema_path("steps")?;
+
+        match steps.len() {
+            0 => py_error!("One or more steps are required for a chain validator"),
+            // a chain with one step is just that step
+            1 => Ok(steps.remove(0)),
+            _ => {
+                let descr = steps.iter().map(|v| v.get_name()).collect::<Vec<_>>().join(",");
+                Ok(Self {
+                    steps,
+                    name: format!("{}[{}]", Self::EXPECTED_TYPE, descr),
+                }
+                .into())
+            }
+        }
+    }
+}
+
+impl Validator for ChainValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let mut steps_iter = self.steps.iter();
+        let first_step = steps_iter.next().unwrap();
+        let value = first_step.validate(py, input, extra, slots, recursion_guard)?;
+
+        steps_iter.try_fold(value, |v, step| {
+            step.validate(py, v.into_ref(py), extra, slots, recursion_guard)
+        })
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
+        self.steps.iter_mut().try_for_each(|v| v.complete(build_context))
+    }
+}
@@ -0,0 +1,213 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::build_tools::{is_strict, SchemaDict, SchemaPath};
+use crate::errors::{ValError, ValLineError, ValResult};
+use crate::input::{
+    native_dict_get_mut, GenericListLike, GenericMapping, Input, JsonInput, JsonObject, NativeDict, NativeInput,
+};
+use crate::recursion_guard::RecursionGuard;
+
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+/// Merge a list of dicts, later dicts overriding earlier ones, then validate the merged dict with a
+/// `typed-dict` validator, e.g. for layered configuration. A single dict is validated as is.
+/// Errors are reported relative to the merged dict, not the individual source dicts.
+#[derive(Debug, Clone)]
+pub struct ChainMapValidator {
+    strict: bool,
+    // if true, values which are dicts in both the existing and the overriding dict are merged recursively
+    deep: bool,
+    validator: Box<CombinedValidator>,
+    name: String,
+}
+
+impl BuildValidator for ChainMapValidator {
+    const EXPECTED_TYPE: &'static str = "chain-map";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let sub_schema: &PyAny = schema.get_as_req(intern!(py, "schema"))?;
+        let validator = build_validator(sub_schema, config, build_context).with_schema_path("schema")?;
+        let name = format!("{}[{}]", Self::EXPECTED_TYPE, validator.get_name());
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            deep: schema.get_as(intern!(py, "deep"))?.unwrap_or(false),
+            validator: Box::new(validator),
+            name,
+        }
+        .into())
+    }
+}
+
+impl Validator for ChainMapValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let strict = extra.strict.unwrap_or(self.strict);
+        let sources = match input.validate_list(strict) {
+            Ok(sources) => sources,
+            // not a list of dicts, validate the input as a single dict
+            Err(ValError::LineErrors(_)) => {
+                return self.validator.validate(py, input, extra, slots, recursion_guard);
+            }
+            Err(err) => return Err(err),
+        };
+        let merged = match sources {
+            GenericListLike::List(sources) => self.merge_py_sources(py, sources.iter(), strict)?,
+            GenericListLike::Tuple(sources) => self.merge_py_sources(py, sources.iter(), strict)?,
+            GenericListLike::Set(sources) => self.merge_py_sources(py, sources.iter(), strict)?,
+            GenericListLike::FrozenSet(sources) => self.merge_py_sources(py, sources.iter(), strict)?,
+            GenericListLike::JsonArray(sources) => {
+                let merged = JsonInput::Object(self.merge_json_sources(sources, strict)?);
+                // the merged object only lives for this call, so errors can't borrow from it
+                return self
+                    .validator
+                    .validate(py, &merged, extra, slots, recursion_guard)
+                    .map_err(|err| err.into_owned(py));
+            }
+            GenericListLike::NativeList(sources) => {
+                let merged = NativeInput::Dict(self.merge_native_sources(sources, strict)?);
+                return self
+                    .validator
+                    .validate(py, &merged, extra, slots, recursion_guard)
+                    .map_err(|err| err.into_owned(py));
+            }
+        };
+        self.validator
+            .validate(py, merged.as_ref(), extra, slots, recursion_guard)
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
+        self.validator.complete(build_context)
+    }
+}
+
+impl ChainMapValidator {
+    fn merge_py_sources<'data>(
+        &self,
+        py: Python<'data>,
+        sources: impl Iterator<Item = &'data PyAny>,
+        strict: bool,
+    ) -> ValResult<'data, &'data PyDict> {
+        let merged = PyDict::new(py);
+        let mut errors: Vec<ValLineError> = Vec::new();
+        for (index, source) in sources.enumerate() {
+            match source.validate_dict(strict) {
+                Ok(GenericMapping::PyDict(source)) => merge_py_dicts(merged, source, self.deep)?,
+                Ok(_) => unreachable!(),
+                Err(ValError::LineErrors(line_errors)) => {
+                    errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index.into())));
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(merged)
+        } else {
+            Err(ValError::LineErrors(errors))
+        }
+    }
+
+    fn merge_json_sources<'data>(&self, sources: &'data [JsonInput], strict: bool) -> ValResult<'data, JsonObject> {
+        let mut merged = JsonObject::new();
+        let mut errors: Vec<ValLineError> = Vec::new();
+        for (index, source) in sources.iter().enumerate() {
+            match source.validate_dict(strict) {
+                Ok(GenericMapping::JsonObject(source)) => merge_json_objects(&mut merged, source, self.deep),
+                Ok(_) => unreachable!(),
+                Err(ValError::LineErrors(line_errors)) => {
+                    errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index.into())));
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(merged)
+        } else {
+            Err(ValError::LineErrors(errors))
+        }
+    }
+
+    fn merge_native_sources<'data>(&self, sources: &'data [NativeInput], strict: bool) -> ValResult<'data, NativeDict> {
+        let mut merged = NativeDict::new();
+        let mut errors: Vec<ValLineError> = Vec::new();
+        for (index, source) in sources.iter().enumerate() {
+            match source.validate_dict(strict) {
+                Ok(GenericMapping::NativeDict(source)) => merge_native_dicts(&mut merged, source, self.deep),
+                Ok(_) => unreachable!(),
+                Err(ValError::LineErrors(line_errors)) => {
+                    errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index.into())));
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(merged)
+        } else {
+            Err(ValError::LineErrors(errors))
+        }
+    }
+}
+
+/// source dicts are never modified, nested dicts are copied before being merged into
+fn merge_py_dicts(target: &PyDict, source: &PyDict, deep: bool) -> PyResult<()> {
+    for (key, value) in source.iter() {
+        if deep {
+            let existing = target.get_item(key).and_then(|v| v.cast_as::<PyDict>().ok());
+            if let (Some(existing), Ok(value)) = (existing, value.cast_as::<PyDict>()) {
+                let nested = existing.copy()?;
+                merge_py_dicts(nested, value, deep)?;
+                target.set_item(key, nested)?;
+                continue;
+            }
+        }
+        target.set_item(key, value)?;
+    }
+    Ok(())
+}
+
+fn merge_json_objects(target: &mut JsonObject, source: &JsonObject, deep: bool) {
+    for (key, value) in source.iter() {
+        if deep {
+            if let (Some(JsonInput::Object(existing)), JsonInput::Object(value)) = (target.get_mut(key), value) {
+                merge_json_objects(existing, value, deep);
+                continue;
+            }
+        }
+        target.insert(key.clone(), value.clone());
+    }
+}
+
+/// as with `merge_py_dicts`, an existing key keeps its position when it's overridden
+fn merge_native_dicts(target: &mut NativeDict, source: &NativeDict, deep: bool) {
+    for (key, value) in source.iter() {
+        if let Some(existing) = native_dict_get_mut(target, key) {
+            match (existing, value) {
+                (NativeInput::Dict(existing), NativeInput::Dict(value)) if deep => {
+                    merge_native_dicts(existing, value, deep)
+                }
+                (existing, value) => *existing = value.clone(),
+            }
+        } else {
+            target.push((key.clone(), value.clone()));
+        }
+    }
+}
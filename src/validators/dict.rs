@@ -1,23 +1,102 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyIterator, PyString};
+
+use ahash::AHashSet;
 
-use crate::build_tools::{is_strict, SchemaDict};
-use crate::errors::{ErrorKind, ValError, ValLineError, ValResult};
-use crate::input::{GenericMapping, Input, JsonObject};
+use crate::build_tools::{
+    debug_with_metadata, is_strict, py_error, validate_length_constraints, SchemaDict, SchemaMetadata, SchemaPath,
+};
+use crate::errors::{ErrorKind, LocItem, ValError, ValLineError, ValResult};
+use crate::input::{
+    native_dict_get, repr_string, GenericMapping, Input, JsonInput, JsonObject, NativeDict, NativeInput,
+};
+use crate::lenient_coercion::LenientCoercionConfig;
 use crate::recursion_guard::RecursionGuard;
 
 use super::any::AnyValidator;
-use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::list::push_errors;
+use super::{
+    build_validator, check_max_input_len, check_max_input_length, json_container_len, json_schema_type,
+    parse_json_bytes, schema_repr_dict, set_json_schema_item, BuildContext, BuildValidator, CombinedValidator, Extra,
+    InputMode, Validator,
+};
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct DictValidator {
     strict: bool,
     key_validator: Box<CombinedValidator>,
     value_validator: Box<CombinedValidator>,
     min_items: Option<usize>,
     max_items: Option<usize>,
+    // from config, checked before `min_items` and `max_items`, see `check_max_input_length`
+    max_input_length: Option<usize>,
+    // maximum length of keys which are strings after validation, checked without a full key validator
+    key_max_length: Option<usize>,
+    fail_fast_per_entry: bool,
+    // applied to string keys before they're validated
+    key_transform: Option<KeyTransform>,
+    // in lax mode, parse string inputs as JSON and validate the object they contain
+    coerce_json_string: bool,
+    // keys which must be in the output, checked after the key validator has been applied
+    required_keys: Vec<String>,
+    metadata: Option<SchemaMetadata>,
     name: String,
+    // e.g. `dict[str, int]`, `None` unless `keys_schema` or `values_schema` is set
+    expected: Option<String>,
+}
+
+debug_with_metadata!(
+    DictValidator,
+    strict,
+    key_validator,
+    value_validator,
+    min_items,
+    max_items,
+    max_input_length,
+    key_max_length,
+    fail_fast_per_entry,
+    key_transform,
+    coerce_json_string,
+    required_keys,
+    name,
+    expected
+);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum KeyTransform {
+    Lower,
+    Upper,
+    /// e.g. `userId` or `user-id` to `user_id`
+    Snake,
+    /// e.g. `user_id` or `user-id` to `userId`
+    Camel,
+}
+
+impl KeyTransform {
+    fn from_schema(schema: &PyDict) -> PyResult<Option<Self>> {
+        match schema.get_as::<&str>(intern!(schema.py(), "key_transform"))? {
+            None => Ok(None),
+            Some("lower") => Ok(Some(Self::Lower)),
+            Some("upper") => Ok(Some(Self::Upper)),
+            Some("snake") => Ok(Some(Self::Snake)),
+            Some("camel") => Ok(Some(Self::Camel)),
+            Some(s) => py_error!(r#"Invalid dict key_transform: "{}""#, s),
+        }
+    }
+
+    fn apply(&self, key: &str) -> String {
+        match self {
+            Self::Lower => key.to_lowercase(),
+            Self::Upper => key.to_uppercase(),
+            Self::Snake => to_snake_case(key),
+            // via snake case so acronyms and hyphens are handled the same way, e.g. `HTTPServer` to `httpServer`
+            Self::Camel => to_camel_case(&to_snake_case(key)),
+        }
+    }
 }
 
 impl BuildValidator for DictValidator {
@@ -28,15 +107,44 @@ impl BuildValidator for DictValidator {
         config: Option<&PyDict>,
         build_context: &mut BuildContext,
     ) -> PyResult<CombinedValidator> {
+        Self::build_for_class(schema, config, build_context, Self::EXPECTED_TYPE).map(Into::into)
+    }
+}
+
+impl DictValidator {
+    /// as `build`, `class_name` is the container named in `dict_type` errors, e.g. `OrderedDict` for a mapping schema
+    pub fn build_for_class(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext,
+        class_name: &str,
+    ) -> PyResult<Self> {
         let py = schema.py();
         let key_validator = match schema.get_item(intern!(py, "keys_schema")) {
-            Some(schema) => Box::new(build_validator(schema, config, build_context)?),
+            Some(schema) => Box::new(build_validator(schema, config, build_context).with_schema_path("keys_schema")?),
             None => Box::new(AnyValidator::build(schema, config, build_context)?),
         };
         let value_validator = match schema.get_item(intern!(py, "values_schema")) {
-            Some(d) => Box::new(build_validator(d, config, build_context)?),
+            Some(d) => Box::new(build_validator(d, config, build_context).with_schema_path("values_schema")?),
             None => Box::new(AnyValidator::build(schema, config, build_context)?),
         };
+        let (min_items, max_items) = validate_length_constraints(
+            py,
+            "min_items",
+            schema.get_as(intern!(py, "min_items"))?,
+            schema.get_as(intern!(py, "max_items"))?,
+        )?;
+        // only named in type errors if the keys or values are validated
+        let expected =
+            match schema.contains(intern!(py, "keys_schema"))? || schema.contains(intern!(py, "values_schema"))? {
+                true => Some(format!(
+                    "{}[{}, {}]",
+                    class_name,
+                    key_validator.expected_repr(),
+                    value_validator.expected_repr()
+                )),
+                false => None,
+            };
         let name = format!(
             "{}[{},{}]",
             Self::EXPECTED_TYPE,
@@ -47,11 +155,18 @@ impl BuildValidator for DictValidator {
             strict: is_strict(schema, config)?,
             key_validator,
             value_validator,
-            min_items: schema.get_as(intern!(py, "min_items"))?,
-            max_items: schema.get_as(intern!(py, "max_items"))?,
+            min_items,
+            max_items,
+            max_input_length: config.get_as(intern!(py, "max_input_length"))?,
+            key_max_length: schema.get_as(intern!(py, "key_max_length"))?,
+            fail_fast_per_entry: schema.get_as(intern!(py, "fail_fast_per_entry"))?.unwrap_or(false),
+            key_transform: KeyTransform::from_schema(schema)?,
+            coerce_json_string: schema.get_as(intern!(py, "coerce_json_string"))?.unwrap_or(false),
+            required_keys: schema.get_as(intern!(py, "required_keys"))?.unwrap_or_default(),
+            metadata: SchemaMetadata::from_schema(schema)?,
             name,
-        }
-        .into())
+            expected,
+        })
     }
 }
 
@@ -64,13 +179,54 @@ impl Validator for DictValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let dict = input.validate_dict(extra.strict.unwrap_or(self.strict))?;
-        match dict {
-            GenericMapping::PyDict(py_dict) => self.validate_dict(py, input, py_dict, extra, slots, recursion_guard),
-            GenericMapping::PyGetAttr(_) => unreachable!(),
-            GenericMapping::JsonObject(json_object) => {
+        let strict = extra.strict.unwrap_or(self.strict);
+        extra.trace(&self.name, || match strict {
+            true => "strict_dict".to_string(),
+            false => "lax_dict".to_string(),
+        });
+        check_max_input_len(self.max_input_length, input)?;
+        if self.coerce_json_string && !strict {
+            if let Ok(either_str) = input.strict_str() {
+                return self.validate_json_string(py, input, &either_str.as_cow()?, extra, slots, recursion_guard);
+            }
+        }
+        let dict = input
+            .validate_dict(strict)
+            .map_err(|err| err.map_kind(|kind| self.expected_kind(kind)))?;
+        match (dict, self.key_transform) {
+            (GenericMapping::PyDict(py_dict), None) => {
+                self.validate_dict(py, input, py_dict, extra, slots, recursion_guard)
+            }
+            (GenericMapping::PyDict(py_dict), Some(key_transform)) => {
+                let py_dict = transform_py_keys(py, py_dict, key_transform)?;
+                self.validate_dict(py, input, py_dict, extra, slots, recursion_guard)
+            }
+            (GenericMapping::PyGetAttr(_), _) => unreachable!(),
+            (GenericMapping::JsonObject(json_object), None) => {
                 self.validate_json_object(py, input, json_object, extra, slots, recursion_guard)
             }
+            (GenericMapping::JsonObject(json_object), Some(key_transform)) => {
+                let transformed = JsonInput::Object(transform_json_keys(json_object, key_transform)?);
+                let json_object = match transformed {
+                    JsonInput::Object(ref json_object) => json_object,
+                    _ => unreachable!(),
+                };
+                // the transformed object only lives for this call, so errors can't borrow from it
+                self.validate_json_object(py, &transformed, json_object, extra, slots, recursion_guard)
+                    .map_err(|err| err.into_owned(py))
+            }
+            (GenericMapping::NativeDict(native_dict), None) => {
+                self.validate_native_dict(py, input, native_dict, extra, slots, recursion_guard)
+            }
+            (GenericMapping::NativeDict(native_dict), Some(key_transform)) => {
+                let transformed = NativeInput::Dict(transform_native_keys(native_dict, key_transform)?);
+                let native_dict = match transformed {
+                    NativeInput::Dict(ref native_dict) => native_dict,
+                    _ => unreachable!(),
+                };
+                self.validate_native_dict(py, &transformed, native_dict, extra, slots, recursion_guard)
+                    .map_err(|err| err.into_owned(py))
+            }
         }
     }
 
@@ -78,6 +234,48 @@ impl Validator for DictValidator {
         &self.name
     }
 
+    fn expected_repr(&self) -> String {
+        match self.expected {
+            Some(ref expected) => expected.clone(),
+            None => Self::EXPECTED_TYPE.to_string(),
+        }
+    }
+
+    fn json_schema(&self, py: Python, slots: &[CombinedValidator], definitions: &PyDict) -> PyResult<PyObject> {
+        let schema = json_schema_type(py, "object")?;
+        set_json_schema_item(schema, "minProperties", self.min_items)?;
+        set_json_schema_item(schema, "maxProperties", self.max_items)?;
+        if !self.required_keys.is_empty() {
+            schema.set_item("required", &self.required_keys)?;
+        }
+        schema.set_item(
+            "additionalProperties",
+            self.value_validator.json_schema(py, slots, definitions)?,
+        )?;
+        schema.set_item("propertyNames", self.key_validator.json_schema(py, slots, definitions)?)?;
+        if let Some(ref metadata) = self.metadata {
+            metadata.update_dict(schema)?;
+        }
+        Ok(schema.into_py(py))
+    }
+
+    fn schema_repr(&self, py: Python, slots: &[CombinedValidator]) -> PyResult<PyObject> {
+        let repr = schema_repr_dict(py, self.get_name())?;
+        repr.set_item("strict", self.strict)?;
+        set_json_schema_item(repr, "min_items", self.min_items)?;
+        set_json_schema_item(repr, "max_items", self.max_items)?;
+        if !self.required_keys.is_empty() {
+            repr.set_item("required_keys", &self.required_keys)?;
+        }
+        repr.set_item("keys_schema", self.key_validator.schema_repr(py, slots)?)?;
+        repr.set_item("values_schema", self.value_validator.schema_repr(py, slots)?)?;
+        Ok(repr.into_py(py))
+    }
+
+    fn metadata(&self) -> Option<&SchemaMetadata> {
+        self.metadata.as_ref()
+    }
+
     fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
         self.key_validator.complete(build_context)?;
         self.value_validator.complete(build_context)
@@ -95,55 +293,61 @@ macro_rules! build_validate {
             slots: &'data [CombinedValidator],
             recursion_guard: &'s mut RecursionGuard,
         ) -> ValResult<'data, PyObject> {
-            let mut op_len: Option<usize> = None;
-            if let Some(min_length) = self.min_items {
-                let input_length = dict.len();
-                if input_length < min_length {
-                    return Err(ValError::new(
-                        ErrorKind::TooShort {
-                            min_length,
-                            input_length,
-                        },
-                        input,
-                    ));
-                }
-                op_len = Some(input_length);
-            }
-            if let Some(max_length) = self.max_items {
-                let input_length = op_len.unwrap_or_else(|| dict.len());
-                if input_length > max_length {
-                    return Err(ValError::new(
-                        ErrorKind::TooLong {
-                            max_length,
-                            input_length,
-                        },
-                        input,
-                    ));
-                }
-            }
+            // only this dict's entries are passed to the callback, not those of dicts nested within it
+            let item_callback = extra.item_callback;
+            let extra = &Extra {
+                item_callback: None,
+                ..*extra
+            };
+            self.check_len(dict.len(), input)?;
             let output = PyDict::new(py);
             let mut errors: Vec<ValLineError> = Vec::new();
 
             let key_validator = self.key_validator.as_ref();
             let value_validator = self.value_validator.as_ref();
+            // validated keys, whether or not their value was valid, so an invalid value isn't also reported missing
+            let mut present_keys: AHashSet<String> = AHashSet::new();
 
             for (key, value) in dict.iter() {
-                let output_key = match key_validator.validate(py, key, extra, slots, recursion_guard) {
+                let sink_mark = extra.sink_mark();
+                let key_result = key_validator
+                    .validate(py, key, extra, slots, recursion_guard)
+                    .and_then(|output_key| self.check_key_length(py, key, output_key));
+                if let Ok(ref output_key) = key_result {
+                    self.trace_key(py, key, output_key, extra)?;
+                    if !self.required_keys.is_empty() {
+                        if let Ok(py_str) = output_key.cast_as::<PyString>(py) {
+                            present_keys.insert(py_str.to_str()?.to_string());
+                        }
+                    }
+                }
+                extra.with_outer_location_since(sink_mark, || LocItem::Key);
+                extra.with_outer_location_since(sink_mark, || key.as_loc_item());
+                let output_key = match key_result {
                     Ok(value) => Some(value),
+                    Err(err @ ValError::LineErrors(_)) if extra.fail_fast => return Err(err),
                     Err(ValError::LineErrors(line_errors)) => {
                         for err in line_errors {
-                            // these are added in reverse order so [key] is shunted along by the second call
+                            // these are added in reverse order so the key marker is shunted along by the second call
                             errors.push(
-                                err.with_outer_location("[key]".into())
+                                err.with_outer_location(LocItem::Key)
                                     .with_outer_location(key.as_loc_item()),
                             );
                         }
+                        // the entry can't be included in the output, so validating its value is wasted work
+                        if self.fail_fast_per_entry {
+                            continue;
+                        }
                         None
                     }
                     Err(err) => return Err(err),
                 };
-                let output_value = match value_validator.validate(py, value, extra, slots, recursion_guard) {
+                let sink_mark = extra.sink_mark();
+                let value_result = value_validator.validate(py, value, extra, slots, recursion_guard);
+                extra.with_outer_location_since(sink_mark, || key.as_loc_item());
+                let output_value = match value_result {
                     Ok(value) => Some(value),
+                    Err(err @ ValError::LineErrors(_)) if extra.fail_fast => return Err(err),
                     Err(ValError::LineErrors(line_errors)) => {
                         for err in line_errors {
                             errors.push(err.with_outer_location(key.as_loc_item()));
@@ -153,12 +357,32 @@ macro_rules! build_validate {
                     Err(err) => return Err(err),
                 };
                 if let (Some(key), Some(value)) = (output_key, output_value) {
-                    output.set_item(key, value)?;
+                    match item_callback {
+                        Some(item_callback) => {
+                            item_callback.callback.call1((&key, &value))?;
+                            if !item_callback.consume {
+                                output.set_item(key, value)?;
+                            }
+                        }
+                        None => output.set_item(key, value)?,
+                    }
+                }
+            }
+            for required_key in &self.required_keys {
+                if !present_keys.contains(required_key) {
+                    errors.push(ValLineError::new_with_loc(
+                        ErrorKind::Missing,
+                        input,
+                        required_key.as_str(),
+                    ));
                 }
             }
 
             if errors.is_empty() {
-                Ok(output.into())
+                match item_callback {
+                    Some(item_callback) if item_callback.consume => Ok(py.None()),
+                    _ => Ok(output.into()),
+                }
             } else {
                 Err(ValError::LineErrors(errors))
             }
@@ -167,6 +391,421 @@ macro_rules! build_validate {
 }
 
 impl DictValidator {
+    /// `DictType` errors name the expected type if the keys or values are validated
+    fn expected_kind(&self, kind: ErrorKind) -> ErrorKind {
+        match self.expected {
+            Some(ref expected) => kind.with_expected(expected),
+            None => kind,
+        }
+    }
+
+    /// with `coerce_json_string`, a string input is parsed as JSON and, if it's an object, validated in its place,
+    /// strings nested inside the object may be JSON too, so each level of parsing uses up one level of `max_depth`
+    fn validate_json_string<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        json_str: &str,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        if self.max_input_length.is_some() {
+            if let Some(input_length) = json_container_len(json_str.as_bytes()) {
+                check_max_input_length(self.max_input_length, input_length, input)?;
+            }
+        }
+        let json_input = match parse_json_bytes(json_str.as_bytes(), false, false) {
+            Ok(json_input @ JsonInput::Object(_)) => json_input,
+            Ok(_) => return Err(ValError::new(self.expected_kind(ErrorKind::DictType), input)),
+            Err(kind) => return Err(ValError::new(kind, input)),
+        };
+        let extra = &Extra {
+            max_depth: match extra.max_depth {
+                Some(0) => return Err(ValError::new(ErrorKind::RecursionLoop, input)),
+                Some(depth) => Some(depth - 1),
+                None => None,
+            },
+            ..*extra
+        };
+        // the parsed object only lives for this call, so errors can't borrow from it
+        self.validate(py, &json_input, extra, slots, recursion_guard)
+            .map_err(|err| err.into_owned(py))
+    }
+
+    fn check_len<'data>(&self, input_length: usize, input: &'data impl Input<'data>) -> ValResult<'data, ()> {
+        check_max_input_length(self.max_input_length, input_length, input)?;
+        if let Some(min_length) = self.min_items {
+            if input_length < min_length {
+                return Err(ValError::new(
+                    ErrorKind::TooShort {
+                        min_length,
+                        input_length,
+                    },
+                    input,
+                ));
+            }
+        }
+        if let Some(max_length) = self.max_items {
+            if input_length > max_length {
+                return Err(ValError::new(
+                    ErrorKind::TooLong {
+                        max_length,
+                        input_length,
+                    },
+                    input,
+                ));
+            }
+        }
+        Ok(())
+    }
+
     build_validate!(validate_dict, PyDict);
     build_validate!(validate_json_object, JsonObject);
+    build_validate!(validate_native_dict, NativeDict);
+
+    fn check_key_length<'data>(
+        &self,
+        py: Python<'data>,
+        key: &'data impl Input<'data>,
+        output_key: PyObject,
+    ) -> ValResult<'data, PyObject> {
+        if let Some(max_length) = self.key_max_length {
+            if let Ok(py_str) = output_key.cast_as::<PyString>(py) {
+                if py_str.to_str()?.chars().count() > max_length {
+                    return Err(ValError::new(ErrorKind::StrTooLong { max_length }, key));
+                }
+            }
+        }
+        Ok(output_key)
+    }
+
+    /// in debug mode, record keys which the key validator changed, e.g. `'1'` to `1` with `keys_schema: 'int'`
+    fn trace_key<'data>(
+        &self,
+        py: Python<'data>,
+        key: &'data impl Input<'data>,
+        output_key: &PyObject,
+        extra: &Extra,
+    ) -> PyResult<()> {
+        if extra.trace.is_none() {
+            return Ok(());
+        }
+        let input_key = key.to_object(py).into_ref(py);
+        let output_key = output_key.as_ref(py);
+        if !input_key.get_type().is(output_key.get_type()) || !input_key.eq(output_key)? {
+            let decision = format!(
+                "coerced key {} to {}",
+                repr_string(input_key)?,
+                repr_string(output_key)?
+            );
+            extra.trace(&self.name, || decision);
+        }
+        Ok(())
+    }
+}
+
+impl DictValidator {
+    /// Used by `SchemaValidator.iterate_validation_errors`, as with `ListValidator::error_iterator` the type and
+    /// length of the input are checked up front, then entries are validated one at a time as the iterator
+    /// is consumed. `None` for strings with `coerce_json_string`, which are validated all at once.
+    pub fn error_iterator(
+        &self,
+        py: Python,
+        input: &PyAny,
+        extra: &Extra,
+        slots: &[CombinedValidator],
+        hide_input_in_errors: bool,
+    ) -> PyResult<Option<PyObject>> {
+        let strict = extra.strict.unwrap_or(self.strict);
+        if self.coerce_json_string && !strict && input.strict_str().is_ok() {
+            return Ok(None);
+        }
+        let mut error_iterator = DictErrorIterator {
+            iterator: None,
+            input: input.to_object(py),
+            pending: VecDeque::new(),
+            present_keys: AHashSet::new(),
+            validator: self.clone(),
+            slots: extra.owned_slots(slots),
+            hide_input_in_errors,
+            strict: extra.strict,
+            context: extra.context.map(|c| c.into_py(py)),
+            max_depth: extra.max_depth,
+            coercion: extra.coercion,
+            input_mode: extra.input_mode,
+            recursion_guard: RecursionGuard::default(),
+            #[cfg(feature = "profiling")]
+            stats: extra.stats.map(|stats| stats.share()),
+        };
+        let checked = check_max_input_len(self.max_input_length, input)
+            .and_then(|_| {
+                input
+                    .validate_dict(strict)
+                    .map_err(|err| err.map_kind(|kind| self.expected_kind(kind)))
+            })
+            .and_then(|dict| match dict {
+                GenericMapping::PyDict(py_dict) => {
+                    self.check_len(py_dict.len(), input)?;
+                    match self.key_transform {
+                        Some(key_transform) => transform_py_keys(py, py_dict, key_transform),
+                        None => Ok(py_dict),
+                    }
+                }
+                _ => unreachable!(),
+            });
+        match checked {
+            // `PyDict::items` would copy every entry into a list, the items view is iterated lazily
+            Ok(py_dict) => {
+                let items = py_dict.call_method0("items")?;
+                error_iterator.iterator = Some(items.iter()?.to_object(py));
+            }
+            Err(err) => push_errors(py, &mut error_iterator.pending, hide_input_in_errors, err)?,
+        }
+        Ok(Some(error_iterator.into_py(py)))
+    }
+}
+
+#[pyclass]
+#[derive(Debug, Clone)]
+struct DictErrorIterator {
+    // iterator over `(key, value)` tuples, `None` once all entries have been validated
+    iterator: Option<PyObject>,
+    input: PyObject,
+    // errors from the last entry validated which haven't been returned yet
+    pending: VecDeque<PyObject>,
+    // as in `build_validate!`, used to check `required_keys` once all entries have been validated
+    present_keys: AHashSet<String>,
+    validator: DictValidator,
+    slots: Arc<[CombinedValidator]>,
+    hide_input_in_errors: bool,
+    strict: Option<bool>,
+    context: Option<PyObject>,
+    max_depth: Option<usize>,
+    coercion: LenientCoercionConfig,
+    input_mode: InputMode,
+    recursion_guard: RecursionGuard,
+    #[cfg(feature = "profiling")]
+    stats: Option<super::profiling::Stats>,
+}
+
+#[pymethods]
+impl DictErrorIterator {
+    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python) -> PyResult<Option<PyObject>> {
+        loop {
+            if let Some(error) = self.pending.pop_front() {
+                return Ok(Some(error));
+            }
+            let next_item = match self.iterator {
+                Some(ref iterator) => PyIterator::from_object(py, iterator.as_ref(py))?.next(),
+                None => return Ok(None),
+            };
+            match next_item {
+                Some(item) => {
+                    let (key, value): (&PyAny, &PyAny) = item?.extract()?;
+                    self.validate_entry(py, key, value)?;
+                }
+                None => {
+                    self.iterator = None;
+                    self.push_missing_keys(py)?;
+                }
+            }
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("DictErrorIterator({})", self.validator.name)
+    }
+}
+
+impl DictErrorIterator {
+    /// as in `build_validate!`, but errors are added to `pending` and there's no output
+    fn validate_entry(&mut self, py: Python, key: &PyAny, value: &PyAny) -> PyResult<()> {
+        let extra = Extra {
+            data: None,
+            field: None,
+            strict: self.strict,
+            context: self.context.as_ref().map(|c| c.as_ref(py)),
+            max_depth: self.max_depth,
+            warnings: None,
+            trace: None,
+            coercion: self.coercion,
+            input_mode: self.input_mode,
+            item_callback: None,
+            fail_fast: false,
+            shared_slots: Some(&self.slots),
+            #[cfg(feature = "profiling")]
+            stats: self.stats.as_ref(),
+        };
+        let validator = &self.validator;
+        let key_result = validator
+            .key_validator
+            .validate(py, key, &extra, &self.slots, &mut self.recursion_guard)
+            .and_then(|output_key| validator.check_key_length(py, key, output_key));
+        match key_result {
+            Ok(output_key) => {
+                if !validator.required_keys.is_empty() {
+                    if let Ok(py_str) = output_key.cast_as::<PyString>(py) {
+                        self.present_keys.insert(py_str.to_str()?.to_string());
+                    }
+                }
+            }
+            Err(err) => {
+                let err = err
+                    .with_outer_location(LocItem::Key)
+                    .with_outer_location(key.as_loc_item());
+                push_errors(py, &mut self.pending, self.hide_input_in_errors, err)?;
+                if validator.fail_fast_per_entry {
+                    return Ok(());
+                }
+            }
+        }
+        let value_result =
+            validator
+                .value_validator
+                .validate(py, value, &extra, &self.slots, &mut self.recursion_guard);
+        if let Err(err) = value_result {
+            let err = err.with_outer_location(key.as_loc_item());
+            push_errors(py, &mut self.pending, self.hide_input_in_errors, err)?;
+        }
+        Ok(())
+    }
+
+    fn push_missing_keys(&mut self, py: Python) -> PyResult<()> {
+        let input = self.input.as_ref(py);
+        let errors: Vec<ValLineError> = self
+            .validator
+            .required_keys
+            .iter()
+            .filter(|required_key| !self.present_keys.contains(*required_key))
+            .map(|required_key| ValLineError::new_with_loc(ErrorKind::Missing, input, required_key.as_str()))
+            .collect();
+        push_errors(
+            py,
+            &mut self.pending,
+            self.hide_input_in_errors,
+            ValError::LineErrors(errors),
+        )
+    }
+}
+
+/// Copy of `dict` with `key_transform` applied to string keys, keys which are transformed to the same string as
+/// an earlier key are `DictDuplicateKey` errors, other keys are unchanged
+fn transform_py_keys<'data>(
+    py: Python<'data>,
+    dict: &'data PyDict,
+    key_transform: KeyTransform,
+) -> ValResult<'data, &'data PyDict> {
+    let output = PyDict::new(py);
+    let mut errors: Vec<ValLineError> = Vec::new();
+    for (key, value) in dict.iter() {
+        let output_key: &PyAny = match key.cast_as::<PyString>() {
+            Ok(py_str) => PyString::new(py, &key_transform.apply(py_str.to_str()?)),
+            Err(_) => key,
+        };
+        if output.contains(output_key)? {
+            let kind = ErrorKind::DictDuplicateKey {
+                key: output_key.str()?.to_string(),
+            };
+            errors.push(ValLineError::new_with_loc(kind, key, key.as_loc_item()));
+        } else {
+            output.set_item(output_key, value)?;
+        }
+    }
+    match errors.is_empty() {
+        true => Ok(output),
+        false => Err(ValError::LineErrors(errors)),
+    }
+}
+
+/// as `transform_py_keys`, JSON keys are always strings
+fn transform_json_keys<'data>(object: &'data JsonObject, key_transform: KeyTransform) -> ValResult<'data, JsonObject> {
+    let mut output = JsonObject::with_capacity(object.len());
+    let mut errors: Vec<ValLineError> = Vec::new();
+    for (key, value) in object.iter() {
+        let output_key = key_transform.apply(key);
+        if output.contains_key(&output_key) {
+            let kind = ErrorKind::DictDuplicateKey { key: output_key };
+            errors.push(ValLineError::new_with_loc(kind, key, key.as_str()));
+        } else {
+            output.insert(output_key, value.clone());
+        }
+    }
+    match errors.is_empty() {
+        true => Ok(output),
+        false => Err(ValError::LineErrors(errors)),
+    }
+}
+
+/// as with `transform_py_keys`, only string keys are transformed
+fn transform_native_keys<'data>(dict: &'data NativeDict, key_transform: KeyTransform) -> ValResult<'data, NativeDict> {
+    let mut output = NativeDict::with_capacity(dict.len());
+    let mut errors: Vec<ValLineError> = Vec::new();
+    for (key, value) in dict.iter() {
+        let output_key = match key {
+            NativeInput::Str(s) => NativeInput::Str(key_transform.apply(s)),
+            _ => key.clone(),
+        };
+        if native_dict_get(&output, &output_key).is_some() {
+            let kind = ErrorKind::DictDuplicateKey {
+                key: Python::with_gil(|py| output_key.to_object(py).as_ref(py).str().map(|s| s.to_string()))?,
+            };
+            errors.push(ValLineError::new_with_loc(kind, key, key.as_loc_item()));
+        } else {
+            output.push((output_key, value.clone()));
+        }
+    }
+    match errors.is_empty() {
+        true => Ok(output),
+        false => Err(ValError::LineErrors(errors)),
+    }
+}
+
+fn to_snake_case(key: &str) -> String {
+    let chars: Vec<char> = key.chars().collect();
+    let mut snake = String::with_capacity(key.len() + 4);
+    for (index, c) in chars.iter().enumerate() {
+        if *c == '-' {
+            snake.push('_');
+        } else if c.is_uppercase() {
+            let prev = index.checked_sub(1).map(|i| chars[i]);
+            let next = chars.get(index + 1);
+            // a word starts at an uppercase letter after a lowercase letter or digit, or at the last letter of an
+            // acronym, e.g. `HTTPServer` to `http_server`
+            let starts_word = match prev {
+                Some(prev) if prev.is_lowercase() || prev.is_ascii_digit() => true,
+                Some(prev) if prev.is_uppercase() => matches!(next, Some(next) if next.is_lowercase()),
+                _ => false,
+            };
+            if starts_word {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(*c);
+        }
+    }
+    snake
+}
+
+fn to_camel_case(snake: &str) -> String {
+    // leading underscores are kept, e.g. for `_private`
+    let body = snake.trim_start_matches('_');
+    let mut camel = snake[..snake.len() - body.len()].to_string();
+    let mut upper_next = false;
+    for c in body.chars() {
+        if c == '_' {
+            upper_next = true;
+        } else if upper_next {
+            camel.extend(c.to_uppercase());
+            upper_next = false;
+        } else {
+            camel.push(c);
+        }
+    }
+    camel
 }
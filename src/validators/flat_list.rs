@@ -0,0 +1,152 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::build_tools::{is_strict, SchemaDict, SchemaPath};
+use crate::errors::{ValError, ValLineError, ValResult};
+use crate::input::{GenericListLike, Input};
+use crate::recursion_guard::RecursionGuard;
+
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+/// Validate a list of lists (nested `max_depth` levels deep) and return a single flat list of the leaf items,
+/// errors are reported at the location of the leaf before flattening, e.g. `[row, column]`
+#[derive(Debug, Clone)]
+pub struct FlatListValidator {
+    strict: bool,
+    item_validator: Option<Box<CombinedValidator>>,
+    max_depth: usize,
+    name: String,
+}
+
+impl BuildValidator for FlatListValidator {
+    const EXPECTED_TYPE: &'static str = "flat-list";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let item_validator = match schema.get_item(intern!(py, "items_schema")) {
+            Some(d) => Some(Box::new(
+                build_validator(d, config, build_context).with_schema_path("items_schema")?,
+            )),
+            None => None,
+        };
+        let inner_name = item_validator.as_ref().map(|v| v.get_name()).unwrap_or("any");
+        let name = format!("{}[{}]", Self::EXPECTED_TYPE, inner_name);
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            item_validator,
+            max_depth: schema.get_as(intern!(py, "max_depth"))?.unwrap_or(1),
+            name,
+        }
+        .into())
+    }
+}
+
+impl Validator for FlatListValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let mut output: Vec<PyObject> = Vec::new();
+        self.flatten(py, input, 0, &mut output, extra, slots, recursion_guard)?;
+        Ok(output.into_py(py))
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
+        match self.item_validator {
+            Some(ref mut v) => v.complete(build_context),
+            None => Ok(()),
+        }
+    }
+}
+
+impl FlatListValidator {
+    /// `depth` is the number of lists already entered, every item nested less than `max_depth` levels within
+    /// the outer list must itself be a list, items beyond that are leaves
+    #[allow(clippy::too_many_arguments)]
+    fn flatten<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        depth: usize,
+        output: &mut Vec<PyObject>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, ()> {
+        if depth > self.max_depth {
+            let item = match self.item_validator {
+                Some(ref v) => v.validate(py, input, extra, slots, recursion_guard)?,
+                None => input.to_object(py),
+            };
+            output.push(item);
+            return Ok(());
+        }
+        let depth = depth + 1;
+        match input.validate_list(extra.strict.unwrap_or(self.strict))? {
+            GenericListLike::List(seq) => {
+                self.flatten_iter(py, seq.iter(), depth, output, extra, slots, recursion_guard)
+            }
+            GenericListLike::Tuple(seq) => {
+                self.flatten_iter(py, seq.iter(), depth, output, extra, slots, recursion_guard)
+            }
+            GenericListLike::Set(seq) => {
+                self.flatten_iter(py, seq.iter(), depth, output, extra, slots, recursion_guard)
+            }
+            GenericListLike::FrozenSet(seq) => {
+                self.flatten_iter(py, seq.iter(), depth, output, extra, slots, recursion_guard)
+            }
+            GenericListLike::JsonArray(seq) => {
+                self.flatten_iter(py, seq.iter(), depth, output, extra, slots, recursion_guard)
+            }
+            GenericListLike::NativeList(seq) => {
+                self.flatten_iter(py, seq.iter(), depth, output, extra, slots, recursion_guard)
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn flatten_iter<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        iter: impl Iterator<Item = &'data (impl Input<'data> + 'data)>,
+        depth: usize,
+        output: &mut Vec<PyObject>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, ()> {
+        let mut errors: Vec<ValLineError> = Vec::new();
+        for (index, item) in iter.enumerate() {
+            let sink_mark = extra.sink_mark();
+            let result = self.flatten(py, item, depth, output, extra, slots, recursion_guard);
+            extra.with_outer_location_since(sink_mark, || index.into());
+            match result {
+                Ok(()) => (),
+                Err(err @ ValError::LineErrors(_)) if extra.fail_fast => return Err(err),
+                Err(ValError::LineErrors(line_errors)) => {
+                    errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index.into())));
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ValError::LineErrors(errors))
+        }
+    }
+}
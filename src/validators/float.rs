@@ -2,18 +2,25 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use crate::build_tools::{is_strict, SchemaDict};
+use crate::build_tools::{debug_with_metadata, is_strict, py_error, schema_or_config_same, SchemaDict, SchemaMetadata};
 use crate::errors::{ErrorKind, ValError, ValResult};
 use crate::input::Input;
 use crate::recursion_guard::RecursionGuard;
 
-use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{
+    json_schema_type, schema_repr_dict, set_json_schema_item, strict_shorthand, BuildContext, BuildValidator,
+    CombinedValidator, Extra, Validator,
+};
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct FloatValidator {
     strict: bool,
+    allow_inf_nan: bool,
+    metadata: Option<SchemaMetadata>,
 }
 
+debug_with_metadata!(FloatValidator, strict, allow_inf_nan);
+
 impl BuildValidator for FloatValidator {
     const EXPECTED_TYPE: &'static str = "float";
 
@@ -33,12 +40,16 @@ impl BuildValidator for FloatValidator {
         } else {
             Ok(Self {
                 strict: is_strict(schema, config)?,
+                allow_inf_nan: allow_inf_nan(schema, config)?,
+                metadata: SchemaMetadata::from_schema(schema)?,
             }
             .into())
         }
     }
 }
 
+strict_shorthand!(StrictFloatBuilder, "strict-float", FloatValidator);
+
 impl Validator for FloatValidator {
     fn validate<'s, 'data>(
         &'s self,
@@ -48,17 +59,41 @@ impl Validator for FloatValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        Ok(input.validate_float(extra.strict.unwrap_or(self.strict))?.into_py(py))
+        let float = input.validate_float(extra.strict.unwrap_or(self.strict))?;
+        if !self.allow_inf_nan && !float.is_finite() {
+            return Err(ValError::new(ErrorKind::FiniteNumberRequired, input));
+        }
+        Ok(float.into_py(py))
     }
 
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
+
+    fn schema_repr(&self, py: Python, _slots: &[CombinedValidator]) -> PyResult<PyObject> {
+        let repr = schema_repr_dict(py, self.get_name())?;
+        repr.set_item("allow_inf_nan", self.allow_inf_nan)?;
+        Ok(repr.into_py(py))
+    }
+
+    fn json_schema(&self, py: Python, _slots: &[CombinedValidator], _definitions: &PyDict) -> PyResult<PyObject> {
+        let schema = json_schema_type(py, "number")?;
+        if let Some(ref metadata) = self.metadata {
+            metadata.update_dict(schema)?;
+        }
+        Ok(schema.into_py(py))
+    }
+
+    fn metadata(&self) -> Option<&SchemaMetadata> {
+        self.metadata.as_ref()
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct ConstrainedFloatValidator {
     strict: bool,
+    metadata: Option<SchemaMetadata>,
+    allow_inf_nan: bool,
     multiple_of: Option<f64>,
     le: Option<f64>,
     lt: Option<f64>,
@@ -66,6 +101,17 @@ pub struct ConstrainedFloatValidator {
     gt: Option<f64>,
 }
 
+debug_with_metadata!(
+    ConstrainedFloatValidator,
+    strict,
+    allow_inf_nan,
+    multiple_of,
+    le,
+    lt,
+    ge,
+    gt
+);
+
 impl Validator for ConstrainedFloatValidator {
     fn validate<'s, 'data>(
         &'s self,
@@ -76,28 +122,31 @@ impl Validator for ConstrainedFloatValidator {
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
         let float = input.validate_float(extra.strict.unwrap_or(self.strict))?;
+        if !self.allow_inf_nan && !float.is_finite() {
+            return Err(ValError::new(ErrorKind::FiniteNumberRequired, input));
+        }
         if let Some(multiple_of) = self.multiple_of {
-            if float % multiple_of != 0.0 {
+            if !is_multiple_of(float, multiple_of) {
                 return Err(ValError::new(ErrorKind::FloatMultipleOf { multiple_of }, input));
             }
         }
         if let Some(le) = self.le {
-            if float > le {
+            if float.is_nan() || float > le {
                 return Err(ValError::new(ErrorKind::FloatLessThanEqual { le }, input));
             }
         }
         if let Some(lt) = self.lt {
-            if float >= lt {
+            if float.is_nan() || float >= lt {
                 return Err(ValError::new(ErrorKind::FloatLessThan { lt }, input));
             }
         }
         if let Some(ge) = self.ge {
-            if float < ge {
+            if float.is_nan() || float < ge {
                 return Err(ValError::new(ErrorKind::FloatGreaterThanEqual { ge }, input));
             }
         }
         if let Some(gt) = self.gt {
-            if float <= gt {
+            if float.is_nan() || float <= gt {
                 return Err(ValError::new(ErrorKind::FloatGreaterThan { gt }, input));
             }
         }
@@ -106,14 +155,53 @@ impl Validator for ConstrainedFloatValidator {
     fn get_name(&self) -> &str {
         "constrained-float"
     }
+
+    fn expected_repr(&self) -> String {
+        FloatValidator::EXPECTED_TYPE.to_string()
+    }
+
+    fn schema_repr(&self, py: Python, _slots: &[CombinedValidator]) -> PyResult<PyObject> {
+        let repr = schema_repr_dict(py, self.get_name())?;
+        repr.set_item("strict", self.strict)?;
+        repr.set_item("allow_inf_nan", self.allow_inf_nan)?;
+        set_json_schema_item(repr, "multiple_of", self.multiple_of)?;
+        set_json_schema_item(repr, "le", self.le)?;
+        set_json_schema_item(repr, "lt", self.lt)?;
+        set_json_schema_item(repr, "ge", self.ge)?;
+        set_json_schema_item(repr, "gt", self.gt)?;
+        Ok(repr.into_py(py))
+    }
+
+    fn json_schema(&self, py: Python, _slots: &[CombinedValidator], _definitions: &PyDict) -> PyResult<PyObject> {
+        let schema = json_schema_type(py, "number")?;
+        set_json_schema_item(schema, "multipleOf", self.multiple_of)?;
+        set_json_schema_item(schema, "maximum", self.le)?;
+        set_json_schema_item(schema, "exclusiveMaximum", self.lt)?;
+        set_json_schema_item(schema, "minimum", self.ge)?;
+        set_json_schema_item(schema, "exclusiveMinimum", self.gt)?;
+        if let Some(ref metadata) = self.metadata {
+            metadata.update_dict(schema)?;
+        }
+        Ok(schema.into_py(py))
+    }
+
+    fn metadata(&self) -> Option<&SchemaMetadata> {
+        self.metadata.as_ref()
+    }
 }
 
 impl ConstrainedFloatValidator {
     pub fn build(schema: &PyDict, config: Option<&PyDict>) -> PyResult<CombinedValidator> {
         let py = schema.py();
+        let multiple_of: Option<f64> = schema.get_as(intern!(py, "multiple_of"))?;
+        if matches!(multiple_of, Some(m) if m <= 0.0) {
+            return py_error!("'multiple_of' should be greater than 0");
+        }
         Ok(Self {
             strict: is_strict(schema, config)?,
-            multiple_of: schema.get_as(intern!(py, "multiple_of"))?,
+            metadata: SchemaMetadata::from_schema(schema)?,
+            allow_inf_nan: allow_inf_nan(schema, config)?,
+            multiple_of,
             le: schema.get_as(intern!(py, "le"))?,
             lt: schema.get_as(intern!(py, "lt"))?,
             ge: schema.get_as(intern!(py, "ge"))?,
@@ -122,3 +210,69 @@ impl ConstrainedFloatValidator {
         .into())
     }
 }
+
+/// Relative tolerance used when checking `multiple_of` for floats.
+///
+/// `0.3 % 0.1` is `0.09999999999999998` because neither number is exactly representable in binary, so instead
+/// we check that `value / multiple_of` is within `MULTIPLE_OF_EPSILON` (relative to the quotient) of a whole number.
+const MULTIPLE_OF_EPSILON: f64 = 1e-9;
+
+/// Upper bound on the tolerance, as a fraction of `multiple_of`, without it the relative tolerance reaches half a
+/// step for large quotients and every value passes, e.g. `2000000001.0` as a multiple of `2.0`.
+const MULTIPLE_OF_MAX_TOLERANCE: f64 = 1e-3;
+
+/// `NaN` and infinite values are rejected unless `allow_inf_nan` is set on the schema or in config
+fn allow_inf_nan(schema: &PyDict, config: Option<&PyDict>) -> PyResult<bool> {
+    let py = schema.py();
+    Ok(schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(false))
+}
+
+fn is_multiple_of(value: f64, multiple_of: f64) -> bool {
+    let quotient = value / multiple_of;
+    let tolerance = (MULTIPLE_OF_EPSILON * quotient.abs().max(1.0)).min(MULTIPLE_OF_MAX_TOLERANCE);
+    (quotient - quotient.round()).abs() <= tolerance
+}
+
+/// Shorthand for a `float` schema with a hard-coded bound, e.g. `positive-float` is `{'type': 'float', 'gt': 0}`,
+/// other constraints still apply and the bound overrides any value set in the schema
+macro_rules! float_shorthand {
+    ($builder:ident, $expected_type:literal, $bound:literal) => {
+        pub struct $builder;
+
+        impl BuildValidator for $builder {
+            const EXPECTED_TYPE: &'static str = $expected_type;
+
+            fn build(
+                schema: &PyDict,
+                config: Option<&PyDict>,
+                _build_context: &mut BuildContext,
+            ) -> PyResult<CombinedValidator> {
+                let schema = schema.copy()?;
+                schema.set_item(intern!(schema.py(), $bound), 0.0)?;
+                ConstrainedFloatValidator::build(schema, config)
+            }
+        }
+    };
+}
+
+/// `finite-float` rejects `NaN` and infinite values even if `allow_inf_nan` is set in config
+pub struct FiniteFloatBuilder;
+
+impl BuildValidator for FiniteFloatBuilder {
+    const EXPECTED_TYPE: &'static str = "finite-float";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let schema = schema.copy()?;
+        schema.set_item(intern!(schema.py(), "allow_inf_nan"), false)?;
+        FloatValidator::build(schema, config, build_context)
+    }
+}
+
+float_shorthand!(PositiveFloatBuilder, "positive-float", "gt");
+float_shorthand!(NegativeFloatBuilder, "negative-float", "lt");
+float_shorthand!(NonNegativeFloatBuilder, "non-negative-float", "ge");
+float_shorthand!(NonPositiveFloatBuilder, "non-positive-float", "le");
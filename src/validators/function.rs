@@ -1,14 +1,17 @@
-use pyo3::exceptions::{PyAssertionError, PyValueError};
+use std::sync::Arc;
+
+use pyo3::exceptions::{PyAssertionError, PyTypeError, PyValueError};
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyAny, PyDict};
 
-use crate::build_tools::SchemaDict;
+use crate::build_tools::{py_error, SchemaDict, SchemaPath};
 use crate::errors::{ErrorKind, PydanticValueError, ValError, ValResult, ValidationError};
 use crate::input::Input;
+use crate::lenient_coercion::LenientCoercionConfig;
 use crate::recursion_guard::RecursionGuard;
 
-use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, InputMode, Validator};
 
 pub struct FunctionBuilder;
 
@@ -20,7 +23,11 @@ impl BuildValidator for FunctionBuilder {
         config: Option<&PyDict>,
         build_context: &mut BuildContext,
     ) -> PyResult<CombinedValidator> {
-        let mode: &str = schema.get_as_req(intern!(schema.py(), "mode"))?;
+        let py = schema.py();
+        let mode: &str = schema.get_as_req(intern!(py, "mode"))?;
+        if schema.get_as(intern!(py, "signature_check"))? == Some(true) {
+            check_signature(py, schema.get_as_req(intern!(py, "function"))?, mode)?;
+        }
         match mode {
             "before" => FunctionBeforeValidator::build(schema, config, build_context),
             "after" => FunctionAfterValidator::build(schema, config, build_context),
@@ -31,6 +38,33 @@ impl BuildValidator for FunctionBuilder {
     }
 }
 
+/// Check at build time that `function` can be called the way it will be called during validation, with the value
+/// as the only positional argument plus the keyword arguments for `mode`, functions without a signature `inspect`
+/// can read (e.g. some builtins) are accepted
+fn check_signature(py: Python, function: &PyAny, mode: &str) -> PyResult<()> {
+    let keywords: &[&str] = match mode {
+        "wrap" => &["validator", "data", "config", "context"],
+        _ => &["data", "config", "context"],
+    };
+    let signature = match py.import("inspect")?.call_method1("signature", (function,)) {
+        Ok(signature) => signature,
+        Err(err) if err.is_instance_of::<PyValueError>(py) || err.is_instance_of::<PyTypeError>(py) => return Ok(()),
+        Err(err) => return Err(err),
+    };
+    let kwargs = pyo3::types::IntoPyDict::into_py_dict(keywords.iter().map(|k| (*k, py.None())), py);
+    match signature.call_method("bind", (py.None(),), Some(kwargs)) {
+        Ok(_) => Ok(()),
+        Err(err) if err.is_instance_of::<PyTypeError>(py) => py_error!(
+            "function signature {} is incompatible with mode \"{}\", it should accept (value, *, {}): {}",
+            signature,
+            mode,
+            keywords.join(", "),
+            err.value(py)
+        ),
+        Err(err) => Err(err),
+    }
+}
+
 macro_rules! kwargs {
     ($py:ident, $($k:ident: $v:expr),* $(,)?) => {{
         Some(pyo3::types::IntoPyDict::into_py_dict([$((stringify!($k), $v.into_py($py)),)*], $py).into())
@@ -46,7 +80,8 @@ macro_rules! impl_build {
                 build_context: &mut BuildContext,
             ) -> PyResult<CombinedValidator> {
                 let py = schema.py();
-                let validator = build_validator(schema.get_as_req(intern!(py, "schema"))?, config, build_context)?;
+                let validator = build_validator(schema.get_as_req(intern!(py, "schema"))?, config, build_context)
+                    .with_schema_path("schema")?;
                 let name = format!("{}[{}]", $name, validator.get_name());
                 Ok(Self {
                     validator: Box::new(validator),
@@ -203,12 +238,17 @@ impl Validator for FunctionWrapValidator {
     ) -> ValResult<'data, PyObject> {
         let validator_kwarg = ValidatorCallable {
             validator: self.validator.clone(),
-            slots: slots.to_vec(),
+            slots: extra.owned_slots(slots),
             data: extra.data.map(|d| d.into_py(py)),
             field: extra.field.map(|f| f.to_string()),
             strict: extra.strict,
             context: extra.context.map(|d| d.into_py(py)),
+            max_depth: extra.max_depth,
+            coercion: extra.coercion,
+            input_mode: extra.input_mode,
             recursion_guard: recursion_guard.clone(),
+            #[cfg(feature = "profiling")]
+            stats: extra.stats.map(|stats| stats.share()),
         };
         let kwargs = kwargs!(
             py,
@@ -239,12 +279,17 @@ impl Validator for FunctionWrapValidator {
 #[derive(Debug, Clone)]
 struct ValidatorCallable {
     validator: Box<CombinedValidator>,
-    slots: Vec<CombinedValidator>,
+    slots: Arc<[CombinedValidator]>,
     data: Option<Py<PyDict>>,
     field: Option<String>,
     strict: Option<bool>,
     context: Option<PyObject>,
+    max_depth: Option<usize>,
+    coercion: LenientCoercionConfig,
+    input_mode: InputMode,
     recursion_guard: RecursionGuard,
+    #[cfg(feature = "profiling")]
+    stats: Option<super::profiling::Stats>,
 }
 
 #[pymethods]
@@ -255,10 +300,21 @@ impl ValidatorCallable {
             field: self.field.as_deref(),
             strict: self.strict,
             context: self.context.as_ref().map(|data| data.as_ref(py)),
+            max_depth: self.max_depth,
+            // this may be called after the outer validation has finished, so warnings can't be recorded
+            warnings: None,
+            trace: None,
+            coercion: self.coercion,
+            input_mode: self.input_mode,
+            item_callback: None,
+            fail_fast: false,
+            shared_slots: Some(&self.slots),
+            #[cfg(feature = "profiling")]
+            stats: self.stats.as_ref(),
         };
         self.validator
             .validate(py, arg, &extra, &self.slots, &mut self.recursion_guard)
-            .map_err(|e| ValidationError::from_val_error(py, "Model".to_object(py), e))
+            .map_err(|e| ValidationError::from_val_error(py, "Model".to_object(py), e, false))
     }
 
     fn __repr__(&self) -> String {
@@ -288,7 +344,7 @@ macro_rules! py_err_string {
     };
 }
 
-fn convert_err<'a>(py: Python<'a>, err: PyErr, input: &'a impl Input<'a>) -> ValError<'a> {
+pub fn convert_err<'a>(py: Python<'a>, err: PyErr, input: &'a impl Input<'a>) -> ValError<'a> {
     // Only ValueError and AssertionError are considered as validation errors,
     // TypeError is now considered as a runtime error to catch errors in function signatures
     if err.is_instance_of::<PyValueError>(py) {
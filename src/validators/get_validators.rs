@@ -0,0 +1,77 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::build_tools::{py_error, SchemaDict};
+use crate::errors::ValResult;
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::function::convert_err;
+use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+/// Support for pydantic v1 custom types which define a `__get_validators__` class method yielding validator
+/// functions, each function is called with the output of the previous one
+#[derive(Debug, Clone)]
+pub struct GetValidatorsValidator {
+    validators: Vec<PyObject>,
+    name: String,
+}
+
+impl BuildValidator for GetValidatorsValidator {
+    const EXPECTED_TYPE: &'static str = "get-validators";
+
+    fn build(
+        schema: &PyDict,
+        _config: Option<&PyDict>,
+        _build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let class: &PyAny = schema.get_as_req(intern!(py, "class_type"))?;
+        let class_name: String = class.getattr(intern!(py, "__name__"))?.extract()?;
+        let get_validators = match class.getattr(intern!(py, "__get_validators__")) {
+            Ok(get_validators) => get_validators,
+            Err(_) => return py_error!("{} has no `__get_validators__` method", class_name),
+        };
+        let validators = get_validators
+            .call0()?
+            .iter()?
+            .map(|v| v.map(|v| v.into_py(py)))
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let message = format!(
+            "`{}.__get_validators__` is deprecated, use a function validator instead",
+            class_name
+        );
+        // pyo3 doesn't expose `DeprecationWarning` yet
+        let category = py.import("builtins")?.getattr(intern!(py, "DeprecationWarning"))?;
+        PyErr::warn(py, category, &message, 1)?;
+
+        Ok(Self {
+            validators,
+            name: format!("{}[{}]", Self::EXPECTED_TYPE, class_name),
+        }
+        .into())
+    }
+}
+
+impl Validator for GetValidatorsValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        _extra: &Extra,
+        _slots: &'data [CombinedValidator],
+        _recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let mut value = input.to_object(py);
+        for validator in &self.validators {
+            value = validator.call1(py, (value,)).map_err(|e| convert_err(py, e, input))?;
+        }
+        Ok(value)
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
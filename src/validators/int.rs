@@ -2,18 +2,25 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use crate::build_tools::{is_strict, SchemaDict};
+use crate::build_tools::{debug_with_metadata, is_strict, py_error, SchemaDict, SchemaMetadata};
 use crate::errors::{ErrorKind, ValError, ValResult};
-use crate::input::Input;
+use crate::input::{float_as_int, Input};
 use crate::recursion_guard::RecursionGuard;
 
-use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{
+    json_schema_type, schema_repr_dict, set_json_schema_item, strict_shorthand, BuildContext, BuildValidator,
+    CombinedValidator, Extra, Validator,
+};
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct IntValidator {
     strict: bool,
+    allow_inexact_int_from_float: bool,
+    metadata: Option<SchemaMetadata>,
 }
 
+debug_with_metadata!(IntValidator, strict, allow_inexact_int_from_float);
+
 impl BuildValidator for IntValidator {
     const EXPECTED_TYPE: &'static str = "int";
 
@@ -33,12 +40,18 @@ impl BuildValidator for IntValidator {
         } else {
             Ok(Self {
                 strict: is_strict(schema, config)?,
+                allow_inexact_int_from_float: schema
+                    .get_as(intern!(py, "allow_inexact_int_from_float"))?
+                    .unwrap_or(false),
+                metadata: SchemaMetadata::from_schema(schema)?,
             }
             .into())
         }
     }
 }
 
+strict_shorthand!(StrictIntBuilder, "strict-int", IntValidator);
+
 impl Validator for IntValidator {
     fn validate<'s, 'data>(
         &'s self,
@@ -48,17 +61,32 @@ impl Validator for IntValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        Ok(input.validate_int(extra.strict.unwrap_or(self.strict))?.into_py(py))
+        let strict = extra.strict.unwrap_or(self.strict);
+        Ok(validate_int(input, strict, self.allow_inexact_int_from_float, extra)?.into_py(py))
     }
 
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
+
+    fn json_schema(&self, py: Python, _slots: &[CombinedValidator], _definitions: &PyDict) -> PyResult<PyObject> {
+        let schema = json_schema_type(py, "integer")?;
+        if let Some(ref metadata) = self.metadata {
+            metadata.update_dict(schema)?;
+        }
+        Ok(schema.into_py(py))
+    }
+
+    fn metadata(&self) -> Option<&SchemaMetadata> {
+        self.metadata.as_ref()
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct ConstrainedIntValidator {
     strict: bool,
+    allow_inexact_int_from_float: bool,
+    metadata: Option<SchemaMetadata>,
     multiple_of: Option<i64>,
     le: Option<i64>,
     lt: Option<i64>,
@@ -66,6 +94,17 @@ pub struct ConstrainedIntValidator {
     gt: Option<i64>,
 }
 
+debug_with_metadata!(
+    ConstrainedIntValidator,
+    strict,
+    allow_inexact_int_from_float,
+    multiple_of,
+    le,
+    lt,
+    ge,
+    gt
+);
+
 impl Validator for ConstrainedIntValidator {
     fn validate<'s, 'data>(
         &'s self,
@@ -75,9 +114,12 @@ impl Validator for ConstrainedIntValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let int = input.validate_int(extra.strict.unwrap_or(self.strict))?;
+        let strict = extra.strict.unwrap_or(self.strict);
+        let int = validate_int(input, strict, self.allow_inexact_int_from_float, extra)?;
+        // constraints are checked on the rust `i64`, no python object is created unless every check passes,
+        // errors only hold a reference to the input
         if let Some(multiple_of) = self.multiple_of {
-            if int % multiple_of != 0 {
+            if int.checked_rem(multiple_of) != Some(0) {
                 return Err(ValError::new(ErrorKind::IntMultipleOf { multiple_of }, input));
             }
         }
@@ -107,14 +149,54 @@ impl Validator for ConstrainedIntValidator {
     fn get_name(&self) -> &str {
         "constrained-int"
     }
+
+    fn expected_repr(&self) -> String {
+        IntValidator::EXPECTED_TYPE.to_string()
+    }
+
+    fn schema_repr(&self, py: Python, _slots: &[CombinedValidator]) -> PyResult<PyObject> {
+        let repr = schema_repr_dict(py, self.get_name())?;
+        repr.set_item("strict", self.strict)?;
+        set_json_schema_item(repr, "multiple_of", self.multiple_of)?;
+        set_json_schema_item(repr, "le", self.le)?;
+        set_json_schema_item(repr, "lt", self.lt)?;
+        set_json_schema_item(repr, "ge", self.ge)?;
+        set_json_schema_item(repr, "gt", self.gt)?;
+        Ok(repr.into_py(py))
+    }
+
+    fn json_schema(&self, py: Python, _slots: &[CombinedValidator], _definitions: &PyDict) -> PyResult<PyObject> {
+        let schema = json_schema_type(py, "integer")?;
+        set_json_schema_item(schema, "multipleOf", self.multiple_of)?;
+        set_json_schema_item(schema, "maximum", self.le)?;
+        set_json_schema_item(schema, "exclusiveMaximum", self.lt)?;
+        set_json_schema_item(schema, "minimum", self.ge)?;
+        set_json_schema_item(schema, "exclusiveMinimum", self.gt)?;
+        if let Some(ref metadata) = self.metadata {
+            metadata.update_dict(schema)?;
+        }
+        Ok(schema.into_py(py))
+    }
+
+    fn metadata(&self) -> Option<&SchemaMetadata> {
+        self.metadata.as_ref()
+    }
 }
 
 impl ConstrainedIntValidator {
     fn build(schema: &PyDict, config: Option<&PyDict>) -> PyResult<CombinedValidator> {
         let py = schema.py();
+        let multiple_of: Option<i64> = schema.get_as(intern!(py, "multiple_of"))?;
+        if matches!(multiple_of, Some(m) if m <= 0) {
+            return py_error!("'multiple_of' should be greater than 0");
+        }
         Ok(Self {
             strict: is_strict(schema, config)?,
-            multiple_of: schema.get_as(intern!(py, "multiple_of"))?,
+            allow_inexact_int_from_float: schema
+                .get_as(intern!(py, "allow_inexact_int_from_float"))?
+                .unwrap_or(false),
+            metadata: SchemaMetadata::from_schema(schema)?,
+            multiple_of,
             le: schema.get_as(intern!(py, "le"))?,
             lt: schema.get_as(intern!(py, "lt"))?,
             ge: schema.get_as(intern!(py, "ge"))?,
@@ -123,3 +205,50 @@ impl ConstrainedIntValidator {
         .into())
     }
 }
+
+/// Shorthand for an `int` schema with a hard-coded bound, e.g. `positive-int` is `{'type': 'int', 'gt': 0}`,
+/// other constraints still apply and the bound overrides any value set in the schema
+macro_rules! int_shorthand {
+    ($builder:ident, $expected_type:literal, $bound:literal) => {
+        pub struct $builder;
+
+        impl BuildValidator for $builder {
+            const EXPECTED_TYPE: &'static str = $expected_type;
+
+            fn build(
+                schema: &PyDict,
+                config: Option<&PyDict>,
+                _build_context: &mut BuildContext,
+            ) -> PyResult<CombinedValidator> {
+                let schema = schema.copy()?;
+                schema.set_item(intern!(schema.py(), $bound), 0)?;
+                ConstrainedIntValidator::build(schema, config)
+            }
+        }
+    };
+}
+
+int_shorthand!(PositiveIntBuilder, "positive-int", "gt");
+int_shorthand!(NegativeIntBuilder, "negative-int", "lt");
+int_shorthand!(NonNegativeIntBuilder, "non-negative-int", "ge");
+int_shorthand!(NonPositiveIntBuilder, "non-positive-int", "le");
+
+/// `input.validate_int` which in lax mode first checks coercion from the input's type hasn't been disabled
+/// via `lenient_coercion`, floats with a fractional part are truncated if `allow_inexact_int_from_float` is set,
+/// otherwise they're an `IntFromFloat` error
+fn validate_int<'data>(
+    input: &'data impl Input<'data>,
+    strict: bool,
+    allow_inexact_int_from_float: bool,
+    extra: &Extra,
+) -> ValResult<'data, i64> {
+    if !strict {
+        extra.coercion.check_int(input)?;
+        if allow_inexact_int_from_float && input.strict_int().is_err() {
+            if let Ok(float) = input.strict_float() {
+                return float_as_int(input, float.trunc());
+            }
+        }
+    }
+    input.validate_int(strict)
+}
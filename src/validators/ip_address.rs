@@ -0,0 +1,143 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyDict, PyInt, PyType};
+
+use crate::build_tools::{is_strict, py_error, SchemaDict};
+use crate::errors::{ErrorKind, ValError, ValResult};
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+/// Validates IPv4 and IPv6 addresses, the output is an `ipaddress.IPv4Address` or `ipaddress.IPv6Address`.
+///
+/// In strict mode only instances of those classes are accepted, in lax mode strings are parsed, and if
+/// `allow_packed` is set, integers and packed bytes (4 or 16 bytes, big-endian) are accepted too.
+#[derive(Debug, Clone)]
+pub struct IpAddressValidator {
+    strict: bool,
+    version: Option<u8>,
+    allow_packed: bool,
+    ipv4_class: Py<PyType>,
+    ipv6_class: Py<PyType>,
+    name: String,
+}
+
+impl BuildValidator for IpAddressValidator {
+    const EXPECTED_TYPE: &'static str = "ip-address";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        _build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let version: Option<u8> = schema.get_as(intern!(py, "version"))?;
+        let name = match version {
+            Some(4) => "ipv4-address".to_string(),
+            Some(6) => "ipv6-address".to_string(),
+            Some(v) => return py_error!("Invalid IP version {}, should be 4 or 6", v),
+            None => Self::EXPECTED_TYPE.to_string(),
+        };
+        let ipaddress = py.import("ipaddress")?;
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            version,
+            allow_packed: schema.get_as(intern!(py, "allow_packed"))?.unwrap_or(false),
+            ipv4_class: ipaddress.getattr(intern!(py, "IPv4Address"))?.extract()?,
+            ipv6_class: ipaddress.getattr(intern!(py, "IPv6Address"))?.extract()?,
+            name,
+        }
+        .into())
+    }
+}
+
+impl Validator for IpAddressValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        _slots: &'data [CombinedValidator],
+        _recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        if input.is_instance(self.ipv4_class.as_ref(py))? {
+            self.check_version(4, input)?;
+            return Ok(input.to_object(py));
+        } else if input.is_instance(self.ipv6_class.as_ref(py))? {
+            self.check_version(6, input)?;
+            return Ok(input.to_object(py));
+        } else if extra.strict.unwrap_or(self.strict) {
+            return Err(ValError::new(ErrorKind::IpAddressType, input));
+        }
+
+        let ip_addr = if let Ok(either_str) = input.strict_str() {
+            match either_str.as_cow()?.parse::<IpAddr>() {
+                Ok(ip_addr) => ip_addr,
+                Err(_) => return Err(ValError::new(ErrorKind::IpAddressParsing, input)),
+            }
+        } else if !self.allow_packed {
+            return Err(ValError::new(ErrorKind::IpAddressType, input));
+        } else if let Ok(int) = input.strict_int() {
+            match u128::try_from(int) {
+                Ok(int) => self.ip_from_int(int),
+                Err(_) => return Err(ValError::new(ErrorKind::IpAddressParsing, input)),
+            }
+        } else if let Ok(either_bytes) = input.strict_bytes() {
+            match either_bytes.as_slice() {
+                b if b.len() == 4 => IpAddr::V4(Ipv4Addr::from(u32::from_be_bytes(b.try_into().unwrap()))),
+                b if b.len() == 16 => IpAddr::V6(Ipv6Addr::from(u128::from_be_bytes(b.try_into().unwrap()))),
+                _ => return Err(ValError::new(ErrorKind::IpAddressParsing, input)),
+            }
+        } else {
+            // most IPv6 addresses are beyond `i64`, so python ints are extracted as `u128` directly
+            let obj = input.to_object(py);
+            let obj = obj.as_ref(py);
+            if obj.cast_as::<PyInt>().is_err() || obj.cast_as::<PyBool>().is_ok() {
+                return Err(ValError::new(ErrorKind::IpAddressType, input));
+            }
+            match obj.extract::<u128>() {
+                Ok(int) => self.ip_from_int(int),
+                // negative or beyond 128 bits
+                Err(_) => return Err(ValError::new(ErrorKind::IpAddressParsing, input)),
+            }
+        };
+
+        // construct the python object from the integer value so the address isn't parsed a second time
+        match ip_addr {
+            IpAddr::V4(ipv4) => {
+                self.check_version(4, input)?;
+                Ok(self.ipv4_class.as_ref(py).call1((u32::from(ipv4),))?.into_py(py))
+            }
+            IpAddr::V6(ipv6) => {
+                self.check_version(6, input)?;
+                Ok(self.ipv6_class.as_ref(py).call1((u128::from(ipv6),))?.into_py(py))
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl IpAddressValidator {
+    fn check_version<'d>(&self, version: u8, input: &'d impl Input<'d>) -> ValResult<'d, ()> {
+        match self.version {
+            Some(expected) if expected != version => {
+                Err(ValError::new(ErrorKind::IpVersion { version: expected }, input))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// like `ipaddress.ip_address`, integers which fit in 32 bits are IPv4 unless IPv6 is required
+    fn ip_from_int(&self, int: u128) -> IpAddr {
+        match u32::try_from(int) {
+            Ok(int) if self.version != Some(6) => IpAddr::V4(Ipv4Addr::from(int)),
+            _ => IpAddr::V6(Ipv6Addr::from(int)),
+        }
+    }
+}
@@ -1,17 +1,18 @@
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyType};
+use pyo3::types::{PyDict, PyTuple, PyType};
 
-use crate::build_tools::SchemaDict;
+use crate::build_tools::{py_error, SchemaDict};
 use crate::errors::{ErrorKind, ValError, ValResult};
-use crate::input::Input;
+use crate::input::{repr_string, Input};
 use crate::recursion_guard::RecursionGuard;
 
-use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{get_class_repr, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub struct IsInstanceValidator {
-    class: Py<PyType>,
+    // like `isinstance`, `class_` may be a tuple of classes, input is valid if it's an instance of any of them
+    classes: Vec<Py<PyType>>,
     class_repr: String,
     name: String,
 }
@@ -24,11 +25,30 @@ impl BuildValidator for IsInstanceValidator {
         _config: Option<&PyDict>,
         _build_context: &mut BuildContext,
     ) -> PyResult<CombinedValidator> {
-        let class: &PyType = schema.get_as_req(intern!(schema.py(), "class_"))?;
-        let class_repr = class.name()?.to_string();
+        let class: &PyAny = schema.get_as_req(intern!(schema.py(), "class_"))?;
+        let (classes, class_repr) = match class.cast_as::<PyTuple>() {
+            Ok(tuple) => {
+                let classes = tuple
+                    .iter()
+                    .map(|c| match c.cast_as::<PyType>() {
+                        Ok(class) => Ok(class),
+                        Err(_) => py_error!("class_ tuple items should be classes, got {}", repr_string(c)?),
+                    })
+                    .collect::<PyResult<Vec<&PyType>>>()?;
+                let names = classes
+                    .iter()
+                    .map(|c| get_class_repr(c))
+                    .collect::<PyResult<Vec<String>>>()?;
+                (classes, format!("({})", names.join(", ")))
+            }
+            Err(_) => {
+                let class: &PyType = class.extract()?;
+                (vec![class], get_class_repr(class)?)
+            }
+        };
         let name = format!("{}[{}]", Self::EXPECTED_TYPE, class_repr);
         Ok(Self {
-            class: class.into(),
+            classes: classes.into_iter().map(Into::into).collect(),
             class_repr,
             name,
         }
@@ -45,15 +65,17 @@ impl Validator for IsInstanceValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        match input.is_instance(self.class.as_ref(py))? {
-            true => Ok(input.to_object(py)),
-            false => Err(ValError::new(
-                ErrorKind::IsInstanceOf {
-                    class: self.class_repr.clone(),
-                },
-                input,
-            )),
+        for class in &self.classes {
+            if input.is_instance(class.as_ref(py))? {
+                return Ok(input.to_object(py));
+            }
         }
+        Err(ValError::new(
+            ErrorKind::IsInstanceOf {
+                class: self.class_repr.clone(),
+            },
+            input,
+        ))
     }
 
     fn get_name(&self) -> &str {
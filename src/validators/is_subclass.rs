@@ -0,0 +1,95 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyType};
+
+use crate::build_tools::{is_strict, SchemaDict};
+use crate::errors::{ErrorKind, ValError, ValResult};
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::{get_class_repr, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+#[derive(Debug, Clone)]
+pub struct IsSubclassValidator {
+    class: Py<PyType>,
+    class_repr: String,
+    strict: bool,
+    name: String,
+}
+
+impl BuildValidator for IsSubclassValidator {
+    const EXPECTED_TYPE: &'static str = "is-subclass";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        _build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let class: &PyType = schema.get_as_req(intern!(schema.py(), "class_"))?;
+        let class_repr = get_class_repr(class)?;
+        let name = format!("{}[{}]", Self::EXPECTED_TYPE, class_repr);
+        Ok(Self {
+            class: class.into(),
+            class_repr,
+            strict: is_strict(schema, config)?,
+            name,
+        }
+        .into())
+    }
+}
+
+impl Validator for IsSubclassValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        _slots: &'data [CombinedValidator],
+        _recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let class = self.class.as_ref(py);
+        if input.is_subclass(class)? {
+            return Ok(input.to_object(py));
+        }
+        // only classes which aren't subclasses get `IsSubclassOf`, anything else isn't even a class
+        let mut is_class = input.is_class();
+        if !is_class && !extra.strict.unwrap_or(self.strict) {
+            if let Ok(either_str) = input.strict_str() {
+                if let Some(found) = class_from_name(py, &either_str.as_cow()?)? {
+                    if found.is_subclass(class)? {
+                        return Ok(found.into_py(py));
+                    }
+                    is_class = true;
+                }
+            }
+        }
+        match is_class {
+            true => Err(ValError::new(
+                ErrorKind::IsSubclassOf {
+                    class: self.class_repr.clone(),
+                },
+                input,
+            )),
+            false => Err(ValError::new(ErrorKind::ClassType, input)),
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Find a class from its dotted path, e.g. `"collections.OrderedDict"`, names without a module are looked up in
+/// `builtins`. Modules are only read from `sys.modules`, nothing is imported.
+fn class_from_name<'py>(py: Python<'py>, name: &str) -> PyResult<Option<&'py PyType>> {
+    let (module_name, class_name) = name.rsplit_once('.').unwrap_or(("builtins", name));
+    let modules: &PyDict = py.import("sys")?.getattr(intern!(py, "modules"))?.cast_as()?;
+    let module = match modules.get_item(module_name) {
+        Some(module) => module,
+        None => return Ok(None),
+    };
+    match module.getattr(class_name) {
+        Ok(attr) => Ok(attr.cast_as::<PyType>().ok()),
+        Err(_) => Ok(None),
+    }
+}
@@ -1,19 +1,36 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use pyo3::exceptions::PyTypeError;
+use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyIterator, PyList, PySet};
 
-use crate::build_tools::SchemaDict;
-use crate::errors::ValResult;
+use crate::build_tools::{is_strict, py_error, validate_length_constraints, SchemaDict, SchemaPath};
+use crate::errors::{py_err_string, ErrorKind, InputValue, PyLineError, ValError, ValResult, ValidationError};
 use crate::input::{GenericListLike, Input};
+use crate::lenient_coercion::LenientCoercionConfig;
 use crate::recursion_guard::RecursionGuard;
 
-use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{
+    build_validator, check_max_input_len, check_max_input_length, schema_repr_dict, set_json_schema_item, BuildContext,
+    BuildValidator, CombinedValidator, Extra, InputMode, Validator,
+};
 
 #[derive(Debug, Clone)]
 pub struct ListValidator {
     strict: bool,
     item_validator: Option<Box<CombinedValidator>>,
     size_range: Option<(Option<usize>, Option<usize>)>,
+    // from config, checked before `size_range`, see `check_max_input_length`
+    max_input_length: Option<usize>,
+    lazy: bool,
+    unique_items: bool,
+    preserve_order: bool,
+    hide_input_in_errors: bool,
     name: String,
+    // e.g. `list[int]`, `None` unless `items_schema` is set
+    expected: Option<String>,
 }
 
 macro_rules! generic_list_like_build {
@@ -28,13 +45,22 @@ macro_rules! generic_list_like_build {
         ) -> PyResult<CombinedValidator> {
             let py = schema.py();
             let item_validator = match schema.get_item(pyo3::intern!(py, "items_schema")) {
-                Some(d) => Some(Box::new(build_validator(d, config, build_context)?)),
+                Some(d) => Some(Box::new(
+                    build_validator(d, config, build_context).with_schema_path("items_schema")?,
+                )),
                 None => None,
             };
             let inner_name = item_validator.as_ref().map(|v| v.get_name()).unwrap_or("any");
             let name = format!($name_template, $name, inner_name);
-            let min_items = schema.get_as(pyo3::intern!(py, "min_items"))?;
-            let max_items = schema.get_as(pyo3::intern!(py, "max_items"))?;
+            let expected = item_validator
+                .as_ref()
+                .map(|v| format!($name_template, $name, v.expected_repr()));
+            let (min_items, max_items) = crate::build_tools::validate_length_constraints(
+                py,
+                "min_items",
+                schema.get_as(pyo3::intern!(py, "min_items"))?,
+                schema.get_as(pyo3::intern!(py, "max_items"))?,
+            )?;
             Ok(Self {
                 strict: crate::build_tools::is_strict(schema, config)?,
                 item_validator,
@@ -43,6 +69,7 @@ macro_rules! generic_list_like_build {
                     false => None,
                 },
                 name,
+                expected,
             }
             .into())
         }
@@ -52,7 +79,56 @@ pub(crate) use generic_list_like_build;
 
 impl BuildValidator for ListValidator {
     const EXPECTED_TYPE: &'static str = "list";
-    generic_list_like_build!();
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let item_validator = match schema.get_item(intern!(py, "items_schema")) {
+            Some(d) => Some(Box::new(
+                build_validator(d, config, build_context).with_schema_path("items_schema")?,
+            )),
+            None => None,
+        };
+        let inner_name = item_validator.as_ref().map(|v| v.get_name()).unwrap_or("any");
+        let expected = item_validator
+            .as_ref()
+            .map(|v| format!("{}[{}]", Self::EXPECTED_TYPE, v.expected_repr()));
+        let lazy = schema.get_as(intern!(py, "lazy"))?.unwrap_or(false);
+        let name = match lazy {
+            true => format!("lazy-{}[{}]", Self::EXPECTED_TYPE, inner_name),
+            false => format!("{}[{}]", Self::EXPECTED_TYPE, inner_name),
+        };
+        let (min_items, max_items) = validate_length_constraints(
+            py,
+            "min_items",
+            schema.get_as(intern!(py, "min_items"))?,
+            schema.get_as(intern!(py, "max_items"))?,
+        )?;
+        let unique_items = schema.get_as(intern!(py, "unique_items"))?.unwrap_or(false);
+        let preserve_order = schema.get_as(intern!(py, "preserve_order"))?.unwrap_or(true);
+        if lazy && (unique_items || !preserve_order) {
+            return py_error!("'unique_items' and 'preserve_order' can't be used with 'lazy'");
+        }
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            item_validator,
+            size_range: match min_items.is_some() || max_items.is_some() {
+                true => Some((min_items, max_items)),
+                false => None,
+            },
+            max_input_length: config.get_as(intern!(py, "max_input_length"))?,
+            lazy,
+            unique_items,
+            preserve_order,
+            hide_input_in_errors: config.get_as(intern!(py, "hide_input_in_errors"))?.unwrap_or(false),
+            name,
+            expected,
+        }
+        .into())
+    }
 }
 
 impl Validator for ListValidator {
@@ -64,17 +140,37 @@ impl Validator for ListValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let seq = input.validate_list(extra.strict.unwrap_or(self.strict))?;
+        if self.lazy {
+            return self.validate_lazy(py, input, extra, slots, recursion_guard);
+        }
+        check_max_input_len(self.max_input_length, input)?;
+        let seq = match comma_separated(py, input, extra, self.max_input_length)? {
+            Some(items) => GenericListLike::List(items),
+            None => self.validate_list_type(input, extra.strict.unwrap_or(self.strict))?,
+        };
+        check_max_input_length(self.max_input_length, seq.generic_len(), input)?;
 
         let length = seq.check_len(self.size_range, input)?;
 
         let output = match self.item_validator {
             Some(ref v) => seq.validate_to_vec(py, length, v, extra, slots, recursion_guard)?,
             None => match seq {
-                GenericListLike::List(list) => return Ok(list.into_py(py)),
+                GenericListLike::List(list) if !self.unique_items && self.preserve_order => return Ok(list.into_py(py)),
                 _ => seq.to_vec(py),
             },
         };
+        if self.unique_items {
+            if let Some(index) = first_duplicate(py, &output)? {
+                return Err(ValError::new(ErrorKind::ListItemNotUnique { index }, input));
+            }
+        }
+        let output = PyList::new(py, output);
+        if !self.preserve_order {
+            if let Err(err) = output.sort() {
+                let error = py_err_string(py, err);
+                return Err(ValError::new(ErrorKind::ListUnsortable { error }, input));
+            }
+        }
         Ok(output.into_py(py))
     }
 
@@ -82,6 +178,26 @@ impl Validator for ListValidator {
         &self.name
     }
 
+    fn expected_repr(&self) -> String {
+        match self.expected {
+            Some(ref expected) => expected.clone(),
+            None => Self::EXPECTED_TYPE.to_string(),
+        }
+    }
+
+    fn schema_repr(&self, py: Python, slots: &[CombinedValidator]) -> PyResult<PyObject> {
+        let repr = schema_repr_dict(py, self.get_name())?;
+        repr.set_item("strict", self.strict)?;
+        if let Some((min_items, max_items)) = self.size_range {
+            set_json_schema_item(repr, "min_items", min_items)?;
+            set_json_schema_item(repr, "max_items", max_items)?;
+        }
+        if let Some(ref item_validator) = self.item_validator {
+            repr.set_item("items_schema", item_validator.schema_repr(py, slots)?)?;
+        }
+        Ok(repr.into_py(py))
+    }
+
     fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
         match self.item_validator {
             Some(ref mut v) => v.complete(build_context),
@@ -89,3 +205,378 @@ impl Validator for ListValidator {
         }
     }
 }
+
+impl ListValidator {
+    /// like `input.validate_list`, but `ListType` errors name the expected type if the items are validated
+    fn validate_list_type<'data>(
+        &self,
+        input: &'data impl Input<'data>,
+        strict: bool,
+    ) -> ValResult<'data, GenericListLike<'data>> {
+        input
+            .validate_list(strict)
+            .map_err(|err| err.with_expected(self.expected.as_deref()))
+    }
+
+    /// In lazy mode we return an iterator which validates each item as it's consumed, so neither validation
+    /// errors nor `min_items` and `max_items` errors are raised until iteration reaches the offending item.
+    fn validate_lazy<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let strict = extra.strict.unwrap_or(self.strict);
+        let py_input = input.to_object(py);
+        let iterator = match py_input.as_ref(py).cast_as::<PyIterator>() {
+            // iterators (e.g. generators) are never consumed here, only when the output is iterated over
+            Ok(iterator) if !strict => iterator.to_object(py),
+            _ => {
+                check_max_input_len(self.max_input_length, input)?;
+                let seq = self.validate_list_type(input, strict)?;
+                check_max_input_length(self.max_input_length, seq.generic_len(), input)?;
+                py_input.as_ref(py).iter()?.to_object(py)
+            }
+        };
+        let (min_items, max_items) = self.size_range.unwrap_or((None, None));
+        let lazy_list = LazyListIterator {
+            input: py_input,
+            iterator,
+            index: 0,
+            min_items,
+            max_items,
+            max_input_length: self.max_input_length,
+            validator: self.item_validator.clone(),
+            slots: extra.owned_slots(slots),
+            title: self.name.clone(),
+            hide_input_in_errors: self.hide_input_in_errors,
+            strict: extra.strict,
+            context: extra.context.map(|c| c.into_py(py)),
+            max_depth: extra.max_depth,
+            coercion: extra.coercion,
+            input_mode: extra.input_mode,
+            recursion_guard: recursion_guard.clone(),
+            #[cfg(feature = "profiling")]
+            stats: extra.stats.map(|stats| stats.share()),
+        };
+        Ok(lazy_list.into_py(py))
+    }
+}
+
+impl ListValidator {
+    /// Used by `SchemaValidator.iterate_validation_errors`, the type and length of the input are checked up front,
+    /// then items are validated one at a time as the iterator is consumed so only one item's errors are held
+    /// at once. `None` if errors can't be found item by item, e.g. with `unique_items`.
+    pub fn error_iterator(
+        &self,
+        py: Python,
+        input: &PyAny,
+        extra: &Extra,
+        slots: &[CombinedValidator],
+        hide_input_in_errors: bool,
+    ) -> PyResult<Option<PyObject>> {
+        if self.lazy || self.unique_items {
+            return Ok(None);
+        }
+        let strict = extra.strict.unwrap_or(self.strict);
+        let mut error_iterator = ListErrorIterator {
+            iterator: None,
+            index: 0,
+            pending: VecDeque::new(),
+            validator: self.item_validator.clone(),
+            slots: extra.owned_slots(slots),
+            hide_input_in_errors,
+            strict: extra.strict,
+            context: extra.context.map(|c| c.into_py(py)),
+            max_depth: extra.max_depth,
+            coercion: extra.coercion,
+            input_mode: extra.input_mode,
+            recursion_guard: RecursionGuard::default(),
+            #[cfg(feature = "profiling")]
+            stats: extra.stats.map(|stats| stats.share()),
+        };
+        let checked = check_max_input_len(self.max_input_length, input).and_then(|_| {
+            let seq = self.validate_list_type(input, strict)?;
+            check_max_input_length(self.max_input_length, seq.generic_len(), input)?;
+            seq.check_len(self.size_range, input)?;
+            Ok(seq)
+        });
+        match checked {
+            // iterate over the items already collected, generators and other iterators have been consumed
+            Ok(seq) if self.item_validator.is_some() => {
+                let items: &PyAny = match seq {
+                    GenericListLike::List(items) => items,
+                    GenericListLike::Tuple(items) => items,
+                    GenericListLike::Set(items) => items,
+                    GenericListLike::FrozenSet(items) => items,
+                    _ => input,
+                };
+                error_iterator.iterator = Some(items.iter()?.to_object(py));
+            }
+            Ok(_) => (),
+            Err(err) => push_errors(py, &mut error_iterator.pending, hide_input_in_errors, err)?,
+        }
+        Ok(Some(error_iterator.into_py(py)))
+    }
+}
+
+/// with `InputMode::Strings`, string inputs are split on commas, e.g. `"a, b"` becomes `["a", "b"]`,
+/// and the empty string becomes an empty list, commas are counted against `max_input_length` before splitting
+fn comma_separated<'data>(
+    py: Python<'data>,
+    input: &'data impl Input<'data>,
+    extra: &Extra,
+    max_input_length: Option<usize>,
+) -> ValResult<'data, Option<&'data PyList>> {
+    if extra.input_mode != InputMode::Strings {
+        return Ok(None);
+    }
+    match input.strict_str() {
+        Ok(either_str) => {
+            let s = either_str.as_cow()?;
+            let items: Vec<&str> = match s.trim() {
+                "" => Vec::new(),
+                s => {
+                    if max_input_length.is_some() {
+                        check_max_input_length(max_input_length, s.matches(',').count() + 1, input)?;
+                    }
+                    s.split(',').map(str::trim).collect()
+                }
+            };
+            Ok(Some(PyList::new(py, items)))
+        }
+        Err(_) => Ok(None),
+    }
+}
+
+/// Index of the first item which is equal to an earlier item, items are compared using a set where possible, if any
+/// item is unhashable we fall back to comparing every pair of items with `==`, this is O(n²) so can be slow
+/// for large lists of unhashable items like dicts, but there's deliberately no limit on the size of the list.
+fn first_duplicate(py: Python, items: &[PyObject]) -> PyResult<Option<usize>> {
+    let seen = PySet::empty(py)?;
+    for (index, item) in items.iter().enumerate() {
+        match seen.contains(item) {
+            Ok(true) => return Ok(Some(index)),
+            Ok(false) => seen.add(item)?,
+            Err(err) if err.is_instance_of::<PyTypeError>(py) => return first_duplicate_by_eq(py, items),
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(None)
+}
+
+fn first_duplicate_by_eq(py: Python, items: &[PyObject]) -> PyResult<Option<usize>> {
+    for (index, item) in items.iter().enumerate().skip(1) {
+        for earlier in &items[..index] {
+            if item.as_ref(py).eq(earlier)? {
+                return Ok(Some(index));
+            }
+        }
+    }
+    Ok(None)
+}
+
+#[pyclass]
+#[derive(Debug, Clone)]
+struct LazyListIterator {
+    input: PyObject,
+    iterator: PyObject,
+    index: usize,
+    min_items: Option<usize>,
+    max_items: Option<usize>,
+    max_input_length: Option<usize>,
+    validator: Option<Box<CombinedValidator>>,
+    slots: Arc<[CombinedValidator]>,
+    title: String,
+    hide_input_in_errors: bool,
+    strict: Option<bool>,
+    context: Option<PyObject>,
+    max_depth: Option<usize>,
+    coercion: LenientCoercionConfig,
+    input_mode: InputMode,
+    recursion_guard: RecursionGuard,
+    #[cfg(feature = "profiling")]
+    stats: Option<super::profiling::Stats>,
+}
+
+#[pymethods]
+impl LazyListIterator {
+    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python) -> PyResult<Option<PyObject>> {
+        let next_item = PyIterator::from_object(py, self.iterator.as_ref(py))?.next();
+        let item = match next_item {
+            // errors raised while iterating over the input are propagated unchanged
+            Some(item) => item?,
+            None => {
+                return match self.min_items {
+                    Some(min_length) if self.index < min_length => Err(self.length_error(
+                        py,
+                        ErrorKind::TooShort {
+                            min_length,
+                            input_length: self.index,
+                        },
+                    )),
+                    _ => Ok(None),
+                };
+            }
+        };
+        let index = self.index;
+        self.index += 1;
+        if let Some(max_input_length) = self.max_input_length {
+            if index >= max_input_length {
+                return Err(self.length_error(
+                    py,
+                    ErrorKind::InputTooLarge {
+                        max_input_length,
+                        input_length: self.index,
+                    },
+                ));
+            }
+        }
+        if let Some(max_length) = self.max_items {
+            if index >= max_length {
+                // we don't know the real length of the input, only how many items we've seen so far
+                return Err(self.length_error(
+                    py,
+                    ErrorKind::TooLong {
+                        max_length,
+                        input_length: self.index,
+                    },
+                ));
+            }
+        }
+        match self.validator {
+            Some(ref validator) => {
+                let extra = Extra {
+                    strict: self.strict,
+                    context: self.context.as_ref().map(|c| c.as_ref(py)),
+                    max_depth: self.max_depth,
+                    coercion: self.coercion,
+                    input_mode: self.input_mode,
+                    shared_slots: Some(&self.slots),
+                    #[cfg(feature = "profiling")]
+                    stats: self.stats.as_ref(),
+                    ..Default::default()
+                };
+                validator
+                    .validate(py, item, &extra, &self.slots, &mut self.recursion_guard)
+                    .map(Some)
+                    .map_err(|e| {
+                        ValidationError::from_val_error(
+                            py,
+                            self.title.to_object(py),
+                            e.with_outer_location(index.into()),
+                            self.hide_input_in_errors,
+                        )
+                    })
+            }
+            None => Ok(Some(item.to_object(py))),
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("LazyListIterator({}, index={})", self.title, self.index)
+    }
+}
+
+impl LazyListIterator {
+    fn length_error(&self, py: Python, kind: ErrorKind) -> PyErr {
+        let error = ValError::new_custom_input(kind, InputValue::PyObject(self.input.clone_ref(py)));
+        ValidationError::from_val_error(py, self.title.to_object(py), error, self.hide_input_in_errors)
+    }
+}
+
+#[pyclass]
+#[derive(Debug, Clone)]
+struct ListErrorIterator {
+    // `None` once all items have been validated
+    iterator: Option<PyObject>,
+    index: usize,
+    // errors from the last item validated which haven't been returned yet
+    pending: VecDeque<PyObject>,
+    validator: Option<Box<CombinedValidator>>,
+    slots: Arc<[CombinedValidator]>,
+    hide_input_in_errors: bool,
+    strict: Option<bool>,
+    context: Option<PyObject>,
+    max_depth: Option<usize>,
+    coercion: LenientCoercionConfig,
+    input_mode: InputMode,
+    recursion_guard: RecursionGuard,
+    #[cfg(feature = "profiling")]
+    stats: Option<super::profiling::Stats>,
+}
+
+#[pymethods]
+impl ListErrorIterator {
+    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python) -> PyResult<Option<PyObject>> {
+        loop {
+            if let Some(error) = self.pending.pop_front() {
+                return Ok(Some(error));
+            }
+            let next_item = match self.iterator {
+                Some(ref iterator) => PyIterator::from_object(py, iterator.as_ref(py))?.next(),
+                None => return Ok(None),
+            };
+            let item = match next_item {
+                Some(item) => item?,
+                None => {
+                    self.iterator = None;
+                    return Ok(None);
+                }
+            };
+            let index = self.index;
+            self.index += 1;
+            if let Some(ref validator) = self.validator {
+                let extra = Extra {
+                    strict: self.strict,
+                    context: self.context.as_ref().map(|c| c.as_ref(py)),
+                    max_depth: self.max_depth,
+                    coercion: self.coercion,
+                    input_mode: self.input_mode,
+                    shared_slots: Some(&self.slots),
+                    #[cfg(feature = "profiling")]
+                    stats: self.stats.as_ref(),
+                    ..Default::default()
+                };
+                let result = validator.validate(py, item, &extra, &self.slots, &mut self.recursion_guard);
+                if let Err(err) = result {
+                    let err = err.with_outer_location(index.into());
+                    push_errors(py, &mut self.pending, self.hide_input_in_errors, err)?;
+                }
+            }
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ListErrorIterator(index={})", self.index)
+    }
+}
+
+/// convert errors to dicts as returned by `ValidationError.errors(include_url=False)` and add them to `pending`
+pub(super) fn push_errors(
+    py: Python,
+    pending: &mut VecDeque<PyObject>,
+    hide_input_in_errors: bool,
+    error: ValError,
+) -> PyResult<()> {
+    match error {
+        ValError::LineErrors(line_errors) => {
+            for line_error in line_errors {
+                let py_line_error: PyLineError = line_error.into_py(py);
+                pending.push_back(py_line_error.as_dict(py, None, hide_input_in_errors, None)?);
+            }
+            Ok(())
+        }
+        ValError::InternalErr(err) => Err(err),
+    }
+}
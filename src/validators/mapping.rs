@@ -0,0 +1,102 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::type_object::PyTypeObject;
+use pyo3::types::{PyDict, PyType};
+
+use crate::build_tools::{SchemaDict, SchemaMetadata};
+use crate::errors::{py_err_string, ErrorKind, ValError, ValResult};
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::dict::DictValidator;
+use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+/// Validate a mapping like a dict, then convert the output to `target_class` (e.g. `OrderedDict`) by calling it
+/// with the validated dict, in lax mode any `Mapping` is accepted as input and converted with `dict(mapping)` first
+#[derive(Debug, Clone)]
+pub struct MappingValidator {
+    dict_validator: Box<CombinedValidator>,
+    // `None` if the target is `dict` itself, so the dict validator's output can be returned as is
+    target_class: Option<Py<PyType>>,
+    name: String,
+}
+
+impl BuildValidator for MappingValidator {
+    const EXPECTED_TYPE: &'static str = "mapping";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let target_class: Option<&PyType> = schema
+            .get_as::<&PyType>(intern!(py, "target_class"))?
+            .filter(|class| !class.is(PyDict::type_object(py)));
+        let class_name = match target_class {
+            Some(class) => class.name()?,
+            None => DictValidator::EXPECTED_TYPE,
+        };
+        let dict_validator = DictValidator::build_for_class(schema, config, build_context, class_name)?;
+        // e.g. "OrderedDict[str,int]"
+        let items_name = dict_validator
+            .get_name()
+            .trim_start_matches(DictValidator::EXPECTED_TYPE);
+        let name = format!("{}{}", class_name, items_name);
+        Ok(Self {
+            dict_validator: Box::new(dict_validator.into()),
+            target_class: target_class.map(|class| class.into()),
+            name,
+        }
+        .into())
+    }
+}
+
+impl Validator for MappingValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let output = self.dict_validator.validate(py, input, extra, slots, recursion_guard)?;
+        let target_class = match self.target_class {
+            Some(ref target_class) => target_class.as_ref(py),
+            None => return Ok(output),
+        };
+        match target_class.call1((output,)) {
+            Ok(mapping) => Ok(mapping.into_py(py)),
+            Err(err) => Err(ValError::new(
+                ErrorKind::MappingTargetClass {
+                    class_name: target_class.name()?.to_string(),
+                    error: py_err_string(py, err),
+                },
+                input,
+            )),
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn json_schema(&self, py: Python, slots: &[CombinedValidator], definitions: &PyDict) -> PyResult<PyObject> {
+        self.dict_validator.json_schema(py, slots, definitions)
+    }
+
+    fn schema_repr(&self, py: Python, slots: &[CombinedValidator]) -> PyResult<PyObject> {
+        let repr = self.dict_validator.schema_repr(py, slots)?;
+        repr.as_ref(py).set_item("name", self.get_name())?;
+        Ok(repr)
+    }
+
+    fn metadata(&self) -> Option<&SchemaMetadata> {
+        self.dict_validator.metadata()
+    }
+
+    fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
+        self.dict_validator.complete(build_context)
+    }
+}
@@ -1,17 +1,25 @@
+use std::cell::RefCell;
+use std::fmt;
 use std::fmt::Debug;
+use std::sync::Arc;
 
 use enum_dispatch::enum_dispatch;
 
-use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::{PyAttributeError, PyTypeError};
 use pyo3::intern;
 use pyo3::once_cell::GILOnceCell;
 use pyo3::prelude::*;
-use pyo3::types::{PyAny, PyByteArray, PyBytes, PyDict, PyString};
+use pyo3::types::{PyAny, PyByteArray, PyBytes, PyDict, PyList, PySet, PyString, PyTuple, PyType};
 
-use crate::build_tools::{py_error, SchemaDict, SchemaError};
-use crate::errors::{ErrorKind, ValError, ValLineError, ValResult, ValidationError};
-use crate::input::{Input, JsonInput};
+use crate::build_tools::{build_warning_count, py_error, SchemaDict, SchemaError, SchemaMetadata, SchemaPath};
+use crate::errors::{
+    errors_url_base, pretty_line_errors, ErrorKind, LocItem, Location, PyLineError, ValError, ValLineError, ValResult,
+    ValidationError,
+};
+use crate::input::{complete_truncated_json, parse_msgpack, BytesInput, Input, JsonInput, NativeInput};
+use crate::lenient_coercion::LenientCoercionConfig;
 use crate::recursion_guard::RecursionGuard;
+use crate::schema_cache;
 
 mod any;
 mod arguments;
@@ -19,72 +27,109 @@ mod bool;
 mod bytes;
 mod call;
 mod callable;
+mod chain;
+mod chain_map;
 mod date;
 mod datetime;
 mod dict;
+mod flat_list;
 mod float;
 mod frozenset;
 mod function;
+mod get_validators;
 mod int;
+mod ip_address;
 mod is_instance;
+mod is_subclass;
 mod list;
 mod literal;
+mod mapping;
+mod namedtuple;
 mod new_class;
 mod none;
 mod nullable;
+#[cfg(feature = "profiling")]
+mod profiling;
 mod recursive;
+mod secret_bytes;
+mod secret_str;
 mod set;
+mod span;
+mod strict;
 mod string;
+mod switch;
 mod time;
 mod timedelta;
 mod tuple;
 mod typed_dict;
 mod union;
+mod url;
 
 #[pyclass(module = "pydantic_core._pydantic_core")]
 #[derive(Debug, Clone)]
 pub struct SchemaValidator {
     validator: CombinedValidator,
-    slots: Vec<CombinedValidator>,
+    // shared with objects which validate after the call that created them has returned, e.g. lazy lists
+    slots: Arc<[CombinedValidator]>,
     schema: PyObject,
+    config: Option<Py<PyDict>>,
     title: PyObject,
+    max_depth: usize,
+    hide_input_in_errors: bool,
+    coercion: LenientCoercionConfig,
+    #[cfg(feature = "profiling")]
+    stats: profiling::Stats,
 }
 
 #[pymethods]
 impl SchemaValidator {
     #[new]
     pub fn py_new(py: Python, schema: &PyAny, config: Option<&PyDict>) -> PyResult<Self> {
-        let self_schema = Self::get_self_schema(py);
-
-        let schema_obj = self_schema
-            .validator
-            .validate(
-                py,
-                schema,
-                &Extra::default(),
-                &self_schema.slots,
-                &mut RecursionGuard::default(),
-            )
-            .map_err(|e| SchemaError::from_val_error(py, e))?;
-        let schema = schema_obj.as_ref(py);
+        let fingerprint = schema_cache::Fingerprint::new(schema, config)?;
+        if let Some(ref fingerprint) = fingerprint {
+            if let Some(validator) = schema_cache::get(py, fingerprint) {
+                return Ok(validator);
+            }
+        }
 
-        let mut build_context = BuildContext::default();
-        let mut validator = build_validator(schema, config, &mut build_context)?;
-        validator.complete(&build_context)?;
-        let slots = build_context.into_slots()?;
-        let title = validator.get_name().into_py(py);
-        Ok(Self {
-            validator,
-            slots,
-            schema: schema.into_py(py),
-            title,
-        })
+        let warning_count = build_warning_count();
+        let schema_validator = Self::build(py, schema, config)?;
+        if let Some(fingerprint) = fingerprint {
+            // a cache hit would skip the warning
+            if build_warning_count() == warning_count {
+                schema_cache::insert(py, fingerprint, schema_validator.clone());
+            }
+        }
+        Ok(schema_validator)
     }
 
     pub fn __reduce__(&self, py: Python) -> PyResult<PyObject> {
-        let args = (self.schema.as_ref(py),);
         let cls = Py::new(py, self.to_owned())?.getattr(py, "__class__")?;
-        Ok((cls, args).into_py(py))
+        Ok((cls, self.__getstate__(py)).into_py(py))
+    }
+
+    /// the schema and config the validator was built from, see `__setstate__`
+    pub fn __getstate__(&self, py: Python) -> PyObject {
+        (self.schema.as_ref(py), self.config.as_ref()).into_py(py)
+    }
+
+    /// replace this validator with one built from `state`, a `(schema, config)` tuple as returned by `__getstate__`
+    pub fn __setstate__(&mut self, py: Python, state: (&PyAny, Option<&PyDict>)) -> PyResult<()> {
+        let (schema, config) = state;
+        *self = Self::py_new(py, schema, config)?;
+        Ok(())
+    }
+
+    /// rebuild the validator from deep copies of the schema and config, so the copy shares no state,
+    /// e.g. mutable defaults, with the original
+    pub fn __deepcopy__(&self, py: Python, memo: &PyAny) -> PyResult<Self> {
+        let deepcopy = py.import("copy")?.getattr("deepcopy")?;
+        let schema = deepcopy.call1((self.schema.as_ref(py), memo))?;
+        let config: Option<&PyDict> = match self.config {
+            Some(ref config) => Some(deepcopy.call1((config.as_ref(py), memo))?.cast_as()?),
+            None => None,
+        };
+        Self::build(py, schema, config)
     }
 
     pub fn validate_python(
@@ -97,13 +142,237 @@ impl SchemaValidator {
         let r = self.validator.validate(
             py,
             input,
-            &Extra::new(strict, context),
+            &self.new_extra(strict, context),
             &self.slots,
             &mut RecursionGuard::default(),
         );
         r.map_err(|e| self.prepare_validation_err(py, e))
     }
 
+    /// like `validate_python`, but returns an awaitable which runs validation in the running event loop's
+    /// default executor, must be called from a coroutine. Validation holds the GIL, so this frees the event loop
+    /// to switch tasks between validations rather than running them in parallel
+    pub fn validate_python_async(
+        slf: PyRef<Self>,
+        py: Python,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> PyResult<PyObject> {
+        let event_loop = py.import("asyncio")?.call_method0("get_running_loop")?;
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("strict", strict)?;
+        kwargs.set_item("context", context)?;
+        let validate = slf.into_py(py).getattr(py, "validate_python")?;
+        let partial = py
+            .import("functools")?
+            .getattr("partial")?
+            .call((validate, input), Some(kwargs))?;
+        let future = event_loop.call_method1("run_in_executor", (py.None(), partial))?;
+        Ok(future.into_py(py))
+    }
+
+    /// validate `input` and return an iterator over its errors, in the same form as
+    /// `ValidationError.errors(include_url=False)`, if the schema is a `list` or `dict` items are validated as the
+    /// iterator is consumed rather than collecting every error first, see `ListValidator::error_iterator`
+    pub fn iterate_validation_errors(
+        &self,
+        py: Python,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> PyResult<PyObject> {
+        let extra = self.new_extra(strict, context);
+        if let CombinedValidator::List(list_validator) = self.root_validator() {
+            if let Some(error_iterator) =
+                list_validator.error_iterator(py, input, &extra, &self.slots, self.hide_input_in_errors)?
+            {
+                return Ok(error_iterator);
+            }
+        }
+        if let CombinedValidator::Dict(dict_validator) = self.root_validator() {
+            if let Some(error_iterator) =
+                dict_validator.error_iterator(py, input, &extra, &self.slots, self.hide_input_in_errors)?
+            {
+                return Ok(error_iterator);
+            }
+        }
+        let errors = match self
+            .validator
+            .validate(py, input, &extra, &self.slots, &mut RecursionGuard::default())
+        {
+            Ok(_) => Vec::new(),
+            Err(ValError::LineErrors(line_errors)) => line_errors
+                .into_iter()
+                .map(|line_error| {
+                    let py_line_error: PyLineError = line_error.into_py(py);
+                    py_line_error.as_dict(py, None, self.hide_input_in_errors, None)
+                })
+                .collect::<PyResult<Vec<_>>>()?,
+            Err(ValError::InternalErr(err)) => return Err(err),
+        };
+        Ok(PyList::new(py, errors).as_ref().iter()?.into_py(py))
+    }
+
+    /// like `validate_python` for a dict schema, but `item_callback` is called with `(key, value)` after each entry
+    /// is validated, e.g. to stream the output of a huge input to disk, with `consume` the output dict isn't built
+    /// and `None` is returned. Errors are still raised at the end, after valid entries have been passed to
+    /// `item_callback`
+    pub fn validate_python_streaming(
+        &self,
+        py: Python,
+        input: &PyAny,
+        item_callback: &PyAny,
+        consume: Option<bool>,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> PyResult<PyObject> {
+        if !matches!(self.root_validator(), CombinedValidator::Dict(_)) {
+            return Err(PyTypeError::new_err(
+                "validate_python_streaming requires a validator with a dict schema",
+            ));
+        }
+        let extra = Extra {
+            item_callback: Some(ItemCallback {
+                callback: item_callback,
+                consume: consume.unwrap_or(false),
+            }),
+            ..self.new_extra(strict, context)
+        };
+        self.validator
+            .validate(py, input, &extra, &self.slots, &mut RecursionGuard::default())
+            .map_err(|e| self.prepare_validation_err(py, e))
+    }
+
+    /// validate every item of `inputs`, returning `(results, errors)`: for each input, either its output and `None`,
+    /// or `None` and a list of its errors in the same form as `ValidationError.errors()`, so one invalid input
+    /// doesn't stop the others being validated
+    pub fn validate_many(
+        &self,
+        py: Python,
+        inputs: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> PyResult<(PyObject, PyObject)> {
+        let extra = self.new_extra(strict, context);
+        let url_base = errors_url_base(py);
+        let results = PyList::empty(py);
+        let errors = PyList::empty(py);
+        for input in inputs.iter()? {
+            match self
+                .validator
+                .validate(py, input?, &extra, &self.slots, &mut RecursionGuard::default())
+            {
+                Ok(output) => {
+                    results.append(output)?;
+                    errors.append(py.None())?;
+                }
+                Err(ValError::LineErrors(line_errors)) => {
+                    let line_errors = line_errors
+                        .into_iter()
+                        .map(|line_error| {
+                            let py_line_error: PyLineError = line_error.into_py(py);
+                            py_line_error.as_dict(py, Some(&url_base), self.hide_input_in_errors, None)
+                        })
+                        .collect::<PyResult<Vec<_>>>()?;
+                    results.append(py.None())?;
+                    errors.append(PyList::new(py, line_errors))?;
+                }
+                Err(ValError::InternalErr(err)) => return Err(err),
+            }
+        }
+        Ok((results.into_py(py), errors.into_py(py)))
+    }
+
+    /// validate `input` after converting it to a `NativeInput`, which is validated as though it were the python
+    /// object it was converted from, so the result should always match `validate_python`, for differential
+    /// testing of coercion, see `NativeInput` for which schemas this applies to
+    pub fn validate_native(
+        &self,
+        py: Python,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> PyResult<PyObject> {
+        self.validate_native_input(py, &NativeInput::from_python(input)?, strict, context)
+    }
+
+    /// like `validate_python`, but also returns a list of warnings, e.g. for extra keys ignored by typed-dicts,
+    /// which have the same shape as items from `ValidationError.errors(include_url=False)`
+    pub fn validate_python_with_warnings(
+        &self,
+        py: Python,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> PyResult<(PyObject, PyObject)> {
+        let warnings = Warnings::default();
+        let extra = Extra {
+            warnings: Some(&warnings),
+            ..self.new_extra(strict, context)
+        };
+        let output = self
+            .validator
+            .validate(py, input, &extra, &self.slots, &mut RecursionGuard::default())
+            .map_err(|e| self.prepare_validation_err(py, e))?;
+        let warnings = warnings
+            .0
+            .into_inner()
+            .iter()
+            .map(|warning| warning.as_dict(py, None, self.hide_input_in_errors, None))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok((output, PyList::new(py, warnings).into_py(py)))
+    }
+
+    /// like `validate_python`, but also returns a trace of the decisions validators made, e.g. whether the dict
+    /// validator used `strict_dict` or `lax_dict` and which keys were coerced, for debugging schemas
+    pub fn validate_debug(
+        &self,
+        py: Python,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> PyResult<(PyObject, PyObject)> {
+        let trace = Trace::default();
+        let extra = Extra {
+            trace: Some(&trace),
+            ..self.new_extra(strict, context)
+        };
+        let output = self
+            .validator
+            .validate(py, input, &extra, &self.slots, &mut RecursionGuard::default())
+            .map_err(|e| self.prepare_validation_err(py, e))?;
+        let trace = trace
+            .0
+            .into_inner()
+            .iter()
+            .map(|entry| entry.as_dict(py))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok((output, PyList::new(py, trace).into_py(py)))
+    }
+
+    /// the number of calls, the number of errors and the cumulative time in seconds (including time spent in child
+    /// validators) for each validator in the schema, over every validation with this `SchemaValidator`,
+    /// only available with the `profiling` feature
+    #[cfg(feature = "profiling")]
+    pub fn statistics(&self, py: Python) -> PyResult<PyObject> {
+        self.stats.as_list(py)
+    }
+
+    /// like `validate_python`, but for data where every value is a string, e.g. HTML form data,
+    /// validation is lax regardless of `strict` and lists also accept comma-separated strings, see `InputMode`
+    pub fn validate_strings(&self, py: Python, input: &PyAny, context: Option<&PyAny>) -> PyResult<PyObject> {
+        let extra = Extra {
+            input_mode: InputMode::Strings,
+            coercion: self.coercion.with_str_coercion(),
+            ..self.new_extra(Some(false), context)
+        };
+        self.validator
+            .validate(py, input, &extra, &self.slots, &mut RecursionGuard::default())
+            .map_err(|e| self.prepare_validation_err(py, e))
+    }
+
+    /// validation stops at the first error, see `Extra.fail_fast`
     pub fn isinstance_python(
         &self,
         py: Python,
@@ -114,7 +383,10 @@ impl SchemaValidator {
         match self.validator.validate(
             py,
             input,
-            &Extra::new(strict, context),
+            &Extra {
+                fail_fast: true,
+                ..self.new_extra(strict, context)
+            },
             &self.slots,
             &mut RecursionGuard::default(),
         ) {
@@ -124,32 +396,40 @@ impl SchemaValidator {
         }
     }
 
+    /// with `allow_partial`, JSON which was cut off mid-document, e.g. a truncated stream, is validated as far as
+    /// it's complete and the incomplete tail is ignored, see `complete_truncated_json`
     pub fn validate_json(
         &self,
         py: Python,
         input: &PyAny,
         strict: Option<bool>,
         context: Option<&PyAny>,
+        source_position: Option<bool>,
+        allow_partial: Option<bool>,
     ) -> PyResult<PyObject> {
-        match parse_json(input)? {
+        match parse_json(input, source_position.unwrap_or(false), allow_partial.unwrap_or(false))? {
             Ok(input) => {
                 let r = self.validator.validate(
                     py,
                     &input,
-                    &Extra::new(strict, context),
+                    &Extra {
+                        input_mode: InputMode::Json,
+                        ..self.new_extra(strict, context)
+                    },
                     &self.slots,
                     &mut RecursionGuard::default(),
                 );
                 r.map_err(|e| self.prepare_validation_err(py, e))
             }
-            Err(e) => {
-                let line_err = ValLineError::new(ErrorKind::InvalidJson { error: e.to_string() }, input);
+            Err(kind) => {
+                let line_err = ValLineError::new(kind, input);
                 let err = ValError::LineErrors(vec![line_err]);
                 Err(self.prepare_validation_err(py, err))
             }
         }
     }
 
+    /// as with `isinstance_python`, validation stops at the first error
     pub fn isinstance_json(
         &self,
         py: Python,
@@ -157,12 +437,16 @@ impl SchemaValidator {
         strict: Option<bool>,
         context: Option<&PyAny>,
     ) -> PyResult<bool> {
-        match parse_json(input)? {
+        match parse_json(input, false, false)? {
             Ok(input) => {
                 match self.validator.validate(
                     py,
                     &input,
-                    &Extra::new(strict, context),
+                    &Extra {
+                        input_mode: InputMode::Json,
+                        fail_fast: true,
+                        ..self.new_extra(strict, context)
+                    },
                     &self.slots,
                     &mut RecursionGuard::default(),
                 ) {
@@ -175,31 +459,205 @@ impl SchemaValidator {
         }
     }
 
-    pub fn validate_assignment(&self, py: Python, field: String, input: &PyAny, data: &PyDict) -> PyResult<PyObject> {
-        let extra = Extra {
-            data: Some(data),
-            field: Some(field.as_str()),
-            strict: None,
-            context: None,
+    /// msgpack is decoded to a `NativeInput` without creating python objects, then validated as if the equivalent
+    /// python object was passed to `validate_python`
+    pub fn validate_msgpack(
+        &self,
+        py: Python,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> PyResult<PyObject> {
+        let parsed = if let Ok(py_bytes) = input.cast_as::<PyBytes>() {
+            parse_msgpack(py_bytes.as_bytes())
+        } else if let Ok(py_byte_array) = input.cast_as::<PyByteArray>() {
+            // safe since decoding doesn't create python objects, so can't run python code which might mutate
+            // the bytearray while it's borrowed
+            parse_msgpack(unsafe { py_byte_array.as_bytes() })
+        } else {
+            let input_type = input.get_type().name().unwrap_or("unknown");
+            return py_error!(PyTypeError; "msgpack input should be bytes or bytearray, not {}", input_type);
         };
-        let r = self
-            .validator
-            .validate(py, input, &extra, &self.slots, &mut RecursionGuard::default());
-        r.map_err(|e| self.prepare_validation_err(py, e))
+        match parsed {
+            Ok(value) => self
+                .validator
+                .validate(
+                    py,
+                    &value,
+                    &self.new_extra(strict, context),
+                    &self.slots,
+                    &mut RecursionGuard::default(),
+                )
+                .map_err(|e| self.prepare_validation_err(py, e)),
+            Err(error) => {
+                let line_err = ValLineError::new(ErrorKind::InvalidMsgpack { error }, input);
+                Err(self.prepare_validation_err(py, ValError::LineErrors(vec![line_err])))
+            }
+        }
+    }
+
+    /// `obj` is either a dict of data (e.g. for typed-dict schemas), which is updated and returned, or a model
+    /// instance, in which case the validated value is set with `object.__setattr__` and `obj` is returned
+    pub fn validate_assignment(
+        &self,
+        py: Python,
+        obj: &PyAny,
+        field: String,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> PyResult<PyObject> {
+        if let Ok(data) = obj.cast_as::<PyDict>() {
+            return self.validate_assignment_data(py, data, &field, input, strict, context);
+        }
+        // work on a copy so the instance is unchanged if validation fails
+        let data = match obj.getattr(intern!(py, "__dict__")).map(|d| d.cast_as::<PyDict>()) {
+            Ok(Ok(model_dict)) => model_dict.copy()?,
+            _ => {
+                return Err(PyTypeError::new_err(
+                    "validate_assignment requires a dict or model instance",
+                ))
+            }
+        };
+        let output = self.validate_assignment_data(py, data, &field, input, strict, context)?;
+        let output = output.as_ref(py);
+        let model_dict: &PyDict = match output.cast_as::<PyTuple>() {
+            Ok(tuple) => tuple.get_item(0)?.cast_as()?,
+            Err(_) => output.cast_as()?,
+        };
+        let value = match model_dict.get_item(&field) {
+            Some(value) => value,
+            None => return Err(PyAttributeError::new_err(format!("No field named '{}'", field))),
+        };
+        new_class::force_setattr(py, obj, &field, value)?;
+        if let Ok(fields_set) = obj.getattr(intern!(py, "__fields_set__")) {
+            if let Ok(fields_set) = fields_set.cast_as::<PySet>() {
+                fields_set.add(&field)?;
+            }
+        }
+        Ok(obj.into_py(py))
+    }
+
+    /// field names and aliases of the top level typed-dict or model, see `Validator::known_fields`
+    pub fn known_fields(&self) -> Option<Vec<String>> {
+        self.validator.known_fields()
+    }
+
+    /// a JSON Schema for the values this validator accepts, see `Validator::json_schema`
+    pub fn json_schema(&self, py: Python) -> PyResult<PyObject> {
+        let definitions = PyDict::new(py);
+        let schema = self.validator.json_schema(py, &self.slots, definitions)?;
+        if !definitions.is_empty() {
+            schema.as_ref(py).set_item("$defs", definitions)?;
+        }
+        Ok(schema)
+    }
+
+    /// a summary of the built validator tree for debugging, see `Validator::schema_repr`
+    pub fn schema_repr(&self, py: Python) -> PyResult<PyObject> {
+        self.validator.schema_repr(py, &self.slots)
+    }
+
+    /// `title`, `description` and `examples` from the schema, empty if none were set
+    pub fn metadata(&self, py: Python) -> PyResult<PyObject> {
+        let metadata = PyDict::new(py);
+        if let Some(schema_metadata) = self.validator.metadata() {
+            schema_metadata.update_dict(metadata)?;
+        }
+        Ok(metadata.into_py(py))
     }
 
     pub fn __repr__(&self) -> String {
-        format!(
-            "SchemaValidator(name={:?}, validator={:#?})",
-            self.validator.get_name(),
-            self.validator
-        )
+        match self.slots.is_empty() {
+            true => format!(
+                "SchemaValidator(name={:?}, validator={:#?})",
+                self.validator.get_name(),
+                self.validator
+            ),
+            false => format!(
+                "SchemaValidator(name={:?}, validator={:#?}, slots={:#?})",
+                self.validator.get_name(),
+                self.validator,
+                self.slots
+            ),
+        }
     }
 }
 
 static SCHEMA_DEFINITION: GILOnceCell<SchemaValidator> = GILOnceCell::new();
 
+/// default for the `max_depth` config option, see `Extra.max_depth`
+const DEFAULT_MAX_DEPTH: usize = 100;
+
 impl SchemaValidator {
+    /// as `validate_native`, for a `NativeInput` built in rust rather than converted from a python object, e.g. by
+    /// a fuzzer, dicts with list or dict keys are a `TypeError`, see `NativeInput::check_keys`
+    pub fn validate_native_input(
+        &self,
+        py: Python,
+        input: &NativeInput,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> PyResult<PyObject> {
+        input.check_keys()?;
+        self.validator
+            .validate(
+                py,
+                input,
+                &self.new_extra(strict, context),
+                &self.slots,
+                &mut RecursionGuard::default(),
+            )
+            .map_err(|e| self.prepare_validation_err(py, e))
+    }
+
+    /// build a validator without consulting `schema_cache`
+    fn build(py: Python, schema: &PyAny, config: Option<&PyDict>) -> PyResult<Self> {
+        let schema_obj = validate_schema(py, schema)?;
+        let schema = schema_obj.as_ref(py);
+
+        let mut build_context = BuildContext::default();
+        let mut validator = build_validator(schema, config, &mut build_context)?;
+        validator.complete(&build_context)?;
+        let slots = build_context.into_slots()?;
+        let title = validator.get_name().into_py(py);
+        let max_depth = config.get_as(intern!(py, "max_depth"))?.unwrap_or(DEFAULT_MAX_DEPTH);
+        let hide_input_in_errors = config.get_as(intern!(py, "hide_input_in_errors"))?.unwrap_or(false);
+        let coercion = LenientCoercionConfig::from_config(config)?;
+        Ok(Self {
+            validator,
+            slots: slots.into(),
+            schema: schema.into_py(py),
+            config: config.map(|c| c.into_py(py)),
+            title,
+            max_depth,
+            hide_input_in_errors,
+            coercion,
+            #[cfg(feature = "profiling")]
+            stats: profiling::Stats::default(),
+        })
+    }
+
+    /// Validate raw bytes from a rust buffer, the bytes are treated like a python `bytes` object but
+    /// no python object is created unless it's required for the output or an error
+    pub fn validate_bytes(
+        &self,
+        py: Python,
+        data: &[u8],
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> PyResult<PyObject> {
+        let input = BytesInput(data);
+        let r = self.validator.validate(
+            py,
+            &input,
+            &self.new_extra(strict, context),
+            &self.slots,
+            &mut RecursionGuard::default(),
+        );
+        r.map_err(|e| self.prepare_validation_err(py, e))
+    }
+
     fn get_self_schema(py: Python) -> &Self {
         SCHEMA_DEFINITION.get_or_init(py, || Self::build_self_schema(py).unwrap())
     }
@@ -217,31 +675,187 @@ impl SchemaValidator {
         };
         Ok(Self {
             validator,
-            slots: build_context.into_slots()?,
+            slots: build_context.into_slots()?.into(),
             schema: py.None(),
+            config: None,
             title: "Self Schema".into_py(py),
+            max_depth: DEFAULT_MAX_DEPTH,
+            hide_input_in_errors: false,
+            coercion: LenientCoercionConfig::default(),
+            #[cfg(feature = "profiling")]
+            stats: profiling::Stats::default(),
         })
     }
 
+    fn validate_assignment_data(
+        &self,
+        py: Python,
+        data: &PyDict,
+        field: &str,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> PyResult<PyObject> {
+        let extra = Extra {
+            data: Some(data),
+            field: Some(field),
+            ..self.new_extra(strict, context)
+        };
+        let r = self
+            .validator
+            .validate(py, input, &extra, &self.slots, &mut RecursionGuard::default());
+        r.map_err(|e| self.prepare_validation_err(py, e))
+    }
+
+    /// `Extra` for validating with this validator's config, with the `profiling` feature calls are recorded
+    /// in `self.stats`
+    fn new_extra<'a>(&'a self, strict: Option<bool>, context: Option<&'a PyAny>) -> Extra<'a> {
+        Extra {
+            shared_slots: Some(&self.slots),
+            #[cfg(feature = "profiling")]
+            stats: Some(&self.stats),
+            ..Extra::new(strict, context, self.max_depth, self.coercion)
+        }
+    }
+
+    /// the validator at the root of the schema, without the `ProfiledValidator` wrapper it has with the
+    /// `profiling` feature
+    fn root_validator(&self) -> &CombinedValidator {
+        #[cfg(feature = "profiling")]
+        if let CombinedValidator::Profiled(ref profiled) = self.validator {
+            return profiled.inner();
+        }
+        &self.validator
+    }
+
     fn prepare_validation_err(&self, py: Python, error: ValError) -> PyErr {
-        ValidationError::from_val_error(py, self.title.clone_ref(py), error)
+        ValidationError::from_val_error(py, self.title.clone_ref(py), error, self.hide_input_in_errors)
     }
 }
 
-fn parse_json(input: &PyAny) -> PyResult<serde_json::Result<JsonInput>> {
+/// `SchemaValidator(schema, config).validate_many(inputs)`, the validator is built once (or taken from the schema
+/// cache) for all the inputs
+#[pyfunction]
+pub fn validate_many(
+    py: Python,
+    schema: &PyAny,
+    inputs: &PyAny,
+    config: Option<&PyDict>,
+    strict: Option<bool>,
+    context: Option<&PyAny>,
+) -> PyResult<(PyObject, PyObject)> {
+    SchemaValidator::py_new(py, schema, config)?.validate_many(py, inputs, strict, context)
+}
+
+/// `SchemaValidator(schema, config).validate_native(input)`
+#[pyfunction]
+pub fn validate_native(
+    py: Python,
+    schema: &PyAny,
+    input: &PyAny,
+    config: Option<&PyDict>,
+    strict: Option<bool>,
+    context: Option<&PyAny>,
+) -> PyResult<PyObject> {
+    SchemaValidator::py_new(py, schema, config)?.validate_native(py, input, strict, context)
+}
+
+/// Check `schema` against the self-schema, returning the validated schema or a `SchemaError`,
+/// also used by `SchemaSerializer` since serializer schemas share the same structure
+pub fn validate_schema(py: Python, schema: &PyAny) -> PyResult<PyObject> {
+    let self_schema = SchemaValidator::get_self_schema(py);
+    self_schema
+        .validator
+        .validate(
+            py,
+            schema,
+            &Extra::default(),
+            &self_schema.slots,
+            &mut RecursionGuard::default(),
+        )
+        .map_err(|e| SchemaError::from_val_error(py, e))
+}
+
+/// Parse JSON from `str`, `bytes` or `bytearray`, bytes are parsed directly without creating an intermediate
+/// python `str`, the inner `Result` is an `InvalidJson` error if the input isn't valid (including invalid UTF-8)
+fn parse_json(input: &PyAny, source_position: bool, allow_partial: bool) -> PyResult<Result<JsonInput, ErrorKind>> {
     if let Ok(py_bytes) = input.cast_as::<PyBytes>() {
-        Ok(serde_json::from_slice(py_bytes.as_bytes()))
+        Ok(parse_json_bytes(py_bytes.as_bytes(), source_position, allow_partial))
     } else if let Ok(py_str) = input.cast_as::<PyString>() {
-        let str = py_str.to_str()?;
-        Ok(serde_json::from_str(str))
+        match py_str.to_str() {
+            Ok(str) => Ok(parse_json_bytes(str.as_bytes(), source_position, allow_partial)),
+            // e.g. lone surrogates, which can't be encoded as UTF-8
+            Err(_) => Ok(Err(ErrorKind::InvalidJson {
+                error: "input is not valid unicode".to_string(),
+                position: None,
+                source_position: None,
+            })),
+        }
     } else if let Ok(py_byte_array) = input.cast_as::<PyByteArray>() {
-        Ok(serde_json::from_slice(unsafe { py_byte_array.as_bytes() }))
+        // the bytes are only borrowed while parsing, which can't run python code that might mutate the bytearray
+        Ok(parse_json_bytes(
+            unsafe { py_byte_array.as_bytes() },
+            source_position,
+            allow_partial,
+        ))
     } else {
         let input_type = input.get_type().name().unwrap_or("unknown");
         py_error!(PyTypeError; "JSON input should be str, bytes or bytearray, not {}", input_type)
     }
 }
 
+/// with `allow_partial`, JSON which ends before the document is complete is parsed as far as it's complete,
+/// see `complete_truncated_json`, any other error is reported as usual
+fn parse_json_bytes(bytes: &[u8], source_position: bool, allow_partial: bool) -> Result<JsonInput, ErrorKind> {
+    serde_json::from_slice(bytes).or_else(|e| {
+        if allow_partial && e.is_eof() {
+            if let Some(Ok(json_input)) = complete_truncated_json(bytes).map(|c| serde_json::from_slice(&c)) {
+                return Ok(json_input);
+            }
+        }
+        let position = json_error_position(bytes, e.line(), e.column());
+        Err(ErrorKind::InvalidJson {
+            error: e.to_string(),
+            position,
+            source_position: match (source_position, position) {
+                (true, Some(position)) => Some((e.line(), json_error_column(bytes, position))),
+                _ => None,
+            },
+        })
+    })
+}
+
+/// Convert serde's one-based line and (byte) column into an offset into `bytes`, `None` if serde didn't record
+/// where the error occurred
+fn json_error_position(bytes: &[u8], line: usize, column: usize) -> Option<usize> {
+    if line == 0 {
+        return None;
+    }
+    let line_start = match line {
+        1 => 0,
+        _ => {
+            bytes
+                .iter()
+                .enumerate()
+                .filter(|(_, b)| **b == b'\n')
+                .nth(line - 2)
+                .map(|(index, _)| index)?
+                + 1
+        }
+    };
+    Some((line_start + column.saturating_sub(1)).min(bytes.len()))
+}
+
+/// One-based column of the byte offset `position` counted in characters rather than bytes (serde's column),
+/// so it matches the offset into the python `str`, invalid UTF-8 is counted as replacement characters
+fn json_error_column(bytes: &[u8], position: usize) -> usize {
+    let line_start = bytes[..position]
+        .iter()
+        .rposition(|b| *b == b'\n')
+        .map_or(0, |index| index + 1);
+    String::from_utf8_lossy(&bytes[line_start..position]).chars().count() + 1
+}
+
 pub trait BuildValidator: Sized {
     const EXPECTED_TYPE: &'static str;
 
@@ -251,6 +865,33 @@ pub trait BuildValidator: Sized {
         -> PyResult<CombinedValidator>;
 }
 
+/// Shorthand for a schema type with `strict: True` hard-coded, e.g. `strict-str` is `{'type': 'str', 'strict': True}`,
+/// so neither config nor `strict=False` when validating can make it lax, see `StrictValidator`
+macro_rules! strict_shorthand {
+    ($builder:ident, $expected_type:literal, $validator:ty) => {
+        pub struct $builder;
+
+        impl BuildValidator for $builder {
+            const EXPECTED_TYPE: &'static str = $expected_type;
+
+            fn build(
+                schema: &PyDict,
+                config: Option<&PyDict>,
+                build_context: &mut BuildContext,
+            ) -> PyResult<CombinedValidator> {
+                let schema = schema.copy()?;
+                schema.set_item(intern!(schema.py(), "strict"), true)?;
+                Ok(super::strict::StrictValidator::wrap(<$validator>::build(
+                    schema,
+                    config,
+                    build_context,
+                )?))
+            }
+        }
+    };
+}
+pub(crate) use strict_shorthand;
+
 fn build_single_validator<'a, T: BuildValidator>(
     val_type: &str,
     schema_dict: &'a PyDict,
@@ -259,15 +900,18 @@ fn build_single_validator<'a, T: BuildValidator>(
 ) -> PyResult<CombinedValidator> {
     let py = schema_dict.py();
     let val: CombinedValidator = if let Some(schema_ref) = schema_dict.get_as::<String>(intern!(py, "ref"))? {
-        let slot_id = build_context.prepare_slot(schema_ref)?;
-        let inner_val = T::build(schema_dict, config, build_context)
-            .map_err(|err| SchemaError::new_err(format!("Error building \"{}\" validator:\n  {}", val_type, err)))?;
+        // a schema with a `ref` is only built once, later uses of the same ref (even while it's still being built,
+        // e.g. when the schema contains itself) point at the existing slot
+        if let Some(slot_id) = build_context.get_slot_id(&schema_ref) {
+            return Ok(recursive::RecursiveRefValidator::create(slot_id, schema_ref));
+        }
+        let slot_id = build_context.prepare_slot(schema_ref.clone())?;
+        let inner_val = T::build(schema_dict, config, build_context).with_schema_path(val_type)?;
         let name = inner_val.get_name().to_string();
         build_context.complete_slot(slot_id, inner_val)?;
-        recursive::RecursiveContainerValidator::create(slot_id, name)
+        recursive::RecursiveContainerValidator::create(slot_id, name, schema_ref)
     } else {
-        T::build(schema_dict, config, build_context)
-            .map_err(|err| SchemaError::new_err(format!("Error building \"{}\" validator:\n  {}", val_type, err)))?
+        T::build(schema_dict, config, build_context).with_schema_path(val_type)?
     };
 
     Ok(val)
@@ -302,13 +946,15 @@ pub fn build_validator<'a>(
         }
     };
     let type_: &str = dict.get_as_req(intern!(py, "type"))?;
-    validator_match!(
+    let validator = validator_match!(
         type_,
         dict,
         config,
         build_context,
         // typed dict e.g. heterogeneous dicts or simply a model
         typed_dict::TypedDictValidator,
+        // typed dict from merged layers of dicts
+        chain_map::ChainMapValidator,
         // unions
         union::UnionValidator,
         union::TaggedUnionValidator,
@@ -318,26 +964,45 @@ pub fn build_validator<'a>(
         new_class::NewClassValidator,
         // strings
         string::StrValidator,
+        string::StrictStrBuilder,
+        secret_str::SecretStrValidator,
         // integers
         int::IntValidator,
+        int::StrictIntBuilder,
+        int::PositiveIntBuilder,
+        int::NegativeIntBuilder,
+        int::NonNegativeIntBuilder,
+        int::NonPositiveIntBuilder,
         // boolean
         bool::BoolValidator,
+        bool::StrictBoolBuilder,
         // floats
         float::FloatValidator,
+        float::StrictFloatBuilder,
+        float::FiniteFloatBuilder,
+        float::PositiveFloatBuilder,
+        float::NegativeFloatBuilder,
+        float::NonNegativeFloatBuilder,
+        float::NonPositiveFloatBuilder,
         // tuples
         tuple::TupleBuilder,
+        namedtuple::NamedTupleValidator,
         // list/arrays
         list::ListValidator,
+        flat_list::FlatListValidator,
         // sets - unique lists
         set::SetValidator,
         // dicts/objects (recursive)
         dict::DictValidator,
+        mapping::MappingValidator,
         // None/null
         none::NoneValidator,
         // functions - before, after, plain & wrap
         function::FunctionBuilder,
         // function call - validation around a function call
         call::CallValidator,
+        // pydantic v1 style `__get_validators__` custom types
+        get_validators::GetValidatorsValidator,
         // recursive (self-referencing) models
         recursive::RecursiveRefValidator,
         // literals
@@ -346,6 +1011,8 @@ pub fn build_validator<'a>(
         any::AnyValidator,
         // bytes
         bytes::BytesValidator,
+        bytes::StrictBytesBuilder,
+        secret_bytes::SecretBytesValidator,
         // dates
         date::DateValidator,
         // times
@@ -356,12 +1023,26 @@ pub fn build_validator<'a>(
         frozenset::FrozenSetValidator,
         // timedelta
         timedelta::TimeDeltaValidator,
+        // ip addresses
+        ip_address::IpAddressValidator,
+        // urls
+        url::UrlValidator,
         // introspection types
         is_instance::IsInstanceValidator,
+        is_subclass::IsSubclassValidator,
         callable::CallableValidator,
         // arguments
         arguments::ArgumentsValidator,
-    )
+        // chain validators
+        chain::ChainValidator,
+        // error location prefix
+        span::SpanValidator,
+        // conditional schema selection
+        switch::SwitchValidator,
+    )?;
+    #[cfg(feature = "profiling")]
+    let validator = profiling::ProfiledValidator::wrap(validator);
+    Ok(validator)
 }
 
 /// More (mostly immutable) data to pass between validators, should probably be class `Context`,
@@ -377,16 +1058,178 @@ pub struct Extra<'a> {
     pub strict: Option<bool>,
     /// context used in validator functions
     pub context: Option<&'a PyAny>,
+    /// how many more times recursive validators may be entered before we give up with a `RecursionLoop` error,
+    /// this is decremented in `validators/recursive.rs` and copied into child calls so sibling branches don't
+    /// share a counter, `None` means no limit
+    pub max_depth: Option<usize>,
+    /// where warnings are recorded, `None` unless validating via `validate_python_with_warnings`,
+    /// otherwise warnings are discarded
+    pub warnings: Option<&'a Warnings>,
+    /// debug mode: where validators record the decisions they made, e.g. lax vs. strict, `None` unless
+    /// validating via `validate_debug`, so tracing costs nothing otherwise
+    pub trace: Option<&'a Trace>,
+    /// which coercions are allowed in lax mode, from the `lenient_coercion` config
+    pub coercion: LenientCoercionConfig,
+    /// what kind of input is being validated, beyond what the `Input` type says, see `InputMode`
+    pub input_mode: InputMode,
+    /// called by the dict validator with each valid entry, `None` unless validating via
+    /// `validate_python_streaming`, the dict validator doesn't pass it on to its keys and values
+    pub item_callback: Option<ItemCallback<'a>>,
+    /// set by `isinstance_python` and `isinstance_json` which only need to know whether the input is valid,
+    /// container validators return the first error from a child unchanged rather than collecting every error
+    pub fail_fast: bool,
+    /// the `SchemaValidator`'s slots, so objects which validate after this call has returned, e.g. lazy lists,
+    /// can share them rather than copying them, see `owned_slots`
+    pub shared_slots: Option<&'a Arc<[CombinedValidator]>>,
+    /// where calls to each validator are counted and timed, see `SchemaValidator.statistics()`
+    #[cfg(feature = "profiling")]
+    pub stats: Option<&'a profiling::Stats>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ItemCallback<'a> {
+    /// called with `(key, value)` after each entry is validated
+    pub callback: &'a PyAny,
+    /// don't build the output dict, the dict validator returns `None`
+    pub consume: bool,
+}
+
+/// Set by the `SchemaValidator` entry point and passed through unchanged by container validators (dict, list,
+/// typed-dict, function etc.) so any validator can tailor coercion to where the data came from.
+/// Validators which read it:
+/// * `list` - with `Strings`, string inputs are split on commas, see `list::comma_separated`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InputMode {
+    /// `validate_python`, `validate_msgpack` (which decodes to a `NativeInput`) and `validate_assignment`
+    #[default]
+    Python,
+    /// `validate_json` and `isinstance_json`
+    Json,
+    /// `validate_strings` for data where every value arrives as a string, e.g. HTML form data:
+    /// validation is always lax, coercion from strings is always allowed and lists also accept comma-separated strings
+    Strings,
 }
 
 impl<'a> Extra<'a> {
-    pub fn new(strict: Option<bool>, context: Option<&'a PyAny>) -> Self {
+    pub fn new(
+        strict: Option<bool>,
+        context: Option<&'a PyAny>,
+        max_depth: usize,
+        coercion: LenientCoercionConfig,
+    ) -> Self {
         Extra {
             strict,
             context,
+            max_depth: Some(max_depth),
+            coercion,
             ..Default::default()
         }
     }
+
+    /// the slots for an object which validates after this call has returned, shared if possible
+    pub fn owned_slots(&self, slots: &[CombinedValidator]) -> Arc<[CombinedValidator]> {
+        match self.shared_slots {
+            Some(shared_slots) => shared_slots.clone(),
+            None => slots.into(),
+        }
+    }
+
+    /// record a warning, these have the same shape as errors but don't cause validation to fail
+    pub fn warn(&self, py: Python, warning: ValLineError) {
+        if let Some(warnings) = self.warnings {
+            warnings.0.borrow_mut().push(warning.into_py(py));
+        }
+    }
+
+    /// record a debug trace entry, `decision` is only called in debug mode, see `Extra.trace`
+    pub fn trace(&self, validator: &str, decision: impl FnOnce() -> String) {
+        if let Some(trace) = self.trace {
+            trace.0.borrow_mut().push(TraceEntry {
+                location: Location::default(),
+                validator: validator.to_string(),
+                decision: decision(),
+            });
+        }
+    }
+
+    /// the number of entries recorded so far in both sinks, warnings and the trace,
+    /// used with `with_outer_location_since` and `discard_warnings`
+    pub fn sink_mark(&self) -> SinkMark {
+        SinkMark {
+            warnings: self.warnings.map_or(0, |warnings| warnings.0.borrow().len()),
+            trace: self.trace.map_or(0, |trace| trace.0.borrow().len()),
+        }
+    }
+
+    /// add a location to the warnings and trace entries recorded since `mark` by a child validator, as with errors,
+    /// `loc_item` is only called if warnings or a trace are being collected
+    pub fn with_outer_location_since(&self, mark: SinkMark, loc_item: impl FnOnce() -> LocItem) {
+        let mut warnings = self.warnings.map(|warnings| warnings.0.borrow_mut());
+        let mut trace = self.trace.map(|trace| trace.0.borrow_mut());
+        let new_warnings = warnings.as_mut().map_or(&mut [][..], |w| &mut w[mark.warnings..]);
+        let new_entries = trace.as_mut().map_or(&mut [][..], |t| &mut t[mark.trace..]);
+        if new_warnings.is_empty() && new_entries.is_empty() {
+            return;
+        }
+        let loc_item = loc_item();
+        for warning in new_warnings.iter_mut() {
+            warning.with_outer_location(loc_item.clone());
+        }
+        for entry in new_entries.iter_mut() {
+            entry.location.with_outer(loc_item.clone());
+        }
+    }
+
+    /// drop the warnings recorded since `mark`, e.g. when a union choice fails its warnings don't apply,
+    /// trace entries are kept since the choices which failed are part of the story
+    pub fn discard_warnings(&self, mark: SinkMark) {
+        if let Some(warnings) = self.warnings {
+            warnings.0.borrow_mut().truncate(mark.warnings);
+        }
+    }
+}
+
+/// a position in both the warnings and trace sinks, see `Extra::sink_mark`
+#[derive(Debug, Clone, Copy)]
+pub struct SinkMark {
+    warnings: usize,
+    trace: usize,
+}
+
+/// Warnings recorded during validation, see `Extra::warn`
+#[derive(Default)]
+pub struct Warnings(RefCell<Vec<PyLineError>>);
+
+impl fmt::Debug for Warnings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Warnings({})", self.0.borrow().len())
+    }
+}
+
+/// Decisions recorded by validators in debug mode, see `Extra::trace`
+#[derive(Default)]
+pub struct Trace(RefCell<Vec<TraceEntry>>);
+
+impl fmt::Debug for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Trace({})", self.0.borrow().len())
+    }
+}
+
+struct TraceEntry {
+    location: Location,
+    validator: String,
+    decision: String,
+}
+
+impl TraceEntry {
+    fn as_dict(&self, py: Python) -> PyResult<PyObject> {
+        let dict = PyDict::new(py);
+        dict.set_item("loc", self.location.to_object(py))?;
+        dict.set_item("validator", &self.validator)?;
+        dict.set_item("decision", &self.decision)?;
+        Ok(dict.into_py(py))
+    }
 }
 
 impl<'a> Extra<'a> {
@@ -396,42 +1239,62 @@ impl<'a> Extra<'a> {
             field: self.field,
             strict: Some(true),
             context: self.context,
+            max_depth: self.max_depth,
+            warnings: self.warnings,
+            trace: self.trace,
+            coercion: self.coercion,
+            input_mode: self.input_mode,
+            item_callback: self.item_callback,
+            fail_fast: self.fail_fast,
+            shared_slots: self.shared_slots,
+            #[cfg(feature = "profiling")]
+            stats: self.stats,
         }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
+#[cfg_attr(not(feature = "profiling"), derive(Debug))]
 #[enum_dispatch]
 pub enum CombinedValidator {
     // typed dict e.g. heterogeneous dicts or simply a model
     TypedDict(typed_dict::TypedDictValidator),
+    // typed dict from merged layers of dicts
+    ChainMap(chain_map::ChainMapValidator),
     // unions
     Union(union::UnionValidator),
     TaggedUnion(union::TaggedUnionValidator),
     // nullables
     Nullable(nullable::NullableValidator),
+    // strict shorthands, e.g. `strict-int`
+    Strict(strict::StrictValidator),
     // model classes
     ModelClass(new_class::NewClassValidator),
     // strings
     Str(string::StrValidator),
     StrConstrained(string::StrConstrainedValidator),
+    SecretStr(secret_str::SecretStrValidator),
     // integers
     Int(int::IntValidator),
     ConstrainedInt(int::ConstrainedIntValidator),
     // booleans
     Bool(bool::BoolValidator),
+    BoolValues(bool::BoolValuesValidator),
     // floats
     Float(float::FloatValidator),
     ConstrainedFloat(float::ConstrainedFloatValidator),
     // lists
     List(list::ListValidator),
+    FlatList(flat_list::FlatListValidator),
     // sets - unique lists
     Set(set::SetValidator),
     // tuples
     TuplePositional(tuple::TuplePositionalValidator),
     TupleVariable(tuple::TupleVariableValidator),
+    NamedTuple(namedtuple::NamedTupleValidator),
     // dicts/objects (recursive)
     Dict(dict::DictValidator),
+    Mapping(mapping::MappingValidator),
     // None/null
     None(none::NoneValidator),
     // functions
@@ -441,6 +1304,8 @@ pub enum CombinedValidator {
     FunctionWrap(function::FunctionWrapValidator),
     // function call - validation around a function call
     FunctionCall(call::CallValidator),
+    // pydantic v1 style `__get_validators__` custom types
+    GetValidators(get_validators::GetValidatorsValidator),
     // recursive (self-referencing) models
     Recursive(recursive::RecursiveContainerValidator),
     RecursiveRef(recursive::RecursiveRefValidator),
@@ -455,6 +1320,7 @@ pub enum CombinedValidator {
     // bytes
     Bytes(bytes::BytesValidator),
     ConstrainedBytes(bytes::BytesConstrainedValidator),
+    SecretBytes(secret_bytes::SecretBytesValidator),
     // dates
     Date(date::DateValidator),
     // times
@@ -465,11 +1331,25 @@ pub enum CombinedValidator {
     FrozenSet(frozenset::FrozenSetValidator),
     // timedelta
     Timedelta(timedelta::TimeDeltaValidator),
+    // ip addresses
+    IpAddress(ip_address::IpAddressValidator),
+    // urls
+    Url(url::UrlValidator),
     // introspection types
     IsInstance(is_instance::IsInstanceValidator),
+    IsSubclass(is_subclass::IsSubclassValidator),
     Callable(callable::CallableValidator),
     // arguments
     Arguments(arguments::ArgumentsValidator),
+    // chain validators
+    Chain(chain::ChainValidator),
+    // error location prefix
+    Span(span::SpanValidator),
+    // conditional schema selection
+    Switch(switch::SwitchValidator),
+    // records calls to the validator it wraps, only with the `profiling` feature
+    #[cfg(feature = "profiling")]
+    Profiled(profiling::ProfiledValidator),
 }
 
 /// This trait must be implemented by all validators, it allows various validators to be accessed consistently,
@@ -490,6 +1370,12 @@ pub trait Validator: Send + Sync + Clone + Debug {
     /// this is used in the error location in unions, and in the top level message in `ValidationError`
     fn get_name(&self) -> &str;
 
+    /// a human readable description of the type this validator expects, e.g. `dict[str, int]`, used in type errors
+    /// by validators of containers, validators whose name isn't a plain type (e.g. `constrained-int`) should override it
+    fn expected_repr(&self) -> String {
+        self.get_name().to_string()
+    }
+
     /// allows validators to ask specific questions of sub-validators in a general way, could be extended
     /// to do more, validators which don't know the question and have sub-validators
     /// should return the result them in an `...iter().all(|v| v.ask(question))` way, ONLY
@@ -498,11 +1384,161 @@ pub trait Validator: Send + Sync + Clone + Debug {
         false
     }
 
+    /// the field names and aliases this validator reads from its input, `None` if it doesn't have fields,
+    /// validators which return their sub-validator's output should forward this, e.g. new-class
+    fn known_fields(&self) -> Option<Vec<String>> {
+        None
+    }
+
     /// this method must be implemented for any validator which holds references to other validators,
     /// it is used by `RecursiveRefValidator` to set its name
     fn complete(&mut self, _build_context: &BuildContext) -> PyResult<()> {
         Ok(())
     }
+
+    /// a [JSON Schema](https://json-schema.org/) describing the values this validator accepts, e.g. for API docs,
+    /// validators which hold other validators should recurse, the default is the empty schema which allows any value.
+    /// Schemas with a `ref` are added to `definitions` (which becomes `$defs`) once and referenced with `$ref`
+    fn json_schema(&self, py: Python, _slots: &[CombinedValidator], _definitions: &PyDict) -> PyResult<PyObject> {
+        Ok(PyDict::new(py).into_py(py))
+    }
+
+    /// a dict summarising the built validator for debugging, e.g. `{"name": "list[int]", "items_schema": {...}}`,
+    /// validators with constraints or child validators should add them, the default is just the name
+    fn schema_repr(&self, py: Python, _slots: &[CombinedValidator]) -> PyResult<PyObject> {
+        Ok(schema_repr_dict(py, self.get_name())?.into_py(py))
+    }
+
+    /// the schema's `title`, `description` and `examples`, `None` if the validator doesn't store them,
+    /// validators which store them should also add them to their JSON Schema
+    fn metadata(&self) -> Option<&SchemaMetadata> {
+        None
+    }
+}
+
+/// the `max_input_length` config is a guard against huge untrusted inputs rather than a constraint on the data,
+/// dict and list validators check it against the number of items before anything else, so it takes precedence
+/// over `min_items` and `max_items`, even where `max_items` is larger
+fn check_max_input_length<'data>(
+    max_input_length: Option<usize>,
+    input_length: usize,
+    input: &'data impl Input<'data>,
+) -> ValResult<'data, ()> {
+    match max_input_length {
+        Some(max_input_length) if input_length > max_input_length => Err(ValError::new(
+            ErrorKind::InputTooLarge {
+                max_input_length,
+                input_length,
+            },
+            input,
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// `check_max_input_length` for inputs whose length is known before they're iterated over or copied, e.g. by lax
+/// coercion or `key_transform`, see `Input::input_len`, validators check the length again once they have a
+/// list or dict since the length of e.g. generators isn't known upfront
+fn check_max_input_len<'data>(
+    max_input_length: Option<usize>,
+    input: &'data impl Input<'data>,
+) -> ValResult<'data, ()> {
+    match (max_input_length, input.input_len()) {
+        (Some(_), Some(input_length)) => check_max_input_length(max_input_length, input_length, input),
+        _ => Ok(()),
+    }
+}
+
+/// The number of items in a JSON array or object, found by scanning rather than parsing `bytes`, `None` if
+/// `bytes` doesn't start with `[` or `{` or the array or object isn't closed, used to check `max_input_length`
+/// before a string is parsed, any other problem with the JSON is left to the parser
+fn json_container_len(bytes: &[u8]) -> Option<usize> {
+    let mut iter = bytes.iter().skip_while(|b| b.is_ascii_whitespace());
+    if !matches!(iter.next(), Some(b'[' | b'{')) {
+        return None;
+    }
+    let mut depth = 1_usize;
+    let mut commas = 0;
+    let mut empty = true;
+    let mut in_string = false;
+    let mut escaped = false;
+    for &b in iter {
+        if in_string {
+            match b {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'"' => in_string = false,
+                _ => (),
+            }
+            continue;
+        }
+        match b {
+            b'"' => in_string = true,
+            b'[' | b'{' => depth += 1,
+            b']' | b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(if empty { 0 } else { commas + 1 });
+                }
+            }
+            b',' if depth == 1 => commas += 1,
+            _ if b.is_ascii_whitespace() => continue,
+            _ => (),
+        }
+        empty = false;
+    }
+    None
+}
+
+/// used with the `validate_default` config, check a field or parameter's `default` is valid when building, in
+/// strict mode since defaults are python objects, it's called from `complete` so all slots have been filled
+pub fn validate_default(
+    py: Python,
+    validator: &CombinedValidator,
+    default: &PyObject,
+    slots: &[CombinedValidator],
+) -> PyResult<()> {
+    let extra = Extra {
+        strict: Some(true),
+        ..Default::default()
+    };
+    match validator.validate(py, default.as_ref(py), &extra, slots, &mut RecursionGuard::default()) {
+        Ok(_) => Ok(()),
+        Err(ValError::LineErrors(line_errors)) => {
+            py_error!("Invalid default value:\n{}", pretty_line_errors(py, line_errors))
+        }
+        Err(ValError::InternalErr(err)) => Err(err),
+    }
+}
+
+/// a JSON Schema `{"type": type_}` which validators can add their constraints to, see `Validator::json_schema`
+pub fn json_schema_type<'py>(py: Python<'py>, type_: &str) -> PyResult<&'py PyDict> {
+    let schema = PyDict::new(py);
+    schema.set_item("type", type_)?;
+    Ok(schema)
+}
+
+/// `{"name": name}` which validators can add their constraints and children to, see `Validator::schema_repr`
+pub fn schema_repr_dict<'py>(py: Python<'py>, name: &str) -> PyResult<&'py PyDict> {
+    let repr = PyDict::new(py);
+    repr.set_item("name", name)?;
+    Ok(repr)
+}
+
+/// `module.qualname` so classes with the same name in different modules, or nested in other classes, can be told apart
+pub fn get_class_repr(class: &PyType) -> PyResult<String> {
+    let py = class.py();
+    let module: &str = class.getattr(intern!(py, "__module__"))?.extract()?;
+    let qualname: &str = class.getattr(intern!(py, "__qualname__"))?.extract()?;
+    Ok(format!("{}.{}", module, qualname))
+}
+
+/// set `key` in a JSON Schema or `schema_repr`, if the constraint is set on the validator
+pub fn set_json_schema_item(schema: &PyDict, key: &str, value: Option<impl ToPyObject>) -> PyResult<()> {
+    match value {
+        Some(value) => schema.set_item(key, value),
+        None => Ok(()),
+    }
 }
 
 /// `BuildContext` is used to store extra information while building validators,
@@ -536,13 +1572,17 @@ impl BuildContext {
 
     /// find a slot by `slot_ref` - iterate over the slots until we find a matching reference - return the index
     pub fn find_slot_id(&self, slot_ref: &str) -> PyResult<usize> {
-        let is_match = |(match_sr, _): &(String, Option<CombinedValidator>)| match_sr == slot_ref;
-        match self.slots.iter().position(is_match) {
+        match self.get_slot_id(slot_ref) {
             Some(id) => Ok(id),
             None => py_error!("Slots Error: ref '{}' not found", slot_ref),
         }
     }
 
+    /// like `find_slot_id`, but `None` if the ref doesn't exist yet
+    pub fn get_slot_id(&self, slot_ref: &str) -> Option<usize> {
+        self.slots.iter().position(|(match_sr, _)| match_sr == slot_ref)
+    }
+
     /// find a validator by `slot_id` - this used in `Validator.complete`, specifically `RecursiveRefValidator`
     /// to set its name
     pub fn find_validator(&self, slot_id: usize) -> PyResult<&CombinedValidator> {
@@ -555,6 +1595,18 @@ impl BuildContext {
         }
     }
 
+    /// A copy of the validators in every slot, e.g. to validate against during `complete`, after all slots
+    /// have been filled
+    pub fn slots_snapshot(&self) -> PyResult<Vec<CombinedValidator>> {
+        self.slots
+            .iter()
+            .map(|(_, opt_validator)| match opt_validator {
+                Some(validator) => Ok(validator.clone()),
+                None => py_error!("Slots Error: slot not yet filled"),
+            })
+            .collect()
+    }
+
     /// Move validators into a new vec which maintains the order of slots, `complete` is called on each validator
     /// at the same time.
     pub fn into_slots(self) -> PyResult<Vec<CombinedValidator>> {
@@ -0,0 +1,268 @@
+use ahash::AHashSet;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyTuple, PyType};
+
+use crate::build_tools::{is_strict, py_error, SchemaDict, SchemaPath};
+use crate::errors::{ErrorKind, ValError, ValLineError, ValResult};
+use crate::input::{GenericListLike, GenericMapping, Input};
+use crate::lookup_key::LookupKey;
+use crate::recursion_guard::RecursionGuard;
+
+use super::any::AnyValidator;
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+#[derive(Debug, Clone)]
+struct NamedTupleField {
+    name: String,
+    lookup_key: LookupKey,
+    validator: CombinedValidator,
+    // from the class's `_field_defaults`, used as is without validation like `typed-dict` defaults
+    default: Option<PyObject>,
+}
+
+/// Validate a sequence (positionally) or, in lax mode, a mapping (by field name) and create an instance of a
+/// `NamedTuple` class with `cls(*values)`, field names and defaults are taken from the class
+#[derive(Debug, Clone)]
+pub struct NamedTupleValidator {
+    strict: bool,
+    class: Py<PyType>,
+    fields: Vec<NamedTupleField>,
+    field_names: AHashSet<String>,
+    name: String,
+}
+
+impl BuildValidator for NamedTupleValidator {
+    const EXPECTED_TYPE: &'static str = "namedtuple";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let class: &PyType = schema.get_as_req(intern!(py, "class_type"))?;
+        let is_namedtuple = class.is_subclass_of::<PyTuple>()? && class.hasattr(intern!(py, "_fields"))?;
+        if !is_namedtuple {
+            return py_error!("class_type should be a NamedTuple class, got {}", class.name()?);
+        }
+        let field_names: Vec<String> = class.getattr(intern!(py, "_fields"))?.extract()?;
+        let field_defaults: &PyDict = class.getattr(intern!(py, "_field_defaults"))?.cast_as()?;
+
+        let fields_schemas: Option<&PyDict> = schema.get_as(intern!(py, "fields"))?;
+        if let Some(fields_schemas) = fields_schemas {
+            for key in fields_schemas.keys() {
+                let key: &str = key.extract()?;
+                if !field_names.iter().any(|name| name == key) {
+                    return py_error!("'{}' is not a field of {}", key, class.name()?);
+                }
+            }
+        }
+
+        let mut fields: Vec<NamedTupleField> = Vec::with_capacity(field_names.len());
+        for name in &field_names {
+            let validator = match fields_schemas.and_then(|d| d.get_item(name)) {
+                Some(field_schema) => build_validator(field_schema, config, build_context)
+                    .with_schema_path(name)
+                    .with_schema_path("fields")?,
+                None => AnyValidator::build(schema, config, build_context)?,
+            };
+            fields.push(NamedTupleField {
+                name: name.clone(),
+                lookup_key: LookupKey::from_string(py, name),
+                validator,
+                default: field_defaults.get_item(name).map(|d| d.into_py(py)),
+            });
+        }
+
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            class: class.into(),
+            name: class.name()?.to_string(),
+            field_names: field_names.into_iter().collect(),
+            fields,
+        }
+        .into())
+    }
+}
+
+impl Validator for NamedTupleValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let strict = extra.strict.unwrap_or(self.strict);
+        if !strict {
+            match input.validate_dict(false) {
+                Ok(mapping) => {
+                    let values = self.validate_mapping(py, input, mapping, extra, slots, recursion_guard)?;
+                    return self.create_instance(py, values);
+                }
+                // not a mapping, try a sequence
+                Err(ValError::LineErrors(_)) => (),
+                Err(err) => return Err(err),
+            }
+        }
+        let values = self.validate_sequence(py, input, input.validate_tuple(strict)?, extra, slots, recursion_guard)?;
+        self.create_instance(py, values)
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
+        self.fields
+            .iter_mut()
+            .try_for_each(|field| field.validator.complete(build_context))
+    }
+}
+
+impl NamedTupleValidator {
+    fn create_instance<'data>(&self, py: Python<'data>, values: Vec<PyObject>) -> ValResult<'data, PyObject> {
+        let instance = self.class.as_ref(py).call1(PyTuple::new(py, values))?;
+        Ok(instance.into_py(py))
+    }
+
+    fn validate_sequence<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        list_like: GenericListLike<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, Vec<PyObject>> {
+        let expected_length = self.fields.len();
+        let input_length = list_like.generic_len();
+        if input_length > expected_length {
+            return Err(ValError::new(
+                ErrorKind::TooLong {
+                    max_length: expected_length,
+                    input_length,
+                },
+                input,
+            ));
+        }
+
+        let mut output: Vec<PyObject> = Vec::with_capacity(expected_length);
+        let mut errors: Vec<ValLineError> = Vec::new();
+        macro_rules! iter {
+            ($list_like:expr) => {
+                for (index, (field, item)) in self.fields.iter().zip($list_like.iter()).enumerate() {
+                    let sink_mark = extra.sink_mark();
+                    let result = field.validator.validate(py, item, extra, slots, recursion_guard);
+                    extra.with_outer_location_since(sink_mark, || index.into());
+                    match result {
+                        Ok(item) => output.push(item),
+                        Err(err @ ValError::LineErrors(_)) if extra.fail_fast => return Err(err),
+                        Err(ValError::LineErrors(line_errors)) => {
+                            errors.extend(
+                                line_errors
+                                    .into_iter()
+                                    .map(|err| err.with_outer_location(index.into())),
+                            );
+                        }
+                        Err(err) => return Err(err),
+                    }
+                }
+            };
+        }
+        match list_like {
+            GenericListLike::List(list_like) => iter!(list_like),
+            GenericListLike::Tuple(list_like) => iter!(list_like),
+            GenericListLike::Set(list_like) => iter!(list_like),
+            GenericListLike::FrozenSet(list_like) => iter!(list_like),
+            GenericListLike::JsonArray(list_like) => iter!(list_like),
+            GenericListLike::NativeList(list_like) => iter!(list_like),
+        }
+
+        for (index, field) in self.fields.iter().enumerate().skip(input_length) {
+            match field.default {
+                Some(ref default) => output.push(default.clone_ref(py)),
+                None => errors.push(ValLineError::new_with_loc(ErrorKind::Missing, input, index)),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(output)
+        } else {
+            Err(ValError::LineErrors(errors))
+        }
+    }
+
+    fn validate_mapping<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        mapping: GenericMapping<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, Vec<PyObject>> {
+        let mut output: Vec<PyObject> = Vec::with_capacity(self.fields.len());
+        let mut errors: Vec<ValLineError> = Vec::new();
+        macro_rules! process {
+            ($dict:ident, $get_method:ident) => {{
+                for field in &self.fields {
+                    match field.lookup_key.$get_method($dict)? {
+                        Some((_, value)) => {
+                            let sink_mark = extra.sink_mark();
+                            let result = field.validator.validate(py, value, extra, slots, recursion_guard);
+                            extra.with_outer_location_since(sink_mark, || field.name.as_str().into());
+                            match result {
+                                Ok(value) => output.push(value),
+                                Err(err @ ValError::LineErrors(_)) if extra.fail_fast => return Err(err),
+                                Err(ValError::LineErrors(line_errors)) => {
+                                    errors.extend(
+                                        line_errors
+                                            .into_iter()
+                                            .map(|err| err.with_outer_location(field.name.as_str().into())),
+                                    );
+                                }
+                                Err(err) => return Err(err),
+                            }
+                        }
+                        None => match field.default {
+                            Some(ref default) => output.push(default.clone_ref(py)),
+                            None => errors.push(ValLineError::new_with_loc(
+                                ErrorKind::Missing,
+                                input,
+                                field.name.as_str(),
+                            )),
+                        },
+                    }
+                }
+                for (raw_key, value) in $dict.iter() {
+                    let is_field = match raw_key.strict_str() {
+                        Ok(key) => self.field_names.contains(key.as_cow()?.as_ref()),
+                        Err(_) => false,
+                    };
+                    if !is_field {
+                        errors.push(ValLineError::new_with_loc(
+                            ErrorKind::ExtraForbidden,
+                            value,
+                            raw_key.as_loc_item(),
+                        ));
+                    }
+                }
+            }};
+        }
+        match mapping {
+            GenericMapping::PyDict(d) => process!(d, py_get_item),
+            GenericMapping::PyGetAttr(_) => unreachable!(),
+            GenericMapping::JsonObject(d) => process!(d, json_get),
+            GenericMapping::NativeDict(d) => process!(d, native_get),
+        }
+
+        if errors.is_empty() {
+            Ok(output)
+        } else {
+            Err(ValError::LineErrors(errors))
+        }
+    }
+}
@@ -5,20 +5,53 @@ use std::ptr::null_mut;
 use pyo3::conversion::AsPyPointer;
 use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyTuple, PyType};
+use pyo3::types::{PyBool, PyDict, PyTuple, PyType};
 use pyo3::{ffi, intern};
 
-use crate::build_tools::SchemaDict;
+use crate::build_tools::{py_error, SchemaDict, SchemaPath};
 use crate::errors::{ErrorKind, ValError, ValResult};
 use crate::input::Input;
 use crate::recursion_guard::RecursionGuard;
 
 use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 
+#[derive(Debug, Clone, PartialEq)]
+enum Revalidate {
+    Always,
+    Never,
+    SubclassInstances,
+}
+
+impl Revalidate {
+    fn from_config(config: Option<&PyDict>) -> PyResult<Self> {
+        let py_revalidate: Option<&PyAny> = match config {
+            Some(config) => config.get_item(intern!(config.py(), "revalidate_models")),
+            None => None,
+        };
+        let py_revalidate = match py_revalidate {
+            Some(value) => value,
+            None => return Ok(Self::Never),
+        };
+        // `revalidate_models` used to be a bool, `True` and `False` are still accepted
+        if let Ok(py_bool) = py_revalidate.cast_as::<PyBool>() {
+            return match py_bool.is_true() {
+                true => Ok(Self::Always),
+                false => Ok(Self::Never),
+            };
+        }
+        match py_revalidate.extract::<&str>().with_schema_path("revalidate_models")? {
+            "never" => Ok(Self::Never),
+            "always" => Ok(Self::Always),
+            "subclass-instances" => Ok(Self::SubclassInstances),
+            s => py_error!(r#"Invalid revalidate_models: "{}""#, s),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct NewClassValidator {
     strict: bool,
-    revalidate: bool,
+    revalidate: Revalidate,
     validator: Box<CombinedValidator>,
     class: Py<PyType>,
     name: String,
@@ -39,7 +72,7 @@ impl BuildValidator for NewClassValidator {
 
         let class: &PyType = schema.get_as_req(intern!(py, "class_type"))?;
         let sub_schema: &PyAny = schema.get_as_req(intern!(py, "schema"))?;
-        let validator = build_validator(sub_schema, config, build_context)?;
+        let validator = build_validator(sub_schema, config, build_context).with_schema_path("schema")?;
 
         let expect_fields_set = validator.ask("return_fields_set");
 
@@ -47,7 +80,7 @@ impl BuildValidator for NewClassValidator {
             // we don't use is_strict here since we don't want validation to be strict in this case if
             // `config.strict` is set, only if this specific field is strict
             strict: schema.get_as(intern!(py, "strict"))?.unwrap_or(false),
-            revalidate: config.get_as(intern!(py, "revalidate_models"))?.unwrap_or(false),
+            revalidate: Revalidate::from_config(config)?,
             validator: Box::new(validator),
             class: class.into(),
             // Get the class's `__name__`, not using `class.name()` since it uses `__qualname__`
@@ -68,21 +101,26 @@ impl Validator for NewClassValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
+        if extra.field.is_some() {
+            // we're validating assignment, the inner validator returns the updated model dict (and fields set),
+            // which `SchemaValidator.validate_assignment` applies to the instance
+            return self.validator.validate(py, input, extra, slots, recursion_guard);
+        }
         let class = self.class.as_ref(py);
+        // exact instances are only revalidated with "always", subclass instances with "always" or
+        // "subclass-instances", with "never" instances of the class or a subclass are returned as-is
         if input.is_type(class)? {
-            if self.revalidate {
-                let fields_set = input.get_attr(intern!(py, "__fields_set__"));
-                let output = self.validator.validate(py, input, extra, slots, recursion_guard)?;
-                if self.expect_fields_set {
-                    let (model_dict, validation_fields_set): (&PyAny, &PyAny) = output.extract(py)?;
-                    let fields_set = fields_set.unwrap_or(validation_fields_set);
-                    Ok(self.create_class(py, model_dict, Some(fields_set))?)
-                } else {
-                    Ok(self.create_class(py, output.as_ref(py), fields_set)?)
-                }
+            if self.revalidate == Revalidate::Always {
+                self.revalidate_instance(py, input, extra, slots, recursion_guard)
             } else {
                 Ok(input.to_object(py))
             }
+        } else if input.is_instance(class)? {
+            if self.revalidate == Revalidate::Never {
+                Ok(input.to_object(py))
+            } else {
+                self.revalidate_instance(py, input, extra, slots, recursion_guard)
+            }
         } else if extra.strict.unwrap_or(self.strict) {
             Err(ValError::new(
                 ErrorKind::ModelClassType {
@@ -104,9 +142,32 @@ impl Validator for NewClassValidator {
     fn get_name(&self) -> &str {
         &self.name
     }
+
+    fn known_fields(&self) -> Option<Vec<String>> {
+        self.validator.known_fields()
+    }
 }
 
 impl NewClassValidator {
+    fn revalidate_instance<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let fields_set = input.get_attr(intern!(py, "__fields_set__"));
+        let output = self.validator.validate(py, input, extra, slots, recursion_guard)?;
+        if self.expect_fields_set {
+            let (model_dict, validation_fields_set): (&PyAny, &PyAny) = output.extract(py)?;
+            let fields_set = fields_set.unwrap_or(validation_fields_set);
+            Ok(self.create_class(py, model_dict, Some(fields_set))?)
+        } else {
+            Ok(self.create_class(py, output.as_ref(py), fields_set)?)
+        }
+    }
+
     fn create_class(&self, py: Python, model_dict: &PyAny, fields_set: Option<&PyAny>) -> PyResult<PyObject> {
         // based on the following but with the second argument of new_func set to an empty tuple as required
         // https://github.com/PyO3/pyo3/blob/d2caa056e9aacc46374139ef491d112cb8af1a25/src/pyclass_init.rs#L35-L77
@@ -1,24 +1,32 @@
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
+use crate::build_tools::{debug_with_metadata, SchemaMetadata};
 use crate::errors::{ErrorKind, ValError, ValResult};
 use crate::input::Input;
 use crate::recursion_guard::RecursionGuard;
 
-use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{json_schema_type, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 
-#[derive(Debug, Clone)]
-pub struct NoneValidator;
+#[derive(Clone)]
+pub struct NoneValidator {
+    metadata: Option<SchemaMetadata>,
+}
+
+debug_with_metadata!(NoneValidator);
 
 impl BuildValidator for NoneValidator {
     const EXPECTED_TYPE: &'static str = "none";
 
     fn build(
-        _schema: &PyDict,
+        schema: &PyDict,
         _config: Option<&PyDict>,
         _build_context: &mut BuildContext,
     ) -> PyResult<CombinedValidator> {
-        Ok(Self.into())
+        Ok(Self {
+            metadata: SchemaMetadata::from_schema(schema)?,
+        }
+        .into())
     }
 }
 
@@ -40,4 +48,16 @@ impl Validator for NoneValidator {
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
+
+    fn json_schema(&self, py: Python, _slots: &[CombinedValidator], _definitions: &PyDict) -> PyResult<PyObject> {
+        let schema = json_schema_type(py, "null")?;
+        if let Some(ref metadata) = self.metadata {
+            metadata.update_dict(schema)?;
+        }
+        Ok(schema.into_py(py))
+    }
+
+    fn metadata(&self) -> Option<&SchemaMetadata> {
+        self.metadata.as_ref()
+    }
 }
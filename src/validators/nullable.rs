@@ -1,20 +1,26 @@
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyList};
 
-use crate::build_tools::SchemaDict;
+use crate::build_tools::{debug_with_metadata, SchemaDict, SchemaMetadata, SchemaPath};
 use crate::errors::ValResult;
 use crate::input::Input;
 use crate::recursion_guard::RecursionGuard;
 
-use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{
+    build_validator, json_schema_type, schema_repr_dict, BuildContext, BuildValidator, CombinedValidator, Extra,
+    Validator,
+};
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct NullableValidator {
     validator: Box<CombinedValidator>,
+    metadata: Option<SchemaMetadata>,
     name: String,
 }
 
+debug_with_metadata!(NullableValidator, validator, name);
+
 impl BuildValidator for NullableValidator {
     const EXPECTED_TYPE: &'static str = "nullable";
 
@@ -23,10 +29,15 @@ impl BuildValidator for NullableValidator {
         config: Option<&PyDict>,
         build_context: &mut BuildContext,
     ) -> PyResult<CombinedValidator> {
-        let schema: &PyAny = schema.get_as_req(intern!(schema.py(), "schema"))?;
-        let validator = Box::new(build_validator(schema, config, build_context)?);
+        let sub_schema: &PyAny = schema.get_as_req(intern!(schema.py(), "schema"))?;
+        let validator = Box::new(build_validator(sub_schema, config, build_context).with_schema_path("schema")?);
         let name = format!("{}[{}]", Self::EXPECTED_TYPE, validator.get_name());
-        Ok(Self { validator, name }.into())
+        Ok(Self {
+            validator,
+            metadata: SchemaMetadata::from_schema(schema)?,
+            name,
+        }
+        .into())
     }
 }
 
@@ -49,6 +60,33 @@ impl Validator for NullableValidator {
         &self.name
     }
 
+    fn expected_repr(&self) -> String {
+        format!("Optional[{}]", self.validator.expected_repr())
+    }
+
+    fn json_schema(&self, py: Python, slots: &[CombinedValidator], definitions: &PyDict) -> PyResult<PyObject> {
+        let schema = PyDict::new(py);
+        let any_of = [
+            self.validator.json_schema(py, slots, definitions)?,
+            json_schema_type(py, "null")?.into_py(py),
+        ];
+        schema.set_item("anyOf", PyList::new(py, any_of))?;
+        if let Some(ref metadata) = self.metadata {
+            metadata.update_dict(schema)?;
+        }
+        Ok(schema.into_py(py))
+    }
+
+    fn schema_repr(&self, py: Python, slots: &[CombinedValidator]) -> PyResult<PyObject> {
+        let repr = schema_repr_dict(py, self.get_name())?;
+        repr.set_item("schema", self.validator.schema_repr(py, slots)?)?;
+        Ok(repr.into_py(py))
+    }
+
+    fn metadata(&self) -> Option<&SchemaMetadata> {
+        self.metadata.as_ref()
+    }
+
     fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
         self.validator.complete(build_context)
     }
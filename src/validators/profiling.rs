@@ -0,0 +1,239 @@
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use indexmap::IndexMap;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use crate::build_tools::SchemaMetadata;
+use crate::errors::ValResult;
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::{BuildContext, CombinedValidator, Extra, Validator};
+
+/// With the `profiling` feature, every validator built by `build_validator` is wrapped in a `ProfiledValidator`
+/// which records calls to the validator it wraps in `Extra.stats`, see `SchemaValidator.statistics()`
+#[derive(Clone)]
+pub struct ProfiledValidator {
+    validator: Box<CombinedValidator>,
+    // unique to this node of the schema, copies of the validator, e.g. in lazy lists, share it
+    id: usize,
+}
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+impl ProfiledValidator {
+    pub fn wrap(validator: CombinedValidator) -> CombinedValidator {
+        Self {
+            validator: Box::new(validator),
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+        }
+        .into()
+    }
+
+    /// the wrapped validator, for code which needs to know what the validator at the root of the schema is
+    pub fn inner(&self) -> &CombinedValidator {
+        &self.validator
+    }
+}
+
+impl Validator for ProfiledValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let stats = match extra.stats {
+            Some(stats) => stats,
+            None => return self.validator.validate(py, input, extra, slots, recursion_guard),
+        };
+        let start = Instant::now();
+        let result = self.validator.validate(py, input, extra, slots, recursion_guard);
+        stats.record(self.id, self.validator.get_name(), start.elapsed(), result.is_err());
+        result
+    }
+
+    fn get_name(&self) -> &str {
+        self.validator.get_name()
+    }
+
+    fn expected_repr(&self) -> String {
+        self.validator.expected_repr()
+    }
+
+    fn ask(&self, question: &str) -> bool {
+        self.validator.ask(question)
+    }
+
+    fn known_fields(&self) -> Option<Vec<String>> {
+        self.validator.known_fields()
+    }
+
+    fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
+        self.validator.complete(build_context)
+    }
+
+    fn json_schema(&self, py: Python, slots: &[CombinedValidator], definitions: &PyDict) -> PyResult<PyObject> {
+        self.validator.json_schema(py, slots, definitions)
+    }
+
+    fn schema_repr(&self, py: Python, slots: &[CombinedValidator]) -> PyResult<PyObject> {
+        self.validator.schema_repr(py, slots)
+    }
+
+    fn metadata(&self) -> Option<&SchemaMetadata> {
+        self.validator.metadata()
+    }
+}
+
+// reprs are the same with and without the `profiling` feature
+impl fmt::Debug for ProfiledValidator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.validator.fmt(f)
+    }
+}
+
+/// the same as `#[derive(Debug)]` except `Profiled` is transparent, the match is exhaustive so a validator added
+/// to `CombinedValidator` but not here fails to build with the `profiling` feature
+macro_rules! combined_validator_debug {
+    ($($variant:ident),* $(,)?) => {
+        impl fmt::Debug for CombinedValidator {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self {
+                    Self::Profiled(validator) => validator.fmt(f),
+                    $(Self::$variant(validator) => f.debug_tuple(stringify!($variant)).field(validator).finish(),)*
+                }
+            }
+        }
+    };
+}
+
+combined_validator_debug!(
+    TypedDict,
+    ChainMap,
+    Union,
+    TaggedUnion,
+    Nullable,
+    Strict,
+    ModelClass,
+    Str,
+    StrConstrained,
+    SecretStr,
+    Int,
+    ConstrainedInt,
+    Bool,
+    BoolValues,
+    Float,
+    ConstrainedFloat,
+    List,
+    FlatList,
+    Set,
+    TuplePositional,
+    TupleVariable,
+    NamedTuple,
+    Dict,
+    Mapping,
+    None,
+    FunctionBefore,
+    FunctionAfter,
+    FunctionPlain,
+    FunctionWrap,
+    FunctionCall,
+    GetValidators,
+    Recursive,
+    RecursiveRef,
+    LiteralSingleString,
+    LiteralSingleInt,
+    LiteralMultipleStrings,
+    LiteralMultipleInts,
+    LiteralGeneral,
+    Any,
+    Bytes,
+    ConstrainedBytes,
+    SecretBytes,
+    Date,
+    Time,
+    Datetime,
+    FrozenSet,
+    Timedelta,
+    IpAddress,
+    Url,
+    IsInstance,
+    IsSubclass,
+    Callable,
+    Arguments,
+    Chain,
+    Span,
+    Switch,
+);
+
+/// Calls to each validator over the life of a `SchemaValidator`, by `ProfiledValidator.id`, a `Mutex` rather than
+/// a `RefCell` since `SchemaValidator` must be `Sync`. Objects which validate after the call that created them
+/// has returned, e.g. lazy lists, record calls via `share`, whereas `clone` copies the stats.
+#[derive(Default)]
+pub struct Stats(Arc<Mutex<IndexMap<usize, ValidatorStats>>>);
+
+#[derive(Default, Clone)]
+struct ValidatorStats {
+    name: String,
+    calls: usize,
+    errors: usize,
+    // includes time spent in child validators
+    time: Duration,
+}
+
+impl fmt::Debug for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Stats({})", self.lock().len())
+    }
+}
+
+impl Clone for Stats {
+    fn clone(&self) -> Self {
+        Self(Arc::new(Mutex::new(self.lock().clone())))
+    }
+}
+
+impl Stats {
+    fn lock(&self) -> MutexGuard<'_, IndexMap<usize, ValidatorStats>> {
+        // stats are only updated after the inner validator returns, so a panic can't leave them half updated
+        self.0.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// the same stats, rather than a copy
+    pub fn share(&self) -> Self {
+        Self(self.0.clone())
+    }
+
+    fn record(&self, id: usize, name: &str, time: Duration, error: bool) {
+        let mut stats = self.lock();
+        let validator_stats = stats.entry(id).or_insert_with(|| ValidatorStats {
+            name: name.to_string(),
+            ..Default::default()
+        });
+        validator_stats.calls += 1;
+        validator_stats.errors += error as usize;
+        validator_stats.time += time;
+    }
+
+    /// `[{"name": ..., "calls": ..., "errors": ..., "time": seconds}]`, one entry per validator in the order they
+    /// were first called, validators with the same name, e.g. two `int` fields, have separate entries
+    pub fn as_list(&self, py: Python) -> PyResult<PyObject> {
+        let list = PyList::empty(py);
+        for validator_stats in self.lock().values() {
+            let d = PyDict::new(py);
+            d.set_item("name", &validator_stats.name)?;
+            d.set_item("calls", validator_stats.calls)?;
+            d.set_item("errors", validator_stats.errors)?;
+            d.set_item("time", validator_stats.time.as_secs_f64())?;
+            list.append(d)?;
+        }
+        Ok(list.into_py(py))
+    }
+}
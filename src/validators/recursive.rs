@@ -7,19 +7,21 @@ use crate::errors::{ErrorKind, ValError, ValResult};
 use crate::input::Input;
 use crate::recursion_guard::RecursionGuard;
 
-use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{schema_repr_dict, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub struct RecursiveContainerValidator {
     validator_id: usize,
     inner_name: String,
+    schema_ref: String,
 }
 
 impl RecursiveContainerValidator {
-    pub fn create(validator_id: usize, inner_name: String) -> CombinedValidator {
+    pub fn create(validator_id: usize, inner_name: String, schema_ref: String) -> CombinedValidator {
         Self {
             validator_id,
             inner_name,
+            schema_ref,
         }
         .into()
     }
@@ -42,6 +44,17 @@ impl Validator for RecursiveContainerValidator {
         &self.inner_name
     }
 
+    fn json_schema(&self, py: Python, slots: &[CombinedValidator], definitions: &PyDict) -> PyResult<PyObject> {
+        json_schema_ref(py, self.validator_id, &self.schema_ref, slots, definitions)
+    }
+
+    /// the referenced validator's summary, with its `ref`
+    fn schema_repr(&self, py: Python, slots: &[CombinedValidator]) -> PyResult<PyObject> {
+        let repr = slots[self.validator_id].schema_repr(py, slots)?;
+        repr.as_ref(py).set_item("ref", &self.schema_ref)?;
+        Ok(repr)
+    }
+
     // complete is not implemented here, instead complete_validators in mod.rs calls complete()
     // on all validators in slots
 }
@@ -50,6 +63,19 @@ impl Validator for RecursiveContainerValidator {
 pub struct RecursiveRefValidator {
     validator_id: usize,
     inner_name: String,
+    schema_ref: String,
+}
+
+impl RecursiveRefValidator {
+    /// the name is set in `complete` since the referenced validator might not be built yet
+    pub fn create(validator_id: usize, schema_ref: String) -> CombinedValidator {
+        Self {
+            validator_id,
+            inner_name: "...".to_string(),
+            schema_ref,
+        }
+        .into()
+    }
 }
 
 impl BuildValidator for RecursiveRefValidator {
@@ -62,11 +88,7 @@ impl BuildValidator for RecursiveRefValidator {
     ) -> PyResult<CombinedValidator> {
         let name: String = schema.get_as_req(intern!(schema.py(), "schema_ref"))?;
         let validator_id = build_context.find_slot_id(&name)?;
-        Ok(Self {
-            validator_id,
-            inner_name: "...".to_string(),
-        }
-        .into())
+        Ok(Self::create(validator_id, name))
     }
 }
 
@@ -86,6 +108,18 @@ impl Validator for RecursiveRefValidator {
         &self.inner_name
     }
 
+    fn json_schema(&self, py: Python, slots: &[CombinedValidator], definitions: &PyDict) -> PyResult<PyObject> {
+        json_schema_ref(py, self.validator_id, &self.schema_ref, slots, definitions)
+    }
+
+    /// just the name and `ref`, the referenced validator is summarised where its `ref` is defined, and recursing
+    /// here would never end for recursive schemas
+    fn schema_repr(&self, py: Python, _slots: &[CombinedValidator]) -> PyResult<PyObject> {
+        let repr = schema_repr_dict(py, self.get_name())?;
+        repr.set_item("ref", &self.schema_ref)?;
+        Ok(repr.into_py(py))
+    }
+
     /// don't need to call complete on the inner validator here, complete_validators takes care of that.
     fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
         let validator = build_context.find_validator(self.validator_id)?;
@@ -94,6 +128,26 @@ impl Validator for RecursiveRefValidator {
     }
 }
 
+/// `{"$ref": "#/$defs/<schema_ref>"}`, the referenced validator's JSON Schema is added to `definitions` the first
+/// time it's seen, so shared and recursive schemas are only generated once
+fn json_schema_ref(
+    py: Python,
+    validator_id: usize,
+    schema_ref: &str,
+    slots: &[CombinedValidator],
+    definitions: &PyDict,
+) -> PyResult<PyObject> {
+    if !definitions.contains(schema_ref)? {
+        // insert a placeholder first so a schema which contains itself stops here rather than recursing forever
+        definitions.set_item(schema_ref, PyDict::new(py))?;
+        let validator = &slots[validator_id];
+        definitions.set_item(schema_ref, validator.json_schema(py, slots, definitions)?)?;
+    }
+    let schema = PyDict::new(py);
+    schema.set_item("$ref", format!("#/$defs/{}", schema_ref))?;
+    Ok(schema.into_py(py))
+}
+
 // see #143 this is a backup in case the identity check recursion guard fails
 // if a single validator "depth" (how many times it's called inside itself) exceeds the limit,
 // we raise a recursion error.
@@ -111,6 +165,15 @@ fn guard_validate<'s, 'data>(
     slots: &'data [CombinedValidator],
     recursion_guard: &'s mut RecursionGuard,
 ) -> ValResult<'data, PyObject> {
+    // the depth counter is copied into a new `Extra` so sibling branches each get the same budget
+    let extra = &Extra {
+        max_depth: match extra.max_depth {
+            Some(0) => return Err(ValError::new(ErrorKind::RecursionLoop, input)),
+            Some(depth) => Some(depth - 1),
+            None => None,
+        },
+        ..*extra
+    };
     if let Some(id) = input.identity() {
         if recursion_guard.contains_or_insert(id) {
             // we don't remove id here, we leave that to the validator which originally added id to `recursion_guard`
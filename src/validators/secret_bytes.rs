@@ -0,0 +1,62 @@
+use pyo3::prelude::*;
+use pyo3::type_object::PyTypeObject;
+use pyo3::types::PyDict;
+
+use crate::errors::ValResult;
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+use crate::secret_bytes::SecretBytes;
+
+use super::bytes::BytesValidator;
+use super::secret_str::mask_input_values;
+use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+/// Validate like `bytes` (with the same constraints), but return a `SecretBytes` and mask the input in errors
+#[derive(Debug, Clone)]
+pub struct SecretBytesValidator {
+    validator: Box<CombinedValidator>,
+}
+
+impl BuildValidator for SecretBytesValidator {
+    const EXPECTED_TYPE: &'static str = "secret-bytes";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        Ok(Self {
+            validator: Box::new(BytesValidator::build(schema, config, build_context)?),
+        }
+        .into())
+    }
+}
+
+impl Validator for SecretBytesValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        // existing `SecretBytes` instances still need to match this validator's constraints
+        let result = if input.is_instance(SecretBytes::type_object(py))? {
+            let secret_bytes: SecretBytes = input.to_object(py).extract(py)?;
+            let secret_value = secret_bytes.get_secret_value(py);
+            self.validator
+                .validate(py, secret_value.as_ref(), extra, slots, recursion_guard)
+        } else {
+            self.validator.validate(py, input, extra, slots, recursion_guard)
+        };
+        match result {
+            Ok(output) => Ok(SecretBytes::new(output.extract(py)?).into_py(py)),
+            Err(err) => Err(mask_input_values(err)),
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
@@ -0,0 +1,80 @@
+use pyo3::prelude::*;
+use pyo3::type_object::PyTypeObject;
+use pyo3::types::{PyDict, PyString};
+
+use crate::errors::{InputValue, ValError, ValResult};
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+use crate::secret_str::SecretStr;
+
+use super::string::StrValidator;
+use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+/// replaces the input value in errors so the secret can't leak via error messages or logs
+const MASKED_INPUT: &str = "**masked**";
+
+/// Validate like `str` (with the same constraints), but return a `SecretStr` and mask the input in errors
+#[derive(Debug, Clone)]
+pub struct SecretStrValidator {
+    validator: Box<CombinedValidator>,
+}
+
+impl BuildValidator for SecretStrValidator {
+    const EXPECTED_TYPE: &'static str = "secret-str";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        Ok(Self {
+            validator: Box::new(StrValidator::build(schema, config, build_context)?),
+        }
+        .into())
+    }
+}
+
+impl Validator for SecretStrValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        // existing `SecretStr` instances still need to match this validator's constraints
+        let result = if input.is_instance(SecretStr::type_object(py))? {
+            let secret_str: SecretStr = input.to_object(py).extract(py)?;
+            let secret_value = PyString::new(py, secret_str.get_secret_value());
+            self.validator
+                .validate(py, secret_value.as_ref(), extra, slots, recursion_guard)
+        } else {
+            self.validator.validate(py, input, extra, slots, recursion_guard)
+        };
+        match result {
+            Ok(output) => Ok(SecretStr::new(output.extract(py)?).into_py(py)),
+            Err(err) => Err(mask_input_values(err)),
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
+
+/// replace the input value in all line errors, shared by all secret validators
+pub fn mask_input_values(err: ValError) -> ValError {
+    match err {
+        ValError::LineErrors(line_errors) => ValError::LineErrors(
+            line_errors
+                .into_iter()
+                .map(|mut err| {
+                    err.input_value = InputValue::String(MASKED_INPUT);
+                    err
+                })
+                .collect(),
+        ),
+        err => err,
+    }
+}
@@ -1,7 +1,7 @@
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PySet};
 
-use crate::build_tools::SchemaDict;
+use crate::build_tools::{SchemaDict, SchemaPath};
 use crate::errors::ValResult;
 use crate::input::{GenericListLike, Input};
 use crate::recursion_guard::RecursionGuard;
@@ -15,6 +15,8 @@ pub struct SetValidator {
     item_validator: Option<Box<CombinedValidator>>,
     size_range: Option<(Option<usize>, Option<usize>)>,
     name: String,
+    // e.g. `set[int]`, `None` unless `items_schema` is set
+    expected: Option<String>,
 }
 
 impl BuildValidator for SetValidator {
@@ -31,7 +33,9 @@ impl Validator for SetValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let seq = input.validate_set(extra.strict.unwrap_or(self.strict))?;
+        let seq = input
+            .validate_set(extra.strict.unwrap_or(self.strict))
+            .map_err(|err| err.with_expected(self.expected.as_deref()))?;
 
         let length = seq.check_len(self.size_range, input)?;
 
@@ -49,6 +53,13 @@ impl Validator for SetValidator {
         &self.name
     }
 
+    fn expected_repr(&self) -> String {
+        match self.expected {
+            Some(ref expected) => expected.clone(),
+            None => Self::EXPECTED_TYPE.to_string(),
+        }
+    }
+
     fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
         match self.item_validator {
             Some(ref mut v) => v.complete(build_context),
@@ -0,0 +1,108 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyString};
+
+use crate::build_tools::{py_error, SchemaDict, SchemaPath};
+use crate::errors::{LocItem, ValError, ValResult};
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+/// Prepend `prefix` to the location of all errors from the inner validator, e.g. so a shared schema reports
+/// errors at the path where it's used
+#[derive(Debug, Clone)]
+pub struct SpanValidator {
+    // stored innermost first to match `Location`, so items can be added to errors in order
+    prefix: Vec<LocItem>,
+    validator: Box<CombinedValidator>,
+    name: String,
+}
+
+impl BuildValidator for SpanValidator {
+    const EXPECTED_TYPE: &'static str = "span";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let mut prefix = schema
+            .get_as_req::<&PyList>(intern!(py, "loc_prefix"))?
+            .iter()
+            .map(loc_item_from_py)
+            .collect::<PyResult<Vec<_>>>()?;
+        prefix.reverse();
+
+        let validator = build_validator(schema.get_as_req(intern!(py, "schema"))?, config, build_context)
+            .with_schema_path("schema")?;
+        // without a prefix this is just the inner validator
+        if prefix.is_empty() {
+            return Ok(validator);
+        }
+        let name = format!("{}[{}]", Self::EXPECTED_TYPE, validator.get_name());
+        Ok(Self {
+            prefix,
+            validator: Box::new(validator),
+            name,
+        }
+        .into())
+    }
+}
+
+fn loc_item_from_py(item: &PyAny) -> PyResult<LocItem> {
+    if let Ok(py_str) = item.cast_as::<PyString>() {
+        Ok(py_str.to_str()?.into())
+    } else if let Ok(index) = item.extract::<usize>() {
+        Ok(index.into())
+    } else {
+        py_error!("loc_prefix items should be strings or non-negative integers")
+    }
+}
+
+impl Validator for SpanValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let sink_mark = extra.sink_mark();
+        let result = self.validator.validate(py, input, extra, slots, recursion_guard);
+        for loc_item in &self.prefix {
+            extra.with_outer_location_since(sink_mark, || loc_item.clone());
+        }
+        match result {
+            Err(ValError::LineErrors(line_errors)) => Err(ValError::LineErrors(
+                line_errors
+                    .into_iter()
+                    .map(|err| {
+                        self.prefix
+                            .iter()
+                            .fold(err, |err, loc_item| err.with_outer_location(loc_item.clone()))
+                    })
+                    .collect(),
+            )),
+            result => result,
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn ask(&self, question: &str) -> bool {
+        self.validator.ask(question)
+    }
+
+    fn known_fields(&self) -> Option<Vec<String>> {
+        self.validator.known_fields()
+    }
+
+    fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
+        self.validator.complete(build_context)
+    }
+}
@@ -0,0 +1,63 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::build_tools::SchemaMetadata;
+use crate::errors::ValResult;
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::{BuildContext, CombinedValidator, Extra, Validator};
+
+/// Built by the `strict_shorthand!` schema types, e.g. `strict-int`, the inner validator is always run in strict
+/// mode, whatever `strict` is passed to `validate_python` etc., so the shorthand can never be made lax
+#[derive(Debug, Clone)]
+pub struct StrictValidator {
+    validator: Box<CombinedValidator>,
+}
+
+impl StrictValidator {
+    pub fn wrap(validator: CombinedValidator) -> CombinedValidator {
+        Self {
+            validator: Box::new(validator),
+        }
+        .into()
+    }
+}
+
+impl Validator for StrictValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        self.validator
+            .validate(py, input, &extra.as_strict(), slots, recursion_guard)
+    }
+
+    fn get_name(&self) -> &str {
+        self.validator.get_name()
+    }
+
+    fn expected_repr(&self) -> String {
+        self.validator.expected_repr()
+    }
+
+    fn json_schema(&self, py: Python, slots: &[CombinedValidator], definitions: &PyDict) -> PyResult<PyObject> {
+        self.validator.json_schema(py, slots, definitions)
+    }
+
+    fn schema_repr(&self, py: Python, slots: &[CombinedValidator]) -> PyResult<PyObject> {
+        self.validator.schema_repr(py, slots)
+    }
+
+    fn metadata(&self) -> Option<&SchemaMetadata> {
+        self.validator.metadata()
+    }
+
+    fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
+        self.validator.complete(build_context)
+    }
+}
@@ -1,20 +1,29 @@
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyString};
+use pyo3::types::{PyBytes, PyDict, PyString};
 use regex::Regex;
 
-use crate::build_tools::{is_strict, py_error, schema_or_config};
+use crate::build_tools::{
+    debug_with_metadata, is_strict, py_error, schema_or_config, validate_length_constraints, SchemaMetadata,
+};
 use crate::errors::{ErrorKind, ValError, ValResult};
-use crate::input::Input;
+use crate::input::{EitherString, Input};
 use crate::recursion_guard::RecursionGuard;
 
-use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{
+    json_schema_type, schema_repr_dict, set_json_schema_item, strict_shorthand, BuildContext, BuildValidator,
+    CombinedValidator, Extra, Validator,
+};
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct StrValidator {
     strict: bool,
+    bytes_encoding: Option<String>,
+    metadata: Option<SchemaMetadata>,
 }
 
+debug_with_metadata!(StrValidator, strict, bytes_encoding);
+
 impl BuildValidator for StrValidator {
     const EXPECTED_TYPE: &'static str = "str";
 
@@ -46,12 +55,16 @@ impl BuildValidator for StrValidator {
         } else {
             Ok(Self {
                 strict: is_strict(schema, config)?,
+                bytes_encoding: get_bytes_encoding(schema, config)?,
+                metadata: SchemaMetadata::from_schema(schema)?,
             }
             .into())
         }
     }
 }
 
+strict_shorthand!(StrictStrBuilder, "strict-str", StrValidator);
+
 impl Validator for StrValidator {
     fn validate<'s, 'data>(
         &'s self,
@@ -61,17 +74,32 @@ impl Validator for StrValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        Ok(input.validate_str(extra.strict.unwrap_or(self.strict))?.into_py(py))
+        let strict = extra.strict.unwrap_or(self.strict);
+        Ok(validate_str(py, input, strict, self.bytes_encoding.as_deref(), extra)?.into_py(py))
     }
 
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
+
+    fn json_schema(&self, py: Python, _slots: &[CombinedValidator], _definitions: &PyDict) -> PyResult<PyObject> {
+        let schema = json_schema_type(py, "string")?;
+        if let Some(ref metadata) = self.metadata {
+            metadata.update_dict(schema)?;
+        }
+        Ok(schema.into_py(py))
+    }
+
+    fn metadata(&self) -> Option<&SchemaMetadata> {
+        self.metadata.as_ref()
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct StrConstrainedValidator {
     strict: bool,
+    bytes_encoding: Option<String>,
+    metadata: Option<SchemaMetadata>,
     pattern: Option<Regex>,
     max_length: Option<usize>,
     min_length: Option<usize>,
@@ -80,6 +108,18 @@ pub struct StrConstrainedValidator {
     to_upper: bool,
 }
 
+debug_with_metadata!(
+    StrConstrainedValidator,
+    strict,
+    bytes_encoding,
+    pattern,
+    max_length,
+    min_length,
+    strip_whitespace,
+    to_lower,
+    to_upper
+);
+
 impl Validator for StrConstrainedValidator {
     fn validate<'s, 'data>(
         &'s self,
@@ -89,12 +129,79 @@ impl Validator for StrConstrainedValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let either_str = input.validate_str(extra.strict.unwrap_or(self.strict))?;
+        let strict = extra.strict.unwrap_or(self.strict);
+        let either_str = validate_str(py, input, strict, self.bytes_encoding.as_deref(), extra)?;
         let cow = either_str.as_cow()?;
         let mut str = cow.as_ref();
+
+        // transformations are applied before constraints are checked: first strip, then case
+        if self.strip_whitespace {
+            str = str.trim();
+        }
+        let transformed = if self.to_lower {
+            Some(PyString::new(py, &str.to_lowercase()))
+        } else if self.to_upper {
+            Some(PyString::new(py, &str.to_uppercase()))
+        } else if self.strip_whitespace {
+            Some(PyString::new(py, str))
+        } else {
+            None
+        };
+
+        match transformed {
+            // errors reference the transformed value, since that's what failed the checks
+            Some(py_string) => {
+                self.check(py_string.to_str()?, py_string.as_ref())?;
+                Ok(py_string.into_py(py))
+            }
+            None => {
+                self.check(str, input)?;
+                // we haven't modified the string, return the original as it might be a PyString
+                Ok(either_str.as_py_string(py).into_py(py))
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        "constrained-str"
+    }
+
+    fn expected_repr(&self) -> String {
+        StrValidator::EXPECTED_TYPE.to_string()
+    }
+
+    fn schema_repr(&self, py: Python, _slots: &[CombinedValidator]) -> PyResult<PyObject> {
+        let repr = schema_repr_dict(py, self.get_name())?;
+        repr.set_item("strict", self.strict)?;
+        repr.set_item("strip_whitespace", self.strip_whitespace)?;
+        repr.set_item("to_lower", self.to_lower)?;
+        repr.set_item("to_upper", self.to_upper)?;
+        set_json_schema_item(repr, "min_length", self.min_length)?;
+        set_json_schema_item(repr, "max_length", self.max_length)?;
+        set_json_schema_item(repr, "pattern", self.pattern.as_ref().map(|p| p.as_str()))?;
+        Ok(repr.into_py(py))
+    }
+
+    fn json_schema(&self, py: Python, _slots: &[CombinedValidator], _definitions: &PyDict) -> PyResult<PyObject> {
+        let schema = json_schema_type(py, "string")?;
+        set_json_schema_item(schema, "minLength", self.min_length)?;
+        set_json_schema_item(schema, "maxLength", self.max_length)?;
+        set_json_schema_item(schema, "pattern", self.pattern.as_ref().map(|p| p.as_str()))?;
+        if let Some(ref metadata) = self.metadata {
+            metadata.update_dict(schema)?;
+        }
+        Ok(schema.into_py(py))
+    }
+
+    fn metadata(&self) -> Option<&SchemaMetadata> {
+        self.metadata.as_ref()
+    }
+}
+
+impl StrConstrainedValidator {
+    fn check<'d>(&self, str: &str, input: &'d impl Input<'d>) -> ValResult<'d, ()> {
         if let Some(min_length) = self.min_length {
             if str.len() < min_length {
-                // return py_error!("{} is shorter than {}", str, min_length);
                 return Err(ValError::new(ErrorKind::StrTooShort { min_length }, input));
             }
         }
@@ -113,30 +220,9 @@ impl Validator for StrConstrainedValidator {
                 ));
             }
         }
-
-        if self.strip_whitespace {
-            str = str.trim();
-        }
-
-        let py_string = if self.to_lower {
-            PyString::new(py, &str.to_lowercase())
-        } else if self.to_upper {
-            PyString::new(py, &str.to_uppercase())
-        } else if self.strip_whitespace {
-            PyString::new(py, str)
-        } else {
-            // we haven't modified the string, return the original as it might be a PyString
-            either_str.as_py_string(py)
-        };
-        Ok(py_string.into_py(py))
-    }
-
-    fn get_name(&self) -> &str {
-        "constrained-str"
+        Ok(())
     }
-}
 
-impl StrConstrainedValidator {
     fn build(schema: &PyDict, config: Option<&PyDict>) -> PyResult<CombinedValidator> {
         let py = schema.py();
         let pattern_str: Option<&str> =
@@ -145,10 +231,12 @@ impl StrConstrainedValidator {
             Some(s) => Some(build_regex(s)?),
             None => None,
         };
-        let min_length: Option<usize> =
-            schema_or_config(schema, config, intern!(py, "min_length"), intern!(py, "str_min_length"))?;
-        let max_length: Option<usize> =
-            schema_or_config(schema, config, intern!(py, "max_length"), intern!(py, "str_max_length"))?;
+        let (min_length, max_length) = validate_length_constraints(
+            py,
+            "min_length",
+            schema_or_config(schema, config, intern!(py, "min_length"), intern!(py, "str_min_length"))?,
+            schema_or_config(schema, config, intern!(py, "max_length"), intern!(py, "str_max_length"))?,
+        )?;
 
         let strip_whitespace: bool = schema_or_config(
             schema,
@@ -161,9 +249,14 @@ impl StrConstrainedValidator {
             schema_or_config(schema, config, intern!(py, "to_lower"), intern!(py, "str_to_lower"))?.unwrap_or(false);
         let to_upper: bool =
             schema_or_config(schema, config, intern!(py, "to_upper"), intern!(py, "str_to_upper"))?.unwrap_or(false);
+        if to_lower && to_upper {
+            return py_error!("'to_lower' and 'to_upper' cannot both be set");
+        }
 
         Ok(Self {
             strict: is_strict(schema, config)?,
+            bytes_encoding: get_bytes_encoding(schema, config)?,
+            metadata: SchemaMetadata::from_schema(schema)?,
             pattern,
             min_length,
             max_length,
@@ -175,9 +268,67 @@ impl StrConstrainedValidator {
     }
 }
 
+/// Patterns are compiled with the rust [regex](https://docs.rs/regex) crate, not python's `re`. Syntax is mostly the
+/// same, but look-around (`(?=...)`, `(?<=...)` etc.) and backreferences aren't supported since the crate guarantees
+/// linear time matching, these patterns fail here when the schema is built.
+/// Matching is via `is_match`, so like `re.search` the pattern isn't anchored unless it includes `^` or `$`.
 fn build_regex(pattern: &str) -> PyResult<Regex> {
     match Regex::new(pattern) {
         Ok(r) => Ok(r),
         Err(e) => py_error!("{}", e),
     }
 }
+
+/// `bytes_encoding` from the schema or `str_bytes_encoding` from config, checked with `codecs.lookup`,
+/// `None` means the default of utf-8 which is decoded in rust without calling python
+fn get_bytes_encoding(schema: &PyDict, config: Option<&PyDict>) -> PyResult<Option<String>> {
+    let py = schema.py();
+    let encoding: Option<&str> = schema_or_config(
+        schema,
+        config,
+        intern!(py, "bytes_encoding"),
+        intern!(py, "str_bytes_encoding"),
+    )?;
+    let encoding = match encoding {
+        Some(encoding) => encoding,
+        None => return Ok(None),
+    };
+    let codec_info = match py.import("codecs")?.call_method1("lookup", (encoding,)) {
+        Ok(codec_info) => codec_info,
+        Err(_) => return py_error!("Invalid bytes_encoding: {:?}", encoding),
+    };
+    let name: String = codec_info.getattr(intern!(py, "name"))?.extract()?;
+    match name.as_str() {
+        "utf-8" => Ok(None),
+        _ => Ok(Some(name)),
+    }
+}
+
+/// `input.validate_str` which in lax mode also accepts numbers if `coerce_numbers_to_str` is set
+/// via `lenient_coercion`, and decodes bytes with `bytes_encoding` if it's set
+fn validate_str<'data>(
+    py: Python<'data>,
+    input: &'data impl Input<'data>,
+    strict: bool,
+    bytes_encoding: Option<&str>,
+    extra: &Extra,
+) -> ValResult<'data, EitherString<'data>> {
+    if !strict {
+        if let Some(either_str) = extra.coercion.number_as_str(py, input)? {
+            return Ok(either_str);
+        }
+        if let Some(encoding) = bytes_encoding {
+            // only python `bytes` and `bytearray` get here, `lax_bytes` would also accept strings
+            if input.strict_str().is_err() {
+                if let Ok(either_bytes) = input.lax_bytes() {
+                    let py_bytes = PyBytes::new(py, either_bytes.as_slice());
+                    return match py_bytes.call_method1("decode", (encoding,)) {
+                        Ok(py_str) => Ok(py_str.cast_as::<PyString>()?.into()),
+                        Err(_) => Err(ValError::new(ErrorKind::StrUnicode, input)),
+                    };
+                }
+            }
+        }
+    }
+    input.validate_str(strict)
+}
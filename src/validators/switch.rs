@@ -0,0 +1,118 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use crate::build_tools::{py_error, SchemaDict, SchemaPath};
+use crate::errors::{ErrorKind, ValError, ValResult};
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::function::convert_err;
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+/// Call each choice's `predicate` with the input in order, the validator of the first choice whose predicate
+/// returns a truthy value is used, if none match `default_schema` is used if set, otherwise validation fails
+#[derive(Debug, Clone)]
+pub struct SwitchValidator {
+    choices: Vec<(PyObject, CombinedValidator)>,
+    default_validator: Option<Box<CombinedValidator>>,
+    name: String,
+}
+
+impl BuildValidator for SwitchValidator {
+    const EXPECTED_TYPE: &'static str = "switch";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let choices: Vec<(PyObject, CombinedValidator)> = schema
+            .get_as_req::<&PyList>(intern!(py, "choices"))?
+            .iter()
+            .enumerate()
+            .map(|(index, choice)| {
+                let choice: &PyDict = choice.cast_as()?;
+                let predicate: &PyAny = choice.get_as_req(intern!(py, "predicate")).with_schema_path(index)?;
+                let choice_schema: &PyAny = choice.get_as_req(intern!(py, "schema")).with_schema_path(index)?;
+                let validator = build_validator(choice_schema, config, build_context)
+                    .with_schema_path("schema")
+                    .with_schema_path(index)?;
+                Ok((predicate.into_py(py), validator))
+            })
+            .collect::<PyResult<_>>()
+            .with_schema_path("choices")?;
+        if choices.is_empty() {
+            return py_error!("One or more choices are required for a switch validator");
+        }
+
+        let default_validator = match schema.get_item(intern!(py, "default_schema")) {
+            Some(default_schema) => Some(Box::new(
+                build_validator(default_schema, config, build_context).with_schema_path("default_schema")?,
+            )),
+            None => None,
+        };
+
+        let descr = choices
+            .iter()
+            .map(|(_, v)| v.get_name())
+            .chain(default_validator.iter().map(|v| v.get_name()))
+            .collect::<Vec<_>>()
+            .join(",");
+        Ok(Self {
+            choices,
+            default_validator,
+            name: format!("{}[{}]", Self::EXPECTED_TYPE, descr),
+        }
+        .into())
+    }
+}
+
+impl Validator for SwitchValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let py_input = input.to_object(py);
+        for (predicate, validator) in &self.choices {
+            let matched = predicate
+                .call1(py, (py_input.clone_ref(py),))
+                .and_then(|result| result.as_ref(py).is_true())
+                .map_err(|err| convert_err(py, err, input))?;
+            if matched {
+                return validator.validate(py, input, extra, slots, recursion_guard);
+            }
+        }
+        match self.default_validator {
+            Some(ref validator) => validator.validate(py, input, extra, slots, recursion_guard),
+            None => Err(ValError::new(ErrorKind::NoMatchForUnion, input)),
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn ask(&self, question: &str) -> bool {
+        self.choices
+            .iter()
+            .map(|(_, v)| v)
+            .chain(self.default_validator.as_deref())
+            .all(|v| v.ask(question))
+    }
+
+    fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
+        self.choices
+            .iter_mut()
+            .try_for_each(|(_, validator)| validator.complete(build_context))?;
+        match self.default_validator {
+            Some(ref mut validator) => validator.complete(build_context),
+            None => Ok(()),
+        }
+    }
+}
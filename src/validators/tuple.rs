@@ -2,7 +2,7 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyTuple};
 
-use crate::build_tools::{is_strict, SchemaDict};
+use crate::build_tools::{is_strict, SchemaDict, SchemaPath};
 use crate::errors::{ErrorKind, ValError, ValLineError, ValResult};
 use crate::input::{GenericListLike, Input};
 use crate::recursion_guard::RecursionGuard;
@@ -34,6 +34,8 @@ pub struct TupleVariableValidator {
     item_validator: Option<Box<CombinedValidator>>,
     size_range: Option<(Option<usize>, Option<usize>)>,
     name: String,
+    // e.g. `tuple[int, ...]`, `None` unless `items_schema` is set
+    expected: Option<String>,
 }
 
 impl TupleVariableValidator {
@@ -49,7 +51,9 @@ impl Validator for TupleVariableValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let seq = input.validate_tuple(extra.strict.unwrap_or(self.strict))?;
+        let seq = input
+            .validate_tuple(extra.strict.unwrap_or(self.strict))
+            .map_err(|err| err.with_expected(self.expected.as_deref()))?;
 
         let length = seq.check_len(self.size_range, input)?;
 
@@ -67,6 +71,13 @@ impl Validator for TupleVariableValidator {
         &self.name
     }
 
+    fn expected_repr(&self) -> String {
+        match self.expected {
+            Some(ref expected) => expected.clone(),
+            None => TupleBuilder::EXPECTED_TYPE.to_string(),
+        }
+    }
+
     fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
         match self.item_validator {
             Some(ref mut v) => v.complete(build_context),
@@ -81,6 +92,8 @@ pub struct TuplePositionalValidator {
     items_validators: Vec<CombinedValidator>,
     extra_validator: Option<Box<CombinedValidator>>,
     name: String,
+    // e.g. `tuple[int, str]`
+    expected: String,
 }
 
 impl TuplePositionalValidator {
@@ -93,18 +106,28 @@ impl TuplePositionalValidator {
         let items: &PyList = schema.get_as_req(intern!(py, "items_schema"))?;
         let validators: Vec<CombinedValidator> = items
             .iter()
-            .map(|item| build_validator(item, config, build_context))
-            .collect::<PyResult<Vec<CombinedValidator>>>()?;
+            .enumerate()
+            .map(|(index, item)| build_validator(item, config, build_context).with_schema_path(index))
+            .collect::<PyResult<Vec<CombinedValidator>>>()
+            .with_schema_path("items_schema")?;
 
         let descr = validators.iter().map(|v| v.get_name()).collect::<Vec<_>>().join(", ");
+        let expected_descr = validators
+            .iter()
+            .map(|v| v.expected_repr())
+            .collect::<Vec<_>>()
+            .join(", ");
         Ok(Self {
             strict: is_strict(schema, config)?,
             items_validators: validators,
             extra_validator: match schema.get_item(intern!(py, "extra_schema")) {
-                Some(v) => Some(Box::new(build_validator(v, config, build_context)?)),
+                Some(v) => Some(Box::new(
+                    build_validator(v, config, build_context).with_schema_path("extra_schema")?,
+                )),
                 None => None,
             },
             name: format!("tuple[{}]", descr),
+            expected: format!("tuple[{}]", expected_descr),
         }
         .into())
     }
@@ -119,7 +142,9 @@ impl Validator for TuplePositionalValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let list_like = input.validate_tuple(extra.strict.unwrap_or(self.strict))?;
+        let list_like = input
+            .validate_tuple(extra.strict.unwrap_or(self.strict))
+            .map_err(|err| err.with_expected(Some(&self.expected)))?;
         let expected_length = self.items_validators.len();
 
         let ll_length = list_like.generic_len();
@@ -151,8 +176,12 @@ impl Validator for TuplePositionalValidator {
                         },
                     };
 
-                    match validator.validate(py, item, extra, slots, recursion_guard) {
+                    let sink_mark = extra.sink_mark();
+                    let result = validator.validate(py, item, extra, slots, recursion_guard);
+                    extra.with_outer_location_since(sink_mark, || index.into());
+                    match result {
                         Ok(item) => output.push(item),
+                        Err(err @ ValError::LineErrors(_)) if extra.fail_fast => return Err(err),
                         Err(ValError::LineErrors(line_errors)) => {
                             errors.extend(
                                 line_errors
@@ -171,6 +200,7 @@ impl Validator for TuplePositionalValidator {
             GenericListLike::Set(list_like) => iter!(list_like),
             GenericListLike::FrozenSet(list_like) => iter!(list_like),
             GenericListLike::JsonArray(list_like) => iter!(list_like),
+            GenericListLike::NativeList(list_like) => iter!(list_like),
         }
         if errors.is_empty() {
             Ok(PyTuple::new(py, &output).into_py(py))
@@ -183,6 +213,10 @@ impl Validator for TuplePositionalValidator {
         &self.name
     }
 
+    fn expected_repr(&self) -> String {
+        self.expected.clone()
+    }
+
     fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
         self.items_validators
             .iter_mut()
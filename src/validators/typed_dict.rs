@@ -1,19 +1,25 @@
 use std::borrow::Cow;
 
+use pyo3::exceptions::PyAttributeError;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyFunction, PyList, PySet, PyString};
 use pyo3::{intern, PyTypeInfo};
 
 use ahash::AHashSet;
 
-use crate::build_tools::{is_strict, py_error, schema_or_config, schema_or_config_same, SchemaDict};
+use crate::build_tools::{
+    get_default, is_strict, py_error, schema_or_config, schema_or_config_same, SchemaDict, SchemaPath,
+};
 use crate::errors::{py_err_string, ErrorKind, ValError, ValLineError, ValResult};
 use crate::input::{GenericMapping, Input};
 use crate::lookup_key::LookupKey;
 use crate::recursion_guard::RecursionGuard;
-use crate::SchemaError;
 
-use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::function::convert_err;
+use super::{
+    build_validator, schema_repr_dict, validate_default, BuildContext, BuildValidator, CombinedValidator, Extra,
+    Validator,
+};
 
 #[derive(Debug, Clone)]
 enum OnError {
@@ -31,15 +37,26 @@ struct TypedDictField {
     on_error: OnError,
     default: Option<PyObject>,
     default_factory: Option<PyObject>,
+    // check `default` is valid when building, see `validate_default`
+    validate_default: bool,
     validator: CombinedValidator,
 }
 
 impl TypedDictField {
-    fn default_value(&self, py: Python) -> PyResult<Option<Cow<PyObject>>> {
+    /// `ValueError`s and `AssertionError`s raised by `default_factory` become errors located at this field,
+    /// other exceptions are raised as is
+    fn default_value<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+    ) -> ValResult<'data, Option<Cow<'s, PyObject>>> {
         if let Some(ref default) = self.default {
             Ok(Some(Cow::Borrowed(default)))
         } else if let Some(ref default_factory) = self.default_factory {
-            Ok(Some(Cow::Owned(default_factory.call0(py)?)))
+            match default_factory.call0(py) {
+                Ok(default) => Ok(Some(Cow::Owned(default))),
+                Err(err) => Err(convert_err(py, err, input).with_outer_location(self.name.clone().into())),
+            }
         } else {
             Ok(None)
         }
@@ -55,6 +72,8 @@ pub struct TypedDictValidator {
     strict: bool,
     from_attributes: bool,
     return_fields_set: bool,
+    // with `total=False` the output only includes fields which were provided, defaults aren't filled in
+    total: bool,
 }
 
 impl BuildValidator for TypedDictValidator {
@@ -94,7 +113,9 @@ impl BuildValidator for TypedDictValidator {
         let extra_validator = match schema.get_item(intern!(py, "extra_validator")) {
             Some(v) => {
                 if check_extra && !forbid_extra {
-                    Some(Box::new(build_validator(v, config, build_context)?))
+                    Some(Box::new(
+                        build_validator(v, config, build_context).with_schema_path("extra_validator")?,
+                    ))
                 } else {
                     return py_error!("extra_validator can only be used if extra_behavior=allow");
                 }
@@ -110,10 +131,11 @@ impl BuildValidator for TypedDictValidator {
             let field_name: &str = key.extract()?;
             let schema: &PyAny = field_info
                 .get_as_req(intern!(py, "schema"))
-                .map_err(|err| SchemaError::new_err(format!("Field '{}':\n  {}", field_name, err)))?;
+                .with_schema_path(field_name)
+                .with_schema_path("fields")?;
 
             let (default, default_factory) = match (
-                field_info.get_as(intern!(py, "default"))?,
+                get_default(field_info)?,
                 field_info.get_as(intern!(py, "default_factory"))?,
             ) {
                 (Some(_default), Some(_default_factory)) => {
@@ -174,13 +196,15 @@ impl BuildValidator for TypedDictValidator {
                 name: field_name.to_string(),
                 lookup_key,
                 name_pystring: PyString::intern(py, field_name).into(),
-                validator: match build_validator(schema, config, build_context) {
-                    Ok(v) => v,
-                    Err(err) => return py_error!("Field \"{}\":\n  {}", field_name, err),
-                },
+                validator: build_validator(schema, config, build_context)
+                    .with_schema_path("schema")
+                    .with_schema_path(field_name)
+                    .with_schema_path("fields")?,
                 required,
                 default,
                 default_factory,
+                validate_default: schema_or_config_same(field_info, config, intern!(py, "validate_default"))?
+                    .unwrap_or(false),
                 on_error,
             });
         }
@@ -192,6 +216,7 @@ impl BuildValidator for TypedDictValidator {
             strict,
             from_attributes,
             return_fields_set,
+            total,
         }
         .into())
     }
@@ -214,15 +239,17 @@ impl Validator for TypedDictValidator {
         let dict = input.validate_typed_dict(strict, self.from_attributes)?;
 
         let output_dict = PyDict::new(py);
-        let mut errors: Vec<ValLineError> = Vec::with_capacity(self.fields.len());
+        let mut errors: Vec<ValLineError> = Vec::new();
         let mut fields_set_vec: Option<Vec<Py<PyString>>> = match self.return_fields_set {
             true => Some(Vec::with_capacity(self.fields.len())),
             false => None,
         };
 
+        // with "ignore" we only look at extra keys if we're collecting warnings
+        let check_extra = self.check_extra || extra.warnings.is_some();
         // we only care about which keys have been used if we're iterating over the object for extra after
         // the first pass
-        let mut used_keys: Option<AHashSet<&str>> = match self.check_extra {
+        let mut used_keys: Option<AHashSet<&str>> = match check_extra {
             true => Some(AHashSet::with_capacity(self.fields.len())),
             false => None,
         };
@@ -232,11 +259,25 @@ impl Validator for TypedDictValidator {
             field: None,
             strict: extra.strict,
             context: extra.context,
+            max_depth: extra.max_depth,
+            warnings: extra.warnings,
+            trace: extra.trace,
+            coercion: extra.coercion,
+            input_mode: extra.input_mode,
+            item_callback: extra.item_callback,
+            fail_fast: extra.fail_fast,
+            shared_slots: extra.shared_slots,
+            #[cfg(feature = "profiling")]
+            stats: extra.stats,
         };
 
         macro_rules! process {
             ($dict:ident, $get_method:ident, $iter_method:ident) => {{
                 for field in &self.fields {
+                    // errors added directly rather than returned by a child validator, see `Extra.fail_fast`
+                    if extra.fail_fast && !errors.is_empty() {
+                        return Err(ValError::LineErrors(errors));
+                    }
                     let op_key_value = match field.lookup_key.$get_method($dict) {
                         Ok(v) => v,
                         Err(err) => {
@@ -256,10 +297,12 @@ impl Validator for TypedDictValidator {
                             // extra logic either way
                             used_keys.insert(used_key);
                         }
-                        match field
+                        let sink_mark = extra.sink_mark();
+                        let result = field
                             .validator
-                            .validate(py, value, &extra, slots, recursion_guard)
-                        {
+                            .validate(py, value, &extra, slots, recursion_guard);
+                        extra.with_outer_location_since(sink_mark, || field.name.clone().into());
+                        match result {
                             Ok(value) => {
                                 output_dict.set_item(&field.name_pystring, value)?;
                                 if let Some(ref mut fs) = fields_set_vec {
@@ -267,41 +310,59 @@ impl Validator for TypedDictValidator {
                                 }
                             }
                             Err(ValError::LineErrors(line_errors)) => match field.on_error {
+                                OnError::Raise if extra.fail_fast => return Err(ValError::LineErrors(line_errors)),
                                 OnError::Raise => {
                                     for err in line_errors {
                                         errors.push(err.with_outer_location(field.name.clone().into()));
                                     }
                                 }
                                 OnError::Omit => continue,
-                                OnError::FallbackOnDefault => {
-                                    if let Some(default_value) = field.default_value(py)? {
+                                OnError::FallbackOnDefault => match field.default_value(py, input) {
+                                    Ok(Some(default_value)) => {
                                         output_dict.set_item(&field.name_pystring, default_value.as_ref())?;
                                     }
-                                }
+                                    Ok(None) => (),
+                                    Err(err @ ValError::LineErrors(_)) if extra.fail_fast => return Err(err),
+                                    Err(ValError::LineErrors(line_errors)) => errors.extend(line_errors),
+                                    Err(err) => return Err(err),
+                                },
                             },
                             Err(err) => return Err(err),
                         }
-                    } else if let Some(default_value) = field.default_value(py)? {
-                        output_dict.set_item(&field.name_pystring, default_value.as_ref())?
-                    } else if !field.required {
+                    } else if !self.total && !field.required {
                         continue;
                     } else {
-                        errors.push(ValLineError::new_with_loc(
-                            ErrorKind::Missing,
-                            input,
-                            field.name.clone(),
-                        ));
+                        match field.default_value(py, input) {
+                            Ok(Some(default_value)) => {
+                                output_dict.set_item(&field.name_pystring, default_value.as_ref())?;
+                            }
+                            Ok(None) if field.required => {
+                                errors.push(ValLineError::new_with_loc(
+                                    ErrorKind::Missing,
+                                    input,
+                                    field.name.clone(),
+                                ));
+                            }
+                            Ok(None) => continue,
+                            Err(err @ ValError::LineErrors(_)) if extra.fail_fast => return Err(err),
+                            Err(ValError::LineErrors(line_errors)) => errors.extend(line_errors),
+                            Err(err) => return Err(err),
+                        }
                     }
                 }
 
-                if self.check_extra {
+                if check_extra {
                     let used_keys = match used_keys {
                         Some(v) => v,
                         None => unreachable!(),
                     };
                     for (raw_key, value) in $dict.$iter_method() {
+                        if extra.fail_fast && !errors.is_empty() {
+                            return Err(ValError::LineErrors(errors));
+                        }
                         let either_str = match raw_key.strict_str() {
                             Ok(k) => k,
+                            Err(err @ ValError::LineErrors(_)) if extra.fail_fast => return Err(err),
                             Err(ValError::LineErrors(line_errors)) => {
                                 for err in line_errors {
                                     errors.push(
@@ -317,6 +378,14 @@ impl Validator for TypedDictValidator {
                             continue;
                         }
 
+                        if !self.check_extra {
+                            extra.warn(
+                                py,
+                                ValLineError::new_with_loc(ErrorKind::ExtraIgnored, value, raw_key.as_loc_item()),
+                            );
+                            continue;
+                        }
+
                         if self.forbid_extra {
                             errors.push(ValLineError::new_with_loc(
                                 ErrorKind::ExtraForbidden,
@@ -332,13 +401,17 @@ impl Validator for TypedDictValidator {
                         }
 
                         if let Some(ref validator) = self.extra_validator {
-                            match validator.validate(py, value, &extra, slots, recursion_guard) {
+                            let sink_mark = extra.sink_mark();
+                            let result = validator.validate(py, value, &extra, slots, recursion_guard);
+                            extra.with_outer_location_since(sink_mark, || raw_key.as_loc_item());
+                            match result {
                                 Ok(value) => {
                                     output_dict.set_item(py_key, value)?;
                                     if let Some(ref mut fs) = fields_set_vec {
                                         fs.push(py_key.into_py(py));
                                     }
                                 }
+                                Err(err @ ValError::LineErrors(_)) if extra.fail_fast => return Err(err),
                                 Err(ValError::LineErrors(line_errors)) => {
                                     for err in line_errors {
                                         errors.push(err.with_outer_location(raw_key.as_loc_item()));
@@ -360,6 +433,7 @@ impl Validator for TypedDictValidator {
             GenericMapping::PyDict(d) => process!(d, py_get_item, iter),
             GenericMapping::PyGetAttr(d) => process!(d, py_get_attr, iter_attrs),
             GenericMapping::JsonObject(d) => process!(d, json_get, iter),
+            GenericMapping::NativeDict(d) => process!(d, native_get, iter),
         }
 
         if !errors.is_empty() {
@@ -376,6 +450,16 @@ impl Validator for TypedDictValidator {
         Self::EXPECTED_TYPE
     }
 
+    fn schema_repr(&self, py: Python, slots: &[CombinedValidator]) -> PyResult<PyObject> {
+        let repr = schema_repr_dict(py, self.get_name())?;
+        let fields = PyDict::new(py);
+        for field in &self.fields {
+            fields.set_item(&field.name, field.validator.schema_repr(py, slots)?)?;
+        }
+        repr.set_item("fields", fields)?;
+        Ok(repr.into_py(py))
+    }
+
     fn ask(&self, question: &str) -> bool {
         if question == "return_fields_set" {
             self.return_fields_set
@@ -384,14 +468,46 @@ impl Validator for TypedDictValidator {
         }
     }
 
+    fn known_fields(&self) -> Option<Vec<String>> {
+        Some(self.known_fields())
+    }
+
     fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
         self.fields
             .iter_mut()
-            .try_for_each(|f| f.validator.complete(build_context))
+            .try_for_each(|f| f.validator.complete(build_context))?;
+        if self.fields.iter().any(|f| f.validate_default && f.default.is_some()) {
+            let slots = build_context.slots_snapshot()?;
+            Python::with_gil(|py| -> PyResult<()> {
+                for field in self.fields.iter() {
+                    if let (true, Some(default)) = (field.validate_default, &field.default) {
+                        validate_default(py, &field.validator, default, &slots)
+                            .with_schema_path("default")
+                            .with_schema_path(&field.name)
+                            .with_schema_path("fields")?;
+                    }
+                }
+                Ok(())
+            })?;
+        }
+        Ok(())
     }
 }
 
 impl TypedDictValidator {
+    /// each field's name followed by its aliases, in field order and without duplicates
+    pub fn known_fields(&self) -> Vec<String> {
+        let mut known_fields: Vec<String> = Vec::with_capacity(self.fields.len());
+        for field in &self.fields {
+            for key in std::iter::once(field.name.as_str()).chain(field.lookup_key.keys()) {
+                if !known_fields.iter().any(|k| k == key) {
+                    known_fields.push(key.to_string());
+                }
+            }
+        }
+        known_fields
+    }
+
     fn validate_assignment<'s, 'data>(
         &'s self,
         py: Python<'data>,
@@ -432,23 +548,48 @@ impl TypedDictValidator {
             Err(err) => Err(err),
         };
 
+        // the field's own validator validates a value as usual, nested typed-dicts mustn't think they're
+        // validating assignment too
+        let field_extra = Extra {
+            data: Some(data),
+            field: None,
+            strict: extra.strict,
+            context: extra.context,
+            max_depth: extra.max_depth,
+            warnings: extra.warnings,
+            trace: extra.trace,
+            coercion: extra.coercion,
+            input_mode: extra.input_mode,
+            item_callback: extra.item_callback,
+            fail_fast: extra.fail_fast,
+            shared_slots: extra.shared_slots,
+            #[cfg(feature = "profiling")]
+            stats: extra.stats,
+        };
+
         if let Some(field) = self.fields.iter().find(|f| f.name == field) {
-            prepare_result(field.validator.validate(py, input, extra, slots, recursion_guard))
+            prepare_result(
+                field
+                    .validator
+                    .validate(py, input, &field_extra, slots, recursion_guard),
+            )
         } else if self.check_extra && !self.forbid_extra {
             // this is the "allow" case of extra_behavior
             match self.extra_validator {
-                Some(ref validator) => prepare_result(validator.validate(py, input, extra, slots, recursion_guard)),
+                Some(ref validator) => {
+                    prepare_result(validator.validate(py, input, &field_extra, slots, recursion_guard))
+                }
                 None => prepare_tuple(input.to_object(py)),
             }
-        } else {
-            // otherwise we raise an error:
-            // - with forbid this is obvious
-            // - with ignore the model should never be overloaded, so an error is the clearest option
+        } else if self.forbid_extra {
             Err(ValError::new_with_loc(
                 ErrorKind::ExtraForbidden,
                 input,
                 field.to_string(),
             ))
+        } else {
+            // with ignore the field can't be set at all, like setting an unknown attribute on a class with `__slots__`
+            Err(PyAttributeError::new_err(format!("No field named '{}'", field)).into())
         }
     }
 }
@@ -5,20 +5,49 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyString};
 
-use ahash::AHashMap;
+use ahash::{AHashMap, AHashSet};
 
-use crate::build_tools::{is_strict, schema_or_config, SchemaDict};
+use crate::build_tools::{is_strict, py_error, schema_or_config, SchemaDict, SchemaPath};
 use crate::errors::{ErrorKind, ValError, ValLineError, ValResult};
 use crate::input::{GenericMapping, Input};
 use crate::lookup_key::LookupKey;
 use crate::recursion_guard::RecursionGuard;
 
-use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{build_validator, schema_repr_dict, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+#[derive(Debug, Clone, PartialEq)]
+enum UnionErrorMode {
+    /// errors from every choice are returned
+    All,
+    /// only errors from the choice which got furthest, e.g. with the deepest error location, are returned
+    Best,
+    /// errors with the same location (within the choice) and kind as an earlier error are dropped
+    Deduplicated,
+}
+
+impl UnionErrorMode {
+    fn from_schema(schema: &PyDict, config: Option<&PyDict>) -> PyResult<Self> {
+        let py = schema.py();
+        let error_mode: Option<&str> = schema_or_config(
+            schema,
+            config,
+            intern!(py, "error_mode"),
+            intern!(py, "union_error_mode"),
+        )?;
+        match error_mode {
+            None | Some("all") => Ok(Self::All),
+            Some("best") => Ok(Self::Best),
+            Some("deduplicated") => Ok(Self::Deduplicated),
+            Some(s) => py_error!(r#"Invalid union error_mode: "{}""#, s),
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct UnionValidator {
     choices: Vec<CombinedValidator>,
     strict: bool,
+    error_mode: UnionErrorMode,
     name: String,
 }
 
@@ -33,14 +62,17 @@ impl BuildValidator for UnionValidator {
         let choices: Vec<CombinedValidator> = schema
             .get_as_req::<&PyList>(intern!(schema.py(), "choices"))?
             .iter()
-            .map(|choice| build_validator(choice, config, build_context))
-            .collect::<PyResult<Vec<CombinedValidator>>>()?;
+            .enumerate()
+            .map(|(index, choice)| build_validator(choice, config, build_context).with_schema_path(index))
+            .collect::<PyResult<Vec<CombinedValidator>>>()
+            .with_schema_path("choices")?;
 
         let descr = choices.iter().map(|v| v.get_name()).collect::<Vec<_>>().join(",");
 
         Ok(Self {
             choices,
             strict: is_strict(schema, config)?,
+            error_mode: UnionErrorMode::from_schema(schema, config)?,
             name: format!("{}[{}]", Self::EXPECTED_TYPE, descr),
         }
         .into())
@@ -56,54 +88,79 @@ impl Validator for UnionValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
+        // with `fail_fast` only whether every choice failed matters, so just the last error is kept
         if extra.strict.unwrap_or(self.strict) {
-            let mut errors: Vec<ValLineError> = Vec::with_capacity(self.choices.len());
+            let mut choice_errors: Vec<Vec<ValLineError>> = Vec::new();
+            let mut last_error: Option<ValError> = None;
             let strict_extra = extra.as_strict();
 
             for validator in &self.choices {
-                let line_errors = match validator.validate(py, input, &strict_extra, slots, recursion_guard) {
-                    Err(ValError::LineErrors(line_errors)) => line_errors,
-                    otherwise => return otherwise,
+                let sink_mark = extra.sink_mark();
+                match validator.validate(py, input, &strict_extra, slots, recursion_guard) {
+                    Err(err @ ValError::LineErrors(_)) if extra.fail_fast => {
+                        extra.discard_warnings(sink_mark);
+                        last_error = Some(err);
+                    }
+                    Err(ValError::LineErrors(line_errors)) => {
+                        // warnings from choices which failed don't apply
+                        extra.discard_warnings(sink_mark);
+                        choice_errors.push(line_errors);
+                    }
+                    otherwise => {
+                        if otherwise.is_ok() {
+                            extra.trace(&self.name, || format!("strict match: {}", validator.get_name()));
+                        }
+                        return otherwise;
+                    }
                 };
-
-                errors.extend(
-                    line_errors
-                        .into_iter()
-                        .map(|err| err.with_outer_location(validator.get_name().into())),
-                );
             }
 
-            Err(ValError::LineErrors(errors))
+            Err(last_error.unwrap_or_else(|| self.union_errors(choice_errors)))
         } else {
             // 1st pass: check if the value is an exact instance of one of the Union types,
             // e.g. use validate in strict mode
             let strict_extra = extra.as_strict();
+            let sink_mark = extra.sink_mark();
             if let Some(res) = self
                 .choices
                 .iter()
-                .map(|validator| validator.validate(py, input, &strict_extra, slots, recursion_guard))
+                .map(|validator| {
+                    let res = validator.validate(py, input, &strict_extra, slots, recursion_guard);
+                    match res {
+                        Ok(_) => extra.trace(&self.name, || format!("strict match: {}", validator.get_name())),
+                        Err(_) => extra.discard_warnings(sink_mark),
+                    }
+                    res
+                })
                 .find(ValResult::is_ok)
             {
                 return res;
             }
 
-            let mut errors: Vec<ValLineError> = Vec::with_capacity(self.choices.len());
+            let mut choice_errors: Vec<Vec<ValLineError>> = Vec::new();
+            let mut last_error: Option<ValError> = None;
 
             // 2nd pass: check if the value can be coerced into one of the Union types, e.g. use validate
             for validator in &self.choices {
-                let line_errors = match validator.validate(py, input, extra, slots, recursion_guard) {
-                    Err(ValError::LineErrors(line_errors)) => line_errors,
-                    success => return success,
+                match validator.validate(py, input, extra, slots, recursion_guard) {
+                    Err(err @ ValError::LineErrors(_)) if extra.fail_fast => {
+                        extra.discard_warnings(sink_mark);
+                        last_error = Some(err);
+                    }
+                    Err(ValError::LineErrors(line_errors)) => {
+                        extra.discard_warnings(sink_mark);
+                        choice_errors.push(line_errors);
+                    }
+                    success => {
+                        if success.is_ok() {
+                            extra.trace(&self.name, || format!("lax match: {}", validator.get_name()));
+                        }
+                        return success;
+                    }
                 };
-
-                errors.extend(
-                    line_errors
-                        .into_iter()
-                        .map(|err| err.with_outer_location(validator.get_name().into())),
-                );
             }
 
-            Err(ValError::LineErrors(errors))
+            Err(last_error.unwrap_or_else(|| self.union_errors(choice_errors)))
         }
     }
 
@@ -111,6 +168,17 @@ impl Validator for UnionValidator {
         &self.name
     }
 
+    fn schema_repr(&self, py: Python, slots: &[CombinedValidator]) -> PyResult<PyObject> {
+        let repr = schema_repr_dict(py, self.get_name())?;
+        let choices = self
+            .choices
+            .iter()
+            .map(|choice| choice.schema_repr(py, slots))
+            .collect::<PyResult<Vec<_>>>()?;
+        repr.set_item("choices", PyList::new(py, choices))?;
+        Ok(repr.into_py(py))
+    }
+
     fn ask(&self, question: &str) -> bool {
         self.choices.iter().all(|v| v.ask(question))
     }
@@ -120,6 +188,59 @@ impl Validator for UnionValidator {
     }
 }
 
+impl UnionValidator {
+    /// combine the errors from each choice according to `error_mode`, `choice_errors` must be in the same
+    /// order as `self.choices`
+    fn union_errors<'a>(&self, choice_errors: Vec<Vec<ValLineError<'a>>>) -> ValError<'a> {
+        let choices_errors = self.choices.iter().zip(choice_errors);
+        let errors: Vec<ValLineError> = match self.error_mode {
+            UnionErrorMode::All => choices_errors
+                .flat_map(|(validator, line_errors)| with_choice_location(validator, line_errors))
+                .collect(),
+            UnionErrorMode::Best => {
+                // `max_by_key` returns the last maximum, we want the first choice to win ties
+                let mut best: Option<(usize, &CombinedValidator, Vec<ValLineError>)> = None;
+                for (validator, line_errors) in choices_errors {
+                    let depth = line_errors.iter().map(|e| e.location.depth()).max().unwrap_or(0);
+                    match best {
+                        Some((best_depth, ..)) if best_depth >= depth => (),
+                        _ => best = Some((depth, validator, line_errors)),
+                    }
+                }
+                match best {
+                    Some((_, validator, line_errors)) => with_choice_location(validator, line_errors).collect(),
+                    None => Vec::new(),
+                }
+            }
+            UnionErrorMode::Deduplicated => {
+                let mut seen = AHashSet::new();
+                choices_errors
+                    .flat_map(|(validator, line_errors)| {
+                        line_errors
+                            .into_iter()
+                            .filter(|e| seen.insert((e.location.clone(), e.kind.to_string())))
+                            .map(|e| e.with_outer_location(validator.get_name().into()))
+                            .collect::<Vec<_>>()
+                    })
+                    .collect()
+            }
+        };
+        ValError::LineErrors(errors)
+    }
+}
+
+fn with_choice_location<'a, 'v>(
+    validator: &'v CombinedValidator,
+    line_errors: Vec<ValLineError<'a>>,
+) -> impl Iterator<Item = ValLineError<'a>> + 'v
+where
+    'a: 'v,
+{
+    line_errors
+        .into_iter()
+        .map(|err| err.with_outer_location(validator.get_name().into()))
+}
+
 #[derive(Debug, Clone)]
 enum Discriminator {
     /// use `LookupKey` to find the tag, same as we do to find values in typed_dict aliases
@@ -184,7 +305,9 @@ impl BuildValidator for TaggedUnionValidator {
         for item in schema.get_as_req::<&PyDict>(intern!(py, "choices"))?.items().iter() {
             let tag: String = item.get_item(0)?.extract()?;
             let value = item.get_item(1)?;
-            let validator = build_validator(value, config, build_context)?;
+            let validator = build_validator(value, config, build_context)
+                .with_schema_path(&tag)
+                .with_schema_path("choices")?;
             if first {
                 first = false;
                 write!(tags_repr, "'{}'", tag).unwrap();
@@ -222,6 +345,7 @@ impl Validator for TaggedUnionValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
+        let strict = extra.strict.unwrap_or(self.strict);
         match self.discriminator {
             Discriminator::LookupKey(ref lookup_key) => {
                 macro_rules! find_validator {
@@ -230,7 +354,7 @@ impl Validator for TaggedUnionValidator {
                         // errors when getting attributes which should be "raised"
                         match lookup_key.$get_method($dict)? {
                             Some((_, value)) => {
-                                if self.strict {
+                                if strict {
                                     value.strict_str()
                                 } else {
                                     value.lax_str()
@@ -240,11 +364,12 @@ impl Validator for TaggedUnionValidator {
                         }
                     }};
                 }
-                let dict = input.validate_typed_dict(self.strict, self.from_attributes)?;
+                let dict = input.validate_typed_dict(strict, self.from_attributes)?;
                 let tag = match dict {
                     GenericMapping::PyDict(dict) => find_validator!(dict, py_get_item),
                     GenericMapping::PyGetAttr(obj) => find_validator!(obj, py_get_attr),
                     GenericMapping::JsonObject(mapping) => find_validator!(mapping, json_get),
+                    GenericMapping::NativeDict(mapping) => find_validator!(mapping, native_get),
                 }?;
                 self.find_call_validator(py, tag.as_cow()?, input, extra, slots, recursion_guard)
             }
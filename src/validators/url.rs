@@ -0,0 +1,118 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::type_object::PyTypeObject;
+use pyo3::types::PyDict;
+
+use crate::build_tools::{is_strict, SchemaDict};
+use crate::errors::{ErrorKind, ValError, ValResult};
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+use crate::url::Url;
+
+use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+#[derive(Debug, Clone)]
+pub struct UrlValidator {
+    strict: bool,
+    max_length: Option<usize>,
+    allowed_schemes: Option<Vec<String>>,
+    expected_schemes: String,
+    host_required: bool,
+}
+
+impl BuildValidator for UrlValidator {
+    const EXPECTED_TYPE: &'static str = "url";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        _build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let allowed_schemes: Option<Vec<String>> = schema.get_as(intern!(py, "allowed_schemes"))?;
+        let expected_schemes = match allowed_schemes {
+            Some(ref schemes) => schemes
+                .iter()
+                .map(|s| format!("'{}'", s))
+                .collect::<Vec<_>>()
+                .join(", "),
+            None => String::new(),
+        };
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            max_length: schema.get_as(intern!(py, "max_length"))?,
+            allowed_schemes,
+            expected_schemes,
+            host_required: schema.get_as(intern!(py, "host_required"))?.unwrap_or(false),
+        }
+        .into())
+    }
+}
+
+impl Validator for UrlValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        _slots: &'data [CombinedValidator],
+        _recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        // existing `Url` instances are already parsed, but still need to match this validator's constraints
+        if input.is_instance(Url::type_object(py))? {
+            let py_input = input.to_object(py);
+            let url: Url = py_input.extract(py)?;
+            self.check_url(&url, input)?;
+            return Ok(py_input);
+        }
+
+        let either_str = match input.validate_str(extra.strict.unwrap_or(self.strict)) {
+            Ok(either_str) => either_str,
+            Err(ValError::LineErrors(_)) => return Err(ValError::new(ErrorKind::UrlType, input)),
+            Err(err) => return Err(err),
+        };
+        let cow = either_str.as_cow()?;
+        let url = match Url::parse(cow.as_ref()) {
+            Ok(url) => url,
+            Err(error) => {
+                return Err(ValError::new(
+                    ErrorKind::UrlParsing {
+                        error: error.to_string(),
+                    },
+                    input,
+                ))
+            }
+        };
+        self.check_url(&url, input)?;
+        Ok(url.into_py(py))
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
+
+impl UrlValidator {
+    /// `max_length` applies to the normalized URL, so it's the same for a string and the `Url` parsed from it
+    fn check_url<'d>(&self, url: &Url, input: &'d impl Input<'d>) -> ValResult<'d, ()> {
+        if let Some(max_length) = self.max_length {
+            if url.as_str().chars().count() > max_length {
+                return Err(ValError::new(ErrorKind::UrlTooLong { max_length }, input));
+            }
+        }
+        if let Some(ref allowed_schemes) = self.allowed_schemes {
+            if !allowed_schemes.iter().any(|s| s == url.scheme()) {
+                return Err(ValError::new(
+                    ErrorKind::UrlSchemeNotAllowed {
+                        expected_schemes: self.expected_schemes.clone(),
+                    },
+                    input,
+                ));
+            }
+        }
+        if self.host_required && !url.has_host() {
+            return Err(ValError::new(ErrorKind::UrlHostRequired, input));
+        }
+        Ok(())
+    }
+}
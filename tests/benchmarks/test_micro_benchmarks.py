@@ -4,6 +4,7 @@ Numerous benchmarks of specific functionality.
 import json
 import os
 import platform
+import tracemalloc
 from datetime import date, datetime, timedelta, timezone
 from typing import Dict, FrozenSet, List, Optional, Set, Union
 
@@ -244,7 +245,9 @@ def test_recursive_model_core(recursive_model_data, benchmark):
                     },
                 },
             },
-        }
+        },
+        # the data is 101 levels deep, one more than the default `max_depth`
+        {'max_depth': 200},
     )
     benchmark(v.validate_python, recursive_model_data)
 
@@ -297,6 +300,41 @@ def test_list_of_ints_core_py(benchmark):
         v.validate_python(list_of_ints_data[1])
 
 
+@pytest.mark.benchmark(group='List[int] constrained')
+def test_list_of_constrained_ints_core_py(benchmark):
+    v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'int', 'ge': -1000, 'le': 1000}})
+
+    @benchmark
+    def t():
+        v.validate_python(list_of_ints_data[0])
+        v.validate_python(list_of_ints_data[1])
+
+
+@pytest.mark.benchmark(group='int constrained reject')
+def test_constrained_int_reject_core(benchmark):
+    # bounds are checked on the rust integer and `isinstance_python` doesn't build a `ValidationError`,
+    # so rejecting an out-of-range int doesn't create any python objects
+    v = SchemaValidator({'type': 'int', 'ge': -1000, 'le': 1000})
+    assert v.isinstance_python(1001) is False
+
+    def peak_memory(f, input_value):
+        tracemalloc.start()
+        try:
+            start, _ = tracemalloc.get_traced_memory()
+            for _ in range(1000):
+                f(input_value)
+            _, peak = tracemalloc.get_traced_memory()
+        finally:
+            tracemalloc.stop()
+        return peak - start
+
+    # rejecting peaks no higher than accepting, whereas building an error for a str would
+    assert peak_memory(v.isinstance_python, 1001) <= peak_memory(v.isinstance_python, 1)
+    assert peak_memory(v.isinstance_python, 'x') > peak_memory(v.isinstance_python, 1)
+
+    benchmark(v.isinstance_python, 1001)
+
+
 @skip_pydantic
 @pytest.mark.benchmark(group='List[int] JSON')
 def test_list_of_ints_pyd_json(benchmark):
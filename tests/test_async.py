@@ -0,0 +1,68 @@
+import asyncio
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError
+
+SCHEMA = {
+    'type': 'typed-dict',
+    'fields': {'name': {'schema': 'str'}, 'ages': {'schema': {'type': 'list', 'items_schema': 'int'}, 'default': []}},
+}
+
+
+def test_validate_python_async():
+    v = SchemaValidator(SCHEMA)
+
+    async def main():
+        return await v.validate_python_async({'name': 'foo', 'ages': ['1', 2]})
+
+    assert asyncio.run(main()) == {'name': 'foo', 'ages': [1, 2]}
+
+
+def test_validate_python_async_error():
+    v = SchemaValidator(SCHEMA)
+
+    async def main():
+        with pytest.raises(ValidationError) as exc_info:
+            await v.validate_python_async({'name': 1})
+        return exc_info.value
+
+    exc = asyncio.run(main())
+    with pytest.raises(ValidationError) as sync_exc_info:
+        v.validate_python({'name': 1})
+    assert exc.errors(include_url=False) == sync_exc_info.value.errors(include_url=False)
+
+
+def test_validate_python_async_strict_context():
+    def f(input_value, *, context, **kwargs):
+        context['calls'] += 1
+        return input_value
+
+    v = SchemaValidator({'type': 'function', 'mode': 'after', 'function': f, 'schema': 'int'})
+
+    async def main():
+        context = {'calls': 0}
+        assert await v.validate_python_async('1', context=context) == 1
+        with pytest.raises(ValidationError, match='Input should be a valid integer'):
+            await v.validate_python_async('1', strict=True, context=context)
+        return context
+
+    assert asyncio.run(main()) == {'calls': 1}
+
+
+def test_validate_python_async_concurrent():
+    v = SchemaValidator(SCHEMA)
+
+    async def main():
+        return await asyncio.gather(*[v.validate_python_async({'name': str(i), 'ages': [i] * i}) for i in range(20)])
+
+    results = asyncio.run(main())
+    assert results == [{'name': str(i), 'ages': [i] * i} for i in range(20)]
+    # defaults aren't mutated between calls
+    assert v.validate_python({'name': 'x'}) == {'name': 'x', 'ages': []}
+
+
+def test_validate_python_async_no_loop():
+    v = SchemaValidator('int')
+    with pytest.raises(RuntimeError, match='no running event loop'):
+        v.validate_python_async(1)
@@ -1,8 +1,10 @@
+import copy
 import pickle
+import warnings
 
 import pytest
 
-from pydantic_core import SchemaError, SchemaValidator
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
 
 
 def test_build_error_type():
@@ -26,6 +28,69 @@ def test_build_error_deep():
         )
 
 
+def test_build_error_path():
+    with pytest.raises(SchemaError) as exc_info:
+        SchemaValidator(
+            {
+                'type': 'list',
+                'items_schema': {'type': 'typed-dict', 'fields': {'a': {'schema': {'type': 'dict', 'min_items': -1}}}},
+            }
+        )
+    assert exc_info.value.args[0] == (
+        'Invalid Schema:\n'
+        'list -> items_schema -> typed-dict -> fields -> a -> schema -> dict -> min_items\n'
+        "  OverflowError: can't convert negative int to unsigned, expected non-negative int"
+    )
+
+
+def test_build_error_path_index():
+    with pytest.raises(SchemaError) as exc_info:
+        SchemaValidator(
+            {
+                'type': 'arguments',
+                'arguments_schema': [
+                    {'name': 'a', 'mode': 'positional_only', 'schema': {'type': 'int'}},
+                    {'name': 'b', 'mode': 'positional_only', 'schema': {'type': 'literal', 'expected': []}},
+                ],
+            }
+        )
+    assert exc_info.value.args[0] == (
+        'Invalid Schema:\narguments -> arguments_schema -> 1 -> schema -> literal\n  "expected" should have length > 0'
+    )
+
+
+def test_build_errors_all():
+    with pytest.raises(SchemaError) as exc_info:
+        SchemaValidator(
+            {
+                'type': 'typed-dict',
+                'fields': {
+                    'a': {'schema': {'type': 'list', 'min_items': 'x', 'max_items': []}},
+                    'b': {'schema': {'type': 'int', 'ge': 'y'}},
+                },
+            }
+        )
+    assert [(e['loc'][-1], e['kind'], e['input_value']) for e in exc_info.value.errors()] == [
+        ('min_items', 'int_parsing', 'x'),
+        ('max_items', 'int_type', []),
+        ('ge', 'int_parsing', 'y'),
+    ]
+    assert exc_info.value.errors()[0]['loc'] == ['typed-dict', 'fields', 'a', 'schema', 'list', 'min_items']
+
+
+def test_build_errors_path():
+    with pytest.raises(SchemaError) as exc_info:
+        SchemaValidator({'type': 'list', 'items_schema': {'type': 'literal', 'expected': []}})
+    assert exc_info.value.errors() == [
+        {'loc': ['list', 'items_schema', 'literal'], 'message': '"expected" should have length > 0'}
+    ]
+
+
+def test_schema_error_message():
+    assert str(SchemaError('test')) == 'test'
+    assert SchemaError('test').errors() == [{'loc': [], 'message': 'test'}]
+
+
 def test_schema_as_string():
     v = SchemaValidator('bool')
     assert v.validate_python('tRuE') is True
@@ -49,6 +114,110 @@ def test_pickle(pickle_protocol: int) -> None:
     assert repr(v1) == repr(v2)
 
 
+def test_pickle_config():
+    v1 = SchemaValidator('int', {'strict': True})
+    v2 = pickle.loads(pickle.dumps(v1))
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v2.validate_python('123')
+
+
+def test_getstate_setstate():
+    schema = {'type': 'list', 'items_schema': 'int'}
+    v1 = SchemaValidator(schema, {'strict': True})
+    state = v1.__getstate__()
+    assert state == (schema, {'strict': True})
+
+    v2 = SchemaValidator('str')
+    v2.__setstate__(state)
+    assert repr(v2) == repr(v1)
+    assert v2.validate_python([1, 2]) == [1, 2]
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v2.validate_python(['1'])
+
+    v2.__setstate__(('int', None))
+    assert v2.validate_python('1') == 1
+
+
+def test_pickle_validation_results():
+    v1 = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'name': {'schema': {'type': 'str', 'max_length': 5}},
+                'ages': {'schema': {'type': 'list', 'items_schema': 'int'}, 'default': []},
+            },
+        },
+        {'str_to_upper': True},
+    )
+    v2 = pickle.loads(pickle.dumps(v1, protocol=5))
+    for input_value in [{'name': 'foo', 'ages': ['1', 2]}, {'name': 'bar'}]:
+        assert v1.validate_python(input_value) == v2.validate_python(input_value)
+    assert v2.validate_python({'name': 'foo'}) == {'name': 'FOO', 'ages': []}
+    for input_value in [{'name': 'foobar'}, {'ages': ['x']}]:
+        with pytest.raises(ValidationError) as exc_info1:
+            v1.validate_python(input_value)
+        with pytest.raises(ValidationError) as exc_info2:
+            v2.validate_python(input_value)
+        assert exc_info1.value.errors() == exc_info2.value.errors()
+
+
+def test_deepcopy():
+    v1 = SchemaValidator(
+        {'type': 'typed-dict', 'fields': {'tags': {'schema': {'type': 'list', 'items_schema': 'str'}, 'default': []}}},
+        {'strict': True},
+    )
+    v2 = copy.deepcopy(v1)
+    assert v2 is not v1
+    assert v2.validate_python({'tags': ['a']}) == {'tags': ['a']}
+    with pytest.raises(ValidationError, match='Input should be a valid list'):
+        v2.validate_python({'tags': ('a',)})
+
+    # mutating the copy's default doesn't affect the original
+    v2.validate_python({})['tags'].append('x')
+    assert v2.validate_python({}) == {'tags': ['x']}
+    assert v1.validate_python({}) == {'tags': []}
+
+
+def test_deepcopy_repr():
+    v1 = SchemaValidator({'type': 'list', 'items_schema': {'type': 'int', 'ge': 1}})
+    v2 = copy.deepcopy(v1)
+    assert repr(v1) == repr(v2)
+    assert v2.validate_python(['1', 2]) == [1, 2]
+
+
+@pytest.mark.parametrize(
+    'schema,key,empty',
+    [
+        ({'type': 'list', 'min_items': 0}, 'min_items', []),
+        ({'type': 'set', 'min_items': 0}, 'min_items', set()),
+        ({'type': 'tuple', 'min_items': 0}, 'min_items', ()),
+        ({'type': 'dict', 'min_items': 0}, 'min_items', {}),
+        ({'type': 'str', 'min_length': 0}, 'min_length', ''),
+        ({'type': 'bytes', 'min_length': 0}, 'min_length', b''),
+    ],
+)
+def test_min_length_zero_warning(schema, key, empty):
+    with pytest.warns(UserWarning, match=f'`{key}: 0` has no effect, omit it to allow empty values'):
+        v = SchemaValidator(schema)
+    assert v.validate_python(empty) == empty
+
+
+def test_min_length_zero_warning_repeated():
+    # validators which warned aren't cached, so building the same schema again warns again
+    for _ in range(2):
+        with pytest.warns(UserWarning, match='`min_items: 0` has no effect'):
+            SchemaValidator({'type': 'list', 'min_items': 0})
+
+
+def test_min_length_zero_warning_error():
+    with pytest.warns(UserWarning):
+        SchemaValidator({'type': 'list', 'min_items': 0})
+    with warnings.catch_warnings():
+        warnings.simplefilter('error')
+        with pytest.raises(SchemaError, match='UserWarning: `min_items: 0` has no effect'):
+            SchemaValidator({'type': 'list', 'min_items': 0})
+
+
 def test_schema_recursive_error():
     schema = {'type': 'union', 'choices': []}
     schema['choices'].append({'type': 'nullable', 'schema': schema})
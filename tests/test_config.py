@@ -1,3 +1,5 @@
+from collections.abc import Mapping
+
 import pytest
 from dirty_equals import HasAttributes, IsInstance
 
@@ -174,7 +176,7 @@ def test_sub_model_merge():
     assert output == IsInstance(MyModel) & HasAttributes(f='test', sub_model=HasAttributes(f='TESTS'))
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python({'f': 'tests', 'sub_model': {'f': ''}})
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'too_long',
             'loc': ['f'],
@@ -190,3 +192,140 @@ def test_sub_model_merge():
             'context': {'min_length': 1},
         },
     ]
+
+
+@pytest.mark.parametrize(
+    'schema,input_value',
+    [
+        ({'type': 'list', 'items_schema': 'int'}, [1, 2, 3]),
+        ({'type': 'list', 'items_schema': 'int'}, (1, 2, 3)),
+        ({'type': 'dict', 'values_schema': 'int'}, {'a': 1, 'b': 2, 'c': 3}),
+        ({'type': 'mapping', 'values_schema': 'int'}, {'a': 1, 'b': 2, 'c': 3}),
+    ],
+)
+def test_max_input_length(schema, input_value):
+    v = SchemaValidator(schema, {'max_input_length': 2})
+    assert v.validate_python(input_value[:2] if isinstance(input_value, (list, tuple)) else {'a': 1})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'input_too_large',
+            'loc': [],
+            'message': 'Input is too large, it should have at most 2 items, got 3 items',
+            'input_value': input_value,
+            'context': {'max_input_length': 2, 'input_length': 3},
+        }
+    ]
+
+
+def test_max_input_length_json():
+    v = SchemaValidator({'type': 'dict', 'values_schema': 'int'}, {'max_input_length': 1})
+    assert v.validate_json('{"a": 1}') == {'a': 1}
+    with pytest.raises(ValidationError, match='Input is too large, it should have at most 1 item, got 2 items'):
+        v.validate_json('{"a": 1, "b": 2}')
+
+
+def test_max_input_length_before_items():
+    calls = []
+
+    def f(input_value, **kwargs):
+        calls.append(input_value)
+        return input_value
+
+    v = SchemaValidator(
+        {'type': 'list', 'items_schema': {'type': 'function', 'mode': 'plain', 'function': f}},
+        {'max_input_length': 2},
+    )
+    with pytest.raises(ValidationError, match='input_too_large'):
+        v.validate_python([1, 2, 3])
+    assert calls == []
+
+
+def test_max_input_length_precedence():
+    # the config guard applies even where the schema allows more items
+    v = SchemaValidator({'type': 'list', 'max_items': 10}, {'max_input_length': 3})
+    with pytest.raises(ValidationError, match='input_too_large'):
+        v.validate_python([1, 2, 3, 4])
+    # and is checked before `min_items`
+    v = SchemaValidator({'type': 'dict', 'min_items': 10}, {'max_input_length': 3})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({1: 1, 2: 2, 3: 3, 4: 4})
+    assert [e['kind'] for e in exc_info.value.errors()] == ['input_too_large']
+    with pytest.raises(ValidationError, match='too_short'):
+        v.validate_python({1: 1})
+
+
+def test_max_input_length_nested():
+    v = SchemaValidator(
+        {'type': 'typed-dict', 'fields': {'tags': {'schema': {'type': 'list', 'items_schema': 'str'}}}},
+        {'max_input_length': 2},
+    )
+    assert v.validate_python({'tags': ['a', 'b']}) == {'tags': ['a', 'b']}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'tags': ['a', 'b', 'c']})
+    assert exc_info.value.errors()[0]['loc'] == ['tags']
+    assert exc_info.value.errors()[0]['kind'] == 'input_too_large'
+
+
+def test_max_input_length_lazy():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int', 'lazy': True}, {'max_input_length': 2})
+    output = v.validate_python(x for x in [1, 2, 3])
+    assert next(output) == 1
+    assert next(output) == 2
+    with pytest.raises(ValidationError, match='Input is too large, it should have at most 2 items, got 3 items'):
+        next(output)
+    with pytest.raises(ValidationError, match='input_too_large'):
+        v.validate_python([1, 2, 3])
+
+
+class CountingMapping(Mapping):
+    def __init__(self, data):
+        self.data = data
+        self.iterated = False
+
+    def __getitem__(self, key):
+        return self.data[key]
+
+    def __len__(self):
+        return len(self.data)
+
+    def __iter__(self):
+        self.iterated = True
+        return iter(self.data)
+
+
+def test_max_input_length_before_copy():
+    # the length of mappings and dict views is known, so they're rejected before being copied into a dict or list
+    v = SchemaValidator({'type': 'dict', 'values_schema': 'int', 'key_transform': 'lower'}, {'max_input_length': 2})
+    mapping = CountingMapping({'a': 1, 'b': 2, 'c': 3})
+    with pytest.raises(ValidationError, match='input_too_large'):
+        v.validate_python(mapping)
+    assert not mapping.iterated
+    assert v.validate_python(CountingMapping({'A': 1})) == {'a': 1}
+
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'}, {'max_input_length': 2})
+    with pytest.raises(ValidationError, match='Input is too large, it should have at most 2 items, got 3 items'):
+        v.validate_python({'a': 1, 'b': 2, 'c': 3}.keys())
+    # generators have no length, so they're counted once collected
+    with pytest.raises(ValidationError, match='Input is too large, it should have at most 2 items, got 3 items'):
+        v.validate_python(x for x in [1, 2, 3])
+
+
+def test_max_input_length_json_string():
+    v = SchemaValidator({'type': 'dict', 'values_schema': 'int', 'coerce_json_string': True}, {'max_input_length': 2})
+    assert v.validate_python('{"a": 1, "b": 2}') == {'a': 1, 'b': 2}
+    # items are counted before the string is parsed, so the trailing comma isn't reported
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('{"a": 1, "b": "[1, 2]", "c,": {"d": 1, "e": 2},}')
+    assert exc_info.value.errors()[0]['kind'] == 'input_too_large'
+    assert exc_info.value.errors()[0]['context'] == {'max_input_length': 2, 'input_length': 4}
+    with pytest.raises(ValidationError, match='invalid_json'):
+        v.validate_python('{"a": 1,}')
+
+
+def test_max_input_length_comma_separated():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'}, {'max_input_length': 2})
+    assert v.validate_strings('1, 2') == [1, 2]
+    with pytest.raises(ValidationError, match='Input is too large, it should have at most 2 items, got 3 items'):
+        v.validate_strings('1, 2, 3')
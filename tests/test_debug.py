@@ -0,0 +1,82 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError
+
+
+def test_dict_lax():
+    v = SchemaValidator({'type': 'dict', 'keys_schema': 'int', 'values_schema': 'int'})
+    assert v.validate_debug({1: 1, '2': '2'}) == (
+        {1: 1, 2: 2},
+        [
+            {'loc': [], 'validator': 'dict[int,int]', 'decision': 'lax_dict'},
+            {'loc': ['2', '[key]'], 'validator': 'dict[int,int]', 'decision': "coerced key '2' to 2"},
+        ],
+    )
+
+
+def test_dict_strict():
+    v = SchemaValidator({'type': 'dict', 'keys_schema': 'str', 'values_schema': 'int'})
+    assert v.validate_debug({'a': 1}, strict=True) == (
+        {'a': 1},
+        [{'loc': [], 'validator': 'dict[str,int]', 'decision': 'strict_dict'}],
+    )
+
+
+def test_nested_location():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'items': {'schema': {'type': 'list', 'items_schema': {'type': 'dict', 'keys_schema': 'int'}}},
+            },
+        }
+    )
+    output, trace = v.validate_debug({'items': [{1: 'a'}, {'2': 'b'}]})
+    assert output == {'items': [{1: 'a'}, {2: 'b'}]}
+    assert [(t['loc'], t['decision']) for t in trace] == [
+        (['items', 0], 'lax_dict'),
+        (['items', 1], 'lax_dict'),
+        (['items', 1, '2', '[key]'], "coerced key '2' to 2"),
+    ]
+
+
+def test_union():
+    v = SchemaValidator({'type': 'union', 'choices': ['int', 'str']})
+    assert v.validate_debug('a') == ('a', [{'loc': [], 'validator': 'union[int,str]', 'decision': 'strict match: str'}])
+    assert v.validate_debug(1.0) == (1, [{'loc': [], 'validator': 'union[int,str]', 'decision': 'lax match: int'}])
+
+
+def test_union_choices_traced():
+    v = SchemaValidator(
+        {
+            'type': 'union',
+            'choices': [
+                {'type': 'dict', 'keys_schema': 'int', 'values_schema': 'int'},
+                {'type': 'dict', 'keys_schema': 'int', 'values_schema': 'str'},
+            ],
+        }
+    )
+    output, trace = v.validate_debug({'1': 'x'})
+    assert output == {1: 'x'}
+    # entries from the choices which failed are kept
+    assert [(t['validator'], t['decision']) for t in trace] == [
+        ('dict[int,int]', 'strict_dict'),
+        ('dict[int,str]', 'strict_dict'),
+        ('dict[int,int]', 'lax_dict'),
+        ('dict[int,int]', "coerced key '1' to 1"),
+        ('dict[int,str]', 'lax_dict'),
+        ('dict[int,str]', "coerced key '1' to 1"),
+        ('union[dict[int,int],dict[int,str]]', 'lax match: dict[int,str]'),
+    ]
+
+
+def test_no_trace_without_debug():
+    v = SchemaValidator({'type': 'dict', 'keys_schema': 'int'})
+    assert v.validate_python({'1': 1}) == {1: 1}
+    assert v.validate_python_with_warnings({'1': 1}) == ({1: 1}, [])
+
+
+def test_errors_still_raised():
+    v = SchemaValidator({'type': 'dict', 'keys_schema': 'int'})
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_debug({'x': 1})
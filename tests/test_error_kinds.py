@@ -0,0 +1,37 @@
+import re
+
+import pytest
+
+from pydantic_core import error_kind_description, list_all_errors
+
+# number of `ErrorKind` variants, update this when adding or removing a variant
+ERROR_KIND_COUNT = 101
+
+
+def test_all_error_kinds_described():
+    all_errors = list_all_errors()
+    assert len(all_errors) == ERROR_KIND_COUNT
+    for error in all_errors:
+        assert error['description'], error['kind']
+        assert error_kind_description(error['kind'])
+
+
+def test_descriptions_include_context_keys():
+    for error in list_all_errors():
+        # `*_plural` placeholders are derived while rendering and aren't part of the context
+        context_keys = {k for k in re.findall(r'{(\w+)}', error['message_template'] or '') if not k.endswith('_plural')}
+        for key in context_keys:
+            assert f'`{key}`' in error['description'], (error['kind'], key)
+
+
+def test_error_kind_description():
+    assert error_kind_description('int_parsing') == 'The string input could not be parsed as an integer, no context.'
+    assert error_kind_description('greater_than') == (
+        'The input is not greater than the `gt` constraint, used for ints, floats, dates, times, datetimes and '
+        'timedeltas. Context: `gt`.'
+    )
+
+
+def test_error_kind_description_unknown():
+    with pytest.raises(ValueError, match='Unknown error kind: "foobar"'):
+        error_kind_description('foobar')
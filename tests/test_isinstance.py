@@ -39,7 +39,7 @@ def test_isinstance_json():
 
     assert v.isinstance_json('"foo"') is False
 
-    with pytest.raises(ValidationError, match=r'Invalid JSON: expected value at line 1 column 1 \[kind=invalid_json,'):
+    with pytest.raises(ValidationError, match=r'invalid_json  Invalid JSON: expected value at line 1 column 1'):
         v.validate_json('x')
 
     # invalid json returns False, not an error!
@@ -65,3 +65,53 @@ def test_internal_error():
 
     with pytest.raises(AttributeError, match="'int' object has no attribute '__dict__'"):
         v.isinstance_json('{"f": 123}')
+
+
+def test_stops_at_first_error():
+    calls = []
+
+    def f(input_value, **kwargs):
+        calls.append(input_value)
+        raise ValueError('wrong')
+
+    item_schema = {'type': 'function', 'mode': 'plain', 'function': f}
+    v = SchemaValidator({'type': 'list', 'items_schema': item_schema})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 2, 3])
+    assert exc_info.value.error_count() == 3
+    assert calls == [1, 2, 3]
+
+    calls.clear()
+    assert v.isinstance_python([1, 2, 3]) is False
+    assert calls == [1]
+
+    calls.clear()
+    assert v.isinstance_json('[1, 2, 3]') is False
+    assert calls == [1]
+
+
+def test_stops_at_missing_field():
+    calls = []
+
+    def f(input_value, **kwargs):
+        calls.append(input_value)
+        return input_value
+
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {'a': {'schema': 'int'}, 'b': {'schema': {'type': 'function', 'mode': 'plain', 'function': f}}},
+        }
+    )
+    assert v.isinstance_python({'b': 1}) is False
+    assert calls == []
+    assert v.isinstance_python({'a': 1, 'b': 2}) is True
+    assert calls == [2]
+
+
+def test_union_fail_fast():
+    v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'union', 'choices': ['int', 'bool']}})
+    assert v.isinstance_python([1, True, '2']) is True
+    assert v.isinstance_python([1, 'x', []]) is False
+    assert v.isinstance_python([[], 'x']) is False
+    assert v.isinstance_json('[1, {}]') is False
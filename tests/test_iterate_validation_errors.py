@@ -0,0 +1,178 @@
+import tracemalloc
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError
+
+RECORD_SCHEMA = {
+    'type': 'typed-dict',
+    'fields': {'id': {'schema': 'int'}, 'name': {'schema': {'type': 'str', 'max_length': 5}}},
+}
+
+
+def sync_errors(v, input_value, **kwargs):
+    try:
+        v.validate_python(input_value, **kwargs)
+    except ValidationError as e:
+        return e.errors(include_url=False)
+    else:
+        return []
+
+
+@pytest.mark.parametrize(
+    'schema,input_value',
+    [
+        ({'type': 'list', 'items_schema': RECORD_SCHEMA}, [{'id': 1, 'name': 'a'}, {'id': 'x', 'name': 'abcdef'}, {}]),
+        ({'type': 'list', 'items_schema': RECORD_SCHEMA}, [{'id': 1, 'name': 'a'}]),
+        ({'type': 'list', 'items_schema': 'int'}, 'not a list'),
+        ({'type': 'list', 'items_schema': 'int', 'max_items': 2}, ['a', 'b', 'c']),
+        ({'type': 'list', 'items_schema': 'int', 'min_items': 2}, ['a']),
+        ({'type': 'list', 'items_schema': 'int'}, ('1', 'x', {2})),
+        # generators are consumed by validation, so this is a function returning a new generator each time
+        ({'type': 'list', 'items_schema': 'int'}, lambda: (x for x in ['a', 'b'])),
+        ({'type': 'list'}, [1, 'x']),
+        ({'type': 'list', 'items_schema': 'int', 'unique_items': True}, [1, 1, 'x']),
+        ({'type': 'list', 'items_schema': 'int', 'unique_items': True}, [1, 1]),
+        ({'type': 'list', 'items_schema': 'int', 'preserve_order': False}, ['x', 2, 'y']),
+        (RECORD_SCHEMA, {'id': 'x', 'name': 'abcdef'}),
+        ({'type': 'dict', 'keys_schema': 'int', 'values_schema': 'int'}, {'a': 1, 2: 'b'}),
+        ({'type': 'dict', 'keys_schema': 'int', 'values_schema': 'int'}, 'not a dict'),
+        ({'type': 'dict', 'values_schema': 'int', 'max_items': 1}, {'a': 'x', 'b': 'y'}),
+        ({'type': 'dict', 'values_schema': RECORD_SCHEMA}, {'a': {'id': 'x', 'name': 'abcdef'}, 'b': {}}),
+        ({'type': 'dict', 'values_schema': 'int', 'required_keys': ['a', 'b']}, {'b': 'x', 'c': 1}),
+        ({'type': 'dict', 'keys_schema': 'int', 'values_schema': 'int', 'fail_fast_per_entry': True}, {'a': 'b'}),
+        ({'type': 'dict', 'key_transform': 'lower', 'values_schema': 'int'}, {'A': 'x', 'a': 2, 'b': 'y'}),
+        ({'type': 'dict', 'key_transform': 'lower', 'values_schema': 'int'}, {'A': 'x', 'b': 'y'}),
+        ({'type': 'dict', 'values_schema': 'int', 'coerce_json_string': True}, '{"a": "x"}'),
+        ('int', 1),
+    ],
+)
+def test_same_as_errors(schema, input_value):
+    v = SchemaValidator(schema)
+    get_input = input_value if callable(input_value) else lambda: input_value
+    assert list(v.iterate_validation_errors(get_input())) == sync_errors(v, get_input())
+
+
+def test_lazy():
+    calls = []
+
+    def f(input_value, **kwargs):
+        calls.append(input_value)
+        raise ValueError(f'bad {input_value}')
+
+    v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'function', 'mode': 'plain', 'function': f}})
+    errors = v.iterate_validation_errors([1, 2, 3])
+    assert calls == []
+    assert next(errors) == {
+        'kind': 'value_error',
+        'loc': [0],
+        'message': 'Value error, bad 1',
+        'input_value': 1,
+        'context': {'error': 'bad 1'},
+    }
+    assert calls == [1]
+    assert [e['loc'] for e in errors] == [[1], [2]]
+    assert calls == [1, 2, 3]
+    assert list(errors) == []
+
+
+def test_dict_lazy():
+    calls = []
+
+    def f(input_value, **kwargs):
+        calls.append(input_value)
+        raise ValueError(f'bad {input_value}')
+
+    v = SchemaValidator({'type': 'dict', 'values_schema': {'type': 'function', 'mode': 'plain', 'function': f}})
+    errors = v.iterate_validation_errors({'a': 1, 'b': 2})
+    assert calls == []
+    assert next(errors)['loc'] == ['a']
+    assert calls == [1]
+    assert [e['loc'] for e in errors] == [['b']]
+    assert calls == [1, 2]
+
+
+def test_recursive_lazy_list():
+    v = SchemaValidator(
+        {
+            'type': 'list',
+            'ref': 'tree',
+            'lazy': True,
+            'items_schema': {'type': 'union', 'choices': ['int', {'type': 'recursive-ref', 'schema_ref': 'tree'}]},
+        }
+    )
+    outer = v.validate_python([1, [2, [3]]])
+    assert next(outer) == 1
+    inner = next(outer)
+    assert next(inner) == 2
+    assert list(next(inner)) == [3]
+
+
+def test_strict_and_context():
+    def f(input_value, *, context, **kwargs):
+        if input_value == context['bad']:
+            raise ValueError('bad')
+        return input_value
+
+    v = SchemaValidator(
+        {'type': 'list', 'items_schema': {'type': 'function', 'mode': 'after', 'function': f, 'schema': 'int'}}
+    )
+    input_value = [1, '2', 3]
+    assert [e['loc'] for e in v.iterate_validation_errors(input_value, context={'bad': 3})] == [[2]]
+    errors = list(v.iterate_validation_errors(input_value, strict=True, context={'bad': 3}))
+    assert errors == sync_errors(v, input_value, strict=True, context={'bad': 3})
+    assert [e['kind'] for e in errors] == ['int_type', 'value_error']
+
+
+def test_hide_input_in_errors():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'}, {'hide_input_in_errors': True})
+    assert list(v.iterate_validation_errors(['x'])) == [
+        {
+            'kind': 'int_parsing',
+            'loc': [0],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+        }
+    ]
+
+
+def test_memory_constant():
+    v = SchemaValidator({'type': 'list', 'items_schema': RECORD_SCHEMA})
+    input_value = [{'id': 'x', 'name': 'abcdef'}] * 10_000
+
+    tracemalloc.start()
+    try:
+        count = 0
+        for _ in v.iterate_validation_errors(input_value):
+            count += 1
+        _, lazy_peak = tracemalloc.get_traced_memory()
+        tracemalloc.reset_peak()
+        all_errors = sync_errors(v, input_value)
+        _, eager_peak = tracemalloc.get_traced_memory()
+    finally:
+        tracemalloc.stop()
+
+    assert count == len(all_errors) == 20_000
+    # errors are created and dropped one item at a time, rather than all being held at once
+    assert lazy_peak < 50_000
+    assert eager_peak > 100 * lazy_peak
+
+
+def test_dict_memory_constant():
+    v = SchemaValidator({'type': 'dict', 'values_schema': RECORD_SCHEMA})
+    input_value = {i: {'id': 'x', 'name': 'abcdef'} for i in range(10_000)}
+
+    tracemalloc.start()
+    try:
+        count = 0
+        for _ in v.iterate_validation_errors(input_value):
+            count += 1
+        _, lazy_peak = tracemalloc.get_traced_memory()
+        tracemalloc.reset_peak()
+        all_errors = sync_errors(v, input_value)
+        _, eager_peak = tracemalloc.get_traced_memory()
+    finally:
+        tracemalloc.stop()
+
+    assert count == len(all_errors) == 20_000
+    assert lazy_peak < 50_000
+    assert eager_peak > 100 * lazy_peak
@@ -1,3 +1,5 @@
+import json
+
 import pytest
 
 from pydantic_core import SchemaValidator, ValidationError
@@ -31,9 +33,9 @@ def test_null():
 def test_str():
     s = SchemaValidator({'type': 'str'})
     assert s.validate_json('"foobar"') == 'foobar'
-    with pytest.raises(ValidationError, match=r'Input should be a valid string \[kind=str_type,'):
+    with pytest.raises(ValidationError, match=r'str_type  Input should be a valid string'):
         s.validate_json('false')
-    with pytest.raises(ValidationError, match=r'Input should be a valid string \[kind=str_type,'):
+    with pytest.raises(ValidationError, match=r'str_type  Input should be a valid string'):
         s.validate_json('123')
 
 
@@ -59,6 +61,47 @@ def test_model():
     assert v.validate_json(input_str) == {'field_a': 'abc', 'field_b': 1}
 
 
+def test_json_python_equivalent():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'name': {'schema': 'str'},
+                'tags': {'schema': {'type': 'list', 'items_schema': 'str'}},
+                'scores': {'schema': {'type': 'dict', 'keys_schema': 'str', 'values_schema': 'float'}},
+                'children': {
+                    'schema': {
+                        'type': 'list',
+                        'items_schema': {'type': 'typed-dict', 'fields': {'id': {'schema': 'int'}}},
+                    }
+                },
+                'anything': {'schema': 'any'},
+            },
+        }
+    )
+
+    # language=json
+    input_str = """
+    {
+        "name": "abc",
+        "tags": ["x", "y"],
+        "scores": {"a": 1, "b": 2.5},
+        "children": [{"id": 1}, {"id": "2"}],
+        "anything": {"nested": [null, true, 1, 1.5, "s"]}
+    }
+    """
+    json_output = v.validate_json(input_str)
+    python_output = v.validate_python(json.loads(input_str))
+    assert json_output == python_output == {
+        'name': 'abc',
+        'tags': ['x', 'y'],
+        'scores': {'a': 1.0, 'b': 2.5},
+        'children': [{'id': 1}, {'id': 2}],
+        'anything': {'nested': [None, True, 1, 1.5, 's']},
+    }
+    assert type(json_output['scores']['a']) is type(python_output['scores']['a']) is float
+
+
 def test_float_no_remainder():
     v = SchemaValidator({'type': 'int'})
     assert v.validate_json('123.0') == 123
@@ -79,7 +122,7 @@ def test_error_loc():
 
     with pytest.raises(ValidationError) as exc_info:
         v.validate_json('{"field_a": [1, 2, "wrong"]}')
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'int_parsing',
             'loc': ['field_a', 2],
@@ -104,23 +147,165 @@ def test_invalid_json():
 
     with pytest.raises(ValidationError) as exc_info:
         v.validate_json('"foobar')
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'invalid_json',
             'loc': [],
             'message': 'Invalid JSON: EOF while parsing a string at line 1 column 7',
             'input_value': '"foobar',
-            'context': {'error': 'EOF while parsing a string at line 1 column 7'},
+            'context': {'error': 'EOF while parsing a string at line 1 column 7', 'position': 6},
         }
     ]
     with pytest.raises(ValidationError) as exc_info:
         v.validate_json('[1,\n2,\n3,]')
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'invalid_json',
             'loc': [],
             'message': 'Invalid JSON: trailing comma at line 3 column 3',
             'input_value': '[1,\n2,\n3,]',
-            'context': {'error': 'trailing comma at line 3 column 3'},
+            'context': {'error': 'trailing comma at line 3 column 3', 'position': 9},
         }
     ]
+
+
+@pytest.mark.parametrize(
+    'input_value,error,position',
+    [
+        (b'{"a": [1, 2', 'EOF while parsing a list at line 1 column 11', 10),
+        (bytearray(b'[1,\n2,]'), 'trailing comma at line 2 column 3', 6),
+        (b'"\xff"', 'invalid unicode code point at line 1 column 3', 2),
+        (b'', 'EOF while parsing a value at line 1 column 0', 0),
+    ],
+)
+def test_invalid_json_bytes(input_value, error, position):
+    v = SchemaValidator({'type': 'any'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(input_value)
+    assert exc_info.value.errors()[0]['context'] == {'error': error, 'position': position}
+
+
+@pytest.mark.parametrize(
+    'input_value,line,column,char',
+    [
+        ('[1, 2 x]', 1, 7, 'x'),
+        ('[1,\n2,\n3,]', 3, 3, ']'),
+        (b'[1,\n2,]', 2, 3, ']'),
+        ('{"caf\u00e9": 1,}', 1, 12, '}'),
+        ('{"caf\u00e9": 1,}'.encode(), 1, 12, '}'),
+        ('["\u00e9\u00e9",\n "\u00e9\u00e9\u00e9" x]', 2, 8, 'x'),
+    ],
+)
+def test_invalid_json_source_position(input_value, line, column, char):
+    v = SchemaValidator({'type': 'any'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(input_value, source_position=True)
+    context = exc_info.value.errors()[0]['context']
+    assert (context['line'], context['column']) == (line, column)
+    # the column counts characters, not bytes, so it indexes into the decoded text
+    text = input_value.decode() if isinstance(input_value, bytes) else input_value
+    assert text.split('\n')[line - 1][column - 1] == char
+
+
+def test_invalid_json_source_position_default():
+    v = SchemaValidator({'type': 'any'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"caf\u00e9": 1,}')
+    assert exc_info.value.errors()[0]['context'] == {
+        'error': 'trailing comma at line 1 column 13',
+        'position': 12,
+    }
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"caf\u00e9": 1,}', source_position=False)
+    assert 'line' not in exc_info.value.errors()[0]['context']
+
+
+def test_invalid_json_source_position_unicode_error():
+    v = SchemaValidator({'type': 'any'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('"\ud800"', source_position=True)
+    assert exc_info.value.errors()[0]['context'] == {'error': 'input is not valid unicode'}
+
+
+def test_json_bytes_unicode():
+    v = SchemaValidator({'type': 'str'})
+    assert v.validate_json('"caf\u00e9"'.encode()) == 'café'
+    assert v.validate_json('"café"'.encode()) == 'café'
+
+
+def test_invalid_json_surrogate():
+    v = SchemaValidator({'type': 'str'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('"\ud800"')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'invalid_json',
+            'loc': [],
+            'message': 'Invalid JSON: input is not valid unicode',
+            'input_value': '"\ud800"',
+            'context': {'error': 'input is not valid unicode'},
+        }
+    ]
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('[1, 2, 3]', [1, 2, 3]),
+        ('[1, 2, 3', [1, 2]),
+        ('[1, 2,', [1, 2]),
+        ('[', []),
+        ('{"a": [1, 2], "b": "xyz', {'a': [1, 2]}),
+        ('{"a": [1, 2], "b": "xyz"', {'a': [1, 2], 'b': 'xyz'}),
+        ('{"a": [1, 2], "b"', {'a': [1, 2]}),
+        ('{"a": [1, 2], "b": ', {'a': [1, 2]}),
+        ('{"a": {"c": 1', {}),
+        ('{"a": {"c": 1}, "d": [{"e": "f\\"', {'a': {'c': 1}}),
+        ('{"a": "café", "b": "café', {'a': 'café'}),
+        (b'{"a": "x", "b": [true, false', {'a': 'x'}),
+        ('[true, false', [True, False]),
+        ('[true, fal', [True]),
+        ('{"a": null', {'a': None}),
+        ('[1, [2, 3], [4', [1, [2, 3]]),
+        ('[{"id": 1, "msg": "a"}, {"id": 2, "ms', [{'id': 1, 'msg': 'a'}]),
+    ],
+)
+def test_allow_partial(input_value, expected):
+    v = SchemaValidator('any')
+    assert v.validate_json(input_value, allow_partial=True) == expected
+
+
+def test_allow_partial_validates():
+    v = SchemaValidator(
+        {'type': 'typed-dict', 'fields': {'a': {'schema': 'int'}, 'b': {'schema': 'str', 'default': ''}}}
+    )
+    assert v.validate_json('{"a": 1, "b": "xy', allow_partial=True) == {'a': 1, 'b': ''}
+    with pytest.raises(ValidationError, match=r'a +missing  Field required'):
+        v.validate_json('{"a": 1', allow_partial=True)
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_json('{"a": "x", "b": "xy', allow_partial=True)
+
+
+def test_allow_partial_records():
+    # the incomplete last record is dropped rather than validated
+    v = SchemaValidator(
+        {
+            'type': 'list',
+            'items_schema': {'type': 'typed-dict', 'fields': {'id': {'schema': 'int'}, 'msg': {'schema': 'str'}}},
+        }
+    )
+    records = '[{"id": 1, "msg": "a"}, {"id": 2, "msg": "b"}, {"id": 3, "ms'
+    assert v.validate_json(records, allow_partial=True) == [{'id': 1, 'msg': 'a'}, {'id': 2, 'msg': 'b'}]
+
+
+@pytest.mark.parametrize('input_value', ['"abc', 'tru', '', '12 34', '[1, 2,, 3', '{"a" 1'])
+def test_allow_partial_invalid(input_value):
+    v = SchemaValidator('any')
+    with pytest.raises(ValidationError, match='Invalid JSON'):
+        v.validate_json(input_value, allow_partial=True)
+
+
+def test_allow_partial_default():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'})
+    with pytest.raises(ValidationError, match='Invalid JSON: EOF while parsing a list'):
+        v.validate_json('[1, 2')
@@ -0,0 +1,190 @@
+from collections import OrderedDict
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
+
+
+@pytest.mark.parametrize(
+    'schema,expected',
+    [
+        ('int', {'type': 'integer'}),
+        ({'type': 'int', 'ge': 1, 'lt': 10}, {'type': 'integer', 'minimum': 1, 'exclusiveMaximum': 10}),
+        ('float', {'type': 'number'}),
+        ({'type': 'float', 'multiple_of': 0.5, 'le': 2.5}, {'type': 'number', 'multipleOf': 0.5, 'maximum': 2.5}),
+        ('str', {'type': 'string'}),
+        (
+            {'type': 'str', 'min_length': 1, 'max_length': 5, 'pattern': '^a'},
+            {'type': 'string', 'minLength': 1, 'maxLength': 5, 'pattern': '^a'},
+        ),
+        ('bool', {'type': 'boolean'}),
+        ('none', {'type': 'null'}),
+        ({'type': 'nullable', 'schema': 'int'}, {'anyOf': [{'type': 'integer'}, {'type': 'null'}]}),
+        # validators without a specific JSON Schema allow any value
+        ('any', {}),
+        ({'type': 'list', 'items_schema': 'int'}, {}),
+    ],
+)
+def test_json_schema(schema, expected):
+    assert SchemaValidator(schema).json_schema() == expected
+
+
+def test_dict():
+    v = SchemaValidator({'type': 'dict', 'keys_schema': 'str', 'values_schema': 'int'})
+    assert v.json_schema() == {
+        'type': 'object',
+        'additionalProperties': {'type': 'integer'},
+        'propertyNames': {'type': 'string'},
+    }
+
+
+def test_dict_any():
+    v = SchemaValidator({'type': 'dict'})
+    assert v.json_schema() == {'type': 'object', 'additionalProperties': {}, 'propertyNames': {}}
+
+
+def test_dict_constraints():
+    v = SchemaValidator(
+        {
+            'type': 'dict',
+            'keys_schema': {'type': 'str', 'max_length': 10},
+            'values_schema': {'type': 'dict', 'values_schema': {'type': 'nullable', 'schema': 'float'}},
+            'min_items': 1,
+            'max_items': 5,
+        }
+    )
+    assert v.json_schema() == {
+        'type': 'object',
+        'minProperties': 1,
+        'maxProperties': 5,
+        'additionalProperties': {
+            'type': 'object',
+            'additionalProperties': {'anyOf': [{'type': 'number'}, {'type': 'null'}]},
+            'propertyNames': {},
+        },
+        'propertyNames': {'type': 'string', 'maxLength': 10},
+    }
+
+
+def test_mapping():
+    v = SchemaValidator({'type': 'mapping', 'values_schema': 'int', 'target_class': OrderedDict, 'max_items': 3})
+    assert v.json_schema() == {
+        'type': 'object',
+        'maxProperties': 3,
+        'additionalProperties': {'type': 'integer'},
+        'propertyNames': {},
+    }
+
+
+@pytest.mark.parametrize(
+    'schema,expected',
+    [
+        ({'type': 'int', 'title': 'Count'}, {'type': 'integer', 'title': 'Count'}),
+        (
+            {'type': 'int', 'gt': 0, 'description': 'a positive int', 'examples': [1, 42]},
+            {'type': 'integer', 'exclusiveMinimum': 0, 'description': 'a positive int', 'examples': [1, 42]},
+        ),
+        ({'type': 'float', 'title': 'Price'}, {'type': 'number', 'title': 'Price'}),
+        (
+            {'type': 'str', 'max_length': 3, 'examples': ['abc']},
+            {'type': 'string', 'maxLength': 3, 'examples': ['abc']},
+        ),
+        ({'type': 'bool', 'true_values': ['si'], 'title': 'Flag'}, {'type': 'boolean', 'title': 'Flag'}),
+        ({'type': 'none', 'description': 'nothing'}, {'type': 'null', 'description': 'nothing'}),
+        (
+            {'type': 'nullable', 'schema': {'type': 'int', 'title': 'Inner'}, 'title': 'Outer'},
+            {'anyOf': [{'type': 'integer', 'title': 'Inner'}, {'type': 'null'}], 'title': 'Outer'},
+        ),
+    ],
+)
+def test_metadata(schema, expected):
+    assert SchemaValidator(schema).json_schema() == expected
+
+
+def test_metadata_dict():
+    v = SchemaValidator(
+        {
+            'type': 'dict',
+            'values_schema': {'type': 'int', 'title': 'Score', 'examples': [10]},
+            'title': 'Scores',
+            'description': 'score by player',
+        }
+    )
+    assert v.json_schema() == {
+        'type': 'object',
+        'additionalProperties': {'type': 'integer', 'title': 'Score', 'examples': [10]},
+        'propertyNames': {},
+        'title': 'Scores',
+        'description': 'score by player',
+    }
+    assert v.metadata() == {'title': 'Scores', 'description': 'score by player'}
+
+
+def test_metadata_accessor():
+    v = SchemaValidator({'type': 'mapping', 'target_class': OrderedDict, 'title': 'Ordered', 'examples': [{}]})
+    assert v.metadata() == {'title': 'Ordered', 'examples': [{}]}
+    assert SchemaValidator('int').metadata() == {}
+    assert SchemaValidator({'type': 'str', 'title': 'Name'}).metadata() == {'title': 'Name'}
+    # validators which don't store metadata
+    assert SchemaValidator('any').metadata() == {}
+
+
+def test_metadata_does_not_affect_validation():
+    v = SchemaValidator({'type': 'int', 'title': 'Count', 'examples': ['not an int']})
+    assert v.validate_python('1') == 1
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python('x')
+
+
+def test_metadata_invalid():
+    with pytest.raises(SchemaError, match='int -> title\n  Input should be a valid string'):
+        SchemaValidator({'type': 'int', 'title': 1})
+
+
+def test_ref_shared():
+    v = SchemaValidator(
+        {
+            'type': 'dict',
+            'keys_schema': {'type': 'str', 'max_length': 5, 'ref': 'name'},
+            'values_schema': {
+                'type': 'dict',
+                'keys_schema': {'type': 'recursive-ref', 'schema_ref': 'name'},
+                'values_schema': 'int',
+            },
+        }
+    )
+    assert v.json_schema() == {
+        'type': 'object',
+        'additionalProperties': {
+            'type': 'object',
+            'additionalProperties': {'type': 'integer'},
+            'propertyNames': {'$ref': '#/$defs/name'},
+        },
+        'propertyNames': {'$ref': '#/$defs/name'},
+        '$defs': {'name': {'type': 'string', 'maxLength': 5}},
+    }
+
+
+def test_ref_recursive():
+    v = SchemaValidator(
+        {
+            'type': 'nullable',
+            'ref': 'tree',
+            'schema': {'type': 'dict', 'values_schema': {'type': 'recursive-ref', 'schema_ref': 'tree'}},
+        }
+    )
+    assert v.json_schema() == {
+        '$ref': '#/$defs/tree',
+        '$defs': {
+            'tree': {
+                'anyOf': [
+                    {'type': 'object', 'additionalProperties': {'$ref': '#/$defs/tree'}, 'propertyNames': {}},
+                    {'type': 'null'},
+                ]
+            }
+        },
+    }
+
+
+def test_no_refs_no_defs():
+    assert '$defs' not in SchemaValidator({'type': 'dict', 'values_schema': 'int'}).json_schema()
@@ -0,0 +1,146 @@
+import re
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
+
+from .conftest import Err
+
+MIXED_CONFIG = {'lenient_coercion': {'coerce_numbers_to_str': True, 'coerce_str_to_int': False}}
+
+
+@pytest.mark.parametrize(
+    'schema,input_value,expected',
+    [
+        ('int', 123, 123),
+        ('int', 1.0, 1),
+        ('int', True, 1),
+        ('int', '123', Err('int_type  Input should be a valid integer')),
+        ('str', 'foo', 'foo'),
+        ('str', 123, '123'),
+        ('str', 1.5, '1.5'),
+        ('str', True, Err('str_type  Input should be a valid string')),
+        ('bool', 'yes', True),
+        ('bool', 0, False),
+        ({'type': 'int', 'ge': 100}, '123', Err('int_type  Input should be a valid integer')),
+        ({'type': 'str', 'max_length': 5}, 123, '123'),
+        ({'type': 'str', 'max_length': 2}, 123, Err('String should have at most 2 characters')),
+    ],
+)
+def test_mixed_flags(schema, input_value, expected):
+    v = SchemaValidator(schema, MIXED_CONFIG)
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert type(output) == type(expected)
+
+
+def test_mixed_flags_json():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {'a': {'schema': 'int'}, 'b': {'schema': 'str'}, 'c': {'schema': 'bool'}},
+        },
+        MIXED_CONFIG,
+    )
+    assert v.validate_json('{"a": 1, "b": 2, "c": "true"}') == {'a': 1, 'b': '2', 'c': True}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"a": "1", "b": "2", "c": true}')
+    assert exc_info.value.errors(include_url=False) == [
+        {'kind': 'int_type', 'loc': ['a'], 'message': 'Input should be a valid integer', 'input_value': '1'}
+    ]
+
+
+@pytest.mark.parametrize(
+    'flags,input_value,expected',
+    [
+        ({}, True, 1),
+        ({}, '1', 1),
+        ({'coerce_bool_to_int': False}, True, Err('int_type  Input should be a valid integer')),
+        ({'coerce_bool_to_int': False}, '1', 1),
+        ({'coerce_str_to_int': False, 'coerce_bool_to_int': False}, 1.0, 1),
+        ({'coerce_str_to_int': False, 'coerce_bool_to_int': False}, '1', Err('Input should be a valid integer')),
+    ],
+)
+def test_int_flags(flags, input_value, expected):
+    v = SchemaValidator('int', {'lenient_coercion': flags})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'flags,input_value,expected',
+    [
+        ({}, 'true', True),
+        ({}, 1, True),
+        ({'coerce_str_to_bool': False}, 'true', Err('bool_type  Input should be a valid boolean')),
+        ({'coerce_str_to_bool': False}, 1, True),
+        ({'coerce_numbers_to_bool': False}, 'true', True),
+        ({'coerce_numbers_to_bool': False}, 1, Err('bool_type  Input should be a valid boolean')),
+        ({'coerce_numbers_to_bool': False}, 0.0, Err('bool_type  Input should be a valid boolean')),
+        ({'coerce_numbers_to_bool': False}, False, False),
+    ],
+)
+def test_bool_flags(flags, input_value, expected):
+    v = SchemaValidator('bool', {'lenient_coercion': flags})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) is expected
+
+
+def test_bool_values_flags():
+    v = SchemaValidator({'type': 'bool', 'true_values': ['si']}, {'lenient_coercion': {'coerce_str_to_bool': False}})
+    assert v.validate_python(1) is True
+    with pytest.raises(ValidationError, match='Input should be a valid boolean'):
+        v.validate_python('si')
+
+
+def test_numbers_to_str_default():
+    v = SchemaValidator('str')
+    with pytest.raises(ValidationError, match='Input should be a valid string'):
+        v.validate_python(123)
+
+
+def test_strict_ignores_flags():
+    v = SchemaValidator('str', {'lenient_coercion': {'coerce_numbers_to_str': True}})
+    assert v.validate_python(123) == '123'
+    with pytest.raises(ValidationError, match='Input should be a valid string'):
+        v.validate_python(123, strict=True)
+
+
+def test_nested_and_function_validators():
+    def f(input_value, *, validator, **kwargs):
+        return validator(input_value) + '!'
+
+    v = SchemaValidator(
+        {
+            'type': 'list',
+            'items_schema': {'type': 'function', 'mode': 'wrap', 'function': f, 'schema': 'str'},
+        },
+        {'lenient_coercion': {'coerce_numbers_to_str': True}},
+    )
+    assert v.validate_python([1, 'a']) == ['1!', 'a!']
+
+
+def test_invalid_flag_value():
+    with pytest.raises(SchemaError, match='lenient_coercion -> coerce_str_to_int\n  TypeError'):
+        SchemaValidator('int', {'lenient_coercion': {'coerce_str_to_int': 'maybe'}})
+
+
+def test_unknown_flag():
+    with pytest.raises(SchemaError) as exc_info:
+        SchemaValidator('int', {'lenient_coercion': {'coerce_str_to_itn': False}})
+    assert str(exc_info.value) == (
+        'Invalid Schema:\n'
+        'lenient_coercion -> coerce_str_to_itn\n'
+        "  Unknown flag, should be one of: 'coerce_numbers_to_str', 'coerce_str_to_int', 'coerce_bool_to_int', "
+        "'coerce_str_to_bool', 'coerce_numbers_to_bool'"
+    )
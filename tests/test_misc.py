@@ -1,13 +1,24 @@
+import copy
+import os
+import pickle
 import re
 import sys
 from pathlib import Path
 
 import pytest
 
-from pydantic_core._pydantic_core import SchemaError, SchemaValidator, ValidationError, __version__
+from pydantic_core._pydantic_core import (
+    PydanticUndefined,
+    PydanticUndefinedType,
+    PydanticValueError,
+    SchemaError,
+    SchemaValidator,
+    ValidationError,
+    __version__,
+)
 
 
-@pytest.mark.parametrize('obj', [ValidationError, SchemaValidator, SchemaError])
+@pytest.mark.parametrize('obj', [ValidationError, SchemaValidator, SchemaError, PydanticUndefinedType])
 def test_module(obj):
     assert obj.__module__ == 'pydantic_core._pydantic_core'
 
@@ -24,6 +35,19 @@ def test_schema_error():
     assert repr(err) == 'SchemaError("test")'
 
 
+def test_undefined():
+    from pydantic_core import PydanticUndefined as PydanticUndefined2
+
+    assert PydanticUndefined is PydanticUndefined2
+    assert isinstance(PydanticUndefined, PydanticUndefinedType)
+    assert repr(PydanticUndefined) == 'PydanticUndefined'
+    assert copy.copy(PydanticUndefined) is PydanticUndefined
+    assert copy.deepcopy(PydanticUndefined) is PydanticUndefined
+    assert pickle.loads(pickle.dumps(PydanticUndefined)) is PydanticUndefined
+    with pytest.raises(TypeError, match='No constructor defined'):
+        PydanticUndefinedType()
+
+
 def test_validation_error():
     v = SchemaValidator('int')
     with pytest.raises(ValidationError) as exc_info:
@@ -56,7 +80,7 @@ def test_validation_error_multiple():
 
     assert exc_info.value.title == 'MyModel'
     assert exc_info.value.error_count() == 2
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'float_parsing',
             'loc': ['x'],
@@ -81,9 +105,260 @@ def test_validation_error_multiple():
     )
 
 
+def test_errors_by_kind():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'a': {'schema': 'int'},
+                'b': {'schema': {'type': 'list', 'items_schema': 'int'}},
+                'c': {'schema': 'str'},
+                'd': {'schema': {'type': 'list', 'max_items': 1}},
+            },
+        }
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x', 'b': ['y', 2], 'c': 3, 'd': [1, 2]})
+
+    errors = exc_info.value.errors()
+    assert exc_info.value.error_count() == len(errors) == 4
+    by_kind = exc_info.value.errors_by_kind()
+    assert list(by_kind) == ['int_parsing', 'str_type', 'too_long']
+    assert by_kind['int_parsing'] == [errors[0], errors[1]]
+    assert by_kind['str_type'] == [errors[2]]
+    assert by_kind['too_long'] == [errors[3]]
+    assert [e['loc'] for e in by_kind['int_parsing']] == [['a'], ['b', 0]]
+    assert 'url' in exc_info.value.errors_by_kind()['str_type'][0]
+    assert 'url' not in exc_info.value.errors_by_kind(include_url=False)['str_type'][0]
+
+
+FRENCH_MESSAGES = {
+    'too_short': 'Le dictionnaire doit avoir au moins {min_length} éléments, pas {input_length}',
+    'int_parsing': "L'entrée doit être un entier valide",
+    'greater_than': "L'entrée doit être supérieure à {gt}{unknown}",
+}
+
+
+def test_error_message_templates():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'a': {'schema': {'type': 'dict', 'min_items': 2}},
+                'b': {'schema': 'int'},
+                'c': {'schema': {'type': 'float', 'gt': 5}},
+                'd': {'schema': 'str'},
+            },
+        }
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': {'x': 1}, 'b': 'x', 'c': 1, 'd': 1})
+
+    errors = exc_info.value.errors(messages=FRENCH_MESSAGES)
+    assert [e['message'] for e in errors] == [
+        'Le dictionnaire doit avoir au moins 2 éléments, pas 1',
+        "L'entrée doit être un entier valide",
+        "L'entrée doit être supérieure à 5.0{unknown}",
+        # kinds without a template use the default English message
+        'Input should be a valid string',
+    ]
+    assert [e['context'] for e in errors if 'context' in e] == [{'min_length': 2, 'input_length': 1}, {'gt': 5.0}]
+    by_kind = exc_info.value.errors_by_kind(messages=FRENCH_MESSAGES)
+    assert by_kind['int_parsing'][0]['message'] == "L'entrée doit être un entier valide"
+    assert exc_info.value.errors()[1]['message'] == (
+        'Input should be a valid integer, unable to parse string as an integer'
+    )
+
+
+def test_error_message_templates_custom_error():
+    def f(input_value, **kwargs):
+        raise PydanticValueError('my_error', 'my message {foo}', {'foo': 'bar'})
+
+    v = SchemaValidator({'type': 'function', 'mode': 'plain', 'function': f})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(1)
+    assert exc_info.value.errors(messages={'my_error': 'mon message {foo}'})[0]['message'] == 'mon message bar'
+    assert exc_info.value.errors(messages={})[0]['message'] == 'my message bar'
+
+
+def test_error_message_templates_single_pass():
+    def f(input_value, **kwargs):
+        raise PydanticValueError('my_error', 'my message', {'a': '{b}', 'b': 'x'})
+
+    v = SchemaValidator({'type': 'function', 'mode': 'plain', 'function': f})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(1)
+    # placeholders in context values aren't substituted
+    assert exc_info.value.errors(messages={'my_error': '{b} {a} {c}'})[0]['message'] == 'x {b} {c}'
+
+
+def test_validation_error_pretty():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {'x': {'schema': 'float'}, 'y': {'schema': {'type': 'list', 'items_schema': 'int'}}},
+        }
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'x': 'x' * 60, 'y': [1, 'foo']})
+
+    assert exc_info.value.pretty(color=False) == (
+        '2 validation errors for typed-dict\n'
+        'Location  Type           Message                                                                Input\n'
+        '--------  -------------  ---------------------------------------------------------------------  '
+        '--------------------------------------------------\n'
+        "x         float_parsing  Input should be a valid number, unable to parse string as an number    "
+        "'xxxxxxxxxxxxxxxxxxxxxxx...xxxxxxxxxxxxxxxxxxxxxx'\n"
+        "y -> 1    int_parsing    Input should be a valid integer, unable to parse string as an integer  'foo'"
+    )
+    # str() is the same table, colored only if stdout is a terminal
+    assert str(exc_info.value) == exc_info.value.pretty(color=False)
+
+
+def test_validation_error_pretty_color():
+    v = SchemaValidator('int')
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('ü' * 3)
+
+    assert exc_info.value.pretty(color=True) == (
+        '1 validation error for int\n'
+        '\x1b[1mLocation\x1b[0m  \x1b[1mType       \x1b[0m  '
+        '\x1b[1mMessage                                                              \x1b[0m  \x1b[1mInput\x1b[0m\n'
+        '--------  -----------  ---------------------------------------------------------------------  -----\n'
+        '          \x1b[31mint_parsing\x1b[0m  '
+        "Input should be a valid integer, unable to parse string as an integer  'üüü'"
+    )
+    # stdout isn't a terminal under pytest
+    assert '\x1b[' not in exc_info.value.pretty()
+
+
+def test_error_url():
+    v = SchemaValidator('int')
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x')
+
+    minor_version = '.'.join(__version__.split('.')[:2])
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'int_parsing',
+            'loc': [],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'x',
+            'url': f'https://errors.pydantic.dev/{minor_version}/v/int_parsing',
+        }
+    ]
+    assert 'url' not in exc_info.value.errors(include_url=False)[0]
+
+
+def test_error_url_base_env():
+    v = SchemaValidator('int')
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x')
+
+    os.environ['PYDANTIC_ERRORS_URL_BASE'] = 'https://docs.example.com/pydantic-errors/'
+    try:
+        url = exc_info.value.errors()[0]['url']
+    finally:
+        del os.environ['PYDANTIC_ERRORS_URL_BASE']
+    assert re.fullmatch(r'https://docs\.example\.com/pydantic-errors/\d+\.\d+/v/int_parsing', url)
+    assert exc_info.value.errors()[0]['url'].startswith('https://errors.pydantic.dev/')
+
+
 @pytest.mark.skipif(sys.platform == 'emscripten', reason='README.md is not mounted in wasm file system')
 def test_readme(import_execute):
     this_dir = Path(__file__).parent
     readme = (this_dir / '..' / 'README.md').read_text()
     example_code = re.search(r'\n```py\n(.*?)\n```\n', readme, re.M | re.S).group(1)
     import_execute(example_code)
+
+
+@pytest.mark.parametrize(
+    'field_name,hidden',
+    [
+        ('password', True),
+        ('new_password2', True),
+        ('client_secret', True),
+        ('API_TOKEN', True),
+        ('passenger', False),
+        ('tokens_used', True),
+        ('name', False),
+    ],
+)
+def test_sensitive_field_input_hidden(field_name, hidden):
+    v = SchemaValidator({'type': 'typed-dict', 'fields': {field_name: {'schema': 'int'}}})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({field_name: 'hunter2'})
+
+    error = exc_info.value.errors()[0]
+    assert error['kind'] == 'int_parsing'
+    assert ('input_value' not in error) is hidden
+    assert ('hunter2' not in str(exc_info.value)) is hidden
+    assert ('hunter2' not in exc_info.value.pretty(color=False)) is hidden
+
+
+def test_sensitive_nested_location():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'secrets': {'schema': {'type': 'list', 'items_schema': 'int'}},
+                'other': {'schema': {'type': 'list', 'items_schema': 'int'}},
+            },
+        }
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'secrets': [1, 'abc'], 'other': [1, 'xyz']})
+
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'int_parsing',
+            'loc': ['secrets', 1],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+        },
+        {
+            'kind': 'int_parsing',
+            'loc': ['other', 1],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'xyz',
+        },
+    ]
+
+
+def test_hide_input_in_errors_config():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {'a': {'schema': 'int'}, 'b': {'schema': {'type': 'list', 'items_schema': 'str'}}},
+        },
+        {'hide_input_in_errors': True},
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'spam', 'b': [1, 2]})
+
+    errors = exc_info.value.errors(include_url=False)
+    assert [e['loc'] for e in errors] == [['a'], ['b', 0], ['b', 1]]
+    assert all('input_value' not in e for e in errors)
+    assert 'spam' not in str(exc_info.value)
+    assert 'spam' not in repr(exc_info.value)
+    assert 'input_value' not in repr(exc_info.value)
+    assert 'input_type=str' in repr(exc_info.value)
+
+
+def test_errors_input_value_truncated():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['x' * 300, 'y'])
+
+    input_values = [e['input_value'] for e in exc_info.value.errors()]
+    assert input_values == ["'" + 'x' * 98 + '...' + 'x' * 97 + "'", 'y']
+    assert len(input_values[0]) == 200
+
+
+def test_hide_input_in_errors_lazy_list():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int', 'lazy': True}, {'hide_input_in_errors': True})
+    output = v.validate_python(['1', 'spam'])
+    assert next(output) == 1
+    with pytest.raises(ValidationError) as exc_info:
+        next(output)
+    assert 'input_value' not in exc_info.value.errors()[0]
+    assert 'spam' not in str(exc_info.value)
@@ -0,0 +1,132 @@
+import gc
+import struct
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError
+
+# msgpack encodings are written out by hand to avoid a dependency on a msgpack library
+ONE_A = b'\x81\xa1a\x01'  # {'a': 1}
+INT_KEYS = b'\x82\x01\xa1x\xa12\xa1y'  # {1: 'x', '2': 'y'}
+
+
+def test_msgpack_types():
+    v = SchemaValidator({'type': 'any'})
+    assert v.validate_msgpack(ONE_A) == {'a': 1}
+    assert v.validate_msgpack(b'\x93\x01\xa1b\xc0') == [1, 'b', None]
+    assert v.validate_msgpack(b'\xc3') is True
+    assert v.validate_msgpack(b'\xc4\x02ab') == b'ab'
+    assert v.validate_msgpack(b'\xcb' + struct.pack('>d', 1.5)) == 1.5
+    assert v.validate_msgpack(b'\xca' + struct.pack('>f', 0.5)) == 0.5
+    assert v.validate_msgpack(b'\xff') == -1
+    # as with JSON, integers beyond 64 bit signed integers become floats
+    assert v.validate_msgpack(b'\xcf\xff\xff\xff\xff\xff\xff\xff\xff') == float(2**64 - 1)
+    assert v.validate_msgpack(bytearray(ONE_A)) == {'a': 1}
+
+
+def test_bytearray_mutated_while_validating():
+    # an array of 100 `{'a': 1}` maps, garbage collection runs while the output is created, which empties the input,
+    # decoding is already done by then since it doesn't create python objects
+    data = bytearray(b'\xdc\x00\x64' + ONE_A * 100)
+    v = SchemaValidator('any')
+
+    def clear(phase, info):
+        data.clear()
+
+    threshold = gc.get_threshold()
+    gc.set_threshold(1)
+    gc.callbacks.append(clear)
+    try:
+        output = v.validate_msgpack(data)
+    finally:
+        gc.callbacks.remove(clear)
+        gc.set_threshold(*threshold)
+    assert data == bytearray()
+    assert output == [{'a': 1}] * 100
+
+
+def test_typed_dict():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {'a': {'schema': 'int'}, 'b': {'schema': 'str', 'default': 'x'}},
+        }
+    )
+    assert v.validate_msgpack(ONE_A) == {'a': 1, 'b': 'x'}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_msgpack(b'\x81\xa1a\xa1z')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'int_parsing',
+            'loc': ['a'],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'z',
+        }
+    ]
+
+
+def test_repeated_keys():
+    # {'a': 1, 1: 'x', 'a': 2, 1.0: 'y', True: 'z'}
+    data = b'\x85\xa1a\x01\x01\xa1x\xa1a\x02\xcb' + struct.pack('>d', 1.0) + b'\xa1y\xc3\xa1z'
+    assert SchemaValidator('any').validate_msgpack(data) == {'a': 2, 1: 'z'}
+    assert list(SchemaValidator('any').validate_msgpack(data)) == ['a', 1]
+
+
+def test_non_str_keys():
+    v = SchemaValidator({'type': 'dict', 'keys_schema': 'int', 'values_schema': 'str'})
+    assert v.validate_msgpack(INT_KEYS) == {1: 'x', 2: 'y'}
+
+    v = SchemaValidator({'type': 'dict', 'keys_schema': {'type': 'int', 'strict': True}})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_msgpack(INT_KEYS)
+    assert exc_info.value.errors(include_url=False) == [
+        {'kind': 'int_type', 'loc': ['2', '[key]'], 'message': 'Input should be a valid integer', 'input_value': '2'}
+    ]
+
+
+def test_str_bytes_distinction():
+    v = SchemaValidator({'type': 'str', 'strict': True})
+    assert v.validate_msgpack(b'\xa2ab') == 'ab'
+    with pytest.raises(ValidationError, match=r'str_type  Input should be a valid string'):
+        v.validate_msgpack(b'\xc4\x02ab')
+
+    v = SchemaValidator({'type': 'bytes', 'strict': True})
+    assert v.validate_msgpack(b'\xc4\x02ab') == b'ab'
+    with pytest.raises(ValidationError, match=r'bytes_type  Input should be a valid bytes'):
+        v.validate_msgpack(b'\xa2ab')
+
+
+@pytest.mark.parametrize(
+    'input_value,error',
+    [
+        (b'', 'IO error while reading marker: failed to fill whole buffer'),
+        (b'\x92\x01', 'IO error while reading marker: failed to fill whole buffer'),
+        (b'\x01\x02', 'trailing data at byte 1'),
+        (b'\x81\x91\x01\x01', "TypeError: unhashable type: 'list'"),
+    ],
+)
+def test_invalid_msgpack(input_value, error):
+    v = SchemaValidator({'type': 'any'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_msgpack(input_value)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'invalid_msgpack',
+            'loc': [],
+            'message': f'Invalid msgpack: {error}',
+            'input_value': input_value,
+            'context': {'error': error},
+        }
+    ]
+
+
+def test_extension_type():
+    v = SchemaValidator({'type': 'any'})
+    with pytest.raises(ValidationError, match=r'invalid_msgpack  Invalid msgpack: .+'):
+        v.validate_msgpack(b'\xd4\x01\x00')
+
+
+def test_wrong_input_type():
+    v = SchemaValidator({'type': 'any'})
+    with pytest.raises(TypeError, match='^msgpack input should be bytes or bytearray, not str$'):
+        v.validate_msgpack('\x01')
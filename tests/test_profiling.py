@@ -0,0 +1,77 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError
+
+pytestmark = pytest.mark.skipif(
+    not hasattr(SchemaValidator, 'statistics'), reason='pydantic-core built without the "profiling" feature'
+)
+
+
+def calls_and_errors(v):
+    return [(s['name'], s['calls'], s['errors']) for s in v.statistics()]
+
+
+def test_statistics_nested():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {'a': {'schema': 'int'}, 'b': {'schema': {'type': 'list', 'items_schema': 'int'}}},
+        }
+    )
+    assert v.statistics() == []
+    assert v.validate_python({'a': 1, 'b': [1, 2, 3]}) == {'a': 1, 'b': [1, 2, 3]}
+    # each validator has its own entry, even if it has the same name as another
+    assert calls_and_errors(v) == [('int', 1, 0), ('int', 3, 0), ('list[int]', 1, 0), ('typed-dict', 1, 0)]
+    typed_dict_stats, list_stats = v.statistics()[3], v.statistics()[2]
+    # time includes child validators
+    assert typed_dict_stats['time'] >= list_stats['time'] > 0
+
+
+def test_statistics_errors():
+    v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'nullable', 'schema': 'str'}})
+    v.validate_python(['a', None])
+    with pytest.raises(ValidationError):
+        v.validate_python(['a', 1, 2])
+    assert calls_and_errors(v) == [('str', 4, 2), ('nullable[str]', 5, 2), ('list[nullable[str]]', 2, 1)]
+
+
+def test_statistics_per_validator():
+    schema = {'type': 'list', 'items_schema': {'type': 'float', 'ge': 0}}
+    v1 = SchemaValidator(schema)
+    v2 = SchemaValidator(schema)
+    v1.validate_python([1, 2])
+    assert calls_and_errors(v1) == [('constrained-float', 2, 0), ('list[constrained-float]', 1, 0)]
+    assert v2.statistics() == []
+
+
+def test_statistics_lazy_list():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int', 'lazy': True})
+    output = v.validate_python([1, 'x'])
+    assert calls_and_errors(v) == [('lazy-list[int]', 1, 0)]
+    assert next(output) == 1
+    with pytest.raises(ValidationError):
+        next(output)
+    # items validated after `validate_python` returned are still recorded
+    assert calls_and_errors(v) == [('lazy-list[int]', 1, 0), ('int', 2, 1)]
+
+
+def test_statistics_function_wrap():
+    def f(input_value, *, validator, **kwargs):
+        return validator(input_value)
+
+    v = SchemaValidator({'type': 'function', 'mode': 'wrap', 'function': f, 'schema': 'int'})
+    assert v.validate_python('1') == 1
+    assert calls_and_errors(v) == [('int', 1, 0), ('function-wrap[int]', 1, 0)]
+
+
+def test_statistics_iterate_validation_errors():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'})
+    assert len(list(v.iterate_validation_errors([1, 'x']))) == 1
+    assert calls_and_errors(v) == [('int', 2, 1)]
+
+
+def test_repr():
+    # reprs are the same as without the profiling wrapper
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'})
+    assert 'Profiled' not in repr(v)
+    assert repr(v).startswith('SchemaValidator(name="list[int]", validator=List(\n    ListValidator {\n')
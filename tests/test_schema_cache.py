@@ -0,0 +1,161 @@
+import gc
+import os
+import subprocess
+import sys
+import weakref
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, clear_schema_cache, schema_cache_info
+
+
+@pytest.fixture(autouse=True)
+def clean_cache():
+    clear_schema_cache()
+    yield
+    clear_schema_cache()
+
+
+def test_cache_hit():
+    schema = {'type': 'typed-dict', 'fields': {'a': {'schema': {'type': 'str', 'pattern': r'^\d+$'}}}}
+    v1 = SchemaValidator(schema)
+    assert schema_cache_info() == {'hits': 0, 'misses': 1, 'size': 1, 'max_size': 512}
+    v2 = SchemaValidator(schema)
+    assert schema_cache_info() == {'hits': 1, 'misses': 1, 'size': 1, 'max_size': 512}
+    assert v1 is not v2
+    assert repr(v1) == repr(v2)
+    assert v2.validate_python({'a': '123'}) == {'a': '123'}
+    with pytest.raises(ValidationError, match='String should match pattern'):
+        v2.validate_python({'a': 'x'})
+
+
+def test_equal_schemas():
+    SchemaValidator({'type': 'list', 'items_schema': 'int'}, {'strict': True})
+    SchemaValidator({'type': 'list', 'items_schema': 'int'}, {'strict': True})
+    assert schema_cache_info()['hits'] == 1
+
+    # config is part of the key
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'})
+    assert v.validate_python(['1']) == [1]
+    assert schema_cache_info() == {'hits': 1, 'misses': 2, 'size': 2, 'max_size': 512}
+
+
+@pytest.mark.parametrize(
+    'other',
+    [
+        {'type': 'int', 'gt': 1.0},
+        {'type': 'int', 'gt': True},
+        {'type': 'int', 'gt': '1'},
+        {'type': 'int', 'ge': 1},
+        {'type': 'int', 'gt': 2},
+        {'type': 'float', 'gt': 1},
+    ],
+    ids=repr,
+)
+def test_different_schemas(other):
+    SchemaValidator({'type': 'int', 'gt': 1})
+    try:
+        SchemaValidator(other)
+    except Exception:
+        # some of these aren't valid schemas, they still mustn't hit the cache
+        pass
+    assert schema_cache_info()['hits'] == 0
+
+
+def test_mutated_schema():
+    schema = {'type': 'int', 'gt': 1}
+    assert SchemaValidator(schema).isinstance_python(1) is False
+    schema['gt'] = 0
+    assert SchemaValidator(schema).isinstance_python(1) is True
+    assert schema_cache_info()['hits'] == 0
+
+
+def test_functions_not_cached():
+    def f1(input_value, **kwargs):
+        return input_value + 1
+
+    def f2(input_value, **kwargs):
+        return input_value + 2
+
+    assert SchemaValidator({'type': 'function', 'mode': 'plain', 'function': f1}).validate_python(1) == 2
+    assert SchemaValidator({'type': 'function', 'mode': 'plain', 'function': f2}).validate_python(1) == 3
+    assert SchemaValidator({'type': 'function', 'mode': 'plain', 'function': f1}).validate_python(1) == 2
+    assert schema_cache_info() == {'hits': 0, 'misses': 0, 'size': 0, 'max_size': 512}
+
+
+def test_mutable_defaults_not_shared():
+    v1 = SchemaValidator({'type': 'typed-dict', 'fields': {'a': {'schema': 'list', 'default': []}}})
+    v2 = SchemaValidator({'type': 'typed-dict', 'fields': {'a': {'schema': 'list', 'default': []}}})
+    assert schema_cache_info()['hits'] == 0
+    v1.validate_python({})['a'].append(1)
+    assert v2.validate_python({}) == {'a': []}
+
+
+def test_schema_error_not_cached():
+    schema = {'type': 'int', 'gt': 'x'}
+    for _ in range(2):
+        with pytest.raises(Exception):
+            SchemaValidator(schema)
+    assert schema_cache_info() == {'hits': 0, 'misses': 2, 'size': 0, 'max_size': 512}
+
+
+def test_clear_schema_cache():
+    SchemaValidator('int')
+    SchemaValidator('int')
+    assert schema_cache_info() == {'hits': 1, 'misses': 1, 'size': 1, 'max_size': 512}
+    clear_schema_cache()
+    assert schema_cache_info() == {'hits': 0, 'misses': 0, 'size': 0, 'max_size': 512}
+
+
+def run_with_cache_size(size: str, code: str) -> str:
+    env = {**os.environ, 'PYDANTIC_CORE_SCHEMA_CACHE_SIZE': size}
+    p = subprocess.run([sys.executable, '-c', code], env=env, capture_output=True, text=True, check=True)
+    return p.stdout.strip()
+
+
+def test_cache_size_env_var():
+    code = """
+from pydantic_core import SchemaValidator, schema_cache_info
+for schema in ['int', 'str', 'int', 'bool', 'str']:
+    SchemaValidator(schema)
+print(schema_cache_info())
+"""
+    # 'int' is the least recently used when 'bool' is added, so it's evicted
+    assert run_with_cache_size('2', code) == "{'hits': 1, 'misses': 4, 'size': 2, 'max_size': 2}"
+    assert run_with_cache_size('0', code) == "{'hits': 0, 'misses': 5, 'size': 0, 'max_size': 0}"
+
+
+def test_object_defaults_not_cached():
+    class Default:
+        pass
+
+    for default in [[], object(), Default()]:
+        schema = {'type': 'typed-dict', 'fields': {'a': {'schema': 'any', 'default': default}}}
+        SchemaValidator(schema)
+        assert SchemaValidator(schema).validate_python({})['a'] is default
+    assert schema_cache_info() == {'hits': 0, 'misses': 0, 'size': 0, 'max_size': 512}
+
+
+def test_classes_not_kept_alive():
+    class MyClass:
+        pass
+
+    v = SchemaValidator({'type': 'is-instance', 'class_': MyClass})
+    assert v.isinstance_python(MyClass())
+    assert schema_cache_info()['size'] == 0
+
+    class_ref = weakref.ref(MyClass)
+    del v, MyClass
+    gc.collect()
+    assert class_ref() is None
+
+
+def test_lru_order():
+    code = """
+from pydantic_core import SchemaValidator, schema_cache_info
+for schema in ['int', 'str', 'bool', 'int', 'float', 'str', 'int', 'float']:
+    SchemaValidator(schema)
+print(schema_cache_info())
+"""
+    # 'str' is evicted by 'float' since 'int' was used more recently, then 'bool' by 'str'
+    assert run_with_cache_size('3', code) == "{'hits': 3, 'misses': 5, 'size': 3, 'max_size': 3}"
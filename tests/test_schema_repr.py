@@ -0,0 +1,96 @@
+from pydantic_core import SchemaValidator
+
+
+def test_simple():
+    assert SchemaValidator('int').schema_repr() == {'name': 'int'}
+    assert SchemaValidator({'type': 'str', 'max_length': 5, 'strict': True}).schema_repr() == {
+        'name': 'constrained-str',
+        'strict': True,
+        'strip_whitespace': False,
+        'to_lower': False,
+        'to_upper': False,
+        'max_length': 5,
+    }
+    assert SchemaValidator({'type': 'str'}, {'str_strip_whitespace': True, 'str_to_lower': True}).schema_repr() == {
+        'name': 'constrained-str',
+        'strict': False,
+        'strip_whitespace': True,
+        'to_lower': True,
+        'to_upper': False,
+    }
+
+
+def test_nested():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'a': {'schema': {'type': 'list', 'items_schema': {'type': 'int', 'ge': 1}, 'max_items': 3}},
+                'b': {
+                    'schema': {
+                        'type': 'union',
+                        'choices': ['str', {'type': 'nullable', 'schema': {'type': 'dict', 'values_schema': 'float'}}],
+                    }
+                },
+            },
+        }
+    )
+    assert v.schema_repr() == {
+        'name': 'typed-dict',
+        'fields': {
+            'a': {
+                'name': 'list[constrained-int]',
+                'strict': False,
+                'max_items': 3,
+                'items_schema': {'name': 'constrained-int', 'strict': False, 'ge': 1},
+            },
+            'b': {
+                'name': 'union[str,nullable[dict[any,float]]]',
+                'choices': [
+                    {'name': 'str'},
+                    {
+                        'name': 'nullable[dict[any,float]]',
+                        'schema': {
+                            'name': 'dict[any,float]',
+                            'strict': False,
+                            'keys_schema': {'name': 'any'},
+                            'values_schema': {'name': 'float', 'allow_inf_nan': False},
+                        },
+                    },
+                ],
+            },
+        },
+    }
+
+
+def test_recursive():
+    v = SchemaValidator(
+        {
+            'type': 'nullable',
+            'ref': 'tree',
+            'schema': {'type': 'list', 'items_schema': {'type': 'recursive-ref', 'schema_ref': 'tree'}},
+        }
+    )
+    assert v.schema_repr() == {
+        'name': 'nullable[list[...]]',
+        'ref': 'tree',
+        'schema': {
+            'name': 'list[...]',
+            'strict': False,
+            'items_schema': {'name': 'nullable[list[...]]', 'ref': 'tree'},
+        },
+    }
+
+
+def test_float_allow_inf_nan():
+    assert SchemaValidator('float').schema_repr() == {'name': 'float', 'allow_inf_nan': False}
+    assert SchemaValidator({'type': 'float', 'allow_inf_nan': True}).schema_repr() == {
+        'name': 'float',
+        'allow_inf_nan': True,
+    }
+    assert SchemaValidator({'type': 'float', 'gt': 0}, {'allow_inf_nan': True}).schema_repr() == {
+        'name': 'constrained-float',
+        'strict': False,
+        'allow_inf_nan': True,
+        'gt': 0.0,
+    }
@@ -0,0 +1,350 @@
+import json
+from datetime import date, datetime, time, timedelta
+from decimal import Decimal
+from enum import Enum
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaSerializer, SchemaValidator
+
+
+class MyModel:
+    # this is not required, but it avoids `__fields_set__` being included in `__dict__`
+    __slots__ = '__dict__', '__fields_set__'
+
+
+MODEL_SCHEMA = {
+    'type': 'new-class',
+    'class_type': MyModel,
+    'schema': {
+        'type': 'typed-dict',
+        'return_fields_set': True,
+        'fields': {
+            'name': {'schema': 'str'},
+            'tags': {'schema': {'type': 'set', 'items_schema': 'str'}},
+            'created': {'schema': 'datetime'},
+            'scores': {'schema': {'type': 'dict', 'keys_schema': 'int', 'values_schema': 'float'}},
+            'location': {'schema': {'type': 'tuple', 'mode': 'positional', 'items_schema': ['float', 'float']}},
+            'duration': {'schema': {'type': 'nullable', 'schema': 'timedelta'}},
+            'data': {'schema': 'bytes'},
+        },
+    },
+}
+
+MODEL_INPUT = {
+    'name': 'foo',
+    'tags': ['a'],
+    'created': '2022-06-01T12:30:00',
+    'scores': {'1': 1.5, 2: '3'},
+    'location': ['1.5', 2],
+    'duration': 90.5,
+    'data': 'xyz',
+}
+
+
+def test_to_python():
+    v = SchemaValidator(MODEL_SCHEMA)
+    s = SchemaSerializer(MODEL_SCHEMA)
+    m = v.validate_python(MODEL_INPUT)
+    assert isinstance(m, MyModel)
+    assert s.to_python(m) == {
+        'name': 'foo',
+        'tags': {'a'},
+        'created': datetime(2022, 6, 1, 12, 30),
+        'scores': {1: 1.5, 2: 3.0},
+        'location': (1.5, 2.0),
+        'duration': timedelta(seconds=90, microseconds=500000),
+        'data': b'xyz',
+    }
+
+
+def test_to_jsonable_python():
+    v = SchemaValidator(MODEL_SCHEMA)
+    s = SchemaSerializer(MODEL_SCHEMA)
+    assert s.to_jsonable_python(v.validate_python(MODEL_INPUT)) == {
+        'name': 'foo',
+        'tags': ['a'],
+        'created': '2022-06-01T12:30:00',
+        'scores': {'1': 1.5, '2': 3.0},
+        'location': [1.5, 2.0],
+        'duration': 'PT90.5S',
+        'data': 'xyz',
+    }
+
+
+def test_json_roundtrip():
+    v = SchemaValidator(MODEL_SCHEMA)
+    s = SchemaSerializer(MODEL_SCHEMA)
+    m = v.validate_python(MODEL_INPUT)
+    json_output = s.to_json(m)
+    assert isinstance(json_output, bytes)
+    assert json.loads(json_output) == s.to_jsonable_python(m)
+    m2 = v.validate_json(json_output)
+    assert m2.__dict__ == m.__dict__
+    assert s.to_json(m2) == json_output
+
+
+def test_to_json_indent():
+    s = SchemaSerializer({'type': 'list', 'items_schema': 'int'})
+    assert s.to_json([1, 2]) == b'[1,2]'
+    assert s.to_json([1, 2], indent=2) == b'[\n  1,\n  2\n]'
+
+
+@pytest.mark.parametrize(
+    'value,expected',
+    [
+        (None, None),
+        (True, True),
+        (1, 1),
+        (1.5, 1.5),
+        ('foo', 'foo'),
+        (b'foo', 'foo'),
+        (date(2022, 1, 2), '2022-01-02'),
+        (time(12, 30, 1), '12:30:01'),
+        (datetime(2022, 1, 2, 12), '2022-01-02T12:00:00'),
+        (timedelta(0), 'PT0S'),
+        (timedelta(days=2), 'P2D'),
+        (timedelta(days=1, seconds=3, microseconds=4), 'P1DT3.000004S'),
+        (timedelta(seconds=-1.5), '-PT1.5S'),
+        (Decimal('1.23'), '1.23'),
+        ((1, 'a'), [1, 'a']),
+        (frozenset([1]), [1]),
+        ({1: 'a', None: 'b', 2.5: 'c'}, {'1': 'a', 'null': 'b', '2.5': 'c'}),
+        ({True: 'a', False: 'b'}, {'true': 'a', 'false': 'b'}),
+    ],
+)
+def test_any_jsonable(value, expected):
+    s = SchemaSerializer('any')
+    assert s.to_jsonable_python(value) == expected
+    assert s.to_python(value) == value
+
+
+@pytest.mark.parametrize('schema', ['timedelta', 'date', 'int', 'float', 'str', 'bytes'])
+def test_roundtrip_scalar(schema):
+    v = SchemaValidator(schema)
+    s = SchemaSerializer(schema)
+    value = v.validate_python({'timedelta': 'P1DT2.5S', 'date': '2022-01-01', 'bytes': 'x'}.get(schema, '123'))
+    assert v.validate_json(s.to_json(value)) == value
+
+
+def test_secret_bytes():
+    v = SchemaValidator({'type': 'secret-bytes'})
+    secret = v.validate_python(b'\x00secret')
+    s = SchemaSerializer('any')
+    assert s.to_python(secret) is secret
+    assert s.to_jsonable_python(secret) == 'AHNlY3JldA=='
+    assert json.loads(s.to_json({'key': secret})) == {'key': 'AHNlY3JldA=='}
+
+
+def test_timedelta_float_config():
+    s = SchemaSerializer('timedelta', {'ser_json_timedelta': 'float'})
+    assert s.to_jsonable_python(timedelta(minutes=1, microseconds=500000)) == 60.5
+    assert s.to_python(timedelta(minutes=1)) == timedelta(minutes=1)
+
+
+def test_enum():
+    class Colour(Enum):
+        red = 'r'
+
+    s = SchemaSerializer('any')
+    assert s.to_json([Colour.red]) == b'["r"]'
+    assert s.to_python([Colour.red]) == [Colour.red]
+
+
+def test_recursive():
+    schema = {
+        'type': 'typed-dict',
+        'ref': 'Branch',
+        'fields': {
+            'width': {'schema': 'int'},
+            'branch': {
+                'schema': {'type': 'nullable', 'schema': {'type': 'recursive-ref', 'schema_ref': 'Branch'}},
+                'default': None,
+            },
+        },
+    }
+    v = SchemaValidator(schema)
+    s = SchemaSerializer(schema)
+    output = v.validate_python({'width': '1', 'branch': {'width': 2, 'branch': {'width': 3}}})
+    json_output = s.to_json(output)
+    assert json.loads(json_output) == {'width': 1, 'branch': {'width': 2, 'branch': {'width': 3, 'branch': None}}}
+    assert v.validate_json(json_output) == output
+
+
+def test_circular_reference():
+    s = SchemaSerializer({'type': 'list', 'items_schema': 'any'})
+    value = []
+    value.append(value)
+    with pytest.raises(ValueError, match=r'Circular reference detected \(id repeated\)'):
+        s.to_python(value)
+
+
+def test_repeated_value_not_circular():
+    s = SchemaSerializer('any')
+    item = [1]
+    assert s.to_jsonable_python([item, item]) == [[1], [1]]
+
+
+def test_unknown_type():
+    s = SchemaSerializer('any')
+    assert s.to_python([object]) == [object]
+    with pytest.raises(ValueError, match='Unable to serialize unknown type: type'):
+        s.to_json([object])
+
+
+def test_invalid_utf8_bytes():
+    s = SchemaSerializer('bytes')
+    with pytest.raises(ValueError, match="bytes are not valid UTF-8 and can't be serialized to JSON"):
+        s.to_json(b'\x81')
+
+
+def test_invalid_schema():
+    with pytest.raises(SchemaError, match="Input tag 'wrong' found using self-schema does not match"):
+        SchemaSerializer({'type': 'wrong'})
+
+
+def test_invalid_config():
+    with pytest.raises(SchemaError, match='Invalid ser_json_timedelta: "seconds"'):
+        SchemaSerializer('timedelta', {'ser_json_timedelta': 'seconds'})
+
+
+def test_plain_serializer():
+    calls = []
+
+    def iso(value, info):
+        calls.append(info.mode)
+        return value.isoformat()
+
+    schema = {
+        'type': 'typed-dict',
+        'fields': {
+            'name': {'schema': 'str'},
+            'created': {
+                'schema': {
+                    'type': 'datetime',
+                    'serialization': {'type': 'function-plain', 'function': iso, 'return_type': 'str'},
+                }
+            },
+        },
+    }
+    v = SchemaValidator(schema)
+    output = v.validate_python({'name': 'foo', 'created': '2022-06-01T12:30:00'})
+    assert output == {'name': 'foo', 'created': datetime(2022, 6, 1, 12, 30)}
+
+    s = SchemaSerializer(schema)
+    assert s.to_json(output) == b'{"name":"foo","created":"2022-06-01T12:30:00"}'
+    assert s.to_python(output) == {'name': 'foo', 'created': '2022-06-01T12:30:00'}
+    assert calls == ['json', 'python']
+
+
+def test_plain_serializer_info():
+    s = SchemaSerializer({'type': 'int', 'serialization': {'type': 'function-plain', 'function': lambda v, info: info}})
+    assert repr(s.to_python(1)) == "SerializationInfo(mode='python')"
+    assert s.to_python(1).mode == 'python'
+
+
+@pytest.mark.parametrize(
+    'return_type,output,expected',
+    [
+        ('any', {'a': date(2022, 1, 1)}, {'a': '2022-01-01'}),
+        ('str', 'foo', 'foo'),
+        # the output doesn't match `return_type`, so it's serialized based on its type
+        ('str', date(2022, 1, 1), '2022-01-01'),
+        ('int', 1, 1),
+        ('float', 1.5, 1.5),
+        ('bool', True, True),
+        ('none', None, None),
+        ('bytes', b'foo', 'foo'),
+        ('datetime', datetime(2022, 1, 1, 12), '2022-01-01T12:00:00'),
+        ('date', date(2022, 1, 1), '2022-01-01'),
+        ('time', time(12, 30), '12:30:00'),
+        ('timedelta', timedelta(seconds=90), 'PT90S'),
+    ],
+)
+def test_plain_serializer_return_type(return_type, output, expected):
+    ser_schema = {'type': 'function-plain', 'function': lambda v, info: output, 'return_type': return_type}
+    s = SchemaSerializer({'type': 'int', 'serialization': ser_schema})
+    assert s.to_jsonable_python(1) == expected
+    assert s.to_python(1) == output
+
+
+def test_wrap_serializer():
+    def f(value, serializer, info):
+        return {'count': len(value), 'items': serializer(value)}
+
+    schema = {
+        'type': 'list',
+        'items_schema': 'date',
+        'serialization': {'type': 'function-wrap', 'function': f},
+    }
+    s = SchemaSerializer(schema)
+    value = [date(2022, 1, 1), date(2022, 1, 2)]
+    assert s.to_python(value) == {'count': 2, 'items': value}
+    assert json.loads(s.to_json(value)) == {'count': 2, 'items': ['2022-01-01', '2022-01-02']}
+
+
+def test_wrap_serializer_nested():
+    def upper(value, serializer, info):
+        return serializer(value).upper()
+
+    schema = {
+        'type': 'typed-dict',
+        'fields': {
+            'name': {'schema': {'type': 'str', 'serialization': {'type': 'function-wrap', 'function': upper}}},
+            'tags': {
+                'schema': {
+                    'type': 'list',
+                    'items_schema': {'type': 'str', 'serialization': {'type': 'function-wrap', 'function': upper}},
+                }
+            },
+        },
+    }
+    s = SchemaSerializer(schema)
+    assert s.to_jsonable_python({'name': 'foo', 'tags': ['a', 'b']}) == {'name': 'FOO', 'tags': ['A', 'B']}
+
+
+def test_wrap_serializer_circular():
+    s = SchemaSerializer(
+        {
+            'type': 'list',
+            'items_schema': 'any',
+            'serialization': {'type': 'function-wrap', 'function': lambda v, serializer, info: serializer(v)},
+        }
+    )
+    value = []
+    value.append(value)
+    with pytest.raises(ValueError, match=r'Circular reference detected \(id repeated\)'):
+        s.to_python(value)
+
+
+def test_wrap_serializer_kept():
+    serializers = []
+
+    def f(value, serializer, info):
+        serializers.append(serializer)
+        return serializer(value)
+
+    s = SchemaSerializer(
+        {'type': 'list', 'items_schema': 'date', 'serialization': {'type': 'function-wrap', 'function': f}}
+    )
+    assert s.to_jsonable_python([date(2022, 1, 1)]) == ['2022-01-01']
+    # the callable can still be used once the serializer has returned
+    assert serializers[0]([date(2022, 1, 2)]) == ['2022-01-02']
+    value = []
+    value.append(value)
+    with pytest.raises(ValueError, match=r'Circular reference detected \(id repeated\)'):
+        s.to_jsonable_python([value])
+
+
+def test_serializer_function_error():
+    def f(value, info):
+        raise RuntimeError('boom')
+
+    s = SchemaSerializer({'type': 'int', 'serialization': {'type': 'function-plain', 'function': f}})
+    with pytest.raises(RuntimeError, match='^boom$'):
+        s.to_json(1)
+
+
+def test_invalid_ser_schema():
+    with pytest.raises(SchemaError, match='int -> serialization'):
+        SchemaSerializer({'type': 'int', 'serialization': {'type': 'function-plain', 'function': 1}})
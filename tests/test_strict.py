@@ -2,7 +2,7 @@ import re
 
 import pytest
 
-from pydantic_core import ValidationError
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
 
 from .conftest import Err, PyAndJson
 
@@ -15,7 +15,7 @@ from .conftest import Err, PyAndJson
         (None, 123, 123),
         (None, '123', 123),
         (True, 123, 123),
-        (True, '123', Err('Input should be a valid integer [kind=int_type')),
+        (True, '123', Err('int_type  Input should be a valid integer')),
     ],
 )
 def test_int_strict_argument(py_and_json: PyAndJson, strict, input_value, expected):
@@ -27,3 +27,64 @@ def test_int_strict_argument(py_and_json: PyAndJson, strict, input_value, expect
     else:
         assert v.isinstance_test(input_value, strict) is True
         assert v.validate_test(input_value, strict) == expected
+
+
+@pytest.mark.parametrize(
+    'shorthand,base_type,input_value',
+    [
+        ('strict-str', 'str', 'foo'),
+        ('strict-str', 'str', 123),
+        ('strict-str', 'str', b'foo'),
+        ('strict-int', 'int', 123),
+        ('strict-int', 'int', '123'),
+        ('strict-int', 'int', 1.0),
+        ('strict-float', 'float', 1.5),
+        ('strict-float', 'float', 1),
+        ('strict-float', 'float', '1.5'),
+        ('strict-bool', 'bool', True),
+        ('strict-bool', 'bool', 'true'),
+        ('strict-bool', 'bool', 1),
+        ('strict-bytes', 'bytes', b'foo'),
+        ('strict-bytes', 'bytes', 'foo'),
+    ],
+)
+@pytest.mark.parametrize('config', [None, {'strict': False}])
+def test_strict_shorthand(shorthand, base_type, input_value, config):
+    v_shorthand = SchemaValidator(shorthand, config)
+    v_long_form = SchemaValidator({'type': base_type, 'strict': True})
+    try:
+        expected = v_long_form.validate_python(input_value)
+    except ValidationError as e:
+        with pytest.raises(ValidationError) as exc_info:
+            v_shorthand.validate_python(input_value)
+        assert exc_info.value.errors(include_url=False) == e.errors(include_url=False)
+    else:
+        assert v_shorthand.validate_python(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'shorthand,input_value',
+    [('strict-str', 123), ('strict-int', '1'), ('strict-float', '1.5'), ('strict-bool', 'true'), ('strict-bytes', 'x')],
+)
+def test_strict_shorthand_strict_false(shorthand, input_value):
+    v = SchemaValidator({'type': 'typed-dict', 'fields': {'x': {'schema': shorthand}}})
+    error = rf'x +{shorthand[7:]}_type  '
+    with pytest.raises(ValidationError, match=error):
+        v.validate_python({'x': input_value}, strict=False)
+    with pytest.raises(ValidationError, match=error):
+        v.validate_strings({'x': input_value})
+    assert v.isinstance_python({'x': input_value}, strict=False) is False
+
+
+def test_strict_shorthand_constraints():
+    v = SchemaValidator({'type': 'strict-int', 'ge': 0}, {'strict': False})
+    assert v.validate_json('1') == 1
+    with pytest.raises(ValidationError, match='Input should be greater than or equal to 0'):
+        v.validate_json('-1')
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_json('"1"')
+
+
+def test_strict_shorthand_no_strict_key():
+    with pytest.raises(SchemaError, match='strict-str -> strict\n  Extra inputs are not permitted'):
+        SchemaValidator({'type': 'strict-str', 'strict': False})
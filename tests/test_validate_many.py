@@ -0,0 +1,112 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, __version__, validate_many
+
+minor_version = '.'.join(__version__.split('.')[:2])
+
+
+def test_validate_many():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'})
+    results, errors = v.validate_many([[1, '2'], 'x', [3, 'y'], []])
+    assert results == [[1, 2], None, None, []]
+    assert errors == [
+        None,
+        [
+            {
+                'kind': 'list_type',
+                'loc': [],
+                'message': 'Input should be a valid list/array, expected list[int]',
+                'input_value': 'x',
+                'context': {'expected': 'list[int]'},
+                'url': f'https://errors.pydantic.dev/{minor_version}/v/list_type',
+            }
+        ],
+        [
+            {
+                'kind': 'int_parsing',
+                'loc': [1],
+                'message': 'Input should be a valid integer, unable to parse string as an integer',
+                'input_value': 'y',
+                'url': f'https://errors.pydantic.dev/{minor_version}/v/int_parsing',
+            }
+        ],
+        None,
+    ]
+
+
+def test_validate_many_matches_validate_python():
+    v = SchemaValidator({'type': 'typed-dict', 'fields': {'a': {'schema': 'int'}, 'b': {'schema': 'str'}}})
+    inputs = [{'a': 1, 'b': 'x'}, {'a': 'x'}, {}]
+    results, errors = v.validate_many(inputs)
+    assert results[0] == v.validate_python(inputs[0])
+    for input_value, error in zip(inputs[1:], errors[1:]):
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(input_value)
+        assert error == exc_info.value.errors()
+
+
+def test_validate_many_generator():
+    v = SchemaValidator('int')
+    assert v.validate_many(str(i) for i in range(3)) == ([0, 1, 2], [None, None, None])
+
+
+def test_validate_many_empty():
+    assert SchemaValidator('int').validate_many([]) == ([], [])
+
+
+def test_validate_many_not_iterable():
+    with pytest.raises(TypeError, match="'int' object is not iterable"):
+        SchemaValidator('int').validate_many(1)
+
+
+def test_validate_many_strict_context():
+    def f(input_value, *, context, **kwargs):
+        context.append(input_value)
+        return input_value
+
+    v = SchemaValidator({'type': 'function', 'mode': 'after', 'function': f, 'schema': 'int'})
+    context = []
+    results, errors = v.validate_many([1, '2'], strict=True, context=context)
+    assert results == [1, None]
+    assert errors[1][0]['kind'] == 'int_type'
+    assert context == [1]
+
+
+def test_validate_many_internal_error():
+    def f(input_value, **kwargs):
+        raise RuntimeError('boom')
+
+    v = SchemaValidator({'type': 'function', 'mode': 'plain', 'function': f})
+    with pytest.raises(RuntimeError, match='^boom$'):
+        v.validate_many([1, 2])
+
+
+def test_validate_many_hide_input():
+    v = SchemaValidator('int', {'hide_input_in_errors': True})
+    _, errors = v.validate_many(['x'])
+    assert 'input_value' not in errors[0][0]
+
+
+def test_validate_many_function():
+    assert validate_many({'type': 'str', 'max_length': 2}, ['a', 'abc']) == (
+        ['a', None],
+        [
+            None,
+            [
+                {
+                    'kind': 'too_long',
+                    'loc': [],
+                    'message': 'String should have at most 2 characters',
+                    'input_value': 'abc',
+                    'context': {'max_length': 2},
+                    'url': f'https://errors.pydantic.dev/{minor_version}/v/too_long',
+                }
+            ],
+        ],
+    )
+
+
+def test_validate_many_function_config():
+    results, errors = validate_many('str', [b'a', 1], {'strict': True})
+    assert results == [None, None]
+    assert [e[0]['kind'] for e in errors] == ['str_type', 'str_type']
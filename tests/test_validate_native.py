@@ -0,0 +1,116 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, validate_native
+
+
+def validate(f, input_value):
+    try:
+        return 'ok', f(input_value)
+    except ValidationError as exc:
+        return 'error', exc.errors()
+
+
+@pytest.mark.parametrize(
+    'schema',
+    [
+        'int',
+        'float',
+        'str',
+        'bool',
+        'bytes',
+        {'type': 'int', 'strict': True},
+        {'type': 'list', 'items_schema': 'int'},
+        {'type': 'set', 'items_schema': 'int'},
+        {'type': 'tuple', 'mode': 'positional', 'items_schema': ['int', 'str']},
+        {'type': 'dict', 'keys_schema': 'int', 'values_schema': 'float'},
+        {'type': 'dict', 'keys_schema': 'str', 'values_schema': 'int', 'min_items': 1},
+        {'type': 'dict', 'key_transform': 'lower', 'values_schema': 'int'},
+        {'type': 'typed-dict', 'fields': {'a': {'schema': 'int'}, 'b': {'schema': 'str', 'default': 'x'}}},
+        {'type': 'typed-dict', 'fields': {'a': {'schema': 'int', 'alias': [['x', 1], ['a']]}}},
+        {'type': 'union', 'choices': ['int', 'str']},
+        {'type': 'chain-map', 'schema': {'type': 'typed-dict', 'fields': {'a': {'schema': 'int'}}}},
+    ],
+)
+@pytest.mark.parametrize(
+    'input_value',
+    [None, True, 1, 1.5, '', '1', 'a', b'1', [], [1, '2'], {}, {'a': 1}, {1: '2.5'}, {'A': 1, 'a': 2}, {'x': [0, 5]}],
+)
+def test_matches_validate_python(schema, input_value):
+    v = SchemaValidator(schema)
+    assert validate(v.validate_native, input_value) == validate(v.validate_python, input_value)
+
+
+def test_dict_int_keys():
+    v = SchemaValidator({'type': 'dict', 'keys_schema': 'str', 'values_schema': 'int'})
+    assert v.validate_native({'a': '1', 'b': 2}) == {'a': 1, 'b': 2}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_native({1: 2})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'str_type',
+            'loc': [1, '[key]'],
+            'message': 'Input should be a valid string',
+            'input_value': 1,
+        }
+    ]
+
+
+def test_key_transform_duplicate():
+    v = SchemaValidator({'type': 'dict', 'key_transform': 'lower'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_native({'A': 1, 'a': 2})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'dict_duplicate_key',
+            'loc': ['a'],
+            'message': "Dictionary keys should be unique after key_transform, 'a' is a duplicate",
+            'input_value': 'a',
+            'context': {'key': 'a'},
+        }
+    ]
+
+
+def test_tagged_union():
+    v = SchemaValidator(
+        {
+            'type': 'tagged-union',
+            'discriminator': 'kind',
+            'choices': {'x': {'type': 'typed-dict', 'fields': {'kind': {'schema': 'str'}, 'v': {'schema': 'int'}}}},
+        }
+    )
+    assert v.validate_native({'kind': 'x', 'v': '3'}) == {'kind': 'x', 'v': 3}
+    assert validate(v.validate_native, {'kind': 'y'}) == validate(v.validate_python, {'kind': 'y'})
+
+
+def test_strict():
+    v = SchemaValidator('int')
+    assert v.validate_native('1') == 1
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_native('1', strict=True)
+
+
+@pytest.mark.parametrize('input_value', [(1, 2), {1, 2}, object(), [1, (2,)], {'a': 1j}])
+def test_unsupported_input(input_value):
+    v = SchemaValidator('any')
+    with pytest.raises(TypeError, match=r'^Unable to convert \w+ to a native value, expected None, bool'):
+        v.validate_native(input_value)
+
+
+def test_validate_native_function():
+    assert validate_native({'type': 'list', 'items_schema': 'int'}, ['1', 2]) == [1, 2]
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        validate_native('int', '1', {'strict': True})
+
+
+def test_int_too_large():
+    v = SchemaValidator('int')
+    assert validate(v.validate_native, 2**64) == validate(v.validate_python, 2**64)
+    assert validate(v.validate_native, 2**64)[1][0]['kind'] == 'int_too_large'
+    assert SchemaValidator('float').validate_native(2**64) == 2.0**64
+
+
+def test_int_float_keys():
+    # `2**53 + 1` isn't equal to `2.0**53`, even though it is as a float
+    v = SchemaValidator({'type': 'dict', 'key_transform': 'lower'})
+    input_value = {2**53 + 1: 'a', 2.0**53: 'b', 1: 'c'}
+    assert v.validate_native(input_value) == v.validate_python(input_value) == input_value
@@ -0,0 +1,66 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError
+
+
+def test_collect():
+    v = SchemaValidator({'type': 'dict', 'keys_schema': 'str', 'values_schema': 'int'})
+    items = []
+    output = v.validate_python_streaming({'a': '1', 'b': 2}, lambda k, v: items.append((k, v)))
+    assert output == {'a': 1, 'b': 2}
+    assert items == [('a', 1), ('b', 2)]
+
+
+def test_consume():
+    v = SchemaValidator({'type': 'dict', 'keys_schema': 'str', 'values_schema': 'int'})
+    items = []
+    assert v.validate_python_streaming({'a': '1', 'b': 2}, lambda k, v: items.append((k, v)), consume=True) is None
+    assert items == [('a', 1), ('b', 2)]
+
+
+def test_consume_large():
+    v = SchemaValidator({'type': 'dict', 'keys_schema': 'int', 'values_schema': 'int'})
+    total = 0
+
+    def callback(key, value):
+        nonlocal total
+        total += value
+
+    assert v.validate_python_streaming({i: str(i) for i in range(10_000)}, callback, consume=True) is None
+    assert total == sum(range(10_000))
+
+
+def test_errors():
+    v = SchemaValidator({'type': 'dict', 'keys_schema': 'str', 'values_schema': 'int'})
+    items = []
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python_streaming({'a': 1, 'b': 'x', 'c': 3}, lambda k, v: items.append((k, v)), consume=True)
+    assert [e['loc'] for e in exc_info.value.errors()] == [['b']]
+    # valid entries have already been passed to the callback
+    assert items == [('a', 1), ('c', 3)]
+
+
+def test_nested_dicts_not_streamed():
+    v = SchemaValidator(
+        {'type': 'dict', 'keys_schema': 'str', 'values_schema': {'type': 'dict', 'values_schema': 'int'}}
+    )
+    items = []
+    output = v.validate_python_streaming({'a': {'x': '1'}, 'b': {}}, lambda k, v: items.append((k, v)))
+    assert output == {'a': {'x': 1}, 'b': {}}
+    assert items == [('a', {'x': 1}), ('b', {})]
+
+
+def test_callback_error():
+    v = SchemaValidator({'type': 'dict'})
+
+    def callback(key, value):
+        raise RuntimeError('disk full')
+
+    with pytest.raises(RuntimeError, match='disk full'):
+        v.validate_python_streaming({'a': 1}, callback)
+
+
+def test_not_dict():
+    v = SchemaValidator({'type': 'list'})
+    with pytest.raises(TypeError, match='validate_python_streaming requires a validator with a dict schema'):
+        v.validate_python_streaming([], print)
@@ -0,0 +1,111 @@
+from datetime import date
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError
+
+LOGIN_FORM = {
+    'type': 'typed-dict',
+    'fields': {
+        'username': {'schema': 'str'},
+        'password': {'schema': 'str'},
+        'age': {'schema': {'type': 'int', 'ge': 18}},
+        'date_of_birth': {'schema': 'date'},
+        'remember_me': {'schema': 'bool', 'default': False},
+        'interests': {'schema': {'type': 'list', 'items_schema': 'str'}, 'default': []},
+    },
+}
+
+
+def test_login_form():
+    v = SchemaValidator(LOGIN_FORM)
+    form = {'username': 'alice', 'password': 'hunter2', 'age': '30', 'date_of_birth': '1992-05-17', 'remember_me': 'on'}
+    assert v.validate_strings(form) == {
+        'username': 'alice',
+        'password': 'hunter2',
+        'age': 30,
+        'date_of_birth': date(1992, 5, 17),
+        'remember_me': True,
+        'interests': [],
+    }
+
+
+def test_login_form_errors():
+    v = SchemaValidator(LOGIN_FORM)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_strings({'username': 'bob', 'password': 'x', 'age': '12', 'date_of_birth': '1992-13-01'})
+    assert [(e['kind'], e['loc']) for e in exc_info.value.errors()] == [
+        ('greater_than_equal', ['age']),
+        ('date_from_datetime_parsing', ['date_of_birth']),
+    ]
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('music,chess', ['music', 'chess']),
+        ('music, chess ', ['music', 'chess']),
+        ('music', ['music']),
+        ('', []),
+        (['music', 'chess'], ['music', 'chess']),
+    ],
+)
+def test_comma_separated_list(input_value, expected):
+    v = SchemaValidator({'type': 'list', 'items_schema': 'str'})
+    assert v.validate_strings(input_value) == expected
+
+
+def test_comma_separated_items_validated():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int', 'max_items': 3})
+    assert v.validate_strings('1,2,3') == [1, 2, 3]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_strings('1,x')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'int_parsing',
+            'loc': [1],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'x',
+        }
+    ]
+    with pytest.raises(ValidationError, match='Input should have at most 3 items, got 4 items'):
+        v.validate_strings('1,2,3,4')
+
+
+def test_strings_not_split_by_validate_python():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'str'})
+    with pytest.raises(ValidationError, match='Input should be a valid list'):
+        v.validate_python('a,b')
+
+
+def test_strings_not_split_by_validate_json():
+    v = SchemaValidator({'type': 'dict', 'values_schema': {'type': 'list', 'items_schema': 'str'}})
+    assert v.validate_strings({'x': 'a,b'}) == {'x': ['a', 'b']}
+    with pytest.raises(ValidationError, match='Input should be a valid list'):
+        v.validate_json('{"x": "a,b"}')
+    assert v.isinstance_json('{"x": ["a", "b"]}') is True
+    assert v.isinstance_json('{"x": "a,b"}') is False
+
+
+def test_strict_ignored():
+    v = SchemaValidator({'type': 'typed-dict', 'fields': {'age': {'schema': 'int'}}}, {'strict': True})
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python({'age': '30'})
+    assert v.validate_strings({'age': '30'}) == {'age': 30}
+
+
+def test_lenient_coercion_config_ignored():
+    v = SchemaValidator('int', {'lenient_coercion': {'coerce_str_to_int': False}})
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python('1')
+    assert v.validate_strings('1') == 1
+
+
+def test_nested_function_validator():
+    def f(input_value, *, validator, **kwargs):
+        return validator(input_value)
+
+    v = SchemaValidator(
+        {'type': 'function', 'mode': 'wrap', 'function': f, 'schema': {'type': 'list', 'items_schema': 'int'}}
+    )
+    assert v.validate_strings('1,2') == [1, 2]
@@ -0,0 +1,75 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError
+
+
+def test_extra_ignored():
+    v = SchemaValidator({'type': 'typed-dict', 'fields': {'a': {'schema': 'int'}}})
+    assert v.validate_python({'a': 1, 'b': 2}) == {'a': 1}
+    assert v.validate_python_with_warnings({'a': '1', 'b': 2, 'c': 'x'}) == (
+        {'a': 1},
+        [
+            {'kind': 'extra_ignored', 'loc': ['b'], 'message': 'Extra input was ignored', 'input_value': 2},
+            {'kind': 'extra_ignored', 'loc': ['c'], 'message': 'Extra input was ignored', 'input_value': 'x'},
+        ],
+    )
+    assert v.validate_python_with_warnings({'a': 1}) == ({'a': 1}, [])
+
+
+@pytest.mark.parametrize('extra_behavior', ['allow', 'forbid'])
+def test_no_warnings(extra_behavior):
+    v = SchemaValidator({'type': 'typed-dict', 'fields': {'a': {'schema': 'int'}}, 'extra_behavior': extra_behavior})
+    assert v.validate_python_with_warnings({'a': 1}) == ({'a': 1}, [])
+
+
+def test_nested_location():
+    inner = {'type': 'typed-dict', 'fields': {'x': {'schema': 'int'}}}
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'items': {'schema': {'type': 'list', 'items_schema': inner}},
+                'by_key': {'schema': {'type': 'dict', 'values_schema': inner}},
+                'pair': {'schema': {'type': 'tuple', 'mode': 'positional', 'items_schema': ['int', inner]}},
+            },
+        }
+    )
+    output, warnings = v.validate_python_with_warnings(
+        {'items': [{'x': 1}, {'x': 2, 'y': 3}], 'by_key': {'k': {'x': 1, 'z': 4}}, 'pair': (1, {'x': 1, 'w': 5})}
+    )
+    assert output == {'items': [{'x': 1}, {'x': 2}], 'by_key': {'k': {'x': 1}}, 'pair': (1, {'x': 1})}
+    assert [(w['loc'], w['input_value']) for w in warnings] == [
+        (['items', 1, 'y'], 3),
+        (['by_key', 'k', 'z'], 4),
+        (['pair', 1, 'w'], 5),
+    ]
+
+
+def test_failed_union_choice_warnings_discarded():
+    v = SchemaValidator(
+        {
+            'type': 'union',
+            'choices': [
+                {'type': 'typed-dict', 'fields': {'a': {'schema': 'int'}, 'b': {'schema': 'int'}}},
+                {'type': 'typed-dict', 'fields': {'a': {'schema': 'str'}}},
+            ],
+        }
+    )
+    output, warnings = v.validate_python_with_warnings({'a': 'x', 'c': 1})
+    assert output == {'a': 'x'}
+    # only the warning from the second choice, the first choice failed
+    assert [w['loc'] for w in warnings] == [['c']]
+
+
+def test_errors_still_raised():
+    v = SchemaValidator({'type': 'typed-dict', 'fields': {'a': {'schema': 'int'}}})
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python_with_warnings({'a': 'x', 'b': 2})
+
+
+def test_hide_input():
+    v = SchemaValidator({'type': 'typed-dict', 'fields': {}}, {'hide_input_in_errors': True})
+    assert v.validate_python_with_warnings({'a': 1}) == (
+        {},
+        [{'kind': 'extra_ignored', 'loc': ['a'], 'message': 'Extra input was ignored'}],
+    )
@@ -0,0 +1,47 @@
+import pytest
+
+from pydantic_core import SchemaValidator
+
+from ..conftest import PyAndJson, plain_repr
+
+
+class Foo:
+    pass
+
+
+@pytest.mark.parametrize('input_value', [None, 1, 'foo', b'bar', [1, 2], {'a': 1}, Foo(), Foo, object()])
+def test_any(input_value):
+    v = SchemaValidator('any')
+    assert v.validate_python(input_value) is input_value
+    # strict is a no-op
+    assert v.validate_python(input_value, strict=True) is input_value
+    assert SchemaValidator({'type': 'any'}).validate_python(input_value) is input_value
+
+
+def test_any_json(py_and_json: PyAndJson):
+    v = py_and_json('any')
+    assert v.validate_test([1, 'a', None, {'b': 2.5}]) == [1, 'a', None, {'b': 2.5}]
+
+
+def test_any_union():
+    v = SchemaValidator({'type': 'union', 'choices': ['int', 'any']})
+    assert v.validate_python(1) == 1
+    # 'any' matches in the strict first pass, so lax coercion to int never happens
+    assert v.validate_python('1') == '1'
+    foo = Foo()
+    assert v.validate_python(foo) is foo
+
+
+def test_any_function_wrap():
+    def f(input_value, *, validator, **kwargs):
+        return ('wrapped', validator(input_value))
+
+    v = SchemaValidator({'type': 'function', 'mode': 'wrap', 'function': f, 'schema': 'any'})
+    foo = Foo()
+    assert v.validate_python(foo) == ('wrapped', foo)
+
+
+def test_any_repr():
+    assert plain_repr(SchemaValidator('any')) == 'SchemaValidator(name="any",validator=Any(AnyValidator))'
+    v = SchemaValidator({'type': 'union', 'choices': ['int', 'any']})
+    assert plain_repr(v).startswith('SchemaValidator(name="union[int,any]"')
@@ -19,10 +19,10 @@ from ..conftest import Err, PyAndJson, plain_repr
         [((1, 'a', True), {}), ((1, 'a', True), {})],
         [([1, 'a', True], None), ((1, 'a', True), {})],
         [((1, 'a', 'true'), None), ((1, 'a', True), {})],
-        ['x', Err('kind=arguments_type,')],
-        [((1, 'a', True), ()), Err('kind=arguments_type,')],
-        [(4, {}), Err('kind=arguments_type,')],
-        [(1, 2, 3), Err('kind=arguments_type,')],
+        ['x', Err('arguments_type')],
+        [((1, 'a', True), ()), Err('arguments_type')],
+        [(4, {}), Err('arguments_type')],
+        [(1, 2, 3), Err('arguments_type')],
         [
             ([1, 'a', True], {'x': 1}),
             Err(
@@ -156,11 +156,11 @@ def test_positional_args(py_and_json: PyAndJson, input_value, expected):
             v.validate_test(input_value)
         # debug(exc_info.value.errors())
         if expected.errors is not None:
-            assert exc_info.value.errors() == expected.errors
+            assert exc_info.value.errors(include_url=False) == expected.errors
     else:
         assert v.validate_test(input_value) == expected
 
-    with pytest.raises(ValidationError, match='kind=arguments_type,'):
+    with pytest.raises(ValidationError, match='arguments_type'):
         # lists are not allowed from python, but no equivalent restriction in JSON
         v.validate_python([(1, 'a', True), None])
 
@@ -172,11 +172,11 @@ def test_positional_args(py_and_json: PyAndJson, input_value, expected):
         [{'a': 1, 'b': 'a', 'c': True}, ((), {'a': 1, 'b': 'a', 'c': True})],
         [(None, {'a': '1', 'b': 'a', 'c': 'True'}), ((), {'a': 1, 'b': 'a', 'c': True})],
         [((), {'a': 1, 'b': 'a', 'c': True}), ((), {'a': 1, 'b': 'a', 'c': True})],
-        [((1,), {'a': 1, 'b': 'a', 'c': True}), Err('kind=unexpected_positional_argument,')],
+        [((1,), {'a': 1, 'b': 'a', 'c': True}), Err('unexpected_positional_argument')],
         [
             ((), {'a': 1, 'b': 'a', 'c': True, 'd': 'wrong'}),
             Err(
-                'kind=unexpected_keyword_argument,',
+                'unexpected_keyword_argument',
                 [
                     {
                         'kind': 'unexpected_keyword_argument',
@@ -190,7 +190,7 @@ def test_positional_args(py_and_json: PyAndJson, input_value, expected):
         [
             ([], {'a': 1, 'b': 'a'}),
             Err(
-                'kind=missing_keyword_argument,',
+                'missing_keyword_argument',
                 [
                     {
                         'kind': 'missing_keyword_argument',
@@ -266,7 +266,7 @@ def test_keyword_args(py_and_json: PyAndJson, input_value, expected):
             v.validate_test(input_value)
         # debug(exc_info.value.errors())
         if expected.errors is not None:
-            assert exc_info.value.errors() == expected.errors
+            assert exc_info.value.errors(include_url=False) == expected.errors
     else:
         assert v.validate_test(input_value) == expected
 
@@ -280,7 +280,7 @@ def test_keyword_args(py_and_json: PyAndJson, input_value, expected):
         [
             ((1,), {'a': 11, 'b': 'bb', 'c': True}),
             Err(
-                'kind=multiple_argument_values,',
+                'multiple_argument_values',
                 [
                     {
                         'kind': 'multiple_argument_values',
@@ -294,7 +294,7 @@ def test_keyword_args(py_and_json: PyAndJson, input_value, expected):
         [
             ([1, 'bb', 'cc'], {'b': 'bb', 'c': True}),
             Err(
-                'kind=unexpected_positional_argument,',
+                'unexpected_positional_argument',
                 [
                     {
                         'kind': 'multiple_argument_values',
@@ -314,7 +314,7 @@ def test_keyword_args(py_and_json: PyAndJson, input_value, expected):
         [
             ((1, 'b1'), {'a': 11, 'b': 'b2', 'c': True}),
             Err(
-                'kind=multiple_argument_values,',
+                'multiple_argument_values',
                 [
                     {
                         'kind': 'multiple_argument_values',
@@ -350,7 +350,7 @@ def test_positional_or_keyword(py_and_json: PyAndJson, input_value, expected):
             v.validate_test(input_value)
         # debug(exc_info.value.errors())
         if expected.errors is not None:
-            assert exc_info.value.errors() == expected.errors
+            assert exc_info.value.errors(include_url=False) == expected.errors
     else:
         assert v.validate_test(input_value) == expected
 
@@ -368,7 +368,7 @@ def test_positional_optional(py_and_json: PyAndJson, input_value, expected):
             v.validate_test(input_value)
         # debug(exc_info.value.errors())
         if expected.errors is not None:
-            assert exc_info.value.errors() == expected.errors
+            assert exc_info.value.errors(include_url=False) == expected.errors
     else:
         assert v.validate_test(input_value) == expected
 
@@ -395,7 +395,7 @@ def test_p_or_k_optional(py_and_json: PyAndJson, input_value, expected):
             v.validate_test(input_value)
         # debug(exc_info.value.errors())
         if expected.errors is not None:
-            assert exc_info.value.errors() == expected.errors
+            assert exc_info.value.errors(include_url=False) == expected.errors
     else:
         assert v.validate_test(input_value) == expected
 
@@ -407,7 +407,7 @@ def test_p_or_k_optional(py_and_json: PyAndJson, input_value, expected):
         [([1], None), ((1,), {})],
         [([], None), ((), {})],
         [([], {}), ((), {})],
-        [([1, 2, 3], {'a': 1}), Err('a\n  Unexpected keyword argument [kind=unexpected_keyword_argument,')],
+        [([1, 2, 3], {'a': 1}), Err('a         unexpected_keyword_argument  Unexpected keyword argument')],
     ],
     ids=repr,
 )
@@ -418,7 +418,7 @@ def test_var_args_only(py_and_json: PyAndJson, input_value, expected):
             v.validate_test(input_value)
         # debug(exc_info.value.errors())
         if expected.errors is not None:
-            assert exc_info.value.errors() == expected.errors
+            assert exc_info.value.errors(include_url=False) == expected.errors
     else:
         assert v.validate_test(input_value) == expected
 
@@ -429,11 +429,11 @@ def test_var_args_only(py_and_json: PyAndJson, input_value, expected):
         [([1, 2, 3], None), ((1, 2, 3), {})],
         [(['1', '2', '3'], None), ((1, 2, 3), {})],
         [([1], None), ((1,), {})],
-        [([], None), Err('0\n  Missing required positional argument')],
+        [([], None), Err('0         missing_positional_argument  Missing required positional argument')],
         [
             (['x'], None),
             Err(
-                'kind=int_parsing,',
+                'int_parsing',
                 [
                     {
                         'kind': 'int_parsing',
@@ -447,7 +447,7 @@ def test_var_args_only(py_and_json: PyAndJson, input_value, expected):
         [
             ([1, 'x', 'y'], None),
             Err(
-                'kind=int_parsing,',
+                'int_parsing',
                 [
                     {
                         'kind': 'int_parsing',
@@ -464,7 +464,7 @@ def test_var_args_only(py_and_json: PyAndJson, input_value, expected):
                 ],
             ),
         ],
-        [([1, 2, 3], {'a': 1}), Err('a\n  Unexpected keyword argument [kind=unexpected_keyword_argument,')],
+        [([1, 2, 3], {'a': 1}), Err('a         unexpected_keyword_argument  Unexpected keyword argument')],
     ],
     ids=repr,
 )
@@ -481,7 +481,7 @@ def test_args_var_args_only(py_and_json: PyAndJson, input_value, expected):
             v.validate_test(input_value)
         # debug(exc_info.value.errors())
         if expected.errors is not None:
-            assert exc_info.value.errors() == expected.errors
+            assert exc_info.value.errors(include_url=False) == expected.errors
     else:
         assert v.validate_test(input_value) == expected
 
@@ -494,7 +494,7 @@ def test_args_var_args_only(py_and_json: PyAndJson, input_value, expected):
         [
             ([1, 'a', 'true', 4, 5], {'b': 'bb', 'c': 3}),
             Err(
-                'kind=unexpected_positional_argument,',
+                'unexpected_positional_argument',
                 [
                     {
                         'kind': 'unexpected_positional_argument',
@@ -531,7 +531,7 @@ def test_both(py_and_json: PyAndJson, input_value, expected):
         with pytest.raises(ValidationError, match=re.escape(expected.message)) as exc_info:
             v.validate_test(input_value)
         if expected.errors is not None:
-            assert exc_info.value.errors() == expected.errors
+            assert exc_info.value.errors(include_url=False) == expected.errors
     else:
         assert v.validate_test(input_value) == expected
 
@@ -543,12 +543,12 @@ def test_both(py_and_json: PyAndJson, input_value, expected):
         [(None, None), ((), {})],
         [(None, {}), ((), {})],
         [([], None), ((), {})],
-        [([1], None), Err('0\n  Unexpected positional argument [kind=unexpected_positional_argument,')],
-        [([], {'a': 1}), Err('a\n  Unexpected keyword argument [kind=unexpected_keyword_argument,')],
+        [([1], None), Err('0         unexpected_positional_argument  Unexpected positional argument')],
+        [([], {'a': 1}), Err('a         unexpected_keyword_argument  Unexpected keyword argument')],
         [
             ([1], {'a': 2}),
             Err(
-                '[kind=unexpected_keyword_argument,',
+                'unexpected_keyword_argument',
                 [
                     {
                         'kind': 'unexpected_positional_argument',
@@ -575,7 +575,7 @@ def test_no_args(py_and_json: PyAndJson, input_value, expected):
             v.validate_test(input_value)
         # debug(exc_info.value.errors())
         if expected.errors is not None:
-            assert exc_info.value.errors() == expected.errors
+            assert exc_info.value.errors(include_url=False) == expected.errors
     else:
         assert v.validate_test(input_value) == expected
 
@@ -628,6 +628,36 @@ def test_default_factory(py_and_json: PyAndJson, input_value, expected):
     assert v.validate_test(input_value) == expected
 
 
+def test_default_factory_value_error():
+    def factory():
+        raise ValueError('no default available')
+
+    v = SchemaValidator(
+        {
+            'type': 'arguments',
+            'arguments_schema': [
+                {'name': 'a', 'mode': 'positional_or_keyword', 'schema': 'int', 'default_factory': factory},
+                {'name': 'b', 'mode': 'keyword_only', 'schema': 'int', 'default_factory': factory},
+            ],
+        }
+    )
+    assert v.validate_python(((1,), {'b': 2})) == ((1,), {'b': 2})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(((), {}))
+    assert [(e['kind'], e['loc']) for e in exc_info.value.errors()] == [('value_error', ['a']), ('value_error', ['b'])]
+
+    v = SchemaValidator(
+        {
+            'type': 'arguments',
+            'arguments_schema': [
+                {'name': 'a', 'mode': 'keyword_only', 'schema': 'int', 'default_factory': lambda: {}['missing']}
+            ],
+        }
+    )
+    with pytest.raises(KeyError, match='missing'):
+        v.validate_python(((), {}))
+
+
 def test_repr():
     v = SchemaValidator(
         {
@@ -704,7 +734,7 @@ def test_kwargs(py_and_json: PyAndJson, input_value, expected):
     [
         [((1,), None), ((1,), {})],
         [(None, {'Foo': 1}), ((), {'a': 1})],
-        [(None, {'a': 1}), Err('a\n  Missing required keyword argument [kind=missing_keyword_argument,')],
+        [(None, {'a': 1}), Err('a         missing_keyword_argument     Missing required keyword argument')],
     ],
     ids=repr,
 )
@@ -728,8 +758,8 @@ def test_alias(py_and_json: PyAndJson, input_value, expected):
         [((1,), None), ((1,), {})],
         [(None, {'Foo': 1}), ((), {'a': 1})],
         [(None, {'a': 1}), ((), {'a': 1})],
-        [(None, {'a': 1, 'b': 2}), Err('b\n  Unexpected keyword argument [kind=unexpected_keyword_argument,')],
-        [(None, {'a': 1, 'Foo': 2}), Err('a\n  Unexpected keyword argument [kind=unexpected_keyword_argument,')],
+        [(None, {'a': 1, 'b': 2}), Err('b         unexpected_keyword_argument  Unexpected keyword argument')],
+        [(None, {'a': 1, 'Foo': 2}), Err('a         unexpected_keyword_argument  Unexpected keyword argument')],
     ],
     ids=repr,
 )
@@ -810,7 +840,7 @@ def test_function_any():
     with pytest.raises(ValidationError, match='Unexpected positional argument'):
         foobar(1, 2, 3, 4)
 
-    with pytest.raises(ValidationError, match='d\n  Unexpected keyword argument'):
+    with pytest.raises(ValidationError, match='d +unexpected_keyword_argument +Unexpected keyword argument'):
         foobar(1, 2, 3, d=4)
 
 
@@ -828,7 +858,7 @@ def test_function_types():
     with pytest.raises(ValidationError) as exc_info:
         foobar(1, 'b')
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'int_parsing',
             'loc': [1],
@@ -846,7 +876,7 @@ def test_function_types():
     with pytest.raises(ValidationError) as exc_info:
         foobar(1, 'b', c='c')
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'int_parsing',
             'loc': [1],
@@ -879,7 +909,7 @@ def create_function(validate):
     assert foobar('1', 2, c=3) == (1, 2, 3)
     with pytest.raises(ValidationError) as exc_info:
         foobar('1', b=2, c=3)
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'missing_positional_argument',
             'loc': [1],
@@ -939,3 +969,16 @@ def test_function_args_kwargs():
     assert foobar(1, 2, 3) == ((1, 2, 3), {})
     assert foobar(a=1, b=2, c=3) == ((), {'a': 1, 'b': 2, 'c': 3})
     assert foobar() == ((), {})
+
+
+def test_validate_default():
+    schema = {
+        'type': 'arguments',
+        'arguments_schema': [
+            {'name': 'a', 'mode': 'positional_or_keyword', 'schema': 'int'},
+            {'name': 'b', 'mode': 'positional_or_keyword', 'schema': 'int', 'default': 'x'},
+        ],
+    }
+    assert SchemaValidator(schema).validate_python(((1,), {})) == ((1,), {'b': 'x'})
+    with pytest.raises(SchemaError, match='arguments_schema -> 1 -> default\n  Invalid default value:\n'):
+        SchemaValidator(schema, {'validate_default': True})
@@ -2,7 +2,7 @@ import re
 
 import pytest
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
 
 from ..conftest import Err, PyAndJson, plain_repr
 
@@ -20,18 +20,12 @@ from ..conftest import Err, PyAndJson, plain_repr
         ('no', False),
         ('true', True),
         ('false', False),
-        (
-            'cheese',
-            Err(
-                'Input should be a valid boolean, '
-                "unable to interpret input [kind=bool_parsing, input_value='cheese', input_type=str]"
-            ),
-        ),
-        (2, Err('Input should be a valid boolean, unable to interpret input [kind=bool_parsing, input_value=2')),
-        ([], Err('Input should be a valid boolean [kind=bool_type, input_value=[], input_type=list]')),
-        (1.1, Err('Input should be a valid boolean [kind=bool_type, input_value=1.1, input_type=float]')),
-        (2, Err('unable to interpret input [kind=bool_parsing, input_value=2, input_type=int]')),
-        (2.0, Err('unable to interpret input [kind=bool_parsing, input_value=2.0, input_type=float]')),
+        ('cheese', Err("bool_parsing  Input should be a valid boolean, unable to interpret input  'cheese'")),
+        (2, Err('bool_parsing  Input should be a valid boolean, unable to interpret input')),
+        ([], Err('bool_type  Input should be a valid boolean  []')),
+        (1.1, Err('bool_type  Input should be a valid boolean  1.1')),
+        (2, Err('bool_parsing  Input should be a valid boolean, unable to interpret input  2')),
+        (2.0, Err('bool_parsing  Input should be a valid boolean, unable to interpret input  2.0')),
     ],
 )
 def test_bool(py_and_json: PyAndJson, input_value, expected):
@@ -48,7 +42,7 @@ def test_bool(py_and_json: PyAndJson, input_value, expected):
 def test_bool_strict(py_and_json: PyAndJson):
     v = py_and_json({'type': 'bool', 'strict': True})
     assert v.validate_test(True) is True
-    error_message = "Input should be a valid boolean [kind=bool_type, input_value='true', input_type=str]"
+    error_message = "bool_type  Input should be a valid boolean  'true'"
     with pytest.raises(ValidationError, match=re.escape(error_message)):
         v.validate_test('true')
 
@@ -61,10 +55,11 @@ def test_bool_error():
 
     assert str(exc_info.value) == (
         '1 validation error for bool\n'
-        '  Input should be a valid boolean, '
-        "unable to interpret input [kind=bool_parsing, input_value='wrong', input_type=str]"
+        'Location  Type          Message                                                     Input\n'
+        '--------  ------------  ----------------------------------------------------------  -------\n'
+        "          bool_parsing  Input should be a valid boolean, unable to interpret input  'wrong'"
     )
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'bool_parsing',
             'loc': [],
@@ -79,6 +74,11 @@ def test_bool_repr():
     assert plain_repr(v) == 'SchemaValidator(name="bool",validator=Bool(BoolValidator{strict:false}))'
     v = SchemaValidator({'type': 'bool', 'strict': True})
     assert plain_repr(v) == 'SchemaValidator(name="bool",validator=Bool(BoolValidator{strict:true}))'
+    v = SchemaValidator({'type': 'bool', 'title': 'Flag'})
+    assert plain_repr(v) == (
+        'SchemaValidator(name="bool",validator=Bool(BoolValidator{strict:false,'
+        'metadata:SchemaMetadata{title:Some("Flag"),description:None,examples:None}}))'
+    )
 
 
 def test_bool_key(py_and_json: PyAndJson):
@@ -88,3 +88,56 @@ def test_bool_key(py_and_json: PyAndJson):
     assert v.validate_test({'true': 1, 'off': 2}, strict=False) == {True: 1, False: 2}
     with pytest.raises(ValidationError, match='Input should be a valid boolean'):
         v.validate_test({'true': 1, 'off': 2}, strict=True)
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('sure', True),
+        ('SURE', True),
+        ('Nope', False),
+        (True, True),
+        (0, False),
+        (1.0, True),
+        ('yes', Err('bool_parsing  Input should be a valid boolean, unable to interpret input')),
+        ('maybe', Err('bool_parsing  Input should be a valid boolean, unable to interpret input')),
+        (2, Err('bool_parsing  Input should be a valid boolean, unable to interpret input')),
+        ([], Err('bool_type  Input should be a valid boolean')),
+    ],
+)
+def test_bool_values(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json({'type': 'bool', 'true_values': ['Sure'], 'false_values': ['nope']})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) is expected
+
+
+def test_bool_values_partial():
+    v = SchemaValidator({'type': 'bool', 'true_values': ['sure']})
+    assert v.validate_python('sure') is True
+    assert v.validate_python('OFF') is False
+    with pytest.raises(ValidationError, match='unable to interpret input'):
+        v.validate_python('yes')
+
+
+def test_bool_values_strict():
+    v = SchemaValidator({'type': 'bool', 'true_values': ['sure'], 'strict': True})
+    assert v.validate_python(True) is True
+    with pytest.raises(ValidationError, match=re.escape('bool_type  Input should be a valid boolean')):
+        v.validate_python('sure')
+
+
+@pytest.mark.parametrize(
+    'schema,overlap',
+    [
+        ({'true_values': ['sure', 'Nope'], 'false_values': ['nope']}, '["nope"]'),
+        # the defaults are used for whichever isn't set
+        ({'true_values': ['sure', 'OFF', 'no']}, '["no", "off"]'),
+        ({'false_values': ['1']}, '["1"]'),
+    ],
+)
+def test_bool_values_overlap(schema, overlap):
+    with pytest.raises(SchemaError, match=re.escape(f"in both 'true_values' and 'false_values': {overlap}")):
+        SchemaValidator({'type': 'bool', **schema})
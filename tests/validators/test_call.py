@@ -15,9 +15,9 @@ from ..conftest import Err, PyAndJson, plain_repr
         [((1, 2, 3), None), 6],
         [(None, {'a': 1, 'b': 1, 'c': 1}), 3],
         [((1,), {'b': 1, 'c': 1}), 3],
-        [((1, 2, 'x'), None), Err('arguments -> 2\n  Input should be a valid integer,')],
+        [((1, 2, 'x'), None), Err('arguments -> 2  int_parsing  Input should be a valid integer,')],
         [((3, 3, 4), None), 10],
-        [((3, 3, 5), None), Err('return-value\n  Input should be less than or equal to 10')],
+        [((3, 3, 5), None), Err('return-value  less_than_equal  Input should be less than or equal to 10')],
     ],
 )
 def test_function_call_arguments(py_and_json: PyAndJson, input_value, expected):
@@ -45,7 +45,7 @@ def test_function_call_arguments(py_and_json: PyAndJson, input_value, expected):
             v.validate_python(input_value)
         # debug(exc_info.value.errors())
         if expected.errors is not None:
-            assert exc_info.value.errors() == expected.errors
+            assert exc_info.value.errors(include_url=False) == expected.errors
     else:
         assert v.validate_python(input_value) == expected
 
@@ -109,7 +109,7 @@ def test_in_union():
     assert v.validate_python(((1,), {})) == 1
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python(((1, 2), {}))
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'unexpected_positional_argument',
             'loc': ['call[my_function]', 'arguments', 1],
@@ -1,3 +1,6 @@
+from functools import partial
+from unittest.mock import Mock
+
 import pytest
 
 from pydantic_core import SchemaValidator, ValidationError
@@ -25,7 +28,7 @@ def test_callable():
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python(42)
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {'kind': 'callable_type', 'loc': [], 'message': 'Input should be callable', 'input_value': 42}
     ]
 
@@ -45,6 +48,12 @@ def test_callable():
         ((1,), False),
         (CallableClass, True),
         (CallableClass(), True),
+        (partial(func), True),
+        (Mock(), True),
+        (len, True),
+        (CallableClass().__call__, True),
+        ('func', False),
+        (None, False),
     ],
 )
 def test_callable_cases(input_value, expected):
@@ -52,11 +61,18 @@ def test_callable_cases(input_value, expected):
     assert v.isinstance_python(input_value) == expected
 
 
+@pytest.mark.parametrize('input_value', [func, partial(func), Mock(), CallableClass()])
+def test_callable_unchanged(input_value):
+    v = SchemaValidator({'type': 'callable'})
+    assert v.validate_python(input_value) is input_value
+    assert v.validate_python(input_value, strict=True) is input_value
+
+
 def test_repr():
     v = SchemaValidator({'type': 'union', 'choices': ['int', 'callable']})
     assert v.isinstance_python(4) is True
     assert v.isinstance_python(func) is True
     assert v.isinstance_python('foo') is False
 
-    with pytest.raises(ValidationError, match=r'callable\s+Input should be callable'):
+    with pytest.raises(ValidationError, match=r'callable +callable_type +Input should be callable'):
         v.validate_python('foo')
@@ -0,0 +1,182 @@
+import re
+from decimal import Decimal
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
+
+from ..conftest import plain_repr
+
+
+def test_chain():
+    validator = SchemaValidator(
+        {
+            'type': 'chain',
+            'steps': [
+                {'type': 'str'},
+                {'type': 'function', 'mode': 'plain', 'function': lambda v, **kwargs: Decimal(v)},
+            ],
+        }
+    )
+
+    assert validator.validate_python('1.44') == Decimal('1.44')
+    assert validator.validate_python(b'1.44') == Decimal('1.44')
+
+
+def test_chain_many():
+    validator = SchemaValidator(
+        {
+            'type': 'chain',
+            'steps': [
+                {'type': 'function', 'mode': 'plain', 'function': lambda v, **kwargs: f'{v}-1'},
+                {'type': 'function', 'mode': 'plain', 'function': lambda v, **kwargs: f'{v}-2'},
+                {'type': 'function', 'mode': 'plain', 'function': lambda v, **kwargs: f'{v}-3'},
+            ],
+        }
+    )
+
+    assert validator.validate_python('input') == 'input-1-2-3'
+
+
+def test_chain_str_parse_int():
+    validator = SchemaValidator(
+        {
+            'type': 'chain',
+            'steps': [
+                {'type': 'str', 'strip_whitespace': True},
+                {'type': 'function', 'mode': 'plain', 'function': lambda v, **kwargs: v.replace('_', '')},
+                {'type': 'int', 'gt': 0},
+            ],
+        }
+    )
+
+    assert validator.validate_python(' 1_000 ') == 1000
+    with pytest.raises(ValidationError) as exc_info:
+        validator.validate_python('-1_0')
+    # the input is the output of the previous step
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'greater_than',
+            'loc': [],
+            'message': 'Input should be greater than 0',
+            'input_value': '-10',
+            'context': {'gt': 0},
+        }
+    ]
+
+
+def test_chain_error_short_circuit():
+    calls = []
+
+    def f(input_value, **kwargs):
+        calls.append(input_value)
+        return input_value
+
+    validator = SchemaValidator(
+        {'type': 'chain', 'steps': [{'type': 'int'}, {'type': 'function', 'mode': 'plain', 'function': f}]}
+    )
+
+    with pytest.raises(ValidationError) as exc_info:
+        validator.validate_python('abc')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'int_parsing',
+            'loc': [],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'abc',
+        }
+    ]
+    assert calls == []
+
+    assert validator.validate_python('123') == 123
+    assert calls == [123]
+
+
+def test_json():
+    validator = SchemaValidator(
+        {
+            'type': 'chain',
+            'steps': [{'type': 'int'}, {'type': 'function', 'mode': 'plain', 'function': lambda v, **kwargs: v * 2}],
+        }
+    )
+    assert validator.validate_json('"21"') == 42
+
+
+def test_flatten():
+    validator = SchemaValidator(
+        {
+            'type': 'chain',
+            'steps': [
+                {'type': 'str'},
+                {'type': 'function', 'mode': 'plain', 'function': lambda v, **kwargs: f'{v}-1'},
+            ],
+        }
+    )
+    assert plain_repr(validator).startswith('SchemaValidator(name="chain[str,function-plain]",')
+
+
+def test_single_step():
+    validator = SchemaValidator({'type': 'chain', 'steps': [{'type': 'str'}]})
+    assert validator.validate_python('input') == 'input'
+    assert plain_repr(validator).startswith('SchemaValidator(name="str",validator=Str(')
+
+
+def test_empty_steps():
+    with pytest.raises(SchemaError, match='One or more steps are required for a chain validator'):
+        SchemaValidator({'type': 'chain', 'steps': []})
+
+
+def test_chain_recursive_ref():
+    def default_leaf(input_value, **kwargs):
+        return input_value or {'name': 'leaf'}
+
+    validator = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'ref': 'Branch',
+            'fields': {
+                'name': {'schema': 'str'},
+                'sub': {
+                    'schema': {
+                        'type': 'chain',
+                        'steps': [
+                            {'type': 'function', 'mode': 'plain', 'function': default_leaf},
+                            {'type': 'nullable', 'schema': {'type': 'recursive-ref', 'schema_ref': 'Branch'}},
+                        ],
+                    },
+                    'default': None,
+                },
+            },
+        }
+    )
+    assert validator.validate_python({'name': 'root', 'sub': {'name': 'branch', 'sub': {'name': 'x', 'sub': 0}}}) == {
+        'name': 'root',
+        'sub': {'name': 'branch', 'sub': {'name': 'x', 'sub': {'name': 'leaf', 'sub': None}}},
+    }
+    with pytest.raises(ValidationError, match=re.escape('sub -> name  str_type  Input should be a valid string')):
+        validator.validate_python({'name': 'root', 'sub': {'name': 1}})
+
+
+def test_intermediate_identity():
+    # values produced by one step are passed to the next step as-is, not copied or converted
+    obj = object()
+    validator = SchemaValidator(
+        {'type': 'chain', 'steps': [{'type': 'function', 'mode': 'plain', 'function': lambda v, **kwargs: obj}, 'any']}
+    )
+    assert validator.validate_python(1) is obj
+
+
+def test_intermediate_dict_locations():
+    validator = SchemaValidator(
+        {
+            'type': 'chain',
+            'steps': [
+                {'type': 'function', 'mode': 'plain', 'function': lambda v, **kwargs: dict(v)},
+                {'type': 'dict', 'values_schema': 'int'},
+            ],
+        }
+    )
+    assert validator.validate_python([('a', '1')]) == {'a': 1}
+    with pytest.raises(ValidationError) as exc_info:
+        validator.validate_python([('a', 'x'), (2, 'y'), (-3, 'z'), ((4, 5), 'w')])
+    assert [e['loc'] for e in exc_info.value.errors()] == [['a'], [2], [-3], ['(4, 5)']]
@@ -0,0 +1,142 @@
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
+
+from ..conftest import PyAndJson
+
+CONFIG_SCHEMA = {
+    'type': 'typed-dict',
+    'fields': {
+        'host': {'schema': 'str'},
+        'port': {'schema': 'int'},
+        'debug': {'schema': 'bool', 'default': False},
+    },
+}
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ({'host': 'localhost', 'port': '80'}, {'host': 'localhost', 'port': 80, 'debug': False}),
+        ([{'host': 'localhost', 'port': 80}], {'host': 'localhost', 'port': 80, 'debug': False}),
+        ([{'host': 'localhost', 'port': 80}, {'port': '8000'}], {'host': 'localhost', 'port': 8000, 'debug': False}),
+        (
+            [{'host': 'localhost', 'port': 80}, {'debug': True}, {'host': 'example.com'}],
+            {'host': 'example.com', 'port': 80, 'debug': True},
+        ),
+    ],
+)
+def test_chain_map(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json({'type': 'chain-map', 'schema': CONFIG_SCHEMA})
+    assert v.validate_test(input_value) == expected
+
+
+def test_invalid_override_single_error(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'chain-map', 'schema': CONFIG_SCHEMA})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([{'host': 'localhost', 'port': 'x'}, {'port': 'y'}])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'int_parsing',
+            'loc': ['port'],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'y',
+        }
+    ]
+
+
+def test_override_fixes_invalid(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'chain-map', 'schema': CONFIG_SCHEMA})
+    assert v.validate_test([{'host': 'localhost', 'port': 'x'}, {'port': 1}]) == {
+        'host': 'localhost',
+        'port': 1,
+        'debug': False,
+    }
+
+
+def test_missing_field(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'chain-map', 'schema': CONFIG_SCHEMA})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([{'host': 'localhost'}, {'debug': True}])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'missing',
+            'loc': ['port'],
+            'message': 'Field required',
+            'input_value': {'host': 'localhost', 'debug': True},
+        }
+    ]
+
+
+def test_source_not_dict(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'chain-map', 'schema': CONFIG_SCHEMA})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([{'host': 'localhost', 'port': 80}, 123])
+    assert exc_info.value.errors(include_url=False) == [
+        {'kind': 'dict_type', 'loc': [1], 'message': 'Input should be a valid dictionary', 'input_value': 123}
+    ]
+
+
+NESTED_SCHEMA = {
+    'type': 'typed-dict',
+    'fields': {
+        'db': {
+            'schema': {
+                'type': 'typed-dict',
+                'fields': {'host': {'schema': 'str'}, 'port': {'schema': 'int'}},
+                'extra_behavior': 'forbid',
+            }
+        }
+    },
+}
+
+
+def test_shallow(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'chain-map', 'schema': NESTED_SCHEMA})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([{'db': {'host': 'localhost', 'port': 5432}}, {'db': {'port': 5433}}])
+    assert exc_info.value.errors(include_url=False) == [
+        {'kind': 'missing', 'loc': ['db', 'host'], 'message': 'Field required', 'input_value': {'port': 5433}}
+    ]
+
+
+def test_deep(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'chain-map', 'schema': NESTED_SCHEMA, 'deep': True})
+    assert v.validate_test([{'db': {'host': 'localhost', 'port': 5432}}, {'db': {'port': '5433'}}]) == {
+        'db': {'host': 'localhost', 'port': 5433}
+    }
+
+
+def test_deep_sources_unchanged():
+    v = SchemaValidator({'type': 'chain-map', 'schema': NESTED_SCHEMA, 'deep': True})
+    defaults = {'db': {'host': 'localhost', 'port': 5432}}
+    assert v.validate_python([defaults, {'db': {'port': 5433}}]) == {'db': {'host': 'localhost', 'port': 5433}}
+    assert defaults == {'db': {'host': 'localhost', 'port': 5432}}
+
+
+def test_deep_replaces_non_dict():
+    v = SchemaValidator(
+        {
+            'type': 'chain-map',
+            'schema': {'type': 'typed-dict', 'fields': {'db': {'schema': {'type': 'dict', 'values_schema': 'int'}}}},
+            'deep': True,
+        }
+    )
+    assert v.validate_python([{'db': 1}, {'db': {'a': 1}}, {'db': {'b': 2}}]) == {'db': {'a': 1, 'b': 2}}
+
+
+def test_strict():
+    v = SchemaValidator({'type': 'chain-map', 'schema': CONFIG_SCHEMA, 'strict': True})
+    assert v.validate_python([{'host': 'localhost', 'port': 80}]) == {'host': 'localhost', 'port': 80, 'debug': False}
+    with pytest.raises(ValidationError, match='Input should be a valid dictionary'):
+        v.validate_python(({'host': 'localhost', 'port': 80},))
+
+
+def test_repr():
+    v = SchemaValidator({'type': 'chain-map', 'schema': CONFIG_SCHEMA})
+    assert repr(v).startswith('SchemaValidator(name="chain-map[typed-dict]"')
+
+
+def test_schema_not_typed_dict():
+    with pytest.raises(SchemaError, match="Input should be 'typed-dict'"):
+        SchemaValidator({'type': 'chain-map', 'schema': {'type': 'int'}})
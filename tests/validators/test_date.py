@@ -16,7 +16,7 @@ from ..conftest import Err, PyAndJson
         pytest.param(date(2022, 6, 8), date(2022, 6, 8), id='date'),
         pytest.param('2022-06-08', date(2022, 6, 8), id='str'),
         pytest.param(b'2022-06-08', date(2022, 6, 8), id='bytes'),
-        pytest.param((1,), Err('Input should be a valid date [kind=date_type'), id='tuple'),
+        pytest.param((1,), Err('date_type  Input should be a valid date'), id='tuple'),
         pytest.param(1654646400, date(2022, 6, 8), id='int'),
         pytest.param(1654646400.00, date(2022, 6, 8), id='float'),
         pytest.param(Decimal('1654646400'), date(2022, 6, 8), id='decimal'),
@@ -28,13 +28,13 @@ from ..conftest import Err, PyAndJson
         pytest.param(
             datetime(2022, 6, 8, 12),
             Err(
-                'Datetimes provided to dates should have zero time '
-                '- e.g. be exact dates [kind=date_from_datetime_inexact'
+                'date_from_datetime_inexact  '
+                'Datetimes provided to dates should have zero time - e.g. be exact dates'
             ),
             id='datetime-inexact',
         ),
         pytest.param(True, Err('Input should be a valid date'), id='bool'),
-        pytest.param(time(1, 2, 3), Err('Input should be a valid date [kind=date_type'), id='time'),
+        pytest.param(time(1, 2, 3), Err('date_type  Input should be a valid date'), id='time'),
     ],
 )
 def test_date(input_value, expected):
@@ -59,20 +59,20 @@ def test_date(input_value, expected):
         (
             1654646401,
             Err(
-                'Datetimes provided to dates should have zero time '
-                '- e.g. be exact dates [kind=date_from_datetime_inexact'
+                'date_from_datetime_inexact  '
+                'Datetimes provided to dates should have zero time - e.g. be exact dates'
             ),
         ),
-        ('wrong', Err('Input should be a valid date or datetime, input is too short [kind=date_from_datetime_parsing')),
+        ('wrong', Err('date_from_datetime_parsing  Input should be a valid date or datetime, input is too short')),
         ('2000-02-29', date(2000, 2, 29)),
         (
             '2001-02-29',
             Err(
-                'Input should be a valid date or datetime, '
-                'day value is outside expected range [kind=date_from_datetime_parsing'
+                'date_from_datetime_parsing  '
+                'Input should be a valid date or datetime, day value is outside expected range'
             ),
         ),
-        ([1], Err('Input should be a valid date [kind=date_type')),
+        ([1], Err('date_type  Input should be a valid date')),
     ],
 )
 def test_date_json(py_and_json: PyAndJson, input_value, expected):
@@ -91,11 +91,11 @@ def test_date_json(py_and_json: PyAndJson, input_value, expected):
     'input_value,expected',
     [
         (date(2022, 6, 8), date(2022, 6, 8)),
-        ('2022-06-08', Err('Input should be a valid date [kind=date_type')),
-        (b'2022-06-08', Err('Input should be a valid date [kind=date_type')),
-        (1654646400, Err('Input should be a valid date [kind=date_type')),
-        (True, Err('Input should be a valid date [kind=date_type')),
-        (datetime(2022, 6, 8), Err('Input should be a valid date [kind=date_type')),
+        ('2022-06-08', Err('date_type  Input should be a valid date')),
+        (b'2022-06-08', Err('date_type  Input should be a valid date')),
+        (1654646400, Err('date_type  Input should be a valid date')),
+        (True, Err('date_type  Input should be a valid date')),
+        (datetime(2022, 6, 8), Err('date_type  Input should be a valid date')),
     ],
     ids=repr,
 )
@@ -109,15 +109,21 @@ def test_date_strict(input_value, expected):
         assert output == expected
 
 
+def test_date_strict_override():
+    v = SchemaValidator({'type': 'date'})
+    assert v.validate_python(datetime(2022, 6, 8)) == date(2022, 6, 8)
+    with pytest.raises(ValidationError, match=re.escape('date_type  Input should be a valid date')):
+        v.validate_python(datetime(2022, 6, 8), strict=True)
+    with pytest.raises(ValidationError, match=re.escape('date_type  Input should be a valid date')):
+        v.validate_python('2022-06-08', strict=True)
+
+
 @pytest.mark.parametrize(
     'input_value,expected',
     [
         ('"2022-06-08"', date(2022, 6, 8)),
-        (
-            '"foobar"',
-            Err('Input should be a valid date in the format YYYY-MM-DD, input is too short [kind=date_parsing,'),
-        ),
-        ('1654646400', Err('Input should be a valid date [kind=date_type')),
+        ('"foobar"', Err('date_parsing  Input should be a valid date in the format YYYY-MM-DD, input is too short')),
+        ('1654646400', Err('date_type  Input should be a valid date')),
     ],
 )
 def test_date_strict_json(input_value, expected):
@@ -134,7 +140,7 @@ def test_date_strict_json_ctx():
     v = SchemaValidator({'type': 'date', 'strict': True})
     with pytest.raises(ValidationError) as exc_info:
         v.validate_json('"foobar"')
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'date_parsing',
             'loc': [],
@@ -153,18 +159,18 @@ def test_date_strict_json_ctx():
         (
             {'le': date(2000, 1, 1)},
             '2000-01-02',
-            Err('Input should be less than or equal to 2000-01-01 [kind=less_than_equal,'),
+            Err('less_than_equal  Input should be less than or equal to 2000-01-01'),
         ),
         ({'lt': '2000-01-01'}, '1999-12-31', date(1999, 12, 31)),
-        ({'lt': '2000-01-01'}, '2000-01-01', Err('Input should be less than 2000-01-01 [kind=less_than,')),
+        ({'lt': '2000-01-01'}, '2000-01-01', Err('less_than  Input should be less than 2000-01-01')),
         ({'ge': '2000-01-01'}, '2000-01-01', date(2000, 1, 1)),
         (
             {'ge': date(2000, 1, 1)},
             '1999-12-31',
-            Err('Input should be greater than or equal to 2000-01-01 [kind=greater_than_equal,'),
+            Err('greater_than_equal  Input should be greater than or equal to 2000-01-01'),
         ),
         ({'gt': date(2000, 1, 1)}, '2000-01-02', date(2000, 1, 2)),
-        ({'gt': date(2000, 1, 1)}, '2000-01-01', Err('Input should be greater than 2000-01-01 [kind=greater_than,')),
+        ({'gt': date(2000, 1, 1)}, '2000-01-01', Err('greater_than  Input should be greater than 2000-01-01')),
     ],
 )
 def test_date_kwargs(kwargs: Dict[str, Any], input_value, expected):
@@ -1,3 +1,4 @@
+import json
 import re
 from collections import OrderedDict
 from collections.abc import Mapping
@@ -6,7 +7,7 @@ from typing import Any, Dict
 import pytest
 from dirty_equals import HasRepr, IsStr
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
 
 from ..conftest import Err, PyAndJson
 
@@ -27,13 +28,16 @@ def test_dict(py_and_json: PyAndJson):
         ({'1': b'1', '2': b'2'}, {'1': '1', '2': '2'}),
         (OrderedDict(a=b'1', b='2'), {'a': '1', 'b': '2'}),
         ({}, {}),
-        ('foobar', Err("Input should be a valid dictionary [kind=dict_type, input_value='foobar', input_type=str]")),
-        ([], Err('Input should be a valid dictionary [kind=dict_type,')),
-        ([('x', 'y')], Err('Input should be a valid dictionary [kind=dict_type,')),
-        ([('x', 'y'), ('z', 'z')], Err('Input should be a valid dictionary [kind=dict_type,')),
-        ((), Err('Input should be a valid dictionary [kind=dict_type,')),
-        ((('x', 'y'),), Err('Input should be a valid dictionary [kind=dict_type,')),
-        ((type('Foobar', (), {'x': 1})()), Err('Input should be a valid dictionary [kind=dict_type,')),
+        ('foobar', Err("dict_type  Input should be a valid dictionary, expected dict[str, str]  'foobar'")),
+        ([], Err('dict_type  Input should be a valid dictionary, expected dict[str, str]')),
+        ([('x', 'y')], Err('dict_type  Input should be a valid dictionary, expected dict[str, str]')),
+        ([('x', 'y'), ('z', 'z')], Err('dict_type  Input should be a valid dictionary, expected dict[str, str]')),
+        ((), Err('dict_type  Input should be a valid dictionary, expected dict[str, str]')),
+        ((('x', 'y'),), Err('dict_type  Input should be a valid dictionary, expected dict[str, str]')),
+        (
+            (type('Foobar', (), {'x': 1})()),
+            Err('dict_type  Input should be a valid dictionary, expected dict[str, str]'),
+        ),
     ],
     ids=repr,
 )
@@ -51,7 +55,7 @@ def test_dict_value_error(py_and_json: PyAndJson):
     assert v.validate_test({'a': 2, 'b': '4'}) == {'a': 2, 'b': 4}
     with pytest.raises(ValidationError, match='Input should be a valid integer') as exc_info:
         v.validate_test({'a': 2, 'b': 'wrong'})
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'int_parsing',
             'loc': ['b'],
@@ -61,11 +65,33 @@ def test_dict_value_error(py_and_json: PyAndJson):
     ]
 
 
+def test_dict_nested_error_location(py_and_json: PyAndJson):
+    v = py_and_json(
+        {
+            'type': 'dict',
+            'values_schema': {'type': 'dict', 'values_schema': {'type': 'dict', 'values_schema': 'int'}},
+        }
+    )
+    assert v.validate_test({'a': {'b': {'c': '1'}}}) == {'a': {'b': {'c': 1}}}
+    with pytest.raises(
+        ValidationError, match=re.escape('a -> b -> c  int_parsing  Input should be a valid integer')
+    ) as exc_info:
+        v.validate_test({'a': {'b': {'c': 'wrong'}}, 'x': {'y': {'z': 1}}})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'int_parsing',
+            'loc': ['a', 'b', 'c'],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'wrong',
+        }
+    ]
+
+
 def test_dict_error_key_int():
     v = SchemaValidator({'type': 'dict', 'values_schema': 'int'})
     with pytest.raises(ValidationError, match='Input should be a valid integer') as exc_info:
         v.validate_python({1: 2, 3: 'wrong'})
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'int_parsing',
             'loc': [3],
@@ -79,7 +105,7 @@ def test_dict_error_key_other():
     v = SchemaValidator({'type': 'dict', 'values_schema': 'int'})
     with pytest.raises(ValidationError, match='Input should be a valid integer') as exc_info:
         v.validate_python({1: 2, (1, 2): 'wrong'})
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'int_parsing',
             'loc': ['(1, 2)'],
@@ -121,9 +147,11 @@ def test_mapping():
 def test_key_error():
     v = SchemaValidator({'type': 'dict', 'keys_schema': {'type': 'int'}, 'values_schema': {'type': 'int'}})
     assert v.validate_python({'1': True}) == {1: 1}
-    with pytest.raises(ValidationError, match=re.escape('x -> [key]\n  Input should be a valid integer')) as exc_info:
+    with pytest.raises(
+        ValidationError, match=re.escape('x -> [key]  int_parsing  Input should be a valid integer')
+    ) as exc_info:
         v.validate_python({'x': 1})
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'int_parsing',
             'loc': ['x', '[key]'],
@@ -133,6 +161,88 @@ def test_key_error():
     ]
 
 
+@pytest.mark.parametrize(
+    'fail_fast,expected_errors',
+    [
+        (False, [(['x', '[key]'], 'int_parsing'), (['x'], 'int_parsing'), (['2'], 'int_parsing')]),
+        (True, [(['x', '[key]'], 'int_parsing'), (['2'], 'int_parsing')]),
+    ],
+)
+def test_fail_fast_per_entry(py_and_json: PyAndJson, fail_fast, expected_errors):
+    v = py_and_json({'type': 'dict', 'keys_schema': 'int', 'values_schema': 'int', 'fail_fast_per_entry': fail_fast})
+    assert v.validate_test({'1': '2'}) == {1: 2}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'x': 'y', '1': 2, '2': 'z'})
+    assert [(e['loc'], e['kind']) for e in exc_info.value.errors()] == expected_errors
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ({'ab': 1}, {'ab': 1}),
+        ({'abc': 1}, {'abc': 1}),
+        ({'abcd': 1}, Err('too_long  String should have at most 3 characters')),
+        ({'abcd': 'x'}, Err('2 validation errors for dict[str,int]')),
+    ],
+)
+def test_key_max_length(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json({'type': 'dict', 'keys_schema': 'str', 'values_schema': 'int', 'key_max_length': 3})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_key_max_length_errors():
+    v = SchemaValidator({'type': 'dict', 'values_schema': 'int', 'key_max_length': 3, 'fail_fast_per_entry': True})
+    assert v.validate_python({'abc': 1, 1234: 2}) == {'abc': 1, 1234: 2}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'abcd': 'x', 'éèêë': 2, 'éèê': 3})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'too_long',
+            'loc': ['abcd', '[key]'],
+            'message': 'String should have at most 3 characters',
+            'input_value': 'abcd',
+            'context': {'max_length': 3},
+        },
+        {
+            'kind': 'too_long',
+            'loc': ['éèêë', '[key]'],
+            'message': 'String should have at most 3 characters',
+            'input_value': 'éèêë',
+            'context': {'max_length': 3},
+        },
+    ]
+
+
+def test_key_max_length_after_coercion():
+    v = SchemaValidator({'type': 'dict', 'keys_schema': {'type': 'str', 'strip_whitespace': True}, 'key_max_length': 3})
+    assert v.validate_python({b' abc ': 1}) == {'abc': 1}
+
+
+def test_int_key_locations():
+    v = SchemaValidator({'type': 'dict', 'values_schema': 'int'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({1: 'a', -2: 'b', '3': 'c', '[key]': 'd'})
+    assert [e['loc'] for e in exc_info.value.errors()] == [[1], [-2], ['3'], ['[key]']]
+    # strings which could be mistaken for an index or the key marker are quoted
+    message = str(exc_info.value)
+    assert '\n1         int_parsing' in message
+    assert '\n-2        int_parsing' in message
+    assert '\n"3"       int_parsing' in message
+    assert '\n"[key]"   int_parsing' in message
+
+
+def test_key_error_location_display():
+    v = SchemaValidator({'type': 'dict', 'keys_schema': 'int', 'values_schema': 'int'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'[key]': 1})
+    assert exc_info.value.errors()[0]['loc'] == ['[key]', '[key]']
+    assert '\n"[key]" -> [key]  int_parsing' in str(exc_info.value)
+
+
 def test_mapping_error():
     class BadMapping(Mapping):
         def __getitem__(self, key):
@@ -148,7 +258,7 @@ def test_mapping_error():
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python(BadMapping())
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'dict_from_mapping',
             'loc': [],
@@ -177,7 +287,7 @@ def test_mapping_error_yield_1():
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python(BadMapping())
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'dict_from_mapping',
             'loc': [],
@@ -200,12 +310,12 @@ def test_mapping_error_yield_1():
             {'1': 1, '2': 2, '3': 3.0, '4': [1, 2, 3, 4]},
             {'1': 1, '2': 2, '3': 3.0, '4': [1, 2, 3, 4]},
         ),
-        ({'min_items': 3}, {1: '2', 3: '4'}, Err('Input should have at least 3 items, got 2 items [kind=too_short,')),
+        ({'min_items': 3}, {1: '2', 3: '4'}, Err('too_short  Input should have at least 3 items, got 2 items')),
         ({'max_items': 4}, {'1': 1, '2': 2, '3': 3.0}, {'1': 1, '2': 2, '3': 3.0}),
         (
             {'max_items': 3},
             {'1': 1, '2': 2, '3': 3.0, '4': [1, 2, 3, 4]},
-            Err('Input should have at most 3 items, got 4 items [kind=too_long,'),
+            Err('too_long  Input should have at most 3 items, got 4 items'),
         ),
     ],
 )
@@ -216,3 +326,264 @@ def test_dict_length_constraints(kwargs: Dict[str, Any], input_value, expected):
             v.validate_python(input_value)
     else:
         assert v.validate_python(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'key_transform,input_value,expected',
+    [
+        ('lower', {'Name': 1, 'AGE': 2}, {'name': 1, 'age': 2}),
+        ('upper', {'Name': 1, 'age': 2}, {'NAME': 1, 'AGE': 2}),
+        (
+            'snake',
+            {'userId': 1, 'HTTPServer': 2, 'user-name': 3, 'v2Api': 4},
+            {'user_id': 1, 'http_server': 2, 'user_name': 3, 'v2_api': 4},
+        ),
+        (
+            'camel',
+            {'user_id': 1, 'HTTPServer': 2, 'user-name': 3, '_private_key': 4},
+            {'userId': 1, 'httpServer': 2, 'userName': 3, '_privateKey': 4},
+        ),
+        ('snake', {'already_snake': 1}, {'already_snake': 1}),
+        ('camel', {'alreadyCamel': 1}, {'alreadyCamel': 1}),
+    ],
+)
+def test_key_transform(py_and_json: PyAndJson, key_transform, input_value, expected):
+    v = py_and_json({'type': 'dict', 'values_schema': 'int', 'key_transform': key_transform})
+    assert v.validate_test(input_value) == expected
+
+
+def test_key_transform_before_key_validation():
+    v = SchemaValidator(
+        {'type': 'dict', 'keys_schema': {'type': 'literal', 'expected': ['a', 'b']}, 'key_transform': 'lower'}
+    )
+    assert v.validate_python({'A': 1, 'b': 2}) == {'a': 1, 'b': 2}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'A': 1, 'C': 2})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'literal_error',
+            'loc': ['c', '[key]'],
+            'message': "Input should be one of: 'a', 'b'",
+            'input_value': 'c',
+            'context': {'expected': "'a', 'b'"},
+        }
+    ]
+
+
+def test_key_transform_non_str_keys():
+    v = SchemaValidator({'type': 'dict', 'key_transform': 'upper'})
+    assert v.validate_python({1: 1, 'a': 2, (1, 'b'): 3}) == {1: 1, 'A': 2, (1, 'b'): 3}
+
+
+def test_key_transform_duplicate(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'dict', 'values_schema': 'int', 'key_transform': 'snake'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'userId': 1, 'user_id': 2, 'x': 'y'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'dict_duplicate_key',
+            'loc': ['user_id'],
+            'message': "Dictionary keys should be unique after key_transform, 'user_id' is a duplicate",
+            'input_value': 'user_id',
+            'context': {'key': 'user_id'},
+        }
+    ]
+
+
+def test_key_transform_mapping():
+    v = SchemaValidator({'type': 'mapping', 'key_transform': 'lower', 'target_class': OrderedDict})
+    output = v.validate_python({'A': 1, 'B': 2})
+    assert output == OrderedDict([('a', 1), ('b', 2)])
+    assert isinstance(output, OrderedDict)
+
+
+def test_key_transform_invalid():
+    with pytest.raises(SchemaError, match="Input should be one of: 'lower', 'upper', 'snake', 'camel'"):
+        SchemaValidator({'type': 'dict', 'key_transform': 'kebab'})
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ({'a': '1'}, {'a': 1}),
+        ('{"a": "1", "b": 2}', {'a': 1, 'b': 2}),
+        ('{}', {}),
+        ('{bad', Err('invalid_json  Invalid JSON: key must be a string at line 1 column 2')),
+        ('[1, 2]', Err('dict_type  Input should be a valid dictionary, expected dict[Any, int]')),
+        ('{"a": "x"}', Err('int_parsing  Input should be a valid integer, unable to parse string as an integer')),
+    ],
+)
+def test_coerce_json_string(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json({'type': 'dict', 'values_schema': 'int', 'coerce_json_string': True})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_coerce_json_string_off():
+    v = SchemaValidator({'type': 'dict', 'values_schema': 'int'})
+    with pytest.raises(ValidationError, match='Input should be a valid dictionary'):
+        v.validate_python('{"a": 1}')
+
+
+def test_coerce_json_string_strict():
+    v = SchemaValidator({'type': 'dict', 'values_schema': 'int', 'coerce_json_string': True})
+    with pytest.raises(ValidationError, match='Input should be a valid dictionary'):
+        v.validate_python('{"a": 1}', strict=True)
+
+
+def test_coerce_json_string_location():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {'x': {'schema': {'type': 'dict', 'values_schema': 'int', 'coerce_json_string': True}}},
+        }
+    )
+    assert v.validate_python({'x': '{"a": 1}'}) == {'x': {'a': 1}}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'x': '{"a": "x"}'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'int_parsing',
+            'loc': ['x', 'a'],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'x',
+        }
+    ]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'x': '{'})
+    assert exc_info.value.errors()[0]['kind'] == 'invalid_json'
+    assert exc_info.value.errors()[0]['loc'] == ['x']
+
+
+def test_coerce_json_string_nested_max_depth():
+    v = SchemaValidator(
+        {
+            'type': 'dict',
+            'ref': 'nested',
+            'values_schema': {'type': 'nullable', 'schema': {'type': 'recursive-ref', 'schema_ref': 'nested'}},
+            'coerce_json_string': True,
+        },
+        {'max_depth': 10},
+    )
+    # each level is a JSON string inside the JSON string of the level above
+    input_value = None
+    for _ in range(3):
+        input_value = json.dumps({'a': input_value})
+    assert v.validate_python(input_value) == {'a': {'a': {'a': None}}}
+
+    for _ in range(20):
+        input_value = json.dumps({'a': input_value})
+    with pytest.raises(ValidationError, match='Recursion error - cyclic reference detected'):
+        v.validate_python(input_value)
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ({'a': 1, 'b': 2}, {'a': 1, 'b': 2}),
+        ({'a': 1, 'b': 2, 'c': '3'}, {'a': 1, 'b': 2, 'c': 3}),
+        ({'a': 1}, Err('b         missing  Field required')),
+        ({}, Err('2 validation errors for dict[any,int]')),
+    ],
+)
+def test_required_keys(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json({'type': 'dict', 'values_schema': 'int', 'required_keys': ['a', 'b']})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_required_keys_errors():
+    v = SchemaValidator({'type': 'dict', 'values_schema': 'int', 'required_keys': ['a', 'b', 'c']})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x', 'd': 4})
+    # `a` is present so it's only reported as invalid, not missing
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'int_parsing',
+            'loc': ['a'],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'x',
+        },
+        {'kind': 'missing', 'loc': ['b'], 'message': 'Field required', 'input_value': {'a': 'x', 'd': 4}},
+        {'kind': 'missing', 'loc': ['c'], 'message': 'Field required', 'input_value': {'a': 'x', 'd': 4}},
+    ]
+
+
+def test_required_keys_coerced():
+    v = SchemaValidator(
+        {'type': 'dict', 'keys_schema': {'type': 'str', 'to_lower': True}, 'required_keys': ['name'], 'min_items': 1}
+    )
+    assert v.validate_python({'NAME': 'x'}) == {'name': 'x'}
+    with pytest.raises(ValidationError, match='Input should have at least 1 item, got 0 items'):
+        v.validate_python({})
+    with pytest.raises(ValidationError, match='name +missing +Field required'):
+        v.validate_python({'other': 'x'})
+
+
+def test_required_keys_json_schema():
+    v = SchemaValidator({'type': 'dict', 'values_schema': 'int', 'required_keys': ['a']})
+    assert v.json_schema() == {
+        'type': 'object',
+        'required': ['a'],
+        'additionalProperties': {'type': 'integer'},
+        'propertyNames': {},
+    }
+
+
+@pytest.mark.parametrize(
+    'schema,expected',
+    [
+        ({'type': 'dict', 'keys_schema': 'str', 'values_schema': 'int'}, 'dict[str, int]'),
+        ({'type': 'dict', 'values_schema': {'type': 'int', 'gt': 0}}, 'dict[Any, int]'),
+        (
+            {'type': 'dict', 'keys_schema': 'str', 'values_schema': {'type': 'list', 'items_schema': 'float'}},
+            'dict[str, list[float]]',
+        ),
+        (
+            {'type': 'dict', 'keys_schema': 'str', 'values_schema': {'type': 'nullable', 'schema': 'int'}},
+            'dict[str, Optional[int]]',
+        ),
+    ],
+)
+def test_dict_type_expected(py_and_json: PyAndJson, schema, expected):
+    v = py_and_json(schema)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([1, 2])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'dict_type',
+            'loc': [],
+            'message': f'Input should be a valid dictionary, expected {expected}',
+            'input_value': [1, 2],
+            'context': {'expected': expected},
+        }
+    ]
+
+
+def test_dict_type_expected_any():
+    v = SchemaValidator({'type': 'dict'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 2])
+    assert exc_info.value.errors(include_url=False) == [
+        {'kind': 'dict_type', 'loc': [], 'message': 'Input should be a valid dictionary', 'input_value': [1, 2]}
+    ]
+
+
+def test_dict_type_expected_nested():
+    v = SchemaValidator(
+        {'type': 'list', 'items_schema': {'type': 'dict', 'keys_schema': 'str', 'values_schema': 'int'}}
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([{'a': 1}, 'x'])
+    assert exc_info.value.errors()[0]['loc'] == [1]
+    assert exc_info.value.errors()[0]['message'] == 'Input should be a valid dictionary, expected dict[str, int]'
+    with pytest.raises(
+        ValidationError, match=r'list_type  Input should be a valid list/array, expected list\[dict\[str, int\]\]'
+    ):
+        v.validate_python('x')
@@ -0,0 +1,86 @@
+import re
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError
+
+from ..conftest import Err, PyAndJson
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ([['a', 'b'], ['c']], ['a', 'b', 'c']),
+        ([[], ['a'], []], ['a']),
+        ([], []),
+        ([['a']], ['a']),
+        (['a'], Err('0         list_type  Input should be a valid list/array')),
+        ('a', Err('list_type  Input should be a valid list/array')),
+    ],
+)
+def test_flat_list(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json({'type': 'flat-list', 'items_schema': 'str'})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_lax_inner():
+    v = SchemaValidator({'type': 'flat-list', 'items_schema': 'int'})
+    assert v.validate_python([(1, '2'), [3]]) == [1, 2, 3]
+    assert v.validate_python(([1], (2,))) == [1, 2]
+
+
+def test_no_items_schema():
+    v = SchemaValidator({'type': 'flat-list'})
+    assert v.validate_python([[1, 'a'], [None]]) == [1, 'a', None]
+
+
+def test_max_depth():
+    v = SchemaValidator({'type': 'flat-list', 'items_schema': 'int', 'max_depth': 2})
+    assert v.validate_python([[[1, 2], [3]], [[4]]]) == [1, 2, 3, 4]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([[[1], 2]])
+    assert exc_info.value.errors(include_url=False) == [
+        {'kind': 'list_type', 'loc': [0, 1], 'message': 'Input should be a valid list/array', 'input_value': 2}
+    ]
+
+    v = SchemaValidator({'type': 'flat-list', 'items_schema': 'int', 'max_depth': 0})
+    assert v.validate_python([1, '2']) == [1, 2]
+
+
+def test_error_locations(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'flat-list', 'items_schema': 'int'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([[1, 'x'], [], [3, 4, 'y']])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'int_parsing',
+            'loc': [0, 1],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'x',
+        },
+        {
+            'kind': 'int_parsing',
+            'loc': [2, 2],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'y',
+        },
+    ]
+
+
+def test_strict():
+    v = SchemaValidator({'type': 'flat-list', 'items_schema': 'int', 'strict': True})
+    assert v.validate_python([[1], [2]]) == [1, 2]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([(1,)])
+    assert exc_info.value.errors(include_url=False) == [
+        {'kind': 'list_type', 'loc': [0], 'message': 'Input should be a valid list/array', 'input_value': (1,)}
+    ]
+
+
+def test_repr():
+    v = SchemaValidator({'type': 'flat-list', 'items_schema': 'int'})
+    assert repr(v).startswith('SchemaValidator(name="flat-list[int]", validator=FlatList(\n')
@@ -5,7 +5,7 @@ from typing import Any, Dict
 
 import pytest
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
 
 from ..conftest import Err, PyAndJson, plain_repr
 
@@ -23,8 +23,8 @@ from ..conftest import Err, PyAndJson, plain_repr
         (1e10, 1e10),
         (True, 1),
         (False, 0),
-        ('wrong', Err('Input should be a valid number, unable to parse string as an number [kind=float_parsing')),
-        ([1, 2], Err('Input should be a valid number [kind=float_type, input_value=[1, 2], input_type=list]')),
+        ('wrong', Err('float_parsing  Input should be a valid number, unable to parse string as an number')),
+        ([1, 2], Err('float_type  Input should be a valid number  [1, 2]')),
     ],
 )
 def test_float(py_and_json: PyAndJson, input_value, expected):
@@ -46,8 +46,8 @@ def test_float(py_and_json: PyAndJson, input_value, expected):
         (42, 42),
         (42.0, 42.0),
         (42.5, 42.5),
-        ('42', Err("Input should be a valid number [kind=float_type, input_value='42', input_type=str]")),
-        (True, Err('Input should be a valid number [kind=float_type, input_value=True, input_type=bool]')),
+        ('42', Err("float_type  Input should be a valid number  '42'")),
+        (True, Err('float_type  Input should be a valid number  True')),
     ],
     ids=repr,
 )
@@ -68,16 +68,9 @@ def test_float_strict(py_and_json: PyAndJson, input_value, expected):
         ({}, 0, 0),
         ({}, '123.456', 123.456),
         ({'ge': 0}, 0, 0),
-        (
-            {'ge': 0},
-            -0.1,
-            Err(
-                'Input should be greater than or equal to 0 '
-                '[kind=greater_than_equal, input_value=-0.1, input_type=float]'
-            ),
-        ),
+        ({'ge': 0}, -0.1, Err('greater_than_equal  Input should be greater than or equal to 0  -0.1')),
         ({'gt': 0}, 0.1, 0.1),
-        ({'gt': 0}, 0, Err('Input should be greater than 0 [kind=greater_than, input_value=0, input_type=int]')),
+        ({'gt': 0}, 0, Err('greater_than  Input should be greater than 0  0')),
         ({'le': 0}, 0, 0),
         ({'le': 0}, -1, -1),
         ({'le': 0}, 0.1, Err('Input should be less than or equal to 0')),
@@ -86,6 +79,21 @@ def test_float_strict(py_and_json: PyAndJson, input_value, expected):
         ({'multiple_of': 0.5}, 0.5, 0.5),
         ({'multiple_of': 0.5}, 1, 1),
         ({'multiple_of': 0.5}, 0.6, Err('Input should be a multiple of 0.5')),
+        ({'multiple_of': 0.1}, 0.3, 0.3),
+        ({'multiple_of': 0.1}, 0.7, 0.7),
+        ({'multiple_of': 0.1}, 1.1, 1.1),
+        ({'multiple_of': 0.1}, -0.3, -0.3),
+        ({'multiple_of': 0.1}, 12345678.9, 12345678.9),
+        ({'multiple_of': 0.1}, 0.35, Err('Input should be a multiple of 0.1')),
+        ({'multiple_of': 0.01}, 0.07, 0.07),
+        ({'multiple_of': 0.01}, 0.075, Err('Input should be a multiple of 0.01')),
+        ({'multiple_of': 0.1}, 0.1000001, Err('Input should be a multiple of 0.1')),
+        ({'multiple_of': 2.0}, 2000000000.0, 2000000000.0),
+        ({'multiple_of': 2.0}, 2000000001.0, Err('Input should be a multiple of 2')),
+        ({'multiple_of': 0.5}, 1e12 + 0.5, 1e12 + 0.5),
+        ({'multiple_of': 0.5}, 1e12 + 0.25, Err('Input should be a multiple of 0.5')),
+        ({'multiple_of': 3.0}, -3e15, -3e15),
+        ({'multiple_of': 3.0}, -3e15 - 1, Err('Input should be a multiple of 3')),
     ],
 )
 def test_float_kwargs(py_and_json: PyAndJson, kwargs: Dict[str, Any], input_value, expected):
@@ -93,7 +101,7 @@ def test_float_kwargs(py_and_json: PyAndJson, kwargs: Dict[str, Any], input_valu
     if isinstance(expected, Err):
         with pytest.raises(ValidationError, match=re.escape(expected.message)) as exc_info:
             v.validate_test(input_value)
-        errors = exc_info.value.errors()
+        errors = exc_info.value.errors(include_url=False)
         assert len(errors) == 1
         if 'context' in errors[0]:
             assert errors[0]['context'] == kwargs
@@ -103,6 +111,12 @@ def test_float_kwargs(py_and_json: PyAndJson, kwargs: Dict[str, Any], input_valu
         assert isinstance(output, float)
 
 
+@pytest.mark.parametrize('multiple_of', [0, 0.0, -0.5])
+def test_multiple_of_not_positive(multiple_of):
+    with pytest.raises(SchemaError, match="'multiple_of' should be greater than 0"):
+        SchemaValidator({'type': 'float', 'multiple_of': multiple_of})
+
+
 def test_union_float(py_and_json: PyAndJson):
     v = py_and_json(
         {'type': 'union', 'choices': [{'type': 'float', 'strict': True}, {'type': 'float', 'multiple_of': 7}]}
@@ -111,7 +125,7 @@ def test_union_float(py_and_json: PyAndJson):
     assert v.validate_test(5) == 5
     with pytest.raises(ValidationError) as exc_info:
         v.validate_test('5')
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {'kind': 'float_type', 'loc': ['float'], 'message': 'Input should be a valid number', 'input_value': '5'},
         {
             'kind': 'multiple_of',
@@ -129,7 +143,7 @@ def test_union_float_simple(py_and_json: PyAndJson):
     with pytest.raises(ValidationError) as exc_info:
         v.validate_test('xxx')
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'float_parsing',
             'loc': ['float'],
@@ -141,9 +155,13 @@ def test_union_float_simple(py_and_json: PyAndJson):
 
 def test_float_repr():
     v = SchemaValidator({'type': 'float'})
-    assert plain_repr(v) == 'SchemaValidator(name="float",validator=Float(FloatValidator{strict:false}))'
+    assert plain_repr(v) == (
+        'SchemaValidator(name="float",validator=Float(FloatValidator{strict:false,allow_inf_nan:false}))'
+    )
     v = SchemaValidator({'type': 'float', 'strict': True})
-    assert plain_repr(v) == 'SchemaValidator(name="float",validator=Float(FloatValidator{strict:true}))'
+    assert plain_repr(v) == (
+        'SchemaValidator(name="float",validator=Float(FloatValidator{strict:true,allow_inf_nan:false}))'
+    )
     v = SchemaValidator({'type': 'float', 'multiple_of': 7})
     assert plain_repr(v).startswith('SchemaValidator(name="constrained-float",validator=ConstrainedFloat(')
 
@@ -161,7 +179,7 @@ def test_float_not_json(input_value, expected):
 
 
 def test_float_nan(py_and_json: PyAndJson):
-    v = py_and_json({'type': 'float'})
+    v = py_and_json({'type': 'float', 'allow_inf_nan': True})
     assert v.validate_test('1' * 800) == float('inf')
     assert v.validate_test('-' + '1' * 800) == float('-inf')
     r = v.validate_test('nan')
@@ -174,3 +192,96 @@ def test_float_key(py_and_json: PyAndJson):
     assert v.validate_test({'1.5': 1, '2.4': 2}) == {1.5: 1, 2.4: 2}
     with pytest.raises(ValidationError, match='Input should be a valid number'):
         v.validate_test({'1.5': 1, '2.5': 2}, strict=True)
+
+
+@pytest.mark.parametrize(
+    'input_value',
+    [float('nan'), float('inf'), float('-inf'), 'nan', 'inf', '-inf', '1' * 800],
+    ids=repr,
+)
+@pytest.mark.parametrize('schema', [{'type': 'float'}, {'type': 'float', 'allow_inf_nan': False}, 'finite-float'])
+def test_float_inf_nan_forbidden(schema, input_value):
+    v = SchemaValidator(schema)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    if input_value == '1' * 800:
+        # inputs with a repr longer than 200 characters are included as the truncated repr
+        input_value = "'" + '1' * 98 + '...' + '1' * 97 + "'"
+    assert exc_info.value.errors(include_url=False) == [
+        {'kind': 'finite_number', 'loc': [], 'message': 'Input should be a finite number', 'input_value': input_value}
+    ]
+    assert v.validate_python(1.5) == 1.5
+
+
+def test_float_inf_nan_forbidden_json():
+    v = SchemaValidator({'type': 'float', 'allow_inf_nan': False})
+    assert v.validate_json('123.4') == 123.4
+    with pytest.raises(ValidationError, match=r'finite_number  Input should be a finite number'):
+        v.validate_json('"inf"')
+
+
+def test_float_inf_nan_config():
+    v = SchemaValidator({'type': 'float', 'ge': 0}, {'allow_inf_nan': False})
+    with pytest.raises(ValidationError, match=r'finite_number  Input should be a finite number'):
+        v.validate_python(float('inf'))
+    v = SchemaValidator({'type': 'float', 'allow_inf_nan': True}, {'allow_inf_nan': False})
+    assert v.validate_python(float('inf')) == float('inf')
+    v = SchemaValidator({'type': 'float', 'ge': 0}, {'allow_inf_nan': True})
+    assert v.validate_python(float('inf')) == float('inf')
+
+
+def test_finite_float_ignores_allow_inf_nan():
+    v = SchemaValidator({'type': 'finite-float', 'allow_inf_nan': True}, {'allow_inf_nan': True})
+    assert v.validate_python(1.5) == 1.5
+    with pytest.raises(ValidationError, match=r'finite_number  Input should be a finite number'):
+        v.validate_python(float('nan'))
+    with pytest.raises(ValidationError, match=r'finite_number  Input should be a finite number'):
+        v.validate_json('"-inf"')
+
+
+@pytest.mark.parametrize(
+    'kwargs,kind',
+    [
+        ({'le': 5}, 'less_than_equal'),
+        ({'lt': 5}, 'less_than'),
+        ({'ge': 5}, 'greater_than_equal'),
+        ({'gt': 5}, 'greater_than'),
+    ],
+)
+def test_float_nan_bounds(kwargs: Dict[str, Any], kind):
+    v = SchemaValidator({'type': 'float', 'allow_inf_nan': True, **kwargs})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(float('nan'))
+    assert exc_info.value.errors()[0]['kind'] == kind
+
+
+@pytest.mark.parametrize(
+    'shorthand,long_form',
+    [
+        ('positive-float', {'type': 'float', 'gt': 0}),
+        ('negative-float', {'type': 'float', 'lt': 0}),
+        ('non-negative-float', {'type': 'float', 'ge': 0}),
+        ('non-positive-float', {'type': 'float', 'le': 0}),
+    ],
+)
+@pytest.mark.parametrize('input_value', [1.5, 1, '0.1', 0, -0.0, -1, '-2.5', 'x'])
+def test_float_shorthand(shorthand, long_form, input_value):
+    v_shorthand = SchemaValidator(shorthand)
+    v_long_form = SchemaValidator(long_form)
+    try:
+        expected = v_long_form.validate_python(input_value)
+    except ValidationError as e:
+        with pytest.raises(ValidationError) as exc_info:
+            v_shorthand.validate_python(input_value)
+        assert exc_info.value.errors(include_url=False) == e.errors(include_url=False)
+    else:
+        assert v_shorthand.validate_python(input_value) == expected
+
+
+def test_float_shorthand_constraints(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'negative-float', 'multiple_of': 0.5})
+    assert v.validate_test(-1.5) == -1.5
+    with pytest.raises(ValidationError, match='Input should be less than 0'):
+        v.validate_test(0.5)
+    with pytest.raises(ValidationError, match='Input should be a multiple of 0.5'):
+        v.validate_test(-0.3)
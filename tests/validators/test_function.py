@@ -5,7 +5,7 @@ from typing import Type
 
 import pytest
 
-from pydantic_core import PydanticValueError, SchemaError, SchemaValidator, ValidationError
+from pydantic_core import PydanticValueError, SchemaError, SchemaValidator, ValidationError, custom_error
 
 from ..conftest import plain_repr
 
@@ -28,7 +28,7 @@ def test_function_before_raise():
     with pytest.raises(ValidationError) as exc_info:
         assert v.validate_python('input value') == 'input value Changed'
     # debug(str(exc_info.value))
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'value_error',
             'loc': [],
@@ -50,7 +50,7 @@ def test_function_before_error():
     assert v.validate_python('1234') == '1234x'
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python('12345')
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'too_long',
             'loc': [],
@@ -79,7 +79,7 @@ def test_function_before_error_model():
     assert v.validate_python({'my_field': '1234'}) == {'my_field': '1234x'}
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python({'my_field': '12345'})
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'too_long',
             'loc': ['my_field'],
@@ -106,7 +106,7 @@ def test_function_wrap_repr():
 
     v = SchemaValidator({'type': 'function', 'mode': 'wrap', 'function': f, 'schema': 'str'})
 
-    assert v.validate_python('input value') == 'ValidatorCallable(Str(StrValidator{strict:false}))'
+    assert v.validate_python('input value') == 'ValidatorCallable(Str(StrValidator{strict:false,bytes_encoding:None}))'
 
 
 def test_function_wrap_str():
@@ -115,7 +115,7 @@ def test_function_wrap_str():
 
     v = SchemaValidator({'type': 'function', 'mode': 'wrap', 'function': f, 'schema': 'str'})
 
-    assert v.validate_python('input value') == 'ValidatorCallable(Str(StrValidator{strict:false}))'
+    assert v.validate_python('input value') == 'ValidatorCallable(Str(StrValidator{strict:false,bytes_encoding:None}))'
 
 
 def test_function_wrap_not_callable():
@@ -135,7 +135,7 @@ def test_wrap_error():
     assert v.validate_python('42') == 84
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python('wrong')
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'int_parsing',
             'loc': [],
@@ -241,7 +241,7 @@ def test_validate_assignment():
 
     m = {'field_a': 'test', 'more': 'foobar'}
     assert v.validate_python({'field_a': 'test'}) == m
-    assert v.validate_assignment('field_a', b'abc', m) == {'field_a': 'abc', 'more': 'foobar'}
+    assert v.validate_assignment(m, 'field_a', b'abc') == {'field_a': 'abc', 'more': 'foobar'}
 
 
 def test_function_wrong_sig():
@@ -286,7 +286,7 @@ def test_class_with_validator():
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python(True)
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {'kind': 'str_type', 'loc': [], 'message': 'Input should be a valid string', 'input_value': True}
     ]
 
@@ -300,7 +300,7 @@ def test_raise_assertion_error():
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python('input value')
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'assertion_error',
             'loc': [],
@@ -320,7 +320,7 @@ def test_raise_assertion_error_plain():
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python('input value')
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'assertion_error',
             'loc': [],
@@ -360,24 +360,39 @@ def test_pydantic_value_error():
     e = PydanticValueError(
         'my_error', 'this is a custom error {missed} {foo} {bar} {spam}', {'foo': 'X', 'bar': 42, 'spam': []}
     )
-    assert e.message() == 'this is a custom error {missed} X 42 []'
+    assert e.message() == 'this is a custom error ? X 42 []'
     assert e.message_template == 'this is a custom error {missed} {foo} {bar} {spam}'
     assert e.kind == 'my_error'
     assert e.context == {'foo': 'X', 'bar': 42, 'spam': []}
-    assert str(e) == 'this is a custom error {missed} X 42 []'
+    assert str(e) == 'this is a custom error ? X 42 []'
     assert repr(e) == (
-        "this is a custom error {missed} X 42 [] [kind=my_error, context={'foo': 'X', 'bar': 42, 'spam': []}]"
+        "this is a custom error ? X 42 [] [kind=my_error, context={'foo': 'X', 'bar': 42, 'spam': []}]"
     )
 
 
 def test_pydantic_value_error_none():
     e = PydanticValueError('my_error', 'this is a custom error {missed}')
-    assert e.message() == 'this is a custom error {missed}'
+    assert e.message() == 'this is a custom error ?'
     assert e.message_template == 'this is a custom error {missed}'
     assert e.kind == 'my_error'
     assert e.context is None
-    assert str(e) == 'this is a custom error {missed}'
-    assert repr(e) == 'this is a custom error {missed} [kind=my_error, context=None]'
+    assert str(e) == 'this is a custom error ?'
+    assert repr(e) == 'this is a custom error ? [kind=my_error, context=None]'
+
+
+@pytest.mark.parametrize(
+    'message_template,context,expected',
+    [
+        ('Value must be one of {choices}', {'choices': ['a', 'b']}, "Value must be one of ['a', 'b']"),
+        ('{a} and {b}', {'a': 1}, '1 and ?'),
+        ('{a}{a}', {'a': 'x'}, 'xx'),
+        # values aren't themselves treated as templates
+        ('got {value}', {'value': '{other}'}, 'got {other}'),
+        ('not a placeholder: {} { a }', {'a': 1}, 'not a placeholder: {} { a }'),
+    ],
+)
+def test_pydantic_value_error_template(message_template, context, expected):
+    assert PydanticValueError('my_error', message_template, context).message() == expected
 
 
 def test_pydantic_value_error_usage():
@@ -389,7 +404,7 @@ def test_pydantic_value_error_usage():
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python(42)
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'my_error',
             'loc': [],
@@ -398,6 +413,57 @@ def test_pydantic_value_error_usage():
             'context': {'foo': 'FOOBAR', 'bar': 42},
         }
     ]
+    # custom errors aren't documented, so have no url
+    assert 'url' not in exc_info.value.errors()[0]
+
+
+def test_custom_error():
+    def f(input_value, **kwargs):
+        if input_value % 2:
+            raise custom_error('not_even', 'Input should be even, got {value}', {'value': input_value})
+        return input_value
+
+    v = SchemaValidator({'type': 'function', 'mode': 'after', 'function': f, 'schema': 'int'})
+    assert v.validate_python('4') == 4
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('3')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'not_even',
+            'loc': [],
+            'message': 'Input should be even, got 3',
+            'input_value': '3',
+            'context': {'value': 3},
+        }
+    ]
+
+
+def test_custom_error_no_context():
+    e = custom_error('my_error', 'this is a custom error')
+    assert isinstance(e, PydanticValueError)
+    assert e.kind == 'my_error'
+    assert e.context is None
+    assert str(e) == 'this is a custom error'
+
+
+@pytest.mark.parametrize('kind', ['', 'my error', '1st_error', 'my-error', 'error!'])
+def test_custom_error_invalid_kind(kind):
+    with pytest.raises(TypeError, match='kind should be a valid python identifier, got '):
+        custom_error(kind, 'this is a custom error')
+    with pytest.raises(TypeError, match='kind should be a valid python identifier, got '):
+        PydanticValueError(kind, 'this is a custom error')
+
+
+def test_custom_error_invalid_kind_in_validator():
+    def f(input_value, **kwargs):
+        raise custom_error('not even', 'Input should be even')
+
+    v = SchemaValidator({'type': 'function', 'mode': 'plain', 'function': f})
+
+    # a mistake in the validator is a runtime error, not a validation error
+    with pytest.raises(TypeError, match='kind should be a valid python identifier'):
+        v.validate_python(3)
 
 
 def test_pydantic_value_error_invalid_dict():
@@ -409,11 +475,12 @@ def test_pydantic_value_error_invalid_dict():
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python(42)
 
-    assert str(exc_info.value) == (
+    assert repr(exc_info.value) == (
         '1 validation error for function-plain\n'
         "  (error rendering message: TypeError: 'tuple' object cannot be converted to 'PyString') "
         '[kind=my_error, input_value=42, input_type=int]'
     )
+    assert "my_error  (error rendering message: TypeError: 'tuple' object cannot be converted" in str(exc_info.value)
     with pytest.raises(TypeError, match="'tuple' object cannot be converted to 'PyString'"):
         exc_info.value.errors()
 
@@ -426,3 +493,50 @@ def test_pydantic_value_error_invalid_type():
 
     with pytest.raises(TypeError, match="argument 'context': 'list' object cannot be converted to 'PyDict'"):
         v.validate_python(42)
+
+
+@pytest.mark.parametrize('mode', ['before', 'after', 'wrap', 'plain'])
+def test_signature_check_incompatible(mode):
+    schema = {'type': 'function', 'mode': mode, 'function': lambda: None, 'signature_check': True}
+    if mode != 'plain':
+        schema['schema'] = 'str'
+    with pytest.raises(SchemaError, match=f'function signature \\(\\) is incompatible with mode "{mode}"'):
+        SchemaValidator(schema)
+
+
+def test_signature_check_message():
+    def f(input_value, *, data):
+        return input_value
+
+    with pytest.raises(SchemaError) as exc_info:
+        SchemaValidator({'type': 'function', 'mode': 'before', 'function': f, 'schema': 'int', 'signature_check': True})
+    assert exc_info.value.args[0] == (
+        'Invalid Schema:\n'
+        'function\n'
+        '  function signature (input_value, *, data) is incompatible with mode "before", '
+        "it should accept (value, *, data, config, context): got an unexpected keyword argument 'config'"
+    )
+
+
+@pytest.mark.parametrize(
+    'mode,function',
+    [
+        ('before', lambda value, *, data, config, context: value),
+        ('after', lambda value, **kwargs: value),
+        ('wrap', lambda value, *, validator, **kwargs: validator(value)),
+        ('plain', lambda *args, **kwargs: args[0]),
+    ],
+)
+def test_signature_check_compatible(mode, function):
+    schema = {'type': 'function', 'mode': mode, 'function': function, 'signature_check': True}
+    if mode != 'plain':
+        schema['schema'] = 'str'
+    v = SchemaValidator(schema)
+    assert v.validate_python(' x ')
+
+
+def test_signature_check_default():
+    # not checked by default, so the error happens on validation
+    v = SchemaValidator({'type': 'function', 'mode': 'before', 'function': lambda: None, 'schema': 'str'})
+    with pytest.raises(TypeError, match='got an unexpected keyword argument'):
+        v.validate_python('x')
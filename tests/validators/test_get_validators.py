@@ -0,0 +1,62 @@
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
+
+
+class Even:
+    def __init__(self, value: int):
+        self.value = value
+
+    @classmethod
+    def __get_validators__(cls):
+        yield cls.validate_int
+        yield cls.validate_even
+
+    @classmethod
+    def validate_int(cls, v):
+        return int(v)
+
+    @classmethod
+    def validate_even(cls, v):
+        if v % 2:
+            raise ValueError('not even')
+        return cls(v)
+
+
+def test_get_validators():
+    with pytest.warns(DeprecationWarning, match=r'`Even.__get_validators__` is deprecated'):
+        v = SchemaValidator({'type': 'get-validators', 'class_type': Even})
+
+    assert 'name="get-validators[Even]"' in repr(v)
+    output = v.validate_python('42')
+    assert isinstance(output, Even)
+    assert output.value == 42
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(3)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'value_error',
+            'loc': [],
+            'message': 'Value error, not even',
+            'input_value': 3,
+            'context': {'error': 'not even'},
+        }
+    ]
+
+
+def test_get_validators_in_dict():
+    with pytest.warns(DeprecationWarning):
+        v = SchemaValidator(
+            {'type': 'typed-dict', 'fields': {'n': {'schema': {'type': 'get-validators', 'class_type': Even}}}}
+        )
+    assert v.validate_python({'n': 2})['n'].value == 2
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'n': 1})
+    assert exc_info.value.errors()[0]['loc'] == ['n']
+
+
+def test_no_get_validators():
+    with pytest.raises(SchemaError, match='int has no `__get_validators__` method'):
+        SchemaValidator({'type': 'get-validators', 'class_type': int})
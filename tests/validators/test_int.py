@@ -4,7 +4,7 @@ from typing import Any, Dict
 
 import pytest
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
 
 from ..conftest import Err, PyAndJson, plain_repr
 
@@ -23,16 +23,16 @@ from ..conftest import Err, PyAndJson, plain_repr
         (int(1e10), int(1e10)),
         pytest.param(
             12.5,
-            Err('Input should be a valid integer, got a number with a fractional part [kind=int_from_float'),
+            Err('int_from_float  Input should be a valid integer, got a number with a fractional part'),
             id='float-remainder',
         ),
         pytest.param(
             'wrong',
-            Err('Input should be a valid integer, unable to parse string as an integer [kind=int_parsing'),
+            Err('int_parsing  Input should be a valid integer, unable to parse string as an integer'),
             id='string',
         ),
-        pytest.param(None, Err('Input should be a valid integer [kind=int_type'), id='list'),
-        pytest.param([1, 2], Err('Input should be a valid integer [kind=int_type'), id='list'),
+        pytest.param(None, Err('int_type  Input should be a valid integer'), id='list'),
+        pytest.param([1, 2], Err('int_type  Input should be a valid integer'), id='list'),
     ],
 )
 def test_int_py_and_json(py_and_json: PyAndJson, input_value, expected):
@@ -54,16 +54,12 @@ def test_int_py_and_json(py_and_json: PyAndJson, input_value, expected):
         pytest.param(
             Decimal('1.001'),
             Err(
-                'Input should be a valid integer, got a number with a fractional part '
-                "[kind=int_from_float, input_value=Decimal('1.001'), input_type=Decimal]"
+                'int_from_float  Input should be a valid integer, got a number with a fractional part  '
+                "Decimal('1.001')"
             ),
             id='decimal-remainder',
         ),
-        pytest.param(
-            (1, 2),
-            Err('Input should be a valid integer [kind=int_type, input_value=(1, 2), input_type=tuple]'),
-            id='tuple',
-        ),
+        pytest.param((1, 2), Err('int_type  Input should be a valid integer  (1, 2)'), id='tuple'),
     ],
 )
 def test_int(input_value, expected):
@@ -83,22 +79,10 @@ def test_int(input_value, expected):
         (0, 0),
         (1, 1),
         (42, 42),
-        pytest.param(
-            42.0,
-            Err('Input should be a valid integer [kind=int_type, input_value=42.0, input_type=float]'),
-            id='float-exact',
-        ),
-        pytest.param(
-            42.5,
-            Err('Input should be a valid integer [kind=int_type, input_value=42.5, input_type=float]'),
-            id='float-remainder',
-        ),
-        pytest.param(
-            '42', Err("Input should be a valid integer [kind=int_type, input_value='42', input_type=str]"), id='string'
-        ),
-        pytest.param(
-            True, Err('Input should be a valid integer [kind=int_type, input_value=True, input_type=bool]'), id='bool'
-        ),
+        pytest.param(42.0, Err('int_type  Input should be a valid integer  42.0'), id='float-exact'),
+        pytest.param(42.5, Err('int_type  Input should be a valid integer  42.5'), id='float-remainder'),
+        pytest.param('42', Err("int_type  Input should be a valid integer  '42'"), id='string'),
+        pytest.param(True, Err('int_type  Input should be a valid integer  True'), id='bool'),
     ],
 )
 def test_int_strict(py_and_json: PyAndJson, input_value, expected):
@@ -116,16 +100,9 @@ def test_int_strict(py_and_json: PyAndJson, input_value, expected):
         ({}, 0, 0),
         ({}, '123.000', 123),
         ({'ge': 0}, 0, 0),
-        (
-            {'ge': 0},
-            -1,
-            Err(
-                'Input should be greater than or equal to 0 '
-                '[kind=greater_than_equal, input_value=-1, input_type=int]'
-            ),
-        ),
+        ({'ge': 0}, -1, Err('greater_than_equal  Input should be greater than or equal to 0  -1')),
         ({'gt': 0}, 1, 1),
-        ({'gt': 0}, 0, Err('Input should be greater than 0 [kind=greater_than, input_value=0, input_type=int]')),
+        ({'gt': 0}, 0, Err('greater_than  Input should be greater than 0  0')),
         ({'le': 0}, 0, 0),
         ({'le': 0}, -1, -1),
         ({'le': 0}, 1, Err('Input should be less than or equal to 0')),
@@ -142,7 +119,7 @@ def test_int_kwargs(py_and_json: PyAndJson, kwargs: Dict[str, Any], input_value,
         with pytest.raises(ValidationError, match=re.escape(expected.message)) as exc_info:
             v.validate_test(input_value)
 
-        errors = exc_info.value.errors()
+        errors = exc_info.value.errors(include_url=False)
         assert len(errors) == 1
         if 'context' in errors[0]:
             assert errors[0]['context'] == kwargs
@@ -159,7 +136,7 @@ def test_union_int(py_and_json: PyAndJson):
     with pytest.raises(ValidationError) as exc_info:
         v.validate_test('5')
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {'kind': 'int_type', 'loc': ['int'], 'message': 'Input should be a valid integer', 'input_value': '5'},
         {
             'kind': 'multiple_of',
@@ -177,7 +154,7 @@ def test_union_int_simple(py_and_json: PyAndJson):
     with pytest.raises(ValidationError) as exc_info:
         v.validate_test('xxx')
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'int_parsing',
             'loc': ['int'],
@@ -189,25 +166,44 @@ def test_union_int_simple(py_and_json: PyAndJson):
 
 def test_int_repr():
     v = SchemaValidator({'type': 'int'})
-    assert plain_repr(v) == 'SchemaValidator(name="int",validator=Int(IntValidator{strict:false}))'
+    assert plain_repr(v) == (
+        'SchemaValidator(name="int",validator=Int('
+        'IntValidator{strict:false,allow_inexact_int_from_float:false}))'
+    )
     v = SchemaValidator({'type': 'int', 'strict': True})
-    assert plain_repr(v) == 'SchemaValidator(name="int",validator=Int(IntValidator{strict:true}))'
+    assert plain_repr(v) == (
+        'SchemaValidator(name="int",validator=Int('
+        'IntValidator{strict:true,allow_inexact_int_from_float:false}))'
+    )
     v = SchemaValidator({'type': 'int', 'multiple_of': 7})
     assert plain_repr(v).startswith('SchemaValidator(name="constrained-int",validator=ConstrainedInt(')
 
 
+@pytest.mark.parametrize('multiple_of', [0, -1])
+def test_multiple_of_not_positive(multiple_of):
+    with pytest.raises(SchemaError, match="'multiple_of' should be greater than 0"):
+        SchemaValidator({'type': 'int', 'multiple_of': multiple_of})
+
+
+def test_multiple_of_min_int():
+    v = SchemaValidator({'type': 'int', 'multiple_of': 2})
+    assert v.validate_python(-(2**63)) == -(2**63)
+    with pytest.raises(ValidationError, match='Input should be a multiple of 2'):
+        v.validate_python(-(2**63) + 1)
+
+
 def test_long_int(py_and_json: PyAndJson):
     v = py_and_json({'type': 'int'})
 
     with pytest.raises(ValidationError) as exc_info:
         v.validate_test('1' * 400)
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'int_nan',
             'loc': [],
             'message': 'Input should be a valid integer, got infinity',
-            'input_value': '1' * 400,
+            'input_value': "'" + '1' * 98 + '...' + '1' * 97 + "'",
             'context': {'nan_value': 'infinity'},
         }
     ]
@@ -229,8 +225,144 @@ def test_int_nan(py_and_json: PyAndJson):
         v.validate_test('nan')
 
 
+@pytest.mark.parametrize('strict', [False, True])
+def test_json_int_exact(strict):
+    v = SchemaValidator({'type': 'int', 'strict': strict})
+    # 2**53 + 1, not representable as a float
+    assert v.validate_json('9007199254740993') == 9007199254740993
+    assert v.validate_json('-9007199254740993') == -9007199254740993
+    assert v.validate_json('9223372036854775807') == 9223372036854775807
+    assert v.validate_json('-9223372036854775808') == -9223372036854775808
+    assert SchemaValidator({'type': 'list', 'items_schema': 'int'}).validate_json('[9007199254740993]') == [
+        9007199254740993
+    ]
+
+
+def test_json_int_too_large():
+    v = SchemaValidator('int')
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('18446744073709551615')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'int_too_large',
+            'loc': [],
+            'message': 'Input should be a valid integer, got a number too large to represent',
+            'input_value': 1.8446744073709552e19,
+        }
+    ]
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_json('18446744073709551615', strict=True)
+    # previously wrapped to `-1`
+    assert SchemaValidator('any').validate_json('18446744073709551615') == 1.8446744073709552e19
+
+
+@pytest.mark.parametrize('input_value', [2.0**63, -(2.0**63) * 2, '1e20'])
+def test_int_too_large_float(input_value):
+    v = SchemaValidator('int')
+    with pytest.raises(
+        ValidationError, match=r'int_too_large  Input should be a valid integer, got a number too large to represent'
+    ):
+        v.validate_python(input_value)
+
+
 def test_int_key(py_and_json: PyAndJson):
     v = py_and_json({'type': 'dict', 'keys_schema': 'int', 'values_schema': 'int'})
     assert v.validate_test({'1': 1, '2': 2}) == {1: 1, 2: 2}
     with pytest.raises(ValidationError, match='Input should be a valid integer'):
         v.validate_test({'1': 1, '2': 2}, strict=True)
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (1.0, 1),
+        (1.5, 1),
+        (1.0000001, 1),
+        (-2.9, -2),
+        (3, 3),
+        ('4', 4),
+        ('4.5', Err('int_from_float  Input should be a valid integer, got a number with a fractional part')),
+        (float('nan'), Err('int_nan  Input should be a valid integer, got NaN')),
+    ],
+)
+def test_allow_inexact_int_from_float(input_value, expected):
+    v = SchemaValidator({'type': 'int', 'allow_inexact_int_from_float': True})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert isinstance(output, int)
+
+
+@pytest.mark.parametrize('allow_inexact', [False, None])
+def test_inexact_int_from_float_default(allow_inexact):
+    schema = {'type': 'int', 'ge': 0}
+    if allow_inexact is not None:
+        schema['allow_inexact_int_from_float'] = allow_inexact
+    v = SchemaValidator(schema)
+    assert v.validate_python(1.0) == 1
+    with pytest.raises(ValidationError, match='got a number with a fractional part'):
+        v.validate_python(1.0000001)
+
+
+def test_allow_inexact_int_from_float_strict():
+    v = SchemaValidator({'type': 'int', 'allow_inexact_int_from_float': True, 'strict': True})
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python(1.5)
+
+
+def test_allow_inexact_int_from_float_constrained(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'int', 'allow_inexact_int_from_float': True, 'lt': 10})
+    assert v.validate_test(9.9) == 9
+    with pytest.raises(ValidationError, match='Input should be less than 10'):
+        v.validate_test(10.5)
+
+
+@pytest.mark.parametrize('allow_inexact,expected', [(False, None), (True, {1: 2, 3: 4})])
+def test_inexact_int_from_float_dict(allow_inexact, expected):
+    int_schema = {'type': 'int', 'allow_inexact_int_from_float': allow_inexact}
+    v = SchemaValidator({'type': 'dict', 'keys_schema': int_schema, 'values_schema': int_schema})
+    assert v.validate_python({1.0: 2.0, 3.0: 4.0}) == {1: 2, 3: 4}
+    if expected is None:
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python({1.5: 2.0, 3.0: 4.1})
+        assert [(e['kind'], e['loc']) for e in exc_info.value.errors()] == [
+            ('int_from_float', ['1.5', '[key]']),
+            ('int_from_float', ['3.0']),
+        ]
+    else:
+        assert v.validate_python({1.5: 2.0, 3.0: 4.1}) == expected
+
+
+@pytest.mark.parametrize(
+    'shorthand,long_form',
+    [
+        ('positive-int', {'type': 'int', 'gt': 0}),
+        ('negative-int', {'type': 'int', 'lt': 0}),
+        ('non-negative-int', {'type': 'int', 'ge': 0}),
+        ('non-positive-int', {'type': 'int', 'le': 0}),
+    ],
+)
+@pytest.mark.parametrize('input_value', [2, 1, '1', 0, -1, '-2', 1.5, 'x'])
+def test_int_shorthand(shorthand, long_form, input_value):
+    v_shorthand = SchemaValidator(shorthand)
+    v_long_form = SchemaValidator(long_form)
+    try:
+        expected = v_long_form.validate_python(input_value)
+    except ValidationError as e:
+        with pytest.raises(ValidationError) as exc_info:
+            v_shorthand.validate_python(input_value)
+        assert exc_info.value.errors(include_url=False) == e.errors(include_url=False)
+    else:
+        assert v_shorthand.validate_python(input_value) == expected
+
+
+def test_int_shorthand_constraints(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'positive-int', 'le': 10, 'gt': -5})
+    assert v.validate_test(10) == 10
+    with pytest.raises(ValidationError, match='Input should be greater than 0'):
+        v.validate_test(0)
+    with pytest.raises(ValidationError, match='Input should be less than or equal to 10'):
+        v.validate_test(11)
@@ -0,0 +1,160 @@
+import re
+from ipaddress import IPv4Address, IPv6Address
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
+
+from ..conftest import Err, PyAndJson
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('192.168.0.1', IPv4Address('192.168.0.1')),
+        ('0.0.0.0', IPv4Address('0.0.0.0')),
+        ('255.255.255.255', IPv4Address('255.255.255.255')),
+        ('::1', IPv6Address('::1')),
+        ('::', IPv6Address('::')),
+        ('2001:db8::8a2e:370:7334', IPv6Address('2001:db8::8a2e:370:7334')),
+        ('2001:0db8:0000:0000:0000:8a2e:0370:7334', IPv6Address('2001:db8::8a2e:370:7334')),
+        ('::ffff:192.168.0.1', IPv6Address('::ffff:c0a8:1')),
+        ('256.0.0.1', Err('ip_address_parsing  Input should be a valid IP address, unable to parse input')),
+        ('192.168.0', Err('ip_address_parsing  Input should be a valid IP address, unable to parse input')),
+        ('2001:db8:::1', Err('ip_address_parsing  Input should be a valid IP address, unable to parse input')),
+        ('1:2:3:4:5:6:7:8:9', Err('ip_address_parsing  Input should be a valid IP address, unable to parse input')),
+        ('', Err('ip_address_parsing  Input should be a valid IP address, unable to parse input')),
+        (123, Err('ip_address_type  Input should be a valid IP address')),
+    ],
+)
+def test_ip_address(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json({'type': 'ip-address'})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+        assert type(output) == type(expected)
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        # the shortest and full forms of the same address are equal
+        ('1:0:0:0:0:0:0:8', '1::8'),
+        ('0001:0000:0000:0000:0000:0000:0000:0008', '1::8'),
+        ('1::2:3:4:5:6:7', '1:0:2:3:4:5:6:7'),
+        ('1:2:3:4:5:6:7::', '1:2:3:4:5:6:7:0'),
+        ('::2:3:4:5:6:7:8', '0:2:3:4:5:6:7:8'),
+    ],
+)
+def test_ipv6_notation(input_value, expected):
+    v = SchemaValidator({'type': 'ip-address'})
+    assert v.validate_python(input_value) == IPv6Address(expected)
+
+
+def test_ip_address_instances():
+    v = SchemaValidator({'type': 'ip-address', 'strict': True})
+    ipv4 = IPv4Address('127.0.0.1')
+    ipv6 = IPv6Address('::1')
+    assert v.validate_python(ipv4) is ipv4
+    assert v.validate_python(ipv6) is ipv6
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('127.0.0.1')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'ip_address_type',
+            'loc': [],
+            'message': 'Input should be a valid IP address',
+            'input_value': '127.0.0.1',
+        }
+    ]
+
+
+def test_ip_address_strict_call():
+    v = SchemaValidator({'type': 'ip-address'})
+    assert v.validate_python('127.0.0.1') == IPv4Address('127.0.0.1')
+    with pytest.raises(ValidationError, match='ip_address_type'):
+        v.validate_python('127.0.0.1', strict=True)
+
+
+@pytest.mark.parametrize(
+    'version,input_value,expected',
+    [
+        (4, '127.0.0.1', IPv4Address('127.0.0.1')),
+        (4, IPv4Address('127.0.0.1'), IPv4Address('127.0.0.1')),
+        (4, '::1', Err('ip_version  Input should be an IPv4 address')),
+        (4, IPv6Address('::1'), Err('ip_version  Input should be an IPv4 address')),
+        (6, '::1', IPv6Address('::1')),
+        (6, '127.0.0.1', Err('ip_version  Input should be an IPv6 address')),
+        (6, IPv4Address('127.0.0.1'), Err('ip_version  Input should be an IPv6 address')),
+    ],
+)
+def test_ip_version(version, input_value, expected):
+    v = SchemaValidator({'type': 'ip-address', 'version': version})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)) as exc_info:
+            v.validate_python(input_value)
+        assert exc_info.value.errors()[0]['context'] == {'version': version}
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_ip_version_invalid():
+    with pytest.raises(SchemaError, match='Input should be one of: 4, 6'):
+        SchemaValidator({'type': 'ip-address', 'version': 5})
+
+
+@pytest.mark.parametrize(
+    'schema,input_value,expected',
+    [
+        ({}, 3232235521, IPv4Address('192.168.0.1')),
+        ({}, 0, IPv4Address('0.0.0.0')),
+        ({}, 2**32 - 1, IPv4Address('255.255.255.255')),
+        ({}, 2**32, IPv6Address('::1:0:0')),
+        ({}, int(IPv6Address('2001:db8::1')), IPv6Address('2001:db8::1')),
+        ({}, 2**63, IPv6Address('::8000:0:0:0')),
+        ({}, 2**64, IPv6Address('::1:0:0:0:0')),
+        ({}, 2**128 - 1, IPv6Address('ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff')),
+        ({}, 2**128, Err('ip_address_parsing')),
+        ({}, -(2**100), Err('ip_address_parsing')),
+        ({}, True, Err('ip_address_type')),
+        ({'version': 6}, 1, IPv6Address('::1')),
+        ({'version': 4}, 2**32, Err('ip_version  Input should be an IPv4 address')),
+        ({}, -1, Err('ip_address_parsing')),
+        ({}, b'\xc0\xa8\x00\x01', IPv4Address('192.168.0.1')),
+        ({}, b'\x00' * 15 + b'\x01', IPv6Address('::1')),
+        ({}, b'\x00\x01', Err('ip_address_parsing')),
+        ({}, 1.5, Err('ip_address_type')),
+    ],
+)
+def test_allow_packed(schema, input_value, expected):
+    v = SchemaValidator({'type': 'ip-address', 'allow_packed': True, **schema})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert type(output) == type(expected)
+
+
+def test_allow_packed_json():
+    v = SchemaValidator({'type': 'ip-address', 'allow_packed': True})
+    assert v.validate_json('3232235521') == IPv4Address('192.168.0.1')
+    assert v.validate_json('"::1"') == IPv6Address('::1')
+
+
+def test_packed_not_allowed():
+    v = SchemaValidator('ip-address')
+    with pytest.raises(ValidationError, match='ip_address_type'):
+        v.validate_python(3232235521)
+    with pytest.raises(ValidationError, match='ip_address_type'):
+        v.validate_python(b'\xc0\xa8\x00\x01')
+
+
+def test_repr():
+    v = SchemaValidator({'type': 'union', 'choices': ['int', {'type': 'ip-address', 'version': 6}]})
+    with pytest.raises(ValidationError, match=r'ipv6-address +ip_version +Input should be an IPv6 address'):
+        v.validate_python('127.0.0.1')
@@ -1,3 +1,5 @@
+import re
+
 import pytest
 
 from pydantic_core import SchemaError, SchemaValidator, ValidationError
@@ -27,19 +29,19 @@ def test_is_instance():
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python(s)
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'is_instance_of',
             'loc': [],
-            'message': 'Input should be an instance of Foo',
+            'message': f'Input should be an instance of {__name__}.Foo',
             'input_value': s,
-            'context': {'class': 'Foo'},
+            'context': {'class': f'{__name__}.Foo'},
         }
     ]
-    with pytest.raises(ValidationError, match='kind=is_instance_of'):
+    with pytest.raises(ValidationError, match='is_instance_of'):
         v.validate_python(Foo)
 
-    with pytest.raises(ValidationError, match='kind=is_instance_of'):
+    with pytest.raises(ValidationError, match='is_instance_of'):
         v.validate_json('"foo"')
 
 
@@ -85,7 +87,7 @@ def test_instancecheck():
     v = SchemaValidator({'type': 'is-instance', 'class_': HasIsInstance})
     assert v.validate_python('true') == 'true'
 
-    with pytest.raises(ValidationError, match='kind=is_instance_of'):
+    with pytest.raises(ValidationError, match='is_instance_of'):
         v.validate_python('other')
 
     with pytest.raises(TypeError, match='intentional error'):
@@ -98,5 +100,95 @@ def test_repr():
     assert v.isinstance_python(Bar()) is True
     assert v.isinstance_python('foo') is False
 
-    with pytest.raises(ValidationError, match=r'is-instance\[Foo\]\s+Input should be an instance of Foo'):
+    foo_repr = re.escape(f'{__name__}.Foo')
+    with pytest.raises(
+        ValidationError, match=rf'is-instance\[{foo_repr}\] +is_instance_of +Input should be an instance of {foo_repr}'
+    ):
         v.validate_python('foo')
+
+
+def test_is_instance_tuple():
+    v = SchemaValidator({'type': 'is-instance', 'class_': (Foo, Spam)})
+    foo, bar, spam = Foo(), Bar(), Spam()
+    assert v.validate_python(foo) is foo
+    assert v.validate_python(bar) is bar
+    assert v.validate_python(spam) is spam
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(123)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'is_instance_of',
+            'loc': [],
+            'message': f'Input should be an instance of ({__name__}.Foo, {__name__}.Spam)',
+            'input_value': 123,
+            'context': {'class': f'({__name__}.Foo, {__name__}.Spam)'},
+        }
+    ]
+    assert repr(v).startswith(f'SchemaValidator(name="is-instance[({__name__}.Foo, {__name__}.Spam)]"')
+
+
+def test_is_instance_tuple_instancecheck():
+    v = SchemaValidator({'type': 'is-instance', 'class_': (int, HasIsInstance)})
+    assert v.validate_python(1) == 1
+    assert v.validate_python('true') == 'true'
+    with pytest.raises(ValidationError, match='is_instance_of'):
+        v.validate_python('other')
+
+
+def test_is_instance_tuple_invalid():
+    with pytest.raises(SchemaError, match='class_ tuple items should be classes, got 123'):
+        SchemaValidator({'type': 'is-instance', 'class_': (Foo, 123)})
+
+
+def test_is_instance_qualname():
+    class Outer:
+        class Inner:
+            pass
+
+    v = SchemaValidator({'type': 'is-instance', 'class_': Outer.Inner})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(1)
+    assert exc_info.value.errors()[0]['context'] == {
+        'class': f'{__name__}.test_is_instance_qualname.<locals>.Outer.Inner'
+    }
+
+
+def test_is_instance_cloned():
+    # lazy lists and recursive schemas hold clones of the item validator, the class references must survive that
+    v = SchemaValidator({'type': 'list', 'lazy': True, 'items_schema': {'type': 'is-instance', 'class_': (Foo, Spam)}})
+    foo, spam = Foo(), Spam()
+    output = v.validate_python([foo, spam, 1])
+    assert next(output) is foo
+    assert next(output) is spam
+    with pytest.raises(ValidationError) as exc_info:
+        next(output)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'is_instance_of',
+            'loc': [2],
+            'message': f'Input should be an instance of ({__name__}.Foo, {__name__}.Spam)',
+            'input_value': 1,
+            'context': {'class': f'({__name__}.Foo, {__name__}.Spam)'},
+        }
+    ]
+
+
+def test_is_instance_recursive():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'ref': 'Branch',
+            'fields': {
+                'leaf': {'schema': {'type': 'is-instance', 'class_': Foo}},
+                'branch': {
+                    'schema': {'type': 'nullable', 'schema': {'type': 'recursive-ref', 'schema_ref': 'Branch'}},
+                    'default': None,
+                },
+            },
+        }
+    )
+    bar = Bar()
+    assert v.validate_python({'leaf': Foo(), 'branch': {'leaf': bar}})['branch']['leaf'] is bar
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'leaf': Foo(), 'branch': {'leaf': Spam()}})
+    assert exc_info.value.errors()[0]['loc'] == ['branch', 'leaf']
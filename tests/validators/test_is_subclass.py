@@ -0,0 +1,172 @@
+import re
+from collections import OrderedDict
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
+
+
+class Foo:
+    pass
+
+
+class Bar(Foo):
+    pass
+
+
+class Spam:
+    pass
+
+
+def test_is_subclass():
+    v = SchemaValidator({'type': 'is-subclass', 'class_': Foo})
+    assert v.validate_python(Foo) is Foo
+    assert v.validate_python(Bar) is Bar
+    assert v.isinstance_python(Spam) is False
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(Spam)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'is_subclass_of',
+            'loc': [],
+            'message': f'Input should be a subclass of {__name__}.Foo',
+            'input_value': Spam,
+            'context': {'class': f'{__name__}.Foo'},
+        }
+    ]
+
+
+def test_instance_not_subclass():
+    v = SchemaValidator({'type': 'is-subclass', 'class_': Foo})
+    foo = Foo()
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(foo)
+    assert exc_info.value.errors(include_url=False) == [
+        {'kind': 'class_type', 'loc': [], 'message': 'Input should be a class', 'input_value': foo}
+    ]
+
+
+@pytest.mark.parametrize('input_value', [123, 'foo', None, [Foo]])
+def test_not_class(input_value):
+    v = SchemaValidator({'type': 'is-subclass', 'class_': Foo})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert exc_info.value.errors(include_url=False) == [
+        {'kind': 'class_type', 'loc': [], 'message': 'Input should be a class', 'input_value': input_value}
+    ]
+
+
+@pytest.mark.parametrize(
+    'schema_class,input_val,value',
+    [
+        (Foo, Foo, True),
+        (Foo, Bar, True),
+        (Bar, Foo, False),
+        (Foo, Foo(), False),
+        (object, int, True),
+        (type, Foo, False),
+        (dict, OrderedDict, True),
+        (int, 123, False),
+    ],
+)
+def test_is_subclass_cases(schema_class, input_val, value):
+    v = SchemaValidator({'type': 'is-subclass', 'class_': schema_class})
+    assert v.isinstance_python(input_val) == value
+
+
+class Meta(type):
+    pass
+
+
+class SubMeta(Meta):
+    pass
+
+
+class WithMeta(metaclass=Meta):
+    pass
+
+
+class WithSubMeta(metaclass=SubMeta):
+    pass
+
+
+def test_metaclass():
+    v = SchemaValidator({'type': 'is-subclass', 'class_': type})
+    assert v.validate_python(Meta) is Meta
+    assert v.validate_python(SubMeta) is SubMeta
+    assert v.isinstance_python(WithMeta) is False
+
+    v = SchemaValidator({'type': 'is-subclass', 'class_': Meta})
+    assert v.validate_python(SubMeta) is SubMeta
+    assert v.isinstance_python(type) is False
+    assert v.isinstance_python(WithSubMeta) is False
+
+
+class HasSubclassCheckMeta(type):
+    def __subclasscheck__(cls, subclass) -> bool:
+        return subclass.__name__.startswith('Yes')
+
+
+class HasSubclassCheck(metaclass=HasSubclassCheckMeta):
+    pass
+
+
+class YesPlease:
+    pass
+
+
+def test_subclasscheck():
+    v = SchemaValidator({'type': 'is-subclass', 'class_': HasSubclassCheck})
+    assert v.validate_python(YesPlease) is YesPlease
+    assert v.isinstance_python(Spam) is False
+
+
+def test_lax_string():
+    v = SchemaValidator({'type': 'is-subclass', 'class_': dict})
+    assert v.validate_python('collections.OrderedDict') is OrderedDict
+    assert v.validate_python('dict') is dict
+    assert v.validate_json('"collections.OrderedDict"') is OrderedDict
+    assert v.isinstance_python('builtins.list') is False
+    assert v.isinstance_python('collections.missing') is False
+    assert v.isinstance_python('not_a_module.Thing') is False
+    # the string refers to a class, it's just not a subclass
+    with pytest.raises(ValidationError, match='is_subclass_of'):
+        v.validate_python('builtins.list')
+    with pytest.raises(ValidationError, match='class_type'):
+        v.validate_python('collections.missing')
+    with pytest.raises(ValidationError, match='class_type'):
+        v.validate_python('collections.OrderedDict', strict=True)
+
+
+def test_lax_string_strict_schema():
+    v = SchemaValidator({'type': 'is-subclass', 'class_': dict, 'strict': True})
+    assert v.validate_python(OrderedDict) is OrderedDict
+    with pytest.raises(ValidationError, match='class_type'):
+        v.validate_python('collections.OrderedDict')
+
+
+def test_is_subclass_invalid():
+    with pytest.raises(SchemaError, match="object cannot be converted to 'PyType'"):
+        SchemaValidator({'type': 'is-subclass', 'class_': Foo()})
+
+
+def test_repr():
+    v = SchemaValidator({'type': 'union', 'choices': ['int', {'type': 'is-subclass', 'class_': Foo}]})
+    foo_repr = re.escape(f'{__name__}.Foo')
+    with pytest.raises(
+        ValidationError, match=rf'is-subclass\[{foo_repr}\] +is_subclass_of +Input should be a subclass of {foo_repr}'
+    ):
+        v.validate_python(Spam)
+
+
+def test_is_subclass_qualname():
+    class Outer:
+        class Foo:
+            pass
+
+    v = SchemaValidator({'type': 'is-subclass', 'class_': Outer.Foo})
+    outer_foo_repr = f'{__name__}.test_is_subclass_qualname.<locals>.Outer.Foo'
+    assert repr(v).startswith(f'SchemaValidator(name="is-subclass[{outer_foo_repr}]"')
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(Foo)
+    assert exc_info.value.errors()[0]['context'] == {'class': outer_foo_repr}
@@ -5,7 +5,7 @@ from typing import Any, Dict
 import pytest
 from dirty_equals import HasRepr, IsStr
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
 
 from ..conftest import Err, PyAndJson
 
@@ -15,8 +15,8 @@ from ..conftest import Err, PyAndJson
     [
         ([1, 2, 3], [1, 2, 3]),
         ([1, 2, '3'], [1, 2, 3]),
-        (5, Err('Input should be a valid list/array [kind=list_type, input_value=5, input_type=int]')),
-        ('5', Err("Input should be a valid list/array [kind=list_type, input_value='5', input_type=str]")),
+        (5, Err('list_type  Input should be a valid list/array, expected list[int]')),
+        ('5', Err("list_type  Input should be a valid list/array, expected list[int]")),
     ],
 )
 def test_list_json(py_and_json: PyAndJson, input_value, expected):
@@ -33,8 +33,14 @@ def test_list_strict():
     assert v.validate_python([1, 2, '33']) == [1, 2, 33]
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python((1, 2, '33'))
-    assert exc_info.value.errors() == [
-        {'kind': 'list_type', 'loc': [], 'message': 'Input should be a valid list/array', 'input_value': (1, 2, '33')}
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'list_type',
+            'loc': [],
+            'message': 'Input should be a valid list/array, expected list[int]',
+            'input_value': (1, 2, '33'),
+            'context': {'expected': 'list[int]'},
+        }
     ]
 
 
@@ -43,8 +49,8 @@ def test_list_strict():
     [
         ([1, 2, '3'], [1, 2, 3]),
         ((1, 2, '3'), [1, 2, 3]),
-        ({1, 2, '3'}, Err('Input should be a valid list/array [kind=list_type,')),
-        (frozenset({1, 2, '3'}), Err('Input should be a valid list/array [kind=list_type,')),
+        ({1, 2, '3'}, Err('list_type  Input should be a valid list/array, expected list[int]')),
+        (frozenset({1, 2, '3'}), Err('list_type  Input should be a valid list/array, expected list[int]')),
         pytest.param(
             {1: 10, 2: 20, '3': '30'}.keys(),
             [1, 2, 3],
@@ -59,9 +65,9 @@ def test_list_strict():
                 platform.python_implementation() == 'PyPy', reason='dict views not implemented in pyo3 for pypy'
             ),
         ),
-        ({1: 10, 2: 20, '3': '30'}, Err('Input should be a valid list/array [kind=list_type,')),
+        ({1: 10, 2: 20, '3': '30'}, Err('list_type  Input should be a valid list/array, expected list[int]')),
         # https://github.com/samuelcolvin/pydantic-core/issues/211
-        ({1: 10, 2: 20, '3': '30'}.items(), Err('Input should be a valid list/array [kind=list_type,')),
+        ({1: 10, 2: 20, '3': '30'}.items(), Err('list_type  Input should be a valid list/array, expected list[int]')),
         ((x for x in [1, 2, '3']), [1, 2, 3]),
     ],
 )
@@ -79,10 +85,10 @@ def test_list_int(input_value, expected):
     [
         ([], []),
         ([1, '2', b'3'], [1, '2', b'3']),
-        (frozenset([1, '2', b'3']), Err('Input should be a valid list/array [kind=list_type,')),
+        (frozenset([1, '2', b'3']), Err('list_type  Input should be a valid list/array')),
         ((), []),
         ((1, '2', b'3'), [1, '2', b'3']),
-        ({1, '2', b'3'}, Err('Input should be a valid list/array [kind=list_type,')),
+        ({1, '2', b'3'}, Err('list_type  Input should be a valid list/array')),
     ],
 )
 def test_list_any(input_value, expected):
@@ -101,7 +107,7 @@ def test_list_error(input_value, index):
     v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'int'}})
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python(input_value)
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'int_parsing',
             'loc': [index],
@@ -116,11 +122,11 @@ def test_list_error(input_value, index):
     [
         ({}, [1, 2, 3, 4], [1, 2, 3, 4]),
         ({'min_items': 3}, [1, 2, 3, 4], [1, 2, 3, 4]),
-        ({'min_items': 3}, [1, 2], Err('Input should have at least 3 items, got 2 items [kind=too_short,')),
-        ({'min_items': 1}, [], Err('Input should have at least 1 item, got 0 items [kind=too_short,')),
+        ({'min_items': 3}, [1, 2], Err('too_short  Input should have at least 3 items, got 2 items')),
+        ({'min_items': 1}, [], Err('too_short  Input should have at least 1 item, got 0 items')),
         ({'max_items': 4}, [1, 2, 3, 4], [1, 2, 3, 4]),
-        ({'max_items': 3}, [1, 2, 3, 4], Err('Input should have at most 3 items, got 4 items [kind=too_long,')),
-        ({'max_items': 1}, [1, 2], Err('Input should have at most 1 item, got 2 items [kind=too_long,')),
+        ({'max_items': 3}, [1, 2, 3, 4], Err('too_long  Input should have at most 3 items, got 4 items')),
+        ({'max_items': 1}, [1, 2], Err('too_long  Input should have at most 1 item, got 2 items')),
     ],
 )
 def test_list_length_constraints(kwargs: Dict[str, Any], input_value, expected):
@@ -136,7 +142,7 @@ def test_length_ctx():
     v = SchemaValidator({'type': 'list', 'min_items': 2, 'max_items': 3})
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python([1])
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'too_short',
             'loc': [],
@@ -149,7 +155,7 @@ def test_length_ctx():
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python([1, 2, 3, 4])
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'too_long',
             'loc': [],
@@ -177,7 +183,7 @@ def test_list_function_val_error():
 
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python([1, 2])
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'value_error',
             'loc': [0],
@@ -218,7 +224,7 @@ def test_generator_error():
     assert v.validate_python(gen(False)) == [1, 2, 3]
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python(gen(True))
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'iteration_error',
             'loc': [],
@@ -226,3 +232,263 @@ def test_generator_error():
             'input_value': HasRepr(IsStr(regex='<generator object test_generator_error.<locals>.gen at 0x[0-9a-f]+>')),
         }
     ]
+
+
+def test_generator_consumed_once():
+    calls = []
+
+    def gen(n: int):
+        for i in range(n):
+            calls.append(i)
+            yield str(i)
+
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int', 'min_items': 2, 'max_items': 3})
+    assert v.validate_python(gen(3)) == [0, 1, 2]
+    assert calls == [0, 1, 2]
+
+    calls.clear()
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(gen(4))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'too_long',
+            'loc': [],
+            'message': 'Input should have at most 3 items, got 4 items',
+            'input_value': HasRepr(IsStr(regex='<generator object .+gen at 0x[0-9a-f]+>')),
+            'context': {'max_length': 3, 'input_length': 4},
+        }
+    ]
+    assert calls == [0, 1, 2, 3]
+
+
+def test_lazy():
+    calls = []
+
+    def gen():
+        for i in ('1', '2', 'wrong', '4'):
+            calls.append(i)
+            yield i
+
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int', 'lazy': True})
+    assert repr(v).startswith('SchemaValidator(name="lazy-list[int]"')
+    output = v.validate_python(gen())
+    assert calls == []
+    assert next(output) == 1
+    assert next(output) == 2
+    assert calls == ['1', '2']
+    with pytest.raises(ValidationError) as exc_info:
+        next(output)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'int_parsing',
+            'loc': [2],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'wrong',
+        }
+    ]
+    assert next(output) == 4
+    assert list(output) == []
+
+
+@pytest.mark.parametrize('input_value', [[1, '2', 3], (1, '2', 3)])
+def test_lazy_sequence(py_and_json: PyAndJson, input_value):
+    v = py_and_json({'type': 'list', 'items_schema': 'int', 'lazy': True})
+    output = v.validate_test(input_value)
+    assert not isinstance(output, list)
+    assert list(output) == [1, 2, 3]
+
+
+def test_lazy_strict():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int', 'lazy': True, 'strict': True})
+    assert list(v.validate_python([1, 2])) == [1, 2]
+    with pytest.raises(ValidationError, match='Input should be a valid list/array'):
+        v.validate_python(x for x in [1, 2])
+    output = v.validate_python([1, '2'], strict=True)
+    assert next(output) == 1
+    with pytest.raises(ValidationError, match=r'int_type  Input should be a valid integer'):
+        next(output)
+
+
+def test_lazy_length():
+    v = SchemaValidator({'type': 'list', 'lazy': True, 'min_items': 2, 'max_items': 3})
+    assert list(v.validate_python(x for x in [1, 2, 3])) == [1, 2, 3]
+
+    output = v.validate_python(x for x in [1])
+    assert next(output) == 1
+    with pytest.raises(ValidationError) as exc_info:
+        next(output)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'too_short',
+            'loc': [],
+            'message': 'Input should have at least 2 items, got 1 item',
+            'input_value': HasRepr(IsStr(regex='<generator object .+ at 0x[0-9a-f]+>')),
+            'context': {'min_length': 2, 'input_length': 1},
+        }
+    ]
+
+    # max_items is only checked as iteration proceeds, so the first items are still returned
+    output = v.validate_python([1, 2, 3, 4, 5])
+    assert [next(output) for _ in range(3)] == [1, 2, 3]
+    with pytest.raises(ValidationError) as exc_info:
+        next(output)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'too_long',
+            'loc': [],
+            'message': 'Input should have at most 3 items, got 4 items',
+            'input_value': [1, 2, 3, 4, 5],
+            'context': {'max_length': 3, 'input_length': 4},
+        }
+    ]
+
+
+def test_lazy_generator_error():
+    def gen():
+        yield 1
+        raise RuntimeError('broken')
+
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int', 'lazy': True})
+    output = v.validate_python(gen())
+    assert next(output) == 1
+    with pytest.raises(RuntimeError, match='^broken$'):
+        next(output)
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ([1, 2, 3], [1, 2, 3]),
+        ([1], [1]),
+        ([], []),
+        ([1, 2, 1, 3, 2], Err('item 2 is a duplicate')),
+        ([1, 1, 1], Err('item 1 is a duplicate')),
+        (['1', 1], Err('item 1 is a duplicate')),
+    ],
+)
+def test_unique_items(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json({'type': 'list', 'items_schema': 'int', 'unique_items': True})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_unique_items_error():
+    v = SchemaValidator({'type': 'list', 'unique_items': True})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 2, 2])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'list_item_not_unique',
+            'loc': [],
+            'message': 'List should have unique items, item 2 is a duplicate',
+            'input_value': [1, 2, 2],
+            'context': {'index': 2},
+        }
+    ]
+
+
+@pytest.mark.parametrize(
+    'input_value,index',
+    [
+        ([{'a': 1}, {'a': 2}], None),
+        ([{'a': 1}], None),
+        ([{'a': 1}, {'a': 2}, {'a': 1}], 2),
+        ([[1], [1], [1]], 1),
+        ([1, [1], 1], 2),
+    ],
+)
+def test_unique_items_unhashable(input_value, index):
+    v = SchemaValidator({'type': 'list', 'unique_items': True})
+    if index is None:
+        assert v.validate_python(input_value) == input_value
+    else:
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(input_value)
+        assert exc_info.value.errors()[0]['context'] == {'index': index}
+
+
+def test_unique_items_unhashable_large():
+    v = SchemaValidator({'type': 'list', 'unique_items': True})
+    input_value = [{'a': i} for i in range(1000)]
+    assert v.validate_python(input_value) == input_value
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value + [{'a': 500}])
+    assert exc_info.value.errors()[0]['context'] == {'index': 1000}
+
+
+def test_preserve_order():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int', 'preserve_order': False})
+    assert v.validate_python([3, '1', 2]) == [1, 2, 3]
+    assert v.validate_python([1]) == [1]
+    assert v.validate_python([]) == []
+
+    v = SchemaValidator({'type': 'list', 'preserve_order': False, 'unique_items': True})
+    input_value = [3, 1, 2]
+    assert v.validate_python(input_value) == [1, 2, 3]
+    # the input isn't sorted in place
+    assert input_value == [3, 1, 2]
+    with pytest.raises(ValidationError, match='item 2 is a duplicate'):
+        v.validate_python([3, 1, 3])
+
+
+def test_preserve_order_unsortable():
+    v = SchemaValidator({'type': 'list', 'preserve_order': False})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 'a'])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'list_unsortable',
+            'loc': [],
+            'message': "Unable to sort list items, error: TypeError: '<' not supported between instances of 'str' "
+            "and 'int'",
+            'input_value': [1, 'a'],
+            'context': {'error': "TypeError: '<' not supported between instances of 'str' and 'int'"},
+        }
+    ]
+
+
+def test_unique_items_lazy():
+    with pytest.raises(SchemaError, match="'unique_items' and 'preserve_order' can't be used with 'lazy'"):
+        SchemaValidator({'type': 'list', 'lazy': True, 'unique_items': True})
+
+
+@pytest.mark.parametrize(
+    'items_schema,expected',
+    [
+        ('int', 'list[int]'),
+        ({'type': 'str', 'max_length': 5}, 'list[str]'),
+        ({'type': 'nullable', 'schema': {'type': 'bytes', 'max_length': 5}}, 'list[Optional[bytes]]'),
+        ({'type': 'list', 'items_schema': 'bool'}, 'list[list[bool]]'),
+    ],
+)
+def test_list_type_expected(py_and_json: PyAndJson, items_schema, expected):
+    v = py_and_json({'type': 'list', 'items_schema': items_schema})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test(1)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'list_type',
+            'loc': [],
+            'message': f'Input should be a valid list/array, expected {expected}',
+            'input_value': 1,
+            'context': {'expected': expected},
+        }
+    ]
+
+
+def test_list_type_expected_any():
+    v = SchemaValidator({'type': 'list'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(1)
+    assert exc_info.value.errors(include_url=False) == [
+        {'kind': 'list_type', 'loc': [], 'message': 'Input should be a valid list/array', 'input_value': 1}
+    ]
+
+
+def test_list_type_expected_lazy():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int', 'lazy': True})
+    with pytest.raises(ValidationError, match=r'Input should be a valid list/array, expected list\[int\]'):
+        v.validate_python(1)
@@ -0,0 +1,122 @@
+from collections import ChainMap, OrderedDict
+from collections.abc import Mapping
+from types import MappingProxyType
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError
+
+from ..conftest import PyAndJson
+
+
+def test_ordered_dict(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'mapping', 'keys_schema': 'str', 'values_schema': 'int', 'target_class': OrderedDict})
+    output = v.validate_test({'b': '1', 'a': 2})
+    assert output == OrderedDict([('b', 1), ('a', 2)])
+    assert type(output) is OrderedDict
+
+
+@pytest.mark.parametrize(
+    'input_value',
+    [
+        {'a': '1'},
+        OrderedDict(a='1'),
+        ChainMap({'a': '1'}),
+        MappingProxyType({'a': '1'}),
+    ],
+    ids=repr,
+)
+def test_lax_mappings(input_value):
+    v = SchemaValidator({'type': 'mapping', 'values_schema': 'int', 'target_class': OrderedDict})
+    output = v.validate_python(input_value)
+    assert output == OrderedDict(a=1)
+    assert type(output) is OrderedDict
+
+
+def test_default_dict_output():
+    v = SchemaValidator({'type': 'mapping', 'values_schema': 'int'})
+    output = v.validate_python(OrderedDict(a='1'))
+    assert output == {'a': 1}
+    assert type(output) is dict
+
+    v = SchemaValidator({'type': 'mapping', 'values_schema': 'int', 'target_class': dict})
+    assert type(v.validate_python(ChainMap({'a': 1}))) is dict
+
+
+def test_strict():
+    v = SchemaValidator({'type': 'mapping', 'values_schema': 'int', 'target_class': OrderedDict, 'strict': True})
+    assert v.validate_python(OrderedDict(a=1)) == OrderedDict(a=1)
+    with pytest.raises(
+        ValidationError, match=r'dict_type  Input should be a valid dictionary, expected OrderedDict\[Any, int\]'
+    ):
+        v.validate_python(ChainMap({'a': 1}))
+
+
+def test_custom_target_class():
+    class FrozenMap(Mapping):
+        def __init__(self, data):
+            self._data = dict(data)
+
+        def __getitem__(self, key):
+            return self._data[key]
+
+        def __iter__(self):
+            return iter(self._data)
+
+        def __len__(self):
+            return len(self._data)
+
+    v = SchemaValidator({'type': 'mapping', 'keys_schema': 'int', 'target_class': FrozenMap})
+    output = v.validate_python(FrozenMap({'1': 'a'}))
+    assert isinstance(output, FrozenMap)
+    assert dict(output) == {1: 'a'}
+
+
+def test_errors():
+    v = SchemaValidator({'type': 'mapping', 'values_schema': 'int', 'max_items': 2, 'target_class': OrderedDict})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(OrderedDict(a='x'))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'int_parsing',
+            'loc': ['a'],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'x',
+        }
+    ]
+    with pytest.raises(ValidationError, match='Input should have at most 2 items, got 3 items'):
+        v.validate_python(ChainMap({'a': 1, 'b': 2, 'c': 3}))
+    with pytest.raises(
+        ValidationError, match=r'dict_type  Input should be a valid dictionary, expected OrderedDict\[Any, int\]'
+    ):
+        v.validate_python([('a', 1)])
+
+
+class Map(dict):
+    def __init__(self, data):
+        if 'x' in data:
+            raise ValueError('x is not allowed')
+        super().__init__(data)
+
+
+def test_target_class_error():
+    v = SchemaValidator({'type': 'typed-dict', 'fields': {'m': {'schema': {'type': 'mapping', 'target_class': Map}}}})
+    assert v.validate_python({'m': {'a': 1}}) == {'m': Map({'a': 1})}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'m': {'x': 1}})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'mapping_target_class',
+            'loc': ['m'],
+            'message': 'Unable to convert dictionary to Map, error: ValueError: x is not allowed',
+            'input_value': {'x': 1},
+            'context': {'class_name': 'Map', 'error': 'ValueError: x is not allowed'},
+        }
+    ]
+
+
+def test_repr():
+    v = SchemaValidator({'type': 'mapping', 'keys_schema': 'str', 'values_schema': 'int', 'target_class': OrderedDict})
+    assert repr(v).startswith('SchemaValidator(name="OrderedDict[str,int]", validator=Mapping(\n')
+    v = SchemaValidator({'type': 'mapping'})
+    assert repr(v).startswith('SchemaValidator(name="dict[any,any]", validator=Mapping(\n')
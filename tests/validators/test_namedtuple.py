@@ -0,0 +1,125 @@
+import re
+from collections import namedtuple
+from typing import NamedTuple
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
+
+from ..conftest import Err, PyAndJson
+
+
+class Point(NamedTuple):
+    x: int
+    y: int
+    label: str = 'origin'
+
+
+POINT_SCHEMA = {'type': 'namedtuple', 'class_type': Point, 'fields': {'x': 'int', 'y': 'int', 'label': 'str'}}
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ([1, 2, 'a'], Point(1, 2, 'a')),
+        (['1', 2], Point(1, 2)),
+        ({'x': 1, 'y': '2', 'label': 'a'}, Point(1, 2, 'a')),
+        ({'y': 2, 'x': 1}, Point(1, 2)),
+        ([1], Err('1         missing  Field required')),
+        ([1, 2, 'a', 'b'], Err('too_long  Input should have at most 3 items, got 4 items')),
+        ({'x': 1, 'y': 2, 'z': 3}, Err('z         extra_forbidden  Extra inputs are not permitted')),
+        ('foo', Err('tuple_type  Input should be a valid tuple')),
+    ],
+)
+def test_namedtuple(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(POINT_SCHEMA)
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+        assert isinstance(output, Point)
+
+
+def test_instance_revalidated():
+    v = SchemaValidator(POINT_SCHEMA)
+    output = v.validate_python(Point(1, 2))
+    assert output == Point(1, 2)
+    assert type(output) is Point
+    assert output._fields == ('x', 'y', 'label')
+    assert tuple(Point.__annotations__) == output._fields
+    assert output._asdict() == {'x': 1, 'y': 2, 'label': 'origin'}
+
+
+def test_positional_errors():
+    v = SchemaValidator(POINT_SCHEMA)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(('a',))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'int_parsing',
+            'loc': [0],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'a',
+        },
+        {'kind': 'missing', 'loc': [1], 'message': 'Field required', 'input_value': ('a',)},
+    ]
+
+
+def test_keyword_errors():
+    v = SchemaValidator(POINT_SCHEMA)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'x': 'a', 'label': 1})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'int_parsing',
+            'loc': ['x'],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'a',
+        },
+        {'kind': 'missing', 'loc': ['y'], 'message': 'Field required', 'input_value': {'x': 'a', 'label': 1}},
+        {'kind': 'str_type', 'loc': ['label'], 'message': 'Input should be a valid string', 'input_value': 1},
+    ]
+
+
+def test_defaults():
+    class Config(NamedTuple):
+        name: str
+        tags: list = []
+        debug: bool = False
+
+    v = SchemaValidator({'type': 'namedtuple', 'class_type': Config, 'fields': {'name': 'str', 'debug': 'bool'}})
+    output = v.validate_python(['a'])
+    assert output == Config('a', [], False)
+    # defaults are used as is, not copied or validated
+    assert output.tags is Config._field_defaults['tags']
+    assert v.validate_python({'name': 'a', 'debug': 'true', 'tags': (1,)}) == Config('a', (1,), True)
+
+
+def test_strict():
+    v = SchemaValidator({**POINT_SCHEMA, 'strict': True})
+    assert v.validate_python((1, 2)) == Point(1, 2)
+    assert v.validate_python(Point(1, 2, 'a')) == Point(1, 2, 'a')
+    with pytest.raises(ValidationError, match=r'tuple_type  Input should be a valid tuple'):
+        v.validate_python([1, 2])
+    with pytest.raises(ValidationError, match=r'tuple_type  Input should be a valid tuple'):
+        v.validate_python({'x': 1, 'y': 2})
+
+
+def test_collections_namedtuple():
+    Pair = namedtuple('Pair', ['first', 'second'], defaults=[None])
+    v = SchemaValidator({'type': 'namedtuple', 'class_type': Pair, 'fields': {'first': 'int'}})
+    assert v.validate_python(['1', 'b']) == Pair(1, 'b')
+    assert v.validate_python({'first': 2}) == Pair(2, None)
+    assert repr(v).startswith('SchemaValidator(name="Pair", validator=NamedTuple(\n')
+
+
+def test_not_namedtuple():
+    with pytest.raises(SchemaError, match='class_type should be a NamedTuple class, got tuple'):
+        SchemaValidator({'type': 'namedtuple', 'class_type': tuple})
+
+
+def test_unknown_field():
+    with pytest.raises(SchemaError, match="'z' is not a field of Point"):
+        SchemaValidator({'type': 'namedtuple', 'class_type': Point, 'fields': {'z': 'int'}})
@@ -339,10 +339,10 @@ def test_model_class_instance_subclass():
 
     m2 = MySubModel()
     assert m2.field_a
+    # with the default revalidate_models="never", subclass instances are accepted as-is
     m3 = v.validate_python(m2)
-    assert m2 != m3
-    assert m3.field_a == 'init_a'
-    assert not hasattr(m3, 'field_b')
+    assert m3 is m2
+    assert m3.field_b == 'init_b'
 
 
 def test_model_class_strict():
@@ -363,7 +363,7 @@ def test_model_class_strict():
             },
         }
     )
-    assert re.search(r'revalidate: \w+', repr(v)).group(0) == 'revalidate: false'
+    assert re.search(r'revalidate: \w+', repr(v)).group(0) == 'revalidate: Never'
     m = MyModel()
     m2 = v.validate_python(m)
     assert isinstance(m, MyModel)
@@ -373,7 +373,7 @@ def test_model_class_strict():
     assert m.field_b == 'init_b'
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python({'field_a': 'test', 'field_b': 12})
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'model_class_type',
             'loc': [],
@@ -417,10 +417,10 @@ def test_revalidate():
                 'from_attributes': True,
                 'fields': {'field_a': {'schema': {'type': 'str'}}, 'field_b': {'schema': {'type': 'int'}}},
             },
-            'config': {'revalidate_models': True},
+            'config': {'revalidate_models': 'always'},
         }
     )
-    assert re.search(r'revalidate: \w+', repr(v)).group(0) == 'revalidate: true'
+    assert re.search(r'revalidate: \w+', repr(v)).group(0) == 'revalidate: Always'
 
     m = v.validate_python({'field_a': 'test', 'field_b': 12})
     assert isinstance(m, MyModel)
@@ -437,7 +437,7 @@ def test_revalidate():
     m4 = MyModel('x', 'not int', {'field_a'})
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python(m4)
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'int_parsing',
             'loc': ['field_b'],
@@ -472,7 +472,7 @@ def test_revalidate_extra():
                 'extra_behavior': 'allow',
                 'fields': {'field_a': {'schema': {'type': 'str'}}, 'field_b': {'schema': {'type': 'int'}}},
             },
-            'config': {'revalidate_models': True},
+            'config': {'revalidate_models': 'always'},
         }
     )
 
@@ -487,3 +487,215 @@ def test_revalidate_extra():
     assert m3 is not m2
     assert m3.__dict__ == {'field_a': 'x', 'field_b': 42, 'another': 42.5}
     assert m3.__fields_set__ == {'field_a', 'field_b', 'another'}
+
+
+def test_revalidate_subclass_instances():
+    class MyModel:
+        __slots__ = '__dict__', '__fields_set__'
+
+        def __init__(self, **kwargs):
+            self.__dict__.update(kwargs)
+
+    class MySubModel(MyModel):
+        pass
+
+    v = SchemaValidator(
+        {
+            'type': 'new-class',
+            'class_type': MyModel,
+            'schema': {
+                'type': 'typed-dict',
+                'return_fields_set': True,
+                'from_attributes': True,
+                'fields': {'field_a': {'schema': {'type': 'str'}}, 'field_b': {'schema': {'type': 'int'}}},
+            },
+            'config': {'revalidate_models': 'subclass-instances'},
+        }
+    )
+    assert re.search(r'revalidate: \w+', repr(v)).group(0) == 'revalidate: SubclassInstances'
+
+    m1 = MyModel(field_a='x', field_b='not int')
+    assert v.validate_python(m1) is m1
+
+    m2 = MySubModel(field_a='x', field_b='42')
+    m3 = v.validate_python(m2)
+    assert m3 is not m2
+    assert type(m3) is MyModel
+    assert m3.__dict__ == {'field_a': 'x', 'field_b': 42}
+    assert m3.__fields_set__ == {'field_a', 'field_b'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(MySubModel(field_a='x', field_b='not int'))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'int_parsing',
+            'loc': ['field_b'],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'not int',
+        }
+    ]
+
+
+@pytest.mark.parametrize(
+    'revalidate_models,strict,revalidated',
+    [
+        (None, False, False),
+        (None, True, False),
+        ('never', False, False),
+        ('never', True, False),
+        ('always', False, True),
+        ('always', True, True),
+        ('subclass-instances', False, True),
+        ('subclass-instances', True, True),
+        # bools are accepted for backwards compatibility
+        (True, False, True),
+        (False, False, False),
+    ],
+)
+def test_revalidate_subclass_instance_modes(revalidate_models, strict, revalidated):
+    class MyModel:
+        __slots__ = '__dict__', '__fields_set__'
+
+        def __init__(self, **kwargs):
+            self.__dict__.update(kwargs)
+
+    class MySubModel(MyModel):
+        pass
+
+    config = {} if revalidate_models is None else {'revalidate_models': revalidate_models}
+    v = SchemaValidator(
+        {
+            'type': 'new-class',
+            'class_type': MyModel,
+            'strict': strict,
+            'schema': {
+                'type': 'typed-dict',
+                'return_fields_set': True,
+                'from_attributes': True,
+                'fields': {'field_a': {'schema': {'type': 'int'}}},
+            },
+            'config': config,
+        }
+    )
+    m = MySubModel(field_a='1')
+    output = v.validate_python(m)
+    if revalidated:
+        assert type(output) is MyModel
+        assert output.__dict__ == {'field_a': 1}
+    else:
+        assert output is m
+        assert output.field_a == '1'
+
+
+def test_revalidate_invalid():
+    with pytest.raises(SchemaError, match="Input should be one of: 'never', 'always', 'subclass-instances'"):
+        SchemaValidator(
+            {
+                'type': 'new-class',
+                'class_type': object,
+                'schema': {'type': 'typed-dict', 'fields': {}},
+                'config': {'revalidate_models': 'sometimes'},
+            }
+        )
+
+
+def test_validate_assignment():
+    class MyModel:
+        __slots__ = '__dict__', '__fields_set__'
+        field_a: str
+        field_b: int
+
+    v = SchemaValidator(
+        {
+            'type': 'new-class',
+            'class_type': MyModel,
+            'schema': {
+                'type': 'typed-dict',
+                'return_fields_set': True,
+                'fields': {'field_a': {'schema': 'str'}, 'field_b': {'schema': 'int', 'default': 1}},
+            },
+        }
+    )
+    m = v.validate_python({'field_a': 'test'})
+    assert m.__fields_set__ == {'field_a'}
+
+    assert v.validate_assignment(m, 'field_b', '123') is m
+    assert m.__dict__ == {'field_a': 'test', 'field_b': 123}
+    assert m.__fields_set__ == {'field_a', 'field_b'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment(m, 'field_b', 'wrong')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'int_parsing',
+            'loc': ['field_b'],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'wrong',
+        }
+    ]
+    # the instance is unchanged
+    assert m.__dict__ == {'field_a': 'test', 'field_b': 123}
+
+    with pytest.raises(AttributeError, match="No field named 'field_c'"):
+        v.validate_assignment(m, 'field_c', 1)
+    assert m.__dict__ == {'field_a': 'test', 'field_b': 123}
+
+
+def test_validate_assignment_setattr():
+    setattr_calls = []
+
+    class MyModel:
+        field_a: str
+
+        def __setattr__(self, key, value):
+            setattr_calls.append((key, value))
+
+    v = SchemaValidator(
+        {
+            'type': 'new-class',
+            'class_type': MyModel,
+            'schema': {'type': 'typed-dict', 'fields': {'field_a': {'schema': 'str'}}},
+        }
+    )
+    m = v.validate_python({'field_a': 'test'})
+    v.validate_assignment(m, 'field_a', b'changed')
+    # object.__setattr__ is used, so the class's own __setattr__ isn't called
+    assert setattr_calls == []
+    assert m.field_a == 'changed'
+
+
+def test_validate_assignment_data():
+    def validate_b(input_value, data, **kwargs):
+        return f'{input_value} (a={data["field_a"]})'
+
+    class MyModel:
+        __slots__ = '__dict__', '__fields_set__'
+
+    v = SchemaValidator(
+        {
+            'type': 'new-class',
+            'class_type': MyModel,
+            'schema': {
+                'type': 'typed-dict',
+                'return_fields_set': True,
+                'fields': {
+                    'field_a': {'schema': 'int'},
+                    'field_b': {
+                        'schema': {'type': 'function', 'mode': 'after', 'function': validate_b, 'schema': 'str'}
+                    },
+                },
+            },
+        }
+    )
+    m = v.validate_python({'field_a': 1, 'field_b': 'x'})
+    assert m.field_b == 'x (a=1)'
+    v.validate_assignment(m, 'field_a', 2)
+    # the function validator on field_b sees the other fields' current values
+    v.validate_assignment(m, 'field_b', 'y')
+    assert m.__dict__ == {'field_a': 2, 'field_b': 'y (a=2)'}
+
+
+def test_validate_assignment_not_model():
+    v = SchemaValidator({'type': 'typed-dict', 'fields': {'field_a': {'schema': 'str'}}})
+    with pytest.raises(TypeError, match='validate_assignment requires a dict or model instance'):
+        v.validate_assignment(123, 'field_a', 'x')
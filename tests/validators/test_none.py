@@ -1,6 +1,6 @@
 import pytest
 
-from pydantic_core import ValidationError
+from pydantic_core import SchemaValidator, ValidationError
 
 from ..conftest import PyAndJson
 
@@ -10,6 +10,53 @@ def test_none(py_and_json: PyAndJson):
     assert v.validate_test(None) is None
     with pytest.raises(ValidationError) as exc_info:
         v.validate_test(1)
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {'kind': 'none_required', 'loc': [], 'message': 'Input should be None/null', 'input_value': 1}
     ]
+
+
+def test_none_dict_values(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'dict', 'keys_schema': 'str', 'values_schema': 'none'})
+    assert v.validate_test({'a': None, 'b': None}) == {'a': None, 'b': None}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'a': None, 'b': 0})
+    assert exc_info.value.errors(include_url=False) == [
+        {'kind': 'none_required', 'loc': ['b'], 'message': 'Input should be None/null', 'input_value': 0}
+    ]
+
+
+def test_none_field_not_missing(py_and_json: PyAndJson):
+    # a field which must be null is distinct from one which may be absent
+    v = py_and_json({'type': 'typed-dict', 'fields': {'a': {'schema': 'none'}}})
+    assert v.validate_test({'a': None}) == {'a': None}
+    with pytest.raises(ValidationError, match='missing'):
+        v.validate_test({})
+
+
+def test_none_tagged_union():
+    v = SchemaValidator(
+        {
+            'type': 'tagged-union',
+            'discriminator': 'kind',
+            'choices': {
+                'empty': {
+                    'type': 'typed-dict',
+                    'fields': {'kind': {'schema': 'str'}, 'value': {'schema': 'none'}},
+                },
+                'full': {'type': 'typed-dict', 'fields': {'kind': {'schema': 'str'}, 'value': {'schema': 'int'}}},
+            },
+        }
+    )
+    assert v.validate_python({'kind': 'empty', 'value': None}) == {'kind': 'empty', 'value': None}
+    assert v.validate_python({'kind': 'full', 'value': 1}) == {'kind': 'full', 'value': 1}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'kind': 'empty', 'value': 1})
+    assert exc_info.value.errors(include_url=False) == [
+        {'kind': 'none_required', 'loc': ['empty', 'value'], 'message': 'Input should be None/null', 'input_value': 1}
+    ]
+
+
+def test_none_repr():
+    v = SchemaValidator({'type': 'union', 'choices': ['none', 'int']})
+    with pytest.raises(ValidationError, match=r'none +none_required +Input should be None/null'):
+        v.validate_python('x')
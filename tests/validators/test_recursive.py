@@ -5,7 +5,7 @@ from dirty_equals import AnyThing, HasAttributes, IsList, IsPartialDict, IsStr
 
 from pydantic_core import SchemaError, SchemaValidator, ValidationError
 
-from ..conftest import Err
+from ..conftest import Err, PyAndJson, plain_repr
 from .test_typed_dict import Cls
 
 
@@ -59,7 +59,7 @@ def test_nullable_error():
     )
     with pytest.raises(ValidationError) as exc_info:
         assert v.validate_python({'width': 123, 'sub_branch': {'width': 'wrong'}})
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'none_required',
             'loc': ['sub_branch', 'none'],
@@ -272,7 +272,7 @@ def test_recursion_branch():
     with pytest.raises(ValidationError) as exc_info:
         assert v.validate_python(b)
     assert exc_info.value.title == 'typed-dict'
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'recursion_loop',
             'loc': ['branch'],
@@ -285,7 +285,7 @@ def test_recursion_branch():
     data.branch = data
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python(data)
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'recursion_loop',
             'loc': ['branch'],
@@ -307,7 +307,7 @@ def test_recursive_list():
     with pytest.raises(ValidationError) as exc_info:
         assert v.validate_python(data)
     assert exc_info.value.title == 'list[...]'
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'recursion_loop',
             'loc': [0],
@@ -352,10 +352,10 @@ def multiple_tuple_schema() -> SchemaValidator:
             {'f1': [1, (3, None)], 'f2': [2, (4, (4, (5, None)))]},
             {'f1': (1, (3, None)), 'f2': (2, (4, (4, (5, None))))},
         ),
-        ({'f1': [1, 2]}, Err(r'f1 -> 1\s+Input should be a valid tuple')),
+        ({'f1': [1, 2]}, Err(r'f1 -> 1 +tuple_type +Input should be a valid tuple')),
         (
             {'f1': [1, (3, None)], 'f2': [2, (4, (4, (5, 6)))]},
-            Err(r'f2 -> 1 -> 1 -> 1 -> 1\s+Input should be a valid tuple'),
+            Err(r'f2 -> 1 -> 1 -> 1 -> 1 +tuple_type +Input should be a valid tuple'),
         ),
     ],
 )
@@ -382,7 +382,7 @@ def test_multiple_tuple_recursion(multiple_tuple_schema: SchemaValidator):
     with pytest.raises(ValidationError) as exc_info:
         multiple_tuple_schema.validate_python({'f1': data, 'f2': data})
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'recursion_loop',
             'loc': ['f1', 1],
@@ -404,7 +404,7 @@ def test_multiple_tuple_recursion_once(multiple_tuple_schema: SchemaValidator):
     with pytest.raises(ValidationError) as exc_info:
         multiple_tuple_schema.validate_python({'f1': data, 'f2': data})
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'recursion_loop',
             'loc': ['f1', 1],
@@ -446,7 +446,7 @@ def test_recursive_wrap():
     t.append(t)
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python(t)
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'recursion_loop',
             'loc': [1],
@@ -477,7 +477,7 @@ def test_union_ref_strictness():
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python({'a': 1, 'b': []})
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {'kind': 'int_type', 'loc': ['b', 'int'], 'message': 'Input should be a valid integer', 'input_value': []},
         {'kind': 'str_type', 'loc': ['b', 'str'], 'message': 'Input should be a valid string', 'input_value': []},
     ]
@@ -499,7 +499,7 @@ def test_union_container_strictness():
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python({'a': 1, 'b': []})
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {'kind': 'int_type', 'loc': ['b', 'int'], 'message': 'Input should be a valid integer', 'input_value': []},
         {'kind': 'str_type', 'loc': ['b', 'str'], 'message': 'Input should be a valid string', 'input_value': []},
     ]
@@ -533,7 +533,7 @@ def test_union_cycle(strict: bool):
 
     with pytest.raises(ValidationError) as exc_info:
         s.validate_python(data)
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'recursion_loop',
             'loc': ['typed-dict', 'foobar', 0],
@@ -568,7 +568,7 @@ def test_function_name():
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python('input value')
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'recursion_loop',
             'loc': ['function-after[...]'],
@@ -609,7 +609,7 @@ def test_function_change_id(strict: bool):
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python('start-0')
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'recursion_loop',
             'loc': IsList(length=(1, 255)),
@@ -642,8 +642,111 @@ def test_many_uses_of_ref():
         'other_names': ['Bob', 'Charlie'],
     }
 
-    with pytest.raises(ValidationError, match=r'other_names -> 2\s+String should have at most 8 characters'):
+    with pytest.raises(ValidationError, match=r'other_names -> 2 +too_long +String should have at most 8 characters'):
         v.validate_python({'name': 'Anne', 'other_names': ['Bob', 'Charlie', 'Daveeeeee']})
 
     long_input = {'name': 'Anne', 'other_names': [f'p-{i}' for i in range(300)]}
     assert v.validate_python(long_input) == long_input
+
+
+def nested_list(depth: int) -> list:
+    value = []
+    for _ in range(depth - 1):
+        value = [value]
+    return value
+
+
+def test_max_depth(py_and_json: PyAndJson):
+    v = py_and_json(
+        {'type': 'list', 'ref': 'the-list', 'items_schema': {'type': 'recursive-ref', 'schema_ref': 'the-list'}}
+    )
+    assert v.validate_test(nested_list(100)) == nested_list(100)
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test(nested_list(101))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'recursion_loop',
+            'loc': [0] * 100,
+            'message': 'Recursion error - cyclic reference detected',
+            'input_value': [],
+        }
+    ]
+
+
+def test_max_depth_config():
+    v = SchemaValidator(
+        {'type': 'list', 'ref': 'the-list', 'items_schema': {'type': 'recursive-ref', 'schema_ref': 'the-list'}},
+        {'max_depth': 3},
+    )
+    assert v.validate_python([[[]]]) == [[[]]]
+    # siblings don't share the depth counter
+    assert v.validate_python([[[]], [[]], [[]]]) == [[[]], [[]], [[]]]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([[[[]]]])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'recursion_loop',
+            'loc': [0, 0, 0],
+            'message': 'Recursion error - cyclic reference detected',
+            'input_value': [],
+        }
+    ]
+
+
+def test_mutually_recursive_repeated_definitions():
+    """
+    like:
+    class Foo:
+        bar: Optional['Bar']
+    class Bar:
+        foo: Optional['Foo']
+        foos: List['Foo']
+    where each model's full schema is included wherever it's used, not just the first time
+    """
+
+    def foo_schema(bar):
+        return {
+            'ref': 'Foo',
+            'type': 'typed-dict',
+            'fields': {'height': {'schema': 'int'}, 'bar': {'schema': {'type': 'nullable', 'schema': bar}}},
+        }
+
+    def bar_schema(foo):
+        return {
+            'ref': 'Bar',
+            'type': 'typed-dict',
+            'fields': {
+                'width': {'schema': 'int'},
+                'foo': {'schema': {'type': 'nullable', 'schema': foo}, 'default': None},
+                'foos': {'schema': {'type': 'list', 'items_schema': foo}, 'default': []},
+            },
+        }
+
+    # the inner definitions of Foo are identical to the outer one so are never built
+    v = SchemaValidator(foo_schema(bar_schema(foo_schema(bar_schema({'type': 'recursive-ref', 'schema_ref': 'Bar'})))))
+    assert plain_repr(v).count('TypedDict(') == 2
+    assert plain_repr(v).count(
+        'RecursiveRef(RecursiveRefValidator{validator_id:0,inner_name:"typed-dict",schema_ref:"Foo"}'
+    ) == 2
+    assert plain_repr(v).count(
+        'RecursiveContainerValidator{validator_id:1,inner_name:"typed-dict",schema_ref:"Bar"}'
+    ) == 1
+
+    assert v.validate_python(
+        {'height': 1, 'bar': {'width': 2, 'foo': {'height': 3, 'bar': None}, 'foos': [{'height': '4', 'bar': None}]}}
+    ) == {
+        'height': 1,
+        'bar': {'width': 2, 'foo': {'height': 3, 'bar': None}, 'foos': [{'height': 4, 'bar': None}]},
+    }
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'height': 1, 'bar': {'width': 2, 'foos': [{'height': 3, 'bar': {'width': 'x'}}]}})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'int_parsing',
+            'loc': ['bar', 'foos', 0, 'bar', 'width'],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'x',
+        }
+    ]
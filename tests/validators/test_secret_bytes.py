@@ -0,0 +1,56 @@
+import pytest
+
+from pydantic_core import SchemaValidator, SecretBytes, ValidationError
+
+from ..conftest import PyAndJson
+
+
+def test_secret_bytes(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'secret-bytes'})
+    secret = v.validate_test('0123456789')
+    assert isinstance(secret, SecretBytes)
+    assert secret.get_secret_value() == b'0123456789'
+    assert len(secret) == 10
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [(b'0123456789', "b'**10 bytes**'"), (b'x', "b'**1 byte**'"), (b'', "b'**0 bytes**'")],
+)
+def test_repr_shows_length(input_value, expected):
+    # the length is visible on purpose, only the content is secret
+    secret = SchemaValidator('secret-bytes').validate_python(input_value)
+    assert str(secret) == expected
+    assert repr(secret) == f'SecretBytes({expected})'
+
+
+def test_masked_errors(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'secret-bytes', 'max_length': 4})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test('hunter2')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'too_long',
+            'loc': [],
+            'message': 'Data should have at most 4 bytes',
+            'input_value': '**masked**',
+            'context': {'max_length': 4},
+        }
+    ]
+    assert 'hunter2' not in str(exc_info.value)
+
+
+def test_eq_hash():
+    v = SchemaValidator('secret-bytes')
+    a = v.validate_python(b'hunter2')
+    assert a == v.validate_python('hunter2')
+    assert a != v.validate_python(b'hunter3')
+    assert a != b'hunter2'
+    assert hash(a) == hash(v.validate_python(b'hunter2'))
+
+
+def test_secret_bytes_instance():
+    secret = SchemaValidator('secret-bytes').validate_python(b'hunter2')
+    assert SchemaValidator({'type': 'secret-bytes', 'strict': True}).validate_python(secret) == secret
+    with pytest.raises(ValidationError, match=r"too_short +Data should have at least 8 bytes +'\*\*masked\*\*'"):
+        SchemaValidator({'type': 'secret-bytes', 'min_length': 8}).validate_python(secret)
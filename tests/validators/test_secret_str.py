@@ -0,0 +1,60 @@
+import pytest
+
+from pydantic_core import SchemaValidator, SecretStr, ValidationError
+
+from ..conftest import PyAndJson
+
+
+def test_secret_str(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'secret-str'})
+    secret = v.validate_test('hunter2')
+    assert isinstance(secret, SecretStr)
+    assert secret.get_secret_value() == 'hunter2'
+    assert repr(secret) == "SecretStr('**********')"
+    assert str(secret) == '**********'
+    assert len(secret) == 7
+
+
+def test_masked_errors(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'secret-str', 'min_length': 8})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test('hunter2')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'too_short',
+            'loc': [],
+            'message': 'String should have at least 8 characters',
+            'input_value': '**masked**',
+            'context': {'min_length': 8},
+        }
+    ]
+    assert 'hunter2' not in str(exc_info.value)
+
+
+def test_masked_type_error():
+    v = SchemaValidator({'type': 'typed-dict', 'fields': {'key': {'schema': 'secret-str'}}})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'key': 123456})
+    assert exc_info.value.errors(include_url=False) == [
+        {'kind': 'str_type', 'loc': ['key'], 'message': 'Input should be a valid string', 'input_value': '**masked**'}
+    ]
+    assert '123456' not in str(exc_info.value)
+
+
+def test_eq_hash():
+    v = SchemaValidator('secret-str')
+    a = v.validate_python('hunter2')
+    assert a == v.validate_python('hunter2')
+    assert a != v.validate_python('hunter3')
+    assert a != 'hunter2'
+    assert hash(a) == hash(v.validate_python('hunter2'))
+    assert len({a, v.validate_python('hunter2'), v.validate_python('hunter3')}) == 2
+
+
+def test_secret_str_instance():
+    secret = SchemaValidator('secret-str').validate_python('hunter2')
+    v = SchemaValidator({'type': 'secret-str', 'strict': True, 'to_upper': True})
+    upper = v.validate_python(secret)
+    assert upper.get_secret_value() == 'HUNTER2'
+    with pytest.raises(ValidationError, match=r"too_long +String should have at most 3 characters +'\*\*masked\*\*'"):
+        SchemaValidator({'type': 'secret-str', 'max_length': 3}).validate_python(secret)
@@ -16,7 +16,7 @@ from ..conftest import Err, PyAndJson
         ([1, 2, 3], {1, 2, 3}),
         ([1, 2, '3'], {1, 2, 3}),
         ([1, 2, 3, 2, 3], {1, 2, 3}),
-        (5, Err('Input should be a valid set [kind=set_type, input_value=5, input_type=int]')),
+        (5, Err('set_type  Input should be a valid set, expected set[int]  5')),
     ],
 )
 def test_set_ints_both(py_and_json: PyAndJson, input_value, expected):
@@ -77,12 +77,12 @@ def test_frozenset_no_validators_both(py_and_json: PyAndJson, input_value, expec
                 platform.python_implementation() == 'PyPy', reason='dict views not implemented in pyo3 for pypy'
             ),
         ),
-        ({1: 10, 2: 20, '3': '30'}, Err('Input should be a valid set [kind=set_type,')),
+        ({1: 10, 2: 20, '3': '30'}, Err('set_type  Input should be a valid set')),
         # https://github.com/samuelcolvin/pydantic-core/issues/211
-        ({1: 10, 2: 20, '3': '30'}.items(), Err('Input should be a valid set [kind=set_type,')),
+        ({1: 10, 2: 20, '3': '30'}.items(), Err('set_type  Input should be a valid set')),
         ((x for x in [1, 2, '3']), {1, 2, 3}),
-        ({'abc'}, Err('0\n  Input should be a valid integer')),
-        ({1: 2}, Err('1 validation error for set[int]\n  Input should be a valid set')),
+        ({'abc'}, Err('0         int_parsing  Input should be a valid integer')),
+        ({1: 2}, Err('set_type  Input should be a valid set')),
         ('abc', Err('Input should be a valid set')),
     ],
 )
@@ -105,7 +105,7 @@ def test_set_multiple_errors():
     v = SchemaValidator({'type': 'set', 'items_schema': {'type': 'int'}})
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python(['a', (1, 2), []])
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'int_parsing',
             'loc': [0],
@@ -122,16 +122,16 @@ def test_set_multiple_errors():
     [
         ({'strict': True}, {1, 2, 3}, {1, 2, 3}),
         ({'strict': True}, set(), set()),
-        ({'strict': True}, [1, 2, 3, 2, 3], Err('Input should be a valid set [kind=set_type,')),
-        ({'strict': True}, [], Err('Input should be a valid set [kind=set_type,')),
-        ({'strict': True}, (), Err('Input should be a valid set [kind=set_type,')),
-        ({'strict': True}, (1, 2, 3), Err('Input should be a valid set [kind=set_type,')),
-        ({'strict': True}, frozenset([1, 2, 3]), Err('Input should be a valid set [kind=set_type,')),
-        ({'strict': True}, 'abc', Err('Input should be a valid set [kind=set_type,')),
+        ({'strict': True}, [1, 2, 3, 2, 3], Err('set_type  Input should be a valid set')),
+        ({'strict': True}, [], Err('set_type  Input should be a valid set')),
+        ({'strict': True}, (), Err('set_type  Input should be a valid set')),
+        ({'strict': True}, (1, 2, 3), Err('set_type  Input should be a valid set')),
+        ({'strict': True}, frozenset([1, 2, 3]), Err('set_type  Input should be a valid set')),
+        ({'strict': True}, 'abc', Err('set_type  Input should be a valid set')),
         ({'min_items': 3}, {1, 2, 3}, {1, 2, 3}),
-        ({'min_items': 3}, {1, 2}, Err('Input should have at least 3 items, got 2 items [kind=too_short,')),
+        ({'min_items': 3}, {1, 2}, Err('too_short  Input should have at least 3 items, got 2 items')),
         ({'max_items': 3}, {1, 2, 3}, {1, 2, 3}),
-        ({'max_items': 3}, {1, 2, 3, 4}, Err('Input should have at most 3 items, got 4 items [kind=too_long,')),
+        ({'max_items': 3}, {1, 2, 3, 4}, Err('too_long  Input should have at most 3 items, got 4 items')),
     ],
 )
 def test_set_kwargs(kwargs: Dict[str, Any], input_value, expected):
@@ -195,7 +195,7 @@ def test_union_set_int_set_str(input_value, expected):
         with pytest.raises(ValidationError, match=re.escape(expected.message)) as exc_info:
             v.validate_python(input_value)
         if expected.errors is not None:
-            assert exc_info.value.errors() == expected.errors
+            assert exc_info.value.errors(include_url=False) == expected.errors
     else:
         assert v.validate_python(input_value) == expected
 
@@ -219,5 +219,5 @@ def test_generator_error():
     assert r == {1, 2, 3}
     assert isinstance(r, set)
 
-    with pytest.raises(ValidationError, match=r'Error iterating over object \[kind=iteration_error,'):
+    with pytest.raises(ValidationError, match=r'iteration_error  Error iterating over object'):
         v.validate_python(gen(True))
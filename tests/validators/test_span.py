@@ -0,0 +1,76 @@
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
+
+from ..conftest import PyAndJson, plain_repr
+
+address_schema = {
+    'type': 'typed-dict',
+    'fields': {'street': {'schema': 'str'}, 'zip': {'schema': {'type': 'str', 'max_length': 5}}},
+}
+
+
+def test_span(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'span', 'loc_prefix': ['address'], 'schema': 'int'})
+    assert v.validate_test('123') == 123
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test('x')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'int_parsing',
+            'loc': ['address'],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'x',
+        }
+    ]
+
+
+def test_shared_schema():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'user': {'schema': {'type': 'span', 'loc_prefix': ['User', 'address'], 'schema': address_schema}},
+                'company': {
+                    'schema': {'type': 'span', 'loc_prefix': ['Company', 'address', 0], 'schema': address_schema}
+                },
+            },
+        }
+    )
+    address = {'street': 'Main St', 'zip': '12345'}
+    assert v.validate_python({'user': address, 'company': address}) == {'user': address, 'company': address}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'user': {'street': 1, 'zip': '123456'}, 'company': {'zip': '1'}})
+    assert [e['loc'] for e in exc_info.value.errors()] == [
+        ['user', 'User', 'address', 'street'],
+        ['user', 'User', 'address', 'zip'],
+        ['company', 'Company', 'address', 0, 'street'],
+    ]
+
+
+def test_nested_spans():
+    v = SchemaValidator(
+        {
+            'type': 'span',
+            'loc_prefix': ['a', 'b'],
+            'schema': {'type': 'span', 'loc_prefix': [1], 'schema': {'type': 'list', 'items_schema': 'int'}},
+        }
+    )
+    assert plain_repr(v).startswith('SchemaValidator(name="span[span[list[int]]]",')
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 'x'])
+    assert [e['loc'] for e in exc_info.value.errors()] == [['a', 'b', 1, 1]]
+
+
+def test_empty_prefix():
+    v = SchemaValidator({'type': 'span', 'loc_prefix': [], 'schema': 'int'})
+    assert plain_repr(v) == (
+        'SchemaValidator(name="int",validator=Int('
+        'IntValidator{strict:false,allow_inexact_int_from_float:false}))'
+    )
+
+
+def test_bad_prefix():
+    with pytest.raises(SchemaError, match='loc_prefix items should be strings or non-negative integers'):
+        SchemaValidator({'type': 'span', 'loc_prefix': ['a', -1], 'schema': 'int'})
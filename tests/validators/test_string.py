@@ -13,11 +13,11 @@ from ..conftest import Err, PyAndJson
     'input_value,expected',
     [
         ('foobar', 'foobar'),
-        (123, Err('Input should be a valid string [kind=str_type, input_value=123, input_type=int]')),
-        (123.456, Err('Input should be a valid string [kind=str_type, input_value=123.456, input_type=float]')),
-        (False, Err('Input should be a valid string [kind=str_type')),
-        (True, Err('Input should be a valid string [kind=str_type')),
-        ([], Err('Input should be a valid string [kind=str_type, input_value=[], input_type=list]')),
+        (123, Err('str_type  Input should be a valid string  123')),
+        (123.456, Err('str_type  Input should be a valid string  123.456')),
+        (False, Err('str_type  Input should be a valid string')),
+        (True, Err('str_type  Input should be a valid string')),
+        ([], Err('str_type  Input should be a valid string  []')),
     ],
 )
 def test_str(py_and_json: PyAndJson, input_value, expected):
@@ -36,21 +36,15 @@ def test_str(py_and_json: PyAndJson, input_value, expected):
         ('🐈 Hello \ud800World', '🐈 Hello \ud800World'),
         (b'foobar', 'foobar'),
         (bytearray(b'foobar'), 'foobar'),
-        (
-            b'\x81',
-            Err('Input should be a valid string, unable to parse raw data as a unicode string [kind=str_unicode'),
-        ),
+        (b'\x81', Err('str_unicode  Input should be a valid string, unable to parse raw data as a unicode string')),
         (
             bytearray(b'\x81'),
-            Err('Input should be a valid string, unable to parse raw data as a unicode string [kind=str_unicode'),
+            Err('str_unicode  Input should be a valid string, unable to parse raw data as a unicode string'),
         ),
         # null bytes are very annoying, but we can't really block them here
         (b'\x00', '\x00'),
-        (123, Err('Input should be a valid string [kind=str_type, input_value=123, input_type=int]')),
-        (
-            Decimal('123'),
-            Err("Input should be a valid string [kind=str_type, input_value=Decimal('123'), input_type=Decimal]"),
-        ),
+        (123, Err('str_type  Input should be a valid string  123')),
+        (Decimal('123'), Err("str_type  Input should be a valid string  Decimal('123')")),
     ],
 )
 def test_str_not_json(input_value, expected):
@@ -72,17 +66,19 @@ def test_str_not_json(input_value, expected):
         ({'strip_whitespace': True}, ' foobar  ', 'foobar'),
         ({'strip_whitespace': True, 'to_upper': True}, ' fooBar', 'FOOBAR'),
         ({'min_length': 5}, '12345', '12345'),
-        ({'min_length': 5}, '1234', Err('String should have at least 5 characters [kind=too_short')),
+        ({'min_length': 5}, '1234', Err('too_short  String should have at least 5 characters')),
         ({'max_length': 5}, '12345', '12345'),
-        ({'max_length': 5}, '123456', Err('String should have at most 5 characters [kind=too_long')),
+        ({'max_length': 5}, '123456', Err('too_long  String should have at most 5 characters')),
         ({'pattern': r'^\d+$'}, '12345', '12345'),
         ({'pattern': r'\d+$'}, 'foobar 123', 'foobar 123'),
-        ({'pattern': r'^\d+$'}, '12345a', Err("String should match pattern '^\\d+$' [kind=str_pattern_mismatch")),
-        # strip comes after length check
-        ({'max_length': 5, 'strip_whitespace': True}, '1234  ', Err('String should have at most 5 characters')),
-        # to_upper and strip comes after pattern check
-        ({'to_upper': True, 'pattern': 'abc'}, 'abc', 'ABC'),
-        ({'strip_whitespace': True, 'pattern': r'\d+$'}, 'foobar 123 ', Err("String should match pattern '\\d+$'")),
+        ({'pattern': r'^\d+$'}, '12345a', Err("str_pattern_mismatch  String should match pattern '^\\d+$'")),
+        # strip and case transformations come before length and pattern checks
+        ({'max_length': 5, 'strip_whitespace': True}, '1234  ', '1234'),
+        ({'min_length': 5, 'strip_whitespace': True}, ' 1234 ', Err('String should have at least 5 characters')),
+        ({'to_upper': True, 'pattern': 'abc'}, 'abc', Err("String should match pattern 'abc'")),
+        ({'to_upper': True, 'pattern': 'ABC'}, 'abc', 'ABC'),
+        ({'strip_whitespace': True, 'pattern': r'^\d+$'}, ' 123 ', '123'),
+        ({'strip_whitespace': True, 'to_lower': True, 'pattern': r'^abc$'}, ' ABC ', 'abc'),
         ({'min_length': 1}, '🐈 Hello', '🐈 Hello'),
     ],
 )
@@ -100,7 +96,7 @@ def test_constrained_str(py_and_json: PyAndJson, kwargs: Dict[str, Any], input_v
     [
         ({}, b'abc', 'abc'),
         ({'strict': True}, 'Foobar', 'Foobar'),
-        ({'strict': True}, 123, Err('Input should be a valid string [kind=str_type, input_value=123, input_type=int]')),
+        ({'strict': True}, 123, Err('str_type  Input should be a valid string  123')),
     ],
 )
 def test_constrained_str_py_only(kwargs: Dict[str, Any], input_value, expected):
@@ -120,7 +116,7 @@ def test_unicode_error():
 
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python('🐈 Hello \ud800World')
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'str_unicode',
             'loc': [],
@@ -146,29 +142,80 @@ def test_str_constrained_config():
         v.validate_python('test long')
 
 
+def test_transformed_error_input():
+    v = SchemaValidator({'type': 'str', 'strip_whitespace': True, 'to_upper': True, 'max_length': 3})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('  abcd  ')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'too_long',
+            'loc': [],
+            'message': 'String should have at most 3 characters',
+            'input_value': 'ABCD',
+            'context': {'max_length': 3},
+        }
+    ]
+
+
+def test_dict_key_transformed(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'dict', 'keys_schema': {'type': 'str', 'strip_whitespace': True, 'to_lower': True}})
+    assert v.validate_test({' Foo ': 1, 'bar': 2}) == {'foo': 1, 'bar': 2}
+    # keys which are equal after normalization collapse, the last value wins as in a python dict
+    assert v.validate_test({'foo': 1, ' FOO': 2}) == {'foo': 2}
+
+
 def test_invalid_regex():
-    # TODO uncomment and fix once #150 is done
-    # with pytest.raises(SchemaError) as exc_info:
-    #     SchemaValidator({'type': 'str', 'pattern': 123})
-    # assert exc_info.value.args[0] == (
-    #     'Error building "str" validator:\n  TypeError: \'int\' object cannot be converted to \'PyString\''
-    # )
+    with pytest.raises(SchemaError) as exc_info:
+        SchemaValidator({'type': 'str', 'pattern': 123})
+    assert exc_info.value.args[0] == (
+        'Invalid Schema:\n'
+        'str -> pattern\n'
+        '  Input should be a valid string [kind=str_type, input_value=123, input_type=int]'
+    )
     with pytest.raises(SchemaError) as exc_info:
         SchemaValidator({'type': 'str', 'pattern': '(abc'})
     assert exc_info.value.args[0] == (
-        'Error building "str" validator:\n'
-        '  SchemaError: regex parse error:\n'
+        'Invalid Schema:\n'
+        'str\n'
+        '  regex parse error:\n'
         '    (abc\n'
         '    ^\n'
         'error: unclosed group'
     )
 
 
+@pytest.mark.parametrize(
+    'schema,config',
+    [
+        ({'type': 'str', 'to_lower': True, 'to_upper': True}, None),
+        ({'type': 'str', 'to_lower': True}, {'str_to_upper': True}),
+        ({'type': 'str'}, {'str_to_lower': True, 'str_to_upper': True}),
+    ],
+)
+def test_lower_and_upper(schema, config):
+    with pytest.raises(SchemaError, match="'to_lower' and 'to_upper' cannot both be set"):
+        SchemaValidator(schema, config)
+
+
+@pytest.mark.parametrize('pattern', [r'(?<=a)b', r'a(?=b)', r'(a)\1'])
+def test_regex_unsupported(pattern):
+    # look-around and backreferences aren't supported by the regex crate
+    with pytest.raises(SchemaError, match='regex parse error'):
+        SchemaValidator({'type': 'str', 'pattern': pattern})
+
+
+def test_regex_config():
+    v = SchemaValidator({'type': 'str'}, {'str_pattern': r'^\d+$'})
+    assert v.validate_python('123') == '123'
+    with pytest.raises(ValidationError, match='str_pattern_mismatch'):
+        v.validate_python('abc')
+
+
 def test_regex_error():
     v = SchemaValidator({'type': 'str', 'pattern': '11'})
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python('12')
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'str_pattern_mismatch',
             'loc': [],
@@ -177,3 +224,54 @@ def test_regex_error():
             'context': {'pattern': '11'},
         }
     ]
+
+
+@pytest.mark.parametrize(
+    'schema,config,input_value,expected',
+    [
+        ({'type': 'str'}, None, b'caf\xc3\xa9', 'café'),
+        (
+            {'type': 'str'},
+            None,
+            b'caf\xe9',
+            Err('str_unicode  Input should be a valid string, unable to parse raw data as a unicode string'),
+        ),
+        ({'type': 'str', 'bytes_encoding': 'latin-1'}, None, b'caf\xe9', 'café'),
+        ({'type': 'str', 'bytes_encoding': 'latin-1'}, None, bytearray(b'caf\xe9'), 'café'),
+        ({'type': 'str', 'bytes_encoding': 'latin-1'}, None, 'café', 'café'),
+        ({'type': 'str'}, {'str_bytes_encoding': 'utf-16'}, 'hi'.encode('utf-16'), 'hi'),
+        ({'type': 'str', 'bytes_encoding': 'utf-16'}, None, b'x', Err('str_unicode')),
+        ({'type': 'str', 'bytes_encoding': 'ascii'}, None, b'caf\xc3\xa9', Err('str_unicode')),
+        ({'type': 'str', 'bytes_encoding': 'cp1252', 'max_length': 3}, None, b'caf\xe9', Err('at most 3 characters')),
+        ({'type': 'str', 'bytes_encoding': 'cp1252', 'to_upper': True}, None, b'caf\xe9', 'CAFÉ'),
+    ],
+)
+def test_bytes_encoding(schema, config, input_value, expected):
+    v = SchemaValidator(schema, config)
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_bytes_encoding_strict():
+    v = SchemaValidator({'type': 'str', 'bytes_encoding': 'latin-1'})
+    with pytest.raises(ValidationError, match=r'str_type  Input should be a valid string'):
+        v.validate_python(b'caf\xe9', strict=True)
+    # json can't contain bytes, strings are unchanged
+    assert v.validate_json('"caf\\u00e9"') == 'café'
+
+
+def test_bytes_encoding_dict_keys():
+    v = SchemaValidator(
+        {'type': 'dict', 'keys_schema': {'type': 'str', 'bytes_encoding': 'latin-1'}, 'values_schema': 'int'}
+    )
+    output = v.validate_python({b'caf\xe9': 1, b'th\xe9': '2', 'x': 3})
+    assert output == {'café': 1, 'thé': 2, 'x': 3}
+    assert all(type(k) == str for k in output)
+
+
+def test_invalid_bytes_encoding():
+    with pytest.raises(SchemaError, match='Invalid bytes_encoding: "foobar"'):
+        SchemaValidator({'type': 'str', 'bytes_encoding': 'foobar'})
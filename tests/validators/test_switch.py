@@ -0,0 +1,117 @@
+import re
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
+
+from ..conftest import PyAndJson
+
+
+def is_str(v):
+    return isinstance(v, str)
+
+
+def is_list(v):
+    return isinstance(v, list)
+
+
+def test_switch(py_and_json: PyAndJson):
+    v = py_and_json(
+        {
+            'type': 'switch',
+            'choices': [
+                {'predicate': is_str, 'schema': {'type': 'str', 'to_upper': True}},
+                {'predicate': is_list, 'schema': {'type': 'list', 'items_schema': 'int'}},
+            ],
+        }
+    )
+    assert v.validate_test('hello') == 'HELLO'
+    assert v.validate_test([1, '2']) == [1, 2]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test(['x'])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'int_parsing',
+            'loc': [0],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'x',
+        }
+    ]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test(123)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'no_match_for_union',
+            'loc': [],
+            'message': 'Input does not match any of the expected cases',
+            'input_value': 123,
+        }
+    ]
+
+
+def test_first_match():
+    calls = []
+
+    def predicate(name, result):
+        def f(v):
+            calls.append(name)
+            return result
+
+        return f
+
+    v = SchemaValidator(
+        {
+            'type': 'switch',
+            'choices': [
+                {'predicate': predicate('a', 0), 'schema': 'str'},
+                {'predicate': predicate('b', [1]), 'schema': 'int'},
+                {'predicate': predicate('c', True), 'schema': 'float'},
+            ],
+        }
+    )
+    assert v.validate_python('1') == 1
+    # predicates after the first match aren't called, results are checked for truthiness
+    assert calls == ['a', 'b']
+    assert repr(v).startswith('SchemaValidator(name="switch[str,int,float]"')
+
+
+def test_default_schema():
+    v = SchemaValidator(
+        {'type': 'switch', 'choices': [{'predicate': is_str, 'schema': 'str'}], 'default_schema': 'int'}
+    )
+    assert v.validate_python('x') == 'x'
+    assert v.validate_python(1.0) == 1
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python([])
+
+
+def test_empty_choices():
+    with pytest.raises(SchemaError, match='One or more choices are required for a switch validator'):
+        SchemaValidator({'type': 'switch', 'choices': [], 'default_schema': 'int'})
+
+
+def test_predicate_raises():
+    def predicate(v):
+        raise ValueError(f'bad input: {v!r}')
+
+    v = SchemaValidator({'type': 'switch', 'choices': [{'predicate': predicate, 'schema': 'int'}]})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(1)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'value_error',
+            'loc': [],
+            'message': 'Value error, bad input: 1',
+            'input_value': 1,
+            'context': {'error': 'bad input: 1'},
+        }
+    ]
+
+
+def test_predicate_type_error():
+    def predicate(v):
+        raise TypeError('broken')
+
+    v = SchemaValidator({'type': 'switch', 'choices': [{'predicate': predicate, 'schema': 'int'}]})
+    with pytest.raises(TypeError, match=re.escape('broken')):
+        v.validate_python(1)
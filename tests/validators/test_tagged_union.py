@@ -108,7 +108,7 @@ def test_simple_tagged_union(py_and_json: PyAndJson, input_value, expected):
         with pytest.raises(ValidationError, match=expected.message) as exc_info:
             v.validate_test(input_value)
         # debug(exc_info.value.errors())
-        assert exc_info.value.errors() == expected.errors
+        assert exc_info.value.errors(include_url=False) == expected.errors
     else:
         assert v.validate_test(input_value) == expected
 
@@ -131,7 +131,7 @@ def test_discriminator_path(py_and_json: PyAndJson):
     assert v.validate_test({'menu': ['x', 'banana'], 'c': 'C', 'd': [1, '2']}) == {'c': 'C', 'd': [1, 2]}
     with pytest.raises(ValidationError) as exc_info:
         v.validate_test({})
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'union_tag_not_found',
             'loc': [],
@@ -224,7 +224,7 @@ def test_discriminator_function(py_and_json: PyAndJson, input_value, expected):
         with pytest.raises(ValidationError, match=expected.message) as exc_info:
             v.validate_python(input_value)
         # debug(exc_info.value.errors())
-        assert exc_info.value.errors() == expected.errors
+        assert exc_info.value.errors(include_url=False) == expected.errors
     else:
         assert v.validate_test(input_value) == expected
 
@@ -269,3 +269,21 @@ def test_downcast_error():
     v = SchemaValidator({'type': 'tagged-union', 'discriminator': lambda x: 123, 'choices': {'str': 'str'}})
     with pytest.raises(TypeError, match="'int' object cannot be converted to 'PyString'"):
         v.validate_python('x')
+
+
+def test_strict_override():
+    v = SchemaValidator(
+        {
+            'type': 'tagged-union',
+            'discriminator': 'foo',
+            'choices': {
+                'apple': {'type': 'typed-dict', 'fields': {'foo': {'schema': 'str'}, 'bar': {'schema': 'int'}}}
+            },
+        }
+    )
+    assert v.validate_python({'foo': b'apple', 'bar': '1'}) == {'foo': 'apple', 'bar': 1}
+    # the override applies to the discriminator as well as the choices
+    with pytest.raises(ValidationError, match='str_type'):
+        v.validate_python({'foo': b'apple', 'bar': 1}, strict=True)
+    with pytest.raises(ValidationError, match='int_type'):
+        v.validate_python({'foo': 'apple', 'bar': '1'}, strict=True)
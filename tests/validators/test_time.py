@@ -17,9 +17,9 @@ from ..conftest import Err, PyAndJson
         pytest.param(time(12, 13, 14, 123), time(12, 13, 14, 123), id='time-micro'),
         pytest.param('12:13:14', time(12, 13, 14), id='str'),
         pytest.param(b'12:13:14', time(12, 13, 14), id='bytes'),
-        pytest.param((1,), Err('Input should be a valid time [kind=time_type'), id='tuple'),
-        pytest.param(date(2022, 6, 8), Err('Input should be a valid time [kind=time_type'), id='date'),
-        pytest.param(datetime(2022, 6, 8), Err('Input should be a valid time [kind=time_type'), id='datetime'),
+        pytest.param((1,), Err('time_type  Input should be a valid time'), id='tuple'),
+        pytest.param(date(2022, 6, 8), Err('time_type  Input should be a valid time'), id='date'),
+        pytest.param(datetime(2022, 6, 8), Err('time_type  Input should be a valid time'), id='datetime'),
         pytest.param(123, time(0, 2, 3), id='int'),
         pytest.param(Decimal('123'), time(0, 2, 3), id='decimal'),
         pytest.param(Decimal('123.123456'), time(0, 2, 3, 123456), id='decimal-6dig'),
@@ -47,8 +47,8 @@ def test_time(input_value, expected):
         pytest.param(
             '12:13:14.1234561',
             Err(
-                'Input should be in a valid time format, '
-                'second fraction value is more than 6 digits long [kind=time_parsing'
+                'time_parsing  '
+                'Input should be in a valid time format, second fraction value is more than 6 digits long'
             ),
             id='str-micro-7dig',
         ),
@@ -58,9 +58,7 @@ def test_time(input_value, expected):
         pytest.param(0, time(0), id='int-zero'),
         pytest.param(
             86400,
-            Err(
-                'Input should be in a valid time format, numeric times may not exceed 86,399 seconds [kind=time_parsing'
-            ),
+            Err('time_parsing  Input should be in a valid time format, numeric times may not exceed 86,399 seconds'),
             id='too-high',
         ),
         pytest.param(
@@ -69,7 +67,7 @@ def test_time(input_value, expected):
         pytest.param(2**32, Err('numeric times may not exceed 86,399 seconds'), id='too-high-2**32'),
         pytest.param(2**64, Err('numeric times may not exceed 86,399 seconds'), id='too-high-2**64'),
         pytest.param(2**100, Err('numeric times may not exceed 86,399 seconds'), id='too-high-2**100'),
-        pytest.param(True, Err('Input should be a valid time [kind=time_type'), id='bool'),
+        pytest.param(True, Err('time_type  Input should be a valid time'), id='bool'),
     ],
 )
 def test_time_json(py_and_json: PyAndJson, input_value, expected):
@@ -86,12 +84,12 @@ def test_time_json(py_and_json: PyAndJson, input_value, expected):
     'input_value,expected',
     [
         (time(12, 13, 14, 15), time(12, 13, 14, 15)),
-        ('12:13:14', Err('Input should be a valid time [kind=time_type')),
-        (b'12:13:14', Err('Input should be a valid time [kind=time_type')),
-        (1654646400, Err('Input should be a valid time [kind=time_type')),
-        (True, Err('Input should be a valid time [kind=time_type')),
-        (date(2022, 6, 8), Err('Input should be a valid time [kind=time_type')),
-        (datetime(2022, 6, 8), Err('Input should be a valid time [kind=time_type')),
+        ('12:13:14', Err('time_type  Input should be a valid time')),
+        (b'12:13:14', Err('time_type  Input should be a valid time')),
+        (1654646400, Err('time_type  Input should be a valid time')),
+        (True, Err('time_type  Input should be a valid time')),
+        (date(2022, 6, 8), Err('time_type  Input should be a valid time')),
+        (datetime(2022, 6, 8), Err('time_type  Input should be a valid time')),
     ],
 )
 def test_time_strict(input_value, expected):
@@ -108,8 +106,8 @@ def test_time_strict(input_value, expected):
     'input_value,expected',
     [
         ('"12:13:14"', time(12, 13, 14)),
-        ('"foobar"', Err('Input should be in a valid time format, invalid character in hour [kind=time_parsing,')),
-        ('123', Err('Input should be a valid time [kind=time_type')),
+        ('"foobar"', Err('time_parsing  Input should be in a valid time format, invalid character in hour')),
+        ('123', Err('time_type  Input should be a valid time')),
     ],
 )
 def test_time_strict_json(input_value, expected):
@@ -145,7 +143,7 @@ def test_time_kwargs(kwargs: Dict[str, Any], input_value, expected):
     if isinstance(expected, Err):
         with pytest.raises(ValidationError, match=re.escape(expected.message)) as exc_info:
             v.validate_python(input_value)
-        errors = exc_info.value.errors()
+        errors = exc_info.value.errors(include_url=False)
         assert len(errors) == 1
         if len(kwargs) == 1:
             key = list(kwargs.keys())[0]
@@ -160,7 +158,7 @@ def test_time_bound_ctx():
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python('12:13')
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'greater_than',
             'loc': [],
@@ -23,7 +23,7 @@ from ..conftest import Err, PyAndJson
         ),
         ('P0Y0M3D2WT1H2M3.5S', timedelta(days=3, weeks=2, hours=1, minutes=2, seconds=3, milliseconds=500)),
         (b'P0Y0M3D2WT1H2M3.5S', timedelta(days=3, weeks=2, hours=1, minutes=2, seconds=3, milliseconds=500)),
-        ((-1,), Err('Input should be a valid timedelta [kind=time_delta_type')),
+        ((-1,), Err('time_delta_type  Input should be a valid timedelta')),
         (3601, timedelta(hours=1, seconds=1)),
         (Decimal('3601.123456'), timedelta(hours=1, seconds=1, microseconds=123456)),
         (Decimal('3601.1234562'), timedelta(hours=1, seconds=1, microseconds=123456)),
@@ -50,8 +50,8 @@ def test_timedelta(input_value, expected):
     'input_value,expected',
     [
         ('"P0Y0M3D2WT1H2M3.5S"', timedelta(days=3, weeks=2, hours=1, minutes=2, seconds=3, milliseconds=500)),
-        ('"errordata"', Err('Input should be a valid timedelta, invalid digit in duration [kind=time_delta_parsing')),
-        ('true', Err('Input should be a valid timedelta [kind=time_delta_type')),
+        ('"errordata"', Err('time_delta_parsing  Input should be a valid timedelta, invalid digit in duration')),
+        ('true', Err('time_delta_type  Input should be a valid timedelta')),
         ('3601', timedelta(hours=1, seconds=1)),
         ('3601.123456', timedelta(hours=1, seconds=1, microseconds=123456)),
         ('-3601', timedelta(hours=-2, seconds=3599)),
@@ -78,8 +78,8 @@ def test_timedelta_json(input_value, expected):
             timedelta(days=3, weeks=2, hours=1, minutes=2, seconds=3, milliseconds=500),
             timedelta(days=3, weeks=2, hours=1, minutes=2, seconds=3, milliseconds=500),
         ),
-        ('P0Y0M3D2WT1H2M3.5S', Err('Input should be a valid timedelta [kind=time_delta_type')),
-        (b'P0Y0M3D2WT1H2M3.5S', Err('Input should be a valid timedelta [kind=time_delta_type')),
+        ('P0Y0M3D2WT1H2M3.5S', Err('time_delta_type  Input should be a valid timedelta')),
+        (b'P0Y0M3D2WT1H2M3.5S', Err('time_delta_type  Input should be a valid timedelta')),
     ],
 )
 def test_timedelta_strict(input_value, expected):
@@ -97,7 +97,7 @@ def test_timedelta_strict(input_value, expected):
     [
         ('"P0Y0M3D2WT1H2M3.5S"', timedelta(days=3, weeks=2, hours=1, minutes=2, seconds=3, milliseconds=500)),
         ('"12345"', Err('Input should be a valid timedelta')),
-        ('true', Err('Input should be a valid timedelta [kind=time_delta_type')),
+        ('true', Err('time_delta_type  Input should be a valid timedelta')),
     ],
 )
 def test_timedelta_strict_json(input_value, expected):
@@ -129,14 +129,14 @@ def test_timedelta_strict_json(input_value, expected):
         (
             {'le': timedelta(seconds=-86400.123)},
             '-PT86400.122S',
-            Err('Input should be less than or equal to -P1DT0.123S [kind=less_than_equal'),
+            Err('less_than_equal  Input should be less than or equal to -P1DT0.123S'),
         ),
         ({'gt': timedelta(seconds=-86400.123)}, timedelta(seconds=-86400.122), timedelta(seconds=-86400.122)),
         ({'gt': timedelta(seconds=-86400.123)}, '-PT86400.122S', timedelta(seconds=-86400.122)),
         (
             {'gt': timedelta(seconds=-86400.123)},
             '-PT86400.124S',
-            Err('Input should be greater than -P1DT0.123S [kind=greater_than'),
+            Err('greater_than  Input should be greater than -P1DT0.123S'),
         ),
     ],
     ids=repr,
@@ -179,7 +179,7 @@ def test_dict_key(py_and_json: PyAndJson):
 
     with pytest.raises(
         ValidationError,
-        match=re.escape('Input should be a valid timedelta, invalid digit in duration [kind=time_delta_parsing'),
+        match=re.escape('time_delta_parsing  Input should be a valid timedelta, invalid digit in duration'),
     ):
         v.validate_test({'errordata': 2})
 
@@ -190,7 +190,7 @@ def test_dict_value(py_and_json: PyAndJson):
 
     with pytest.raises(
         ValidationError,
-        match=re.escape('Input should be a valid timedelta, invalid digit in duration [kind=time_delta_parsing'),
+        match=re.escape('time_delta_parsing  Input should be a valid timedelta, invalid digit in duration'),
     ):
         v.validate_test({4: 'errordata'})
 
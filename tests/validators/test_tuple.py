@@ -14,18 +14,13 @@ from ..conftest import Err, PyAndJson
     'mode,items,input_value,expected',
     [
         ('variable', {'type': 'int'}, [1, 2, 3], (1, 2, 3)),
-        (
-            'variable',
-            {'type': 'int'},
-            1,
-            Err('Input should be a valid tuple [kind=tuple_type, input_value=1, input_type=int]'),
-        ),
+        ('variable', {'type': 'int'}, 1, Err('tuple_type  Input should be a valid tuple, expected tuple[int, ...]  1')),
         ('positional', [{'type': 'int'}, {'type': 'int'}, {'type': 'int'}], [1, 2, '3'], (1, 2, 3)),
         (
             'positional',
             [{'type': 'int'}, {'type': 'int'}, {'type': 'int'}],
             5,
-            Err('Input should be a valid tuple [kind=tuple_type, input_value=5, input_type=int]'),
+            Err('tuple_type  Input should be a valid tuple, expected tuple[int, int, int]  5'),
         ),
     ],
     ids=repr,
@@ -62,19 +57,24 @@ def test_tuple_strict_passes_with_tuple(mode, items, input_value, expected):
 
 
 @pytest.mark.parametrize(
-    'mode,items', [('variable', {'type': 'int'}), ('positional', [{'type': 'int'}, {'type': 'int'}, {'type': 'int'}])]
+    'mode,items,expected',
+    [
+        ('variable', {'type': 'int'}, 'tuple[int, ...]'),
+        ('positional', [{'type': 'int'}, {'type': 'int'}, {'type': 'int'}], 'tuple[int, int, int]'),
+    ],
 )
 @pytest.mark.parametrize('wrong_coll_type', [list, set, frozenset])
-def test_tuple_strict_fails_without_tuple(wrong_coll_type: Type[Any], mode, items):
+def test_tuple_strict_fails_without_tuple(wrong_coll_type: Type[Any], mode, items, expected):
     v = SchemaValidator({'type': 'tuple', 'mode': mode, 'items_schema': items, 'strict': True})
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python(wrong_coll_type([1, 2, '33']))
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'tuple_type',
             'loc': [],
-            'message': 'Input should be a valid tuple',
+            'message': f'Input should be a valid tuple, expected {expected}',
             'input_value': wrong_coll_type([1, 2, '33']),
+            'context': {'expected': expected},
         }
     ]
 
@@ -84,9 +84,9 @@ def test_tuple_strict_fails_without_tuple(wrong_coll_type: Type[Any], mode, item
     [
         ({}, (1, 2, 3, 4), (1, 2, 3, 4)),
         ({'min_items': 3}, (1, 2, 3, 4), (1, 2, 3, 4)),
-        ({'min_items': 3}, (1, 2), Err('Input should have at least 3 items, got 2 items [kind=too_short,')),
+        ({'min_items': 3}, (1, 2), Err('too_short  Input should have at least 3 items, got 2 items')),
         ({'max_items': 4}, (1, 2, 3, 4), (1, 2, 3, 4)),
-        ({'max_items': 3}, (1, 2, 3, 4), Err('Input should have at most 3 items, got 4 items [kind=too_long,')),
+        ({'max_items': 3}, (1, 2, 3, 4), Err('too_long  Input should have at most 3 items, got 4 items')),
     ],
 )
 def test_tuple_var_len_kwargs(kwargs: Dict[str, Any], input_value, expected):
@@ -120,11 +120,11 @@ def test_tuple_var_len_kwargs(kwargs: Dict[str, Any], input_value, expected):
                 platform.python_implementation() == 'PyPy', reason='dict views not implemented in pyo3 for pypy'
             ),
         ),
-        ({1: 10, 2: 20, '3': '30'}, Err('Input should be a valid tuple [kind=tuple_type,')),
+        ({1: 10, 2: 20, '3': '30'}, Err('tuple_type  Input should be a valid tuple')),
         # https://github.com/samuelcolvin/pydantic-core/issues/211
-        ({1: 10, 2: 20, '3': '30'}.items(), Err('Input should be a valid tuple [kind=tuple_type,')),
-        ({1, 2, '3'}, Err('Input should be a valid tuple [kind=tuple_type,')),
-        (frozenset([1, 2, '3']), Err('Input should be a valid tuple [kind=tuple_type,')),
+        ({1: 10, 2: 20, '3': '30'}.items(), Err('tuple_type  Input should be a valid tuple')),
+        ({1, 2, '3'}, Err('tuple_type  Input should be a valid tuple')),
+        (frozenset([1, 2, '3']), Err('tuple_type  Input should be a valid tuple')),
     ],
 )
 def test_tuple_validate(input_value, expected, mode, items):
@@ -161,7 +161,7 @@ def test_tuple_var_len_errors(input_value, index):
     v = SchemaValidator({'type': 'tuple', 'mode': 'variable', 'items_schema': {'type': 'int'}})
     with pytest.raises(ValidationError) as exc_info:
         assert v.validate_python(input_value)
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'int_parsing',
             'loc': [index],
@@ -189,7 +189,7 @@ def test_tuple_fix_len_errors(input_value, items, index):
     v = SchemaValidator({'type': 'tuple', 'mode': 'positional', 'items_schema': items})
     with pytest.raises(ValidationError) as exc_info:
         assert v.validate_python(input_value)
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'int_parsing',
             'loc': [index],
@@ -204,14 +204,14 @@ def test_multiple_missing(py_and_json: PyAndJson):
     assert v.validate_test([1, 2, 3, 4]) == (1, 2, 3, 4)
     with pytest.raises(ValidationError) as exc_info:
         v.validate_test([1])
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {'kind': 'missing', 'loc': [1], 'message': 'Field required', 'input_value': [1]},
         {'kind': 'missing', 'loc': [2], 'message': 'Field required', 'input_value': [1]},
         {'kind': 'missing', 'loc': [3], 'message': 'Field required', 'input_value': [1]},
     ]
     with pytest.raises(ValidationError) as exc_info:
         v.validate_test([1, 2, 3])
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {'kind': 'missing', 'loc': [3], 'message': 'Field required', 'input_value': [1, 2, 3]}
     ]
 
@@ -221,7 +221,7 @@ def test_extra_arguments(py_and_json: PyAndJson):
     assert v.validate_test([1, 2]) == (1, 2)
     with pytest.raises(ValidationError) as exc_info:
         v.validate_test([1, 2, 3, 4])
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'too_long',
             'loc': [],
@@ -236,7 +236,7 @@ def test_positional_empty(py_and_json: PyAndJson):
     v = py_and_json({'type': 'tuple', 'mode': 'positional', 'items_schema': []})
     assert v.validate_test([]) == ()
     assert v.validate_python(()) == ()
-    with pytest.raises(ValidationError, match='kind=too_long,'):
+    with pytest.raises(ValidationError, match='too_long'):
         v.validate_test([1])
 
 
@@ -269,15 +269,17 @@ def test_union_tuple_list(input_value, expected):
                         # first of all, not a tuple of ints ..
                         'kind': 'tuple_type',
                         'loc': ['tuple[int, ...]'],
-                        'message': 'Input should be a valid tuple',
+                        'message': 'Input should be a valid tuple, expected tuple[int, ...]',
                         'input_value': [5],
+                        'context': {'expected': 'tuple[int, ...]'},
                     },
                     # .. and not a tuple of strings, either
                     {
                         'kind': 'tuple_type',
                         'loc': ['tuple[str, ...]'],
-                        'message': 'Input should be a valid tuple',
+                        'message': 'Input should be a valid tuple, expected tuple[str, ...]',
                         'input_value': [5],
+                        'context': {'expected': 'tuple[str, ...]'},
                     },
                 ],
             ),
@@ -299,7 +301,7 @@ def test_union_tuple_var_len(input_value, expected):
         with pytest.raises(ValidationError, match=re.escape(expected.message)) as exc_info:
             v.validate_python(input_value)
         if expected.errors is not None:
-            assert exc_info.value.errors() == expected.errors
+            assert exc_info.value.errors(include_url=False) == expected.errors
     else:
         assert v.validate_python(input_value) == expected
 
@@ -317,14 +319,16 @@ def test_union_tuple_var_len(input_value, expected):
                     {
                         'kind': 'tuple_type',
                         'loc': ['tuple[int, int, int]'],
-                        'message': 'Input should be a valid tuple',
+                        'message': 'Input should be a valid tuple, expected tuple[int, int, int]',
                         'input_value': [5, '1', 1],
+                        'context': {'expected': 'tuple[int, int, int]'},
                     },
                     {
                         'kind': 'tuple_type',
                         'loc': ['tuple[str, str, str]'],
-                        'message': 'Input should be a valid tuple',
+                        'message': 'Input should be a valid tuple, expected tuple[str, str, str]',
                         'input_value': [5, '1', 1],
+                        'context': {'expected': 'tuple[str, str, str]'},
                     },
                 ],
             ),
@@ -356,7 +360,7 @@ def test_union_tuple_fix_len(input_value, expected):
         with pytest.raises(ValidationError, match=re.escape(expected.message)) as exc_info:
             v.validate_python(input_value)
         if expected.errors is not None:
-            assert exc_info.value.errors() == expected.errors
+            assert exc_info.value.errors(include_url=False) == expected.errors
     else:
         assert v.validate_python(input_value) == expected
 
@@ -366,7 +370,9 @@ def test_tuple_fix_error():
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python([1])
 
-    assert exc_info.value.errors() == [{'kind': 'missing', 'loc': [1], 'message': 'Field required', 'input_value': [1]}]
+    assert exc_info.value.errors(include_url=False) == [
+        {'kind': 'missing', 'loc': [1], 'message': 'Field required', 'input_value': [1]}
+    ]
 
 
 def test_tuple_fix_extra():
@@ -377,7 +383,9 @@ def test_tuple_fix_extra():
     assert v.validate_python([1, 'a', 'b', 'c', 'd']) == (1, 'a', 'b', 'c', 'd')
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python([1])
-    assert exc_info.value.errors() == [{'kind': 'missing', 'loc': [1], 'message': 'Field required', 'input_value': [1]}]
+    assert exc_info.value.errors(include_url=False) == [
+        {'kind': 'missing', 'loc': [1], 'message': 'Field required', 'input_value': [1]}
+    ]
 
 
 def test_tuple_fix_extra_any():
@@ -388,7 +396,9 @@ def test_tuple_fix_extra_any():
     assert v.validate_python([b'1', 2, b'3']) == ('1', 2, b'3')
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python([])
-    assert exc_info.value.errors() == [{'kind': 'missing', 'loc': [0], 'message': 'Field required', 'input_value': []}]
+    assert exc_info.value.errors(include_url=False) == [
+        {'kind': 'missing', 'loc': [0], 'message': 'Field required', 'input_value': []}
+    ]
 
 
 def test_generator_error():
@@ -402,5 +412,5 @@ def test_generator_error():
     v = SchemaValidator({'type': 'tuple', 'items_schema': 'int'})
     assert v.validate_python(gen(False)) == (1, 2, 3)
 
-    with pytest.raises(ValidationError, match=r'Error iterating over object \[kind=iteration_error,'):
+    with pytest.raises(ValidationError, match=r'iteration_error  Error iterating over object'):
         v.validate_python(gen(True))
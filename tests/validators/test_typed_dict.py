@@ -7,7 +7,7 @@ from typing import Mapping
 import pytest
 from dirty_equals import HasRepr, IsStr
 
-from pydantic_core import Config, SchemaError, SchemaValidator, ValidationError
+from pydantic_core import Config, PydanticUndefined, SchemaError, SchemaValidator, ValidationError
 
 from ..conftest import Err, PyAndJson
 
@@ -62,7 +62,7 @@ def test_strict():
 
     with pytest.raises(ValidationError) as exc_info:
         assert v.validate_python({'field_a': 123, 'field_b': '123'})
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {'kind': 'str_type', 'loc': ['field_a'], 'message': 'Input should be a valid string', 'input_value': 123},
         {'kind': 'int_type', 'loc': ['field_b'], 'message': 'Input should be a valid integer', 'input_value': '123'},
     ]
@@ -97,8 +97,9 @@ def test_missing_error():
         str(exc_info.value)
         == """\
 1 validation error for typed-dict
-field_b
-  Field required [kind=missing, input_value={'field_a': b'abc'}, input_type=dict]"""
+Location  Type     Message         Input
+--------  -------  --------------  -------------------
+field_b   missing  Field required  {'field_a': b'abc'}"""
     )
 
 
@@ -107,15 +108,11 @@ field_b
     [
         ({}, {'a': '123'}, {'a': 123}),
         ({}, Map(a=123), {'a': 123}),
-        ({}, {b'a': '123'}, Err('Field required [kind=missing,')),
+        ({}, {b'a': '123'}, Err('missing  Field required')),
         ({}, {'a': '123', 'c': 4}, {'a': 123}),
         ({'typed_dict_extra_behavior': 'allow'}, {'a': '123', 'c': 4}, {'a': 123, 'c': 4}),
-        (
-            {'typed_dict_extra_behavior': 'allow'},
-            {'a': '123', b'c': 4},
-            Err('Keys should be strings [kind=invalid_key,'),
-        ),
-        ({'strict': True}, Map(a=123), Err('Input should be a valid dictionary [kind=dict_type,')),
+        ({'typed_dict_extra_behavior': 'allow'}, {'a': '123', b'c': 4}, Err('invalid_key  Keys should be strings')),
+        ({'strict': True}, Map(a=123), Err('dict_type  Input should be a valid dictionary')),
     ],
     ids=repr,
 )
@@ -160,7 +157,7 @@ def test_forbid_extra():
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python({'field_a': 'abc', 'field_b': 1})
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {'kind': 'extra_forbidden', 'loc': ['field_b'], 'message': 'Extra inputs are not permitted', 'input_value': 1}
     ]
 
@@ -199,7 +196,7 @@ def test_allow_extra_validate():
 
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python({'field_a': 'test', 'other_value': 12.5})
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'int_from_float',
             'loc': ['other_value'],
@@ -238,7 +235,15 @@ def test_validate_assignment():
 
     assert v.validate_python({'field_a': 'test'}) == ({'field_a': 'test'}, {'field_a'})
 
-    assert v.validate_assignment('field_a', b'abc', {'field_a': 'test'}) == ({'field_a': 'abc'}, {'field_a'})
+    assert v.validate_assignment({'field_a': 'test'}, 'field_a', b'abc') == ({'field_a': 'abc'}, {'field_a'})
+
+
+def test_validate_assignment_strict():
+    v = SchemaValidator({'type': 'typed-dict', 'fields': {'field_a': {'schema': 'int'}}})
+
+    assert v.validate_assignment({'field_a': 1}, 'field_a', '2') == {'field_a': 2}
+    with pytest.raises(ValidationError, match='int_type'):
+        v.validate_assignment({'field_a': 1}, 'field_a', '2', strict=True)
 
 
 def test_validate_assignment_functions():
@@ -275,7 +280,7 @@ def test_validate_assignment_functions():
     assert calls == ['func_a', 'func_b']
     calls.clear()
 
-    assert v.validate_assignment('field_a', 'new-val', {'field_a': 'testtest', 'field_b': 6}) == (
+    assert v.validate_assignment({'field_a': 'testtest', 'field_b': 6}, 'field_a', 'new-val') == (
         {'field_a': 'new-valnew-val', 'field_b': 6},
         {'field_a'},
     )
@@ -289,10 +294,19 @@ def test_validate_assignment_ignore_extra():
 
     assert v.validate_python({'field_a': 'test'}) == ({'field_a': 'test'}, {'field_a'})
 
+    with pytest.raises(AttributeError, match="No field named 'other_field'"):
+        v.validate_assignment({'field_a': 'test'}, 'other_field', 456)
+
+
+def test_validate_assignment_forbid_extra():
+    v = SchemaValidator(
+        {'type': 'typed-dict', 'fields': {'field_a': {'schema': {'type': 'str'}}}, 'extra_behavior': 'forbid'}
+    )
+
     with pytest.raises(ValidationError) as exc_info:
-        v.validate_assignment('other_field', 456, {'field_a': 'test'})
+        v.validate_assignment({'field_a': 'test'}, 'other_field', 456)
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'extra_forbidden',
             'loc': ['other_field'],
@@ -302,6 +316,23 @@ def test_validate_assignment_ignore_extra():
     ]
 
 
+def test_validate_assignment_nested():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'field_a': {'schema': 'str'},
+                'sub': {'schema': {'type': 'typed-dict', 'fields': {'field_a': {'schema': 'int'}}}},
+            },
+        }
+    )
+    # the nested typed-dict validates its input normally
+    assert v.validate_assignment({'field_a': 'test', 'sub': {'field_a': 1}}, 'sub', {'field_a': '2'}) == {
+        'field_a': 'test',
+        'sub': {'field_a': 2},
+    }
+
+
 def test_validate_assignment_allow_extra():
     v = SchemaValidator(
         {'type': 'typed-dict', 'fields': {'field_a': {'schema': {'type': 'str'}}}, 'extra_behavior': 'allow'}
@@ -309,7 +340,7 @@ def test_validate_assignment_allow_extra():
 
     assert v.validate_python({'field_a': 'test'}) == {'field_a': 'test'}
 
-    assert v.validate_assignment('other_field', 456, {'field_a': 'test'}) == {'field_a': 'test', 'other_field': 456}
+    assert v.validate_assignment({'field_a': 'test'}, 'other_field', 456) == {'field_a': 'test', 'other_field': 456}
 
 
 def test_validate_assignment_allow_extra_validate():
@@ -322,11 +353,11 @@ def test_validate_assignment_allow_extra_validate():
         }
     )
 
-    assert v.validate_assignment('other_field', '456', {'field_a': 'test'}) == {'field_a': 'test', 'other_field': 456}
+    assert v.validate_assignment({'field_a': 'test'}, 'other_field', '456') == {'field_a': 'test', 'other_field': 456}
 
     with pytest.raises(ValidationError) as exc_info:
-        assert v.validate_assignment('other_field', 'xyz', {'field_a': 'test'})
-    assert exc_info.value.errors() == [
+        assert v.validate_assignment({'field_a': 'test'}, 'other_field', 'xyz')
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'int_parsing',
             'loc': ['other_field'],
@@ -343,7 +374,7 @@ def test_json_error():
     with pytest.raises(ValidationError) as exc_info:
         v.validate_json('{"field_a": [123, "wrong"]}')
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'int_parsing',
             'loc': ['field_a', 1],
@@ -369,7 +400,7 @@ def test_fields_required_by_default():
     with pytest.raises(ValidationError) as exc_info:
         assert v.validate_python({'x': 'pika'})
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {'kind': 'missing', 'loc': ['y'], 'message': 'Field required', 'input_value': {'x': 'pika'}}
     ]
 
@@ -418,7 +449,7 @@ def test_all_optional_fields():
     with pytest.raises(ValidationError) as exc_info:
         assert v.validate_python({'x': 123})
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {'kind': 'str_type', 'loc': ['x'], 'message': 'Input should be a valid string', 'input_value': 123}
     ]
 
@@ -442,11 +473,54 @@ def test_all_optional_fields_with_required_fields():
     with pytest.raises(ValidationError) as exc_info:
         assert v.validate_python({'y': 'chu'}) == ({'y': 'chu'}, {'y'})
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {'kind': 'missing', 'loc': ['x'], 'message': 'Field required', 'input_value': {'y': 'chu'}}
     ]
 
 
+@pytest.mark.parametrize(
+    'extra_behavior,input_value,expected',
+    [
+        ('ignore', {}, ({}, set())),
+        ('ignore', {'x': 'pika', 'z': 1}, ({'x': 'pika'}, {'x'})),
+        ('ignore', {'y': 'chu'}, ({'y': 'chu'}, {'y'})),
+        ('allow', {'z': 1}, ({'z': 1}, {'z'})),
+        ('forbid', {'x': 'pika'}, ({'x': 'pika'}, {'x'})),
+        ('forbid', {'z': 1}, Err('z         extra_forbidden  Extra inputs are not permitted  1')),
+    ],
+)
+def test_partial_defaults_extra(py_and_json: PyAndJson, extra_behavior, input_value, expected):
+    """With `total=False` the output only contains the fields which were provided, defaults aren't filled in"""
+    v = py_and_json(
+        {
+            'type': 'typed-dict',
+            'total': False,
+            'return_fields_set': True,
+            'extra_behavior': extra_behavior,
+            'fields': {'x': {'schema': 'str'}, 'y': {'schema': 'str', 'default': 'bulbi'}},
+        }
+    )
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_partial_fallback_on_default():
+    """With `total=False` a default is still used by `on_error='fallback_on_default'` for a provided field"""
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'total': False,
+            'fields': {'x': {'schema': 'int', 'default': 1, 'on_error': 'fallback_on_default'}},
+        }
+    )
+    assert v.validate_python({}) == {}
+    assert v.validate_python({'x': 'pika'}) == {'x': 1}
+    assert v.validate_python({'x': 2}) == {'x': 2}
+
+
 def test_field_required_and_default():
     """A field cannot be required and have a default value"""
     with pytest.raises(SchemaError, match="Field 'x': a required field cannot have a default value"):
@@ -455,12 +529,27 @@ def test_field_required_and_default():
         )
 
 
+def test_default_undefined():
+    """A default of PydanticUndefined is the same as no default"""
+    v = SchemaValidator(
+        {'type': 'typed-dict', 'fields': {'x': {'schema': 'str', 'required': True, 'default': PydanticUndefined}}}
+    )
+    assert v.validate_python({'x': 'pika'}) == {'x': 'pika'}
+    with pytest.raises(ValidationError, match=r'x +missing  Field required'):
+        v.validate_python({})
+
+    v = SchemaValidator(
+        {'type': 'typed-dict', 'fields': {'x': {'schema': 'str', 'required': False, 'default': PydanticUndefined}}}
+    )
+    assert v.validate_python({}) == {}
+
+
 def test_alias(py_and_json: PyAndJson):
     v = py_and_json({'type': 'typed-dict', 'fields': {'field_a': {'alias': 'FieldA', 'schema': 'int'}}})
     assert v.validate_test({'FieldA': '123'}) == {'field_a': 123}
-    with pytest.raises(ValidationError, match=r'field_a\n +Field required \[kind=missing,'):
+    with pytest.raises(ValidationError, match=r'field_a +missing  Field required'):
         assert v.validate_test({'foobar': '123'})
-    with pytest.raises(ValidationError, match=r'field_a\n +Field required \[kind=missing,'):
+    with pytest.raises(ValidationError, match=r'field_a +missing  Field required'):
         assert v.validate_test({'field_a': '123'})
 
 
@@ -489,7 +578,7 @@ def test_alias_allow_pop(py_and_json: PyAndJson):
     assert v.validate_test({'FieldA': '123'}) == ({'field_a': 123}, {'field_a'})
     assert v.validate_test({'field_a': '123'}) == ({'field_a': 123}, {'field_a'})
     assert v.validate_test({'FieldA': '1', 'field_a': '2'}) == ({'field_a': 1}, {'field_a'})
-    with pytest.raises(ValidationError, match=r'field_a\n +Field required \[kind=missing,'):
+    with pytest.raises(ValidationError, match=r'field_a +missing  Field required'):
         assert v.validate_test({'foobar': '123'})
 
 
@@ -497,10 +586,10 @@ def test_alias_allow_pop(py_and_json: PyAndJson):
     'input_value,expected',
     [
         ({'foo': {'bar': '123'}}, {'field_a': 123}),
-        ({'x': '123'}, Err(r'field_a\n +Field required \[kind=missing,')),
-        ({'foo': '123'}, Err(r'field_a\n +Field required \[kind=missing,')),
-        ({'foo': [1, 2, 3]}, Err(r'field_a\n +Field required \[kind=missing,')),
-        ({'foo': {'bat': '123'}}, Err(r'field_a\n +Field required \[kind=missing,')),
+        ({'x': '123'}, Err(r'field_a +missing  Field required')),
+        ({'foo': '123'}, Err(r'field_a +missing  Field required')),
+        ({'foo': [1, 2, 3]}, Err(r'field_a +missing  Field required')),
+        ({'foo': {'bat': '123'}}, Err(r'field_a +missing  Field required')),
     ],
     ids=repr,
 )
@@ -522,11 +611,11 @@ def test_alias_path(py_and_json: PyAndJson, input_value, expected):
         ({'foo': (1, 2, 3, 4)}, ({'field_a': 4}, {'field_a'})),
         ({'spam': 5}, ({'field_a': 5}, {'field_a'})),
         ({'spam': 1, 'foo': {'bar': {'bat': 2}}}, ({'field_a': 2}, {'field_a'})),
-        ({'foo': {'x': 2}}, Err(r'field_a\n +Field required \[kind=missing,')),
-        ({'x': '123'}, Err(r'field_a\n +Field required \[kind=missing,')),
-        ({'x': {2: 33}}, Err(r'field_a\n +Field required \[kind=missing,')),
-        ({'foo': '01234'}, Err(r'field_a\n +Field required \[kind=missing,')),
-        ({'foo': [1]}, Err(r'field_a\n +Field required \[kind=missing,')),
+        ({'foo': {'x': 2}}, Err(r'field_a +missing  Field required')),
+        ({'x': '123'}, Err(r'field_a +missing  Field required')),
+        ({'x': {2: 33}}, Err(r'field_a +missing  Field required')),
+        ({'foo': '01234'}, Err(r'field_a +missing  Field required')),
+        ({'foo': [1]}, Err(r'field_a +missing  Field required')),
     ],
     ids=repr,
 )
@@ -603,10 +692,42 @@ def test_alias_build_error(alias_schema, error):
         SchemaValidator({'type': 'typed-dict', 'fields': {'field_a': {'schema': 'int', **alias_schema}}})
 
 
+def test_known_fields():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'field_name': {'alias': 'fieldName', 'schema': 'int'},
+                'plain': {'schema': 'str'},
+                'by_path': {'alias': [['foo', 'bar'], ['spam', 0]], 'schema': 'int'},
+                'same': {'alias': 'same', 'schema': 'int'},
+            },
+            'populate_by_name': True,
+        }
+    )
+    assert v.known_fields() == ['field_name', 'fieldName', 'plain', 'by_path', 'foo', 'spam', 'same']
+    assert SchemaValidator({'type': 'typed-dict', 'fields': {}}).known_fields() == []
+    assert SchemaValidator('int').known_fields() is None
+
+
+def test_known_fields_new_class():
+    class MyModel:
+        pass
+
+    v = SchemaValidator(
+        {
+            'type': 'new-class',
+            'class_type': MyModel,
+            'schema': {'type': 'typed-dict', 'fields': {'field_name': {'alias': 'fieldName', 'schema': 'int'}}},
+        }
+    )
+    assert v.known_fields() == ['field_name', 'fieldName']
+
+
 def test_empty_model():
     v = SchemaValidator({'type': 'typed-dict', 'fields': {}, 'return_fields_set': True})
     assert v.validate_python({}) == ({}, set())
-    with pytest.raises(ValidationError, match=re.escape('Input should be a valid dictionary [kind=dict_type,')):
+    with pytest.raises(ValidationError, match=re.escape('dict_type  Input should be a valid dictionary')):
         v.validate_python('x')
 
 
@@ -650,7 +771,7 @@ def test_model_deep():
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python({'field_a': '1', 'field_b': {'field_c': '2', 'field_d': {'field_e': '4', 'field_f': 'xx'}}})
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'int_parsing',
             'loc': ['field_b', 'field_d', 'field_f'],
@@ -686,13 +807,10 @@ class MyDataclass:
         (dict(a=1, b=2, c='ham'), ({'a': 1, 'b': 2, 'c': 'ham'}, {'a', 'b', 'c'})),
         (Map(a=1, b=2, c='ham'), ({'a': 1, 'b': 2, 'c': 'ham'}, {'a', 'b', 'c'})),
         # using type gives `__module__ == 'builtins'`
-        (type('Testing', (), {}), Err('[kind=dict_attributes_type,')),
-        (
-            '123',
-            Err('Input should be a valid dictionary or instance to extract fields from [kind=dict_attributes_type,'),
-        ),
-        ([(1, 2)], Err('kind=dict_attributes_type,')),
-        (((1, 2),), Err('kind=dict_attributes_type,')),
+        (type('Testing', (), {}), Err('dict_attributes_type')),
+        ('123', Err('dict_attributes_type  Input should be a valid dictionary or instance to extract fields from')),
+        ([(1, 2)], Err('dict_attributes_type')),
+        (((1, 2),), Err('dict_attributes_type')),
     ],
     ids=repr,
 )
@@ -725,7 +843,7 @@ def test_from_attributes_type_error():
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python('123')
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'dict_attributes_type',
             'loc': [],
@@ -766,7 +884,7 @@ def test_from_attributes_missing():
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python(Foobar())
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'missing',
             'loc': ['c'],
@@ -792,7 +910,7 @@ def test_from_attributes_error():
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python(Foobar())
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'get_attribute_error',
             'loc': ['b'],
@@ -899,7 +1017,7 @@ def test_from_attributes_error_error():
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python(Foobar())
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'get_attribute_error',
             'loc': ['x'],
@@ -917,7 +1035,7 @@ def test_from_attributes_error_error():
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python(UnInitError())
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'get_attribute_error',
             'loc': ['x'],
@@ -938,10 +1056,10 @@ def test_from_attributes_error_error():
         (Cls(foo=(1, 2, 3, 4)), {'my_field': 4}),
         (Cls(spam=5), {'my_field': 5}),
         (Cls(spam=1, foo=Cls(bar=Cls(bat=2))), {'my_field': 2}),
-        (Cls(x='123'), Err(r'my_field\n +Field required \[kind=missing,')),
-        (Cls(x={2: 33}), Err(r'my_field\n +Field required \[kind=missing,')),
-        (Cls(foo='01234'), Err(r'my_field\n +Field required \[kind=missing,')),
-        (Cls(foo=[1]), Err(r'my_field\n +Field required \[kind=missing,')),
+        (Cls(x='123'), Err(r'my_field +missing  Field required')),
+        (Cls(x={2: 33}), Err(r'my_field +missing  Field required')),
+        (Cls(foo='01234'), Err(r'my_field +missing  Field required')),
+        (Cls(foo=[1]), Err(r'my_field +missing  Field required')),
         (Cls, Err(r'Input should be a valid dictionary')),
     ],
     ids=repr,
@@ -979,7 +1097,7 @@ def test_from_attributes_path_error():
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python(PropertyError())
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'get_attribute_error',
             'loc': ['my_field'],
@@ -1005,7 +1123,7 @@ def test_alias_extra(py_and_json: PyAndJson):
     with pytest.raises(ValidationError) as exc_info:
         assert v.validate_test({'FieldA': '...'}) == {'field_a': 1}
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'int_parsing',
             'loc': ['field_a'],
@@ -1101,6 +1219,50 @@ def test_bad_default_factory(default_factory, error_message):
         v.validate_python({})
 
 
+def test_default_factory_value_error():
+    def factory():
+        raise ValueError('no default available')
+
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'x': {'schema': 'str', 'default_factory': factory},
+                'y': {'schema': 'int'},
+                'z': {'schema': 'int', 'on_error': 'fallback_on_default', 'default_factory': factory},
+            },
+        }
+    )
+    assert v.validate_python({'x': 'a', 'y': 1, 'z': 2}) == {'x': 'a', 'y': 1, 'z': 2}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'z': 'wrong'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'value_error',
+            'loc': ['x'],
+            'message': 'Value error, no default available',
+            'input_value': {'z': 'wrong'},
+            'context': {'error': 'no default available'},
+        },
+        {'kind': 'missing', 'loc': ['y'], 'message': 'Field required', 'input_value': {'z': 'wrong'}},
+        {
+            'kind': 'value_error',
+            'loc': ['z'],
+            'message': 'Value error, no default available',
+            'input_value': {'z': 'wrong'},
+            'context': {'error': 'no default available'},
+        },
+    ]
+
+
+def test_default_factory_other_error():
+    v = SchemaValidator(
+        {'type': 'typed-dict', 'fields': {'x': {'schema': 'str', 'default_factory': lambda: {}['missing']}}}
+    )
+    with pytest.raises(KeyError, match='missing'):
+        v.validate_python({})
+
+
 class TestOnError:
     def test_on_error_bad_name(self):
         with pytest.raises(SchemaError, match="Input should be one of: 'raise', 'omit', 'fallback_on_default'"):
@@ -1123,7 +1285,7 @@ class TestOnError:
         assert v.validate_test({'x': 'foo'}) == {'x': 'foo'}
         with pytest.raises(ValidationError) as exc_info:
             v.validate_test({'x': ['foo']})
-        assert exc_info.value.errors() == [
+        assert exc_info.value.errors(include_url=False) == [
             {'input_value': ['foo'], 'kind': 'str_type', 'loc': ['x'], 'message': 'Input should be a valid string'}
         ]
 
@@ -1132,7 +1294,7 @@ class TestOnError:
         assert v.validate_test({'x': 'foo'}) == {'x': 'foo'}
         with pytest.raises(ValidationError) as exc_info:
             v.validate_test({'x': ['foo']})
-        assert exc_info.value.errors() == [
+        assert exc_info.value.errors(include_url=False) == [
             {'input_value': ['foo'], 'kind': 'str_type', 'loc': ['x'], 'message': 'Input should be a valid string'}
         ]
 
@@ -1211,3 +1373,82 @@ class TestOnError:
         assert v.validate_test({'x': ['foo']}) == {'x': '1'}
         assert v.validate_test({'x': ['foo', 'bar']}) == {'x': '2'}
         assert v.validate_test({'x': {'a': 'b'}}) == {'x': "{'a': 'b'}"}
+
+
+def test_validate_default_invalid():
+    schema = {
+        'type': 'typed-dict',
+        'fields': {'numbers': {'schema': {'type': 'list', 'items_schema': 'int'}, 'default': ['not_an_int']}},
+    }
+    # defaults aren't validated unless `validate_default` is set
+    assert SchemaValidator(schema).validate_python({}) == {'numbers': ['not_an_int']}
+    with pytest.raises(SchemaError) as exc_info:
+        SchemaValidator(schema, {'validate_default': True})
+    assert str(exc_info.value) == (
+        'Invalid Schema:\n'
+        'fields -> numbers -> default\n'
+        '  Invalid default value:\n'
+        '0\n'
+        "  Input should be a valid integer [kind=int_type, input_value='not_an_int', input_type=str]"
+    )
+
+
+def test_validate_default_valid():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'numbers': {'schema': {'type': 'list', 'items_schema': 'int'}, 'default': [1, 2]},
+                'name': {'schema': 'str', 'default_factory': lambda: 123},
+            },
+        },
+        {'validate_default': True},
+    )
+    assert v.validate_python({'name': 'x'}) == {'numbers': [1, 2], 'name': 'x'}
+
+
+def test_validate_default_strict():
+    schema = {'type': 'typed-dict', 'fields': {'a': {'schema': 'int', 'default': '1', 'validate_default': True}}}
+    with pytest.raises(
+        SchemaError, match='fields -> a -> default\n  Invalid default value:\n  Input should be a valid'
+    ):
+        SchemaValidator(schema)
+
+
+def test_validate_default_field_overrides_config():
+    schema = {'type': 'typed-dict', 'fields': {'a': {'schema': 'int', 'default': 'x', 'validate_default': False}}}
+    v = SchemaValidator(schema, {'validate_default': True})
+    assert v.validate_python({}) == {'a': 'x'}
+
+
+def test_validate_default_recursive():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'ref': 'Branch',
+            'fields': {
+                'name': {'schema': 'str'},
+                'sub_branch': {
+                    'schema': {'type': 'nullable', 'schema': {'type': 'recursive-ref', 'schema_ref': 'Branch'}},
+                    'default': {'name': 'leaf', 'sub_branch': None},
+                },
+            },
+        },
+        {'validate_default': True},
+    )
+    assert v.validate_python({'name': 'root'}) == {'name': 'root', 'sub_branch': {'name': 'leaf', 'sub_branch': None}}
+    with pytest.raises(SchemaError, match='fields -> sub_branch -> default\n  Invalid default value:\nname\n'):
+        SchemaValidator(
+            {
+                'type': 'typed-dict',
+                'ref': 'Branch',
+                'fields': {
+                    'name': {'schema': 'str'},
+                    'sub_branch': {
+                        'schema': {'type': 'nullable', 'schema': {'type': 'recursive-ref', 'schema_ref': 'Branch'}},
+                        'default': {'name': 1, 'sub_branch': None},
+                    },
+                },
+            },
+            {'validate_default': True},
+        )
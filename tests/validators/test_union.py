@@ -100,7 +100,7 @@ class TestModelClass:
     def test_error(self, schema_validator: SchemaValidator):
         with pytest.raises(ValidationError) as exc_info:
             schema_validator.validate_python({'a': 2})
-        assert exc_info.value.errors() == [
+        assert exc_info.value.errors(include_url=False) == [
             {'kind': 'missing', 'loc': ['ModelA', 'b'], 'message': 'Field required', 'input_value': {'a': 2}},
             {'kind': 'missing', 'loc': ['ModelB', 'c'], 'message': 'Field required', 'input_value': {'a': 2}},
             {'kind': 'missing', 'loc': ['ModelB', 'd'], 'message': 'Field required', 'input_value': {'a': 2}},
@@ -179,7 +179,7 @@ def test_nullable_via_union():
     assert v.validate_python(1) == 1
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python('hello')
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {'kind': 'none_required', 'loc': ['none'], 'message': 'Input should be None/null', 'input_value': 'hello'},
         {
             'kind': 'int_parsing',
@@ -205,7 +205,7 @@ def test_union_list_bool_int():
     assert v.validate_python(['1', '0']) == [1, 0]
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python([3, 'true'])
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {
             'kind': 'bool_parsing',
             'loc': ['list[bool]', 0],
@@ -240,7 +240,89 @@ def test_strict_union():
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python('123')
 
-    assert exc_info.value.errors() == [
+    assert exc_info.value.errors(include_url=False) == [
         {'kind': 'bool_type', 'loc': ['bool'], 'message': 'Input should be a valid boolean', 'input_value': '123'},
         {'kind': 'int_type', 'loc': ['int'], 'message': 'Input should be a valid integer', 'input_value': '123'},
     ]
+
+
+def many_models_schema(**extra):
+    fields = {'a': {'schema': 'int'}, 'b': {'schema': 'str'}}
+    return {'type': 'union', 'choices': [{'type': 'typed-dict', 'fields': fields} for _ in range(10)], **extra}
+
+
+def test_error_mode_all():
+    v = SchemaValidator(many_models_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x'})
+    assert exc_info.value.error_count() == 20
+
+
+def test_error_mode_deduplicated():
+    v = SchemaValidator(many_models_schema(error_mode='deduplicated'))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'int_parsing',
+            'loc': ['typed-dict', 'a'],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'x',
+        },
+        {'kind': 'missing', 'loc': ['typed-dict', 'b'], 'message': 'Field required', 'input_value': {'a': 'x'}},
+    ]
+
+
+def test_error_mode_deduplicated_different_kinds():
+    v = SchemaValidator({'type': 'union', 'choices': ['int', 'str', 'bool'], 'error_mode': 'deduplicated'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1])
+    # same (empty) location within each choice but different kinds, so nothing is dropped
+    assert [e['kind'] for e in exc_info.value.errors()] == ['int_type', 'str_type', 'bool_type']
+
+
+def test_error_mode_config():
+    v = SchemaValidator(many_models_schema(), {'union_error_mode': 'deduplicated'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x'})
+    assert exc_info.value.error_count() == 2
+
+
+def test_error_mode_best():
+    v = SchemaValidator(
+        {
+            'type': 'union',
+            'choices': [
+                'int',
+                {'type': 'typed-dict', 'fields': {'a': {'schema': 'str'}}},
+                {'type': 'typed-dict', 'fields': {'a': {'schema': {'type': 'list', 'items_schema': 'int'}}}},
+                {'type': 'typed-dict', 'fields': {'a': {'schema': {'type': 'list', 'items_schema': 'bool'}}}},
+            ],
+            'error_mode': 'best',
+        }
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': ['x']})
+    # the two list choices got equally far, the first wins
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'kind': 'int_parsing',
+            'loc': ['typed-dict', 'a', 0],
+            'message': 'Input should be a valid integer, unable to parse string as an integer',
+            'input_value': 'x',
+        }
+    ]
+
+
+def test_error_mode_best_strict():
+    v = SchemaValidator({'type': 'union', 'choices': ['bool', 'int'], 'strict': True, 'error_mode': 'best'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('123')
+    assert exc_info.value.errors(include_url=False) == [
+        {'kind': 'bool_type', 'loc': ['bool'], 'message': 'Input should be a valid boolean', 'input_value': '123'}
+    ]
+
+
+def test_error_mode_invalid():
+    with pytest.raises(SchemaError, match="Input should be one of: 'all', 'best', 'deduplicated'"):
+        SchemaValidator({'type': 'union', 'choices': ['int'], 'error_mode': 'other'})